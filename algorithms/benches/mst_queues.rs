@@ -0,0 +1,53 @@
+//! Wall-clock comparison of MST-PRIM with each priority queue.
+//!
+//! Run with `cargo bench -p algorithms`. The report shows the matching
+//! key-comparison counts, which unlike these timings are reproducible.
+
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+use algorithms::analysis::random_connected_graph;
+use algorithms::graph::adjacency_list::AdjacencyList;
+use algorithms::graph::mst::mst_prim;
+use algorithms::graph::representation::Weight;
+use algorithms::heap::fibonacci_heap::FibonacciHeap;
+use algorithms::heap::min_queue::{ArrayQueue, MinQueue};
+use algorithms::heap::priority_queue::MinPriorityQueue;
+use algorithms::random::XorShift64;
+
+/// Runs per measurement; the fastest one is reported
+const RUNS: usize = 5;
+
+fn main() {
+    let mut rng = XorShift64::new(42);
+    println!(
+        "{:>6} {:>8} {:>14} {:>14} {:>14}",
+        "|V|", "|E|", "binary heap", "Fibonacci", "array"
+    );
+    for n in [250, 500, 1000, 2000] {
+        for m in [4 * n, n * (n - 1) / 2] {
+            let g = random_connected_graph(n, m, 1000, &mut rng);
+            println!(
+                "{:>6} {:>8} {:>14?} {:>14?} {:>14?}",
+                n,
+                m,
+                fastest(&g, MinPriorityQueue::new),
+                fastest(&g, FibonacciHeap::new),
+                fastest(&g, ArrayQueue::new),
+            );
+        }
+    }
+}
+
+/// Fastest of `RUNS` runs of MST-PRIM on `g` with a fresh queue each
+fn fastest<Q: MinQueue<Weight, usize>>(g: &AdjacencyList, new_queue: fn() -> Q) -> Duration {
+    (0..RUNS)
+        .map(|_| {
+            let mut queue = new_queue();
+            let start = Instant::now();
+            black_box(mst_prim(g, 0, &mut queue));
+            start.elapsed()
+        })
+        .min()
+        .unwrap_or_default()
+}
@@ -0,0 +1,158 @@
+//! # Operation Counting
+//!
+//! Counts the elementary operations an algorithm performs by listening to
+//! its [`trace`](crate::trace). Together with the input generators below
+//! this lets the report show empirical best, average and worst case costs.
+
+use crate::graph::adjacency_list::AdjacencyList;
+use crate::graph::representation::Graph;
+use crate::random::{RandomSource, shuffle};
+use crate::trace::{TraceEvent, Tracer};
+
+/// Number of key comparisons and array writes performed by one run.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct Counts {
+    pub comparisons: u64,
+    pub writes: u64,
+}
+
+/// Tracer that only counts operations.
+///
+/// Comparisons are `Compare` and `CompareKey` events; writes are shifts,
+/// key insertions, plain writes and both halves of a swap.
+#[derive(Debug, Default, Clone)]
+pub struct OpCounter {
+    counts: Counts,
+}
+
+impl OpCounter {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn counts(&self) -> Counts {
+        self.counts
+    }
+}
+
+impl<T> Tracer<T> for OpCounter {
+    fn record(&mut self, event: TraceEvent<T>, _array: &[T]) {
+        match event {
+            TraceEvent::Compare { .. } | TraceEvent::CompareKey { .. } => {
+                self.counts.comparisons += 1
+            }
+            TraceEvent::Shift { .. } | TraceEvent::Insert { .. } | TraceEvent::Write { .. } => {
+                self.counts.writes += 1
+            }
+            TraceEvent::Swap { .. } => self.counts.writes += 2,
+            TraceEvent::SelectKey { .. } => {}
+        }
+    }
+}
+
+/// Which kind of input an experiment runs on.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum InputCase {
+    Best,
+    Average,
+    Worst,
+}
+
+impl InputCase {
+    pub const ALL: [InputCase; 3] = [InputCase::Best, InputCase::Average, InputCase::Worst];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            InputCase::Best => "best",
+            InputCase::Average => "average",
+            InputCase::Worst => "worst",
+        }
+    }
+}
+
+/// `⟨1, 2, ..., n⟩`
+pub fn sorted_input(n: usize) -> Vec<i64> {
+    (1..=n as i64).collect()
+}
+
+/// `⟨n, n - 1, ..., 1⟩`
+pub fn reversed_input(n: usize) -> Vec<i64> {
+    (1..=n as i64).rev().collect()
+}
+
+/// A uniformly random permutation of `⟨1, 2, ..., n⟩`
+pub fn random_input<R: RandomSource>(n: usize, rng: &mut R) -> Vec<i64> {
+    let mut data = sorted_input(n);
+    shuffle(&mut data, rng);
+    data
+}
+
+/// Permutation of `⟨1, ..., n⟩` on which every MERGE alternates between
+/// `L` and `R` until the very end, maximizing the number of comparisons
+pub fn merge_sort_worst_input(n: usize) -> Vec<i64> {
+    fn unmerge(values: Vec<i64>) -> Vec<i64> {
+        if values.len() <= 1 {
+            return values;
+        }
+        // MERGE-SORT puts the extra element of an odd split on the left
+        let (left, right): (Vec<_>, Vec<_>) =
+            values.iter().enumerate().partition(|(i, _)| i % 2 == 0);
+        let mut result = unmerge(left.into_iter().map(|(_, v)| *v).collect());
+        result.extend(unmerge(right.into_iter().map(|(_, v)| *v).collect()));
+        result
+    }
+    unmerge(sorted_input(n))
+}
+
+/// Permutation of `⟨1, ..., n⟩` on which every Lomuto PARTITION picks the
+/// median as pivot. Built by replaying PARTITION on positions and handing
+/// out ranks so that the first `(m - 1) / 2` scanned elements are smaller.
+pub fn quicksort_best_input(n: usize) -> Vec<i64> {
+    fn build(ids: &mut [usize], low_rank: usize, rank: &mut [usize]) {
+        let m = ids.len();
+        if m == 0 {
+            return;
+        }
+        let k = (m - 1) / 2;
+        // The first k elements stay on the low side, the pivot moves to k.
+        ids.swap(k, m - 1);
+        rank[ids[k]] = low_rank + k;
+        let (low, high) = ids.split_at_mut(k);
+        build(low, low_rank, rank);
+        build(&mut high[1..], low_rank + k + 1, rank);
+    }
+
+    let mut ids: Vec<usize> = (0..n).collect();
+    let mut rank = vec![0; n];
+    build(&mut ids, 0, &mut rank);
+    rank.into_iter().map(|r| r as i64 + 1).collect()
+}
+
+/// Connected undirected graph on `n` vertices with `min(m, n(n - 1)/2)`
+/// distinct edges and random weights in `1..=max_weight`: a random spanning
+/// path first, then random extra edges
+pub fn random_connected_graph<R: RandomSource>(
+    n: usize,
+    m: usize,
+    max_weight: usize,
+    rng: &mut R,
+) -> AdjacencyList {
+    let mut order: Vec<usize> = (0..n).collect();
+    shuffle(&mut order, rng);
+    let mut g = AdjacencyList::undirected(n);
+    let weight = |rng: &mut R| rng.gen_range(1, max_weight + 1) as i64;
+    for pair in order.windows(2) {
+        g.add_weighted_edge(pair[0], pair[1], weight(rng));
+    }
+
+    let target = m.min(n * n.saturating_sub(1) / 2);
+    let mut count = n.saturating_sub(1);
+    while count < target {
+        let (u, v) = (rng.gen_range(0, n), rng.gen_range(0, n));
+        if u != v && !g.has_edge(u, v) {
+            g.add_weighted_edge(u, v, weight(rng));
+            count += 1;
+        }
+    }
+    g
+}
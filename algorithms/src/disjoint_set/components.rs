@@ -0,0 +1,88 @@
+use std::fmt::Write;
+
+use super::forest::DisjointSetForest;
+use super::union_find::DisjointSet;
+use crate::graph::adjacency_list::AdjacencyList;
+use crate::graph::representation::{Graph, labels};
+
+/// # Connected Components
+///
+/// Puts the vertices of every connected component of `g` into one set by a
+/// `UNION` per edge (CLRS Section 19.1), then numbers the components
+/// `0, 1, ...` in the order of their first vertex. Two vertices `u` and `v`
+/// lie in the same component exactly when their numbers agree, which is
+/// what SAME-COMPONENT answers. Directed edges are taken as undirected, so
+/// a directed graph yields its weakly connected components.
+///
+/// # Pseudocode (CLRS)
+///
+/// ```text
+/// CONNECTED-COMPONENTS(G)
+/// for each vertex v ∈ G.V
+///     MAKE-SET(v)
+/// for each edge (u, v) ∈ G.E
+///     if FIND-SET(u) ≠ FIND-SET(v)
+///         UNION(u, v)
+///
+/// SAME-COMPONENT(u, v)
+/// if FIND-SET(u) == FIND-SET(v)
+///     return TRUE
+/// else return FALSE
+/// ```
+///
+/// # Example
+///
+/// ```rust
+/// use algorithms::disjoint_set::components::connected_components;
+/// use algorithms::disjoint_set::linked_list::DisjointSetList;
+/// use algorithms::graph::parse::parse_edge_list;
+///
+/// let g = parse_edge_list("undirected\na b\nc d\nb e\n").unwrap();
+/// let component = connected_components(&g, &mut DisjointSetList::new());
+///
+/// // a b c d e
+/// assert_eq!(component, [0, 0, 1, 1, 0]);
+/// ```
+///
+/// # Notes
+///
+/// * Makes `|V|` MAKE-SETs and at most `|E|` UNIONs and `|V| + 2|E|`
+///   FIND-SETs, so the time depends on the representation of `sets`.
+///
+/// # Panics
+///
+/// Panics if `sets` is not empty.
+pub fn connected_components<G: Graph, D: DisjointSet>(g: &G, sets: &mut D) -> Vec<usize> {
+    assert!(sets.is_empty(), "connected components need empty sets");
+    for _ in g.vertices() {
+        sets.make_set();
+    }
+    for edge in g.edges() {
+        sets.union(edge.from, edge.to);
+    }
+
+    let mut number = vec![None; g.vertex_count()];
+    let mut count = 0;
+    g.vertices()
+        .map(|v| {
+            let root = sets.find_set(v);
+            *number[root].get_or_insert_with(|| {
+                count += 1;
+                count - 1
+            })
+        })
+        .collect()
+}
+
+/// The vertices of every connected component, found with a disjoint-set
+/// forest
+pub(crate) fn describe(g: &AdjacencyList, _: usize) -> String {
+    let component = connected_components(g, &mut DisjointSetForest::new());
+    let count = component.iter().max().map_or(0, |c| c + 1);
+    let mut text = String::new();
+    for c in 0..count {
+        let members: Vec<usize> = g.vertices().filter(|&v| component[v] == c).collect();
+        let _ = writeln!(text, "Component {}: {}", c + 1, labels(g, &members));
+    }
+    text
+}
@@ -0,0 +1,121 @@
+/// # Disjoint-Set Forest
+///
+/// Every set is a rooted tree whose root is the representative (CLRS
+/// Section 19.3). Elements are the indices `0, 1, ...` handed out by
+/// [`make_set`](Self::make_set). Union by rank and path compression
+/// together give **O(m α(n))** time for `m` operations on `n` elements.
+///
+/// # Pseudocode (CLRS)
+///
+/// ```text
+/// MAKE-SET(x)
+/// x.p = x
+/// x.rank = 0
+///
+/// UNION(x, y)
+/// LINK(FIND-SET(x), FIND-SET(y))
+///
+/// LINK(x, y)
+/// if x.rank > y.rank
+///     y.p = x
+/// else x.p = y
+///     if x.rank == y.rank
+///         y.rank = y.rank + 1
+///
+/// FIND-SET(x)
+/// if x ≠ x.p
+///     x.p = FIND-SET(x.p)
+/// return x.p
+/// ```
+///
+/// # Example
+///
+/// ```rust
+/// use algorithms::disjoint_set::forest::DisjointSetForest;
+///
+/// let mut sets = DisjointSetForest::new();
+/// let (a, b, c) = (sets.make_set(), sets.make_set(), sets.make_set());
+/// sets.union(a, b);
+///
+/// assert_eq!(sets.find_set(a), sets.find_set(b));
+/// assert_ne!(sets.find_set(a), sets.find_set(c));
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct DisjointSetForest {
+    parent: Vec<usize>,
+    rank: Vec<usize>,
+    pointer_ops: u64,
+}
+
+impl DisjointSetForest {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Number of elements created so far.
+    pub fn len(&self) -> usize {
+        self.parent.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.parent.is_empty()
+    }
+
+    /// `MAKE-SET(x)` for a new element `x`, which is returned.
+    pub fn make_set(&mut self) -> usize {
+        let x = self.parent.len();
+        self.parent.push(x);
+        self.rank.push(0);
+        x
+    }
+
+    /// `FIND-SET(x)` with path compression, without recursion.
+    pub fn find_set(&mut self, x: usize) -> usize {
+        let mut root = x;
+        self.pointer_ops += 1;
+        while self.parent[root] != root {
+            root = self.parent[root];
+            self.pointer_ops += 1;
+        }
+        let mut current = x;
+        while current != root {
+            current = std::mem::replace(&mut self.parent[current], root);
+            self.pointer_ops += 1;
+        }
+        root
+    }
+
+    /// `UNION(x, y)`; returns `false` if `x` and `y` were already in the
+    /// same set.
+    pub fn union(&mut self, x: usize, y: usize) -> bool {
+        let (x, y) = (self.find_set(x), self.find_set(y));
+        if x == y {
+            return false;
+        }
+        self.link(x, y);
+        true
+    }
+
+    /// `x.rank`: an upper bound on the height of `x` in its tree.
+    pub fn rank(&self, x: usize) -> usize {
+        self.rank[x]
+    }
+
+    /// Pointers followed or changed so far.
+    pub fn pointer_ops(&self) -> u64 {
+        self.pointer_ops
+    }
+
+    /// `LINK(x, y)` for two roots, by rank
+    fn link(&mut self, x: usize, y: usize) {
+        self.pointer_ops += 1;
+        if self.rank[x] > self.rank[y] {
+            self.parent[y] = x;
+        } else {
+            self.parent[x] = y;
+            if self.rank[x] == self.rank[y] {
+                self.rank[y] += 1;
+            }
+        }
+    }
+}
@@ -0,0 +1,145 @@
+use std::fmt::Write;
+
+use super::forest::DisjointSetForest;
+use super::union_find::DisjointSet;
+use crate::graph::adjacency_list::AdjacencyList;
+use crate::graph::representation::Graph;
+
+/// # Tarjan's Offline Least-Common-Ancestors
+///
+/// Answers every query `{u, v}` in `pairs` with the least common ancestor
+/// of `u` and `v` in the tree `tree` rooted at `root`, in a single walk of
+/// the tree (CLRS Problem 19-3). When `LCA(u)` returns, the set of `u`
+/// holds the subtree of `u`, and every set along the current path has the
+/// path vertex it hangs from as its ancestor. So once `u` is black, a query
+/// `{u, v}` with `v` already black is answered by the ancestor of the set
+/// containing `v`.
+///
+/// # Pseudocode (CLRS)
+///
+/// ```text
+/// LCA(u)
+/// MAKE-SET(u)
+/// FIND-SET(u).ancestor = u
+/// for each child v of u in T
+///     LCA(v)
+///     UNION(u, v)
+///     FIND-SET(u).ancestor = u
+/// u.color = BLACK
+/// for each node v such that {u, v} ∈ P
+///     if v.color == BLACK
+///         print "The least common ancestor of" u "and" v "is" FIND-SET(v).ancestor
+/// ```
+///
+/// # Example
+///
+/// ```rust
+/// use algorithms::disjoint_set::forest::DisjointSetForest;
+/// use algorithms::disjoint_set::lca::offline_lca;
+/// use algorithms::graph::parse::parse_edge_list;
+///
+/// //     r
+/// //    / \
+/// //   a   b
+/// //  / \
+/// // c   d
+/// let tree = parse_edge_list("r a\nr b\na c\na d\n").unwrap();
+/// let pairs = [(3, 4), (3, 2), (1, 1)];
+///
+/// let lca = offline_lca(&tree, 0, &pairs, &mut DisjointSetForest::new());
+/// assert_eq!(lca, [Some(1), Some(0), Some(1)]);
+/// ```
+///
+/// # Notes
+///
+/// * The walk is a depth-first search with an explicit stack, so deep trees
+///   do not overflow the call stack.
+/// * Children of `u` are its neighbors not yet visited, so `tree` may be
+///   directed away from the root or undirected. On a graph that is not a
+///   tree, the answers refer to the depth-first tree from `root`.
+/// * Queries with a vertex outside the tree of `root` are answered `None`.
+/// * With a [`DisjointSetForest`](super::forest::DisjointSetForest) the
+///   running time is **O((V + |P|) α(V))**.
+///
+/// # Panics
+///
+/// Panics if `sets` is not empty or `root` is not a vertex of `tree`.
+pub fn offline_lca<G: Graph, D: DisjointSet>(
+    tree: &G,
+    root: usize,
+    pairs: &[(usize, usize)],
+    sets: &mut D,
+) -> Vec<Option<usize>> {
+    assert!(sets.is_empty(), "offline LCA needs empty sets");
+    let n = tree.vertex_count();
+    assert!(root < n, "root {} is not a vertex of T", root);
+
+    let mut queries: Vec<Vec<(usize, usize)>> = vec![Vec::new(); n];
+    for (i, &(u, v)) in pairs.iter().enumerate() {
+        queries[u].push((v, i));
+        if u != v {
+            queries[v].push((u, i));
+        }
+    }
+
+    // Elements are handed out in visiting order, so they index 0..n too
+    let mut element = vec![None; n];
+    let mut ancestor = vec![root; n];
+    let mut black = vec![false; n];
+    let mut answers = vec![None; pairs.len()];
+    // (u, children of u, next child to visit)
+    let mut stack: Vec<(usize, Vec<usize>, usize)> = Vec::new();
+    let mut entering = Some(root);
+
+    loop {
+        if let Some(u) = entering.take() {
+            let x = sets.make_set();
+            element[u] = Some(x);
+            ancestor[sets.find_set(x)] = u;
+            stack.push((u, tree.neighbors(u).map(|(v, _)| v).collect(), 0));
+        }
+        let Some((u, children, next)) = stack.last_mut() else {
+            break;
+        };
+        let u = *u;
+        if let Some(&v) = children.get(*next) {
+            *next += 1;
+            if element[v].is_none() {
+                entering = Some(v);
+            }
+            continue;
+        }
+        stack.pop();
+
+        black[u] = true;
+        for &(v, i) in &queries[u] {
+            if let Some(y) = element[v].filter(|_| black[v]) {
+                answers[i] = Some(ancestor[sets.find_set(y)]);
+            }
+        }
+        if let Some(&(parent, _, _)) = stack.last() {
+            let x = element[parent].expect("vertices on the stack are visited");
+            let y = element[u].expect("vertices on the stack are visited");
+            sets.union(x, y);
+            ancestor[sets.find_set(x)] = parent;
+        }
+    }
+    answers
+}
+
+/// The least common ancestor of every pair of vertices in the tree rooted
+/// at `root`
+pub(crate) fn describe(g: &AdjacencyList, root: usize) -> String {
+    let pairs: Vec<(usize, usize)> = g
+        .vertices()
+        .flat_map(|u| g.vertices().filter(move |&v| u < v).map(move |v| (u, v)))
+        .collect();
+    let lca = offline_lca(g, root, &pairs, &mut DisjointSetForest::new());
+    let mut text = String::new();
+    for (&(u, v), w) in pairs.iter().zip(lca) {
+        if let Some(w) = w {
+            let _ = writeln!(text, "LCA({}, {}) = {}", g.label(u), g.label(v), g.label(w));
+        }
+    }
+    text
+}
@@ -0,0 +1,109 @@
+/// # Linked-List Disjoint Sets
+///
+/// Every set is a linked list whose head is the representative and whose
+/// members all point back to the set (CLRS Section 19.2). `FIND-SET` reads
+/// one pointer; `UNION` appends one list to the other and must update the
+/// set pointer of every appended member. With the weighted-union heuristic
+/// the shorter list is always the one appended, so an element moves at
+/// most `lg n` times.
+///
+/// # Example
+///
+/// ```rust
+/// use algorithms::disjoint_set::linked_list::DisjointSetList;
+///
+/// let mut sets = DisjointSetList::new();
+/// let (a, b, c) = (sets.make_set(), sets.make_set(), sets.make_set());
+/// sets.union(a, b);
+/// sets.union(c, a);
+///
+/// // c's single-element list was appended to the longer one
+/// assert_eq!(sets.find_set(c), a);
+/// assert_eq!(sets.members(c), [a, b, c]);
+/// ```
+///
+/// # Notes
+///
+/// * `m` operations, `n` of them `MAKE-SET`, take **O(m + n lg n)** time.
+#[derive(Debug, Clone, Default)]
+pub struct DisjointSetList {
+    /// Representative of the set containing each element
+    head: Vec<usize>,
+    /// Successor of each element in its list
+    next: Vec<Option<usize>>,
+    /// Last element of the list headed by each representative
+    tail: Vec<usize>,
+    /// Length of the list headed by each representative
+    size: Vec<usize>,
+    pointer_ops: u64,
+}
+
+impl DisjointSetList {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Number of elements created so far.
+    pub fn len(&self) -> usize {
+        self.head.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.head.is_empty()
+    }
+
+    /// `MAKE-SET(x)` for a new element `x`, which is returned.
+    pub fn make_set(&mut self) -> usize {
+        let x = self.head.len();
+        self.head.push(x);
+        self.next.push(None);
+        self.tail.push(x);
+        self.size.push(1);
+        x
+    }
+
+    /// `FIND-SET(x)`: the head of the list containing `x`.
+    pub fn find_set(&mut self, x: usize) -> usize {
+        self.pointer_ops += 1;
+        self.head[x]
+    }
+
+    /// `UNION(x, y)` by weighted union; returns `false` if `x` and `y` were
+    /// already in the same set.
+    pub fn union(&mut self, x: usize, y: usize) -> bool {
+        let (mut a, mut b) = (self.find_set(x), self.find_set(y));
+        if a == b {
+            return false;
+        }
+        if self.size[a] < self.size[b] {
+            std::mem::swap(&mut a, &mut b);
+        }
+        self.next[self.tail[a]] = Some(b);
+        self.pointer_ops += 1;
+        let mut member = Some(b);
+        while let Some(z) = member {
+            self.head[z] = a;
+            self.pointer_ops += 1;
+            member = self.next[z];
+        }
+        self.tail[a] = self.tail[b];
+        self.size[a] += self.size[b];
+        true
+    }
+
+    /// Elements of the set containing `x`, in list order.
+    pub fn members(&self, x: usize) -> Vec<usize> {
+        let mut members = Vec::new();
+        let mut member = Some(self.head[x]);
+        while let Some(z) = member {
+            members.push(z);
+            member = self.next[z];
+        }
+        members
+    }
+
+    /// Pointers followed or changed so far.
+    pub fn pointer_ops(&self) -> u64 {
+        self.pointer_ops
+    }
+}
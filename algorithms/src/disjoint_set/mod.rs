@@ -0,0 +1,5 @@
+pub mod components;
+pub mod forest;
+pub mod lca;
+pub mod linked_list;
+pub mod union_find;
@@ -0,0 +1,86 @@
+//! # Disjoint Sets
+//!
+//! The operations of CLRS Section 19.1 as a trait, so the clients of
+//! union-find run on either representation. Both count the pointers they
+//! follow or change, which makes the gap in running time measurable:
+//!
+//! | Representation                          | `m` operations, `n` MAKE-SETs |
+//! |-----------------------------------------|-------------------------------|
+//! | [`DisjointSetList`] (weighted union)    | O(m + n lg n)                 |
+//! | [`DisjointSetForest`] (rank, compression) | O(m α(n))                   |
+//!
+//! [`DisjointSetList`]: super::linked_list::DisjointSetList
+//! [`DisjointSetForest`]: super::forest::DisjointSetForest
+
+use super::forest::DisjointSetForest;
+use super::linked_list::DisjointSetList;
+
+/// # Disjoint Set
+///
+/// A dynamic collection of disjoint sets over the elements `0, 1, ...`,
+/// each set identified by a representative member.
+pub trait DisjointSet {
+    /// `MAKE-SET(x)` for a new element `x`, which is returned.
+    fn make_set(&mut self) -> usize;
+
+    /// `FIND-SET(x)`: the representative of the set containing `x`.
+    fn find_set(&mut self, x: usize) -> usize;
+
+    /// `UNION(x, y)`; returns `false` if `x` and `y` were already in the
+    /// same set.
+    fn union(&mut self, x: usize, y: usize) -> bool;
+
+    /// Number of elements created so far.
+    fn len(&self) -> usize;
+
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Pointers followed or changed so far.
+    fn pointer_ops(&self) -> u64;
+}
+
+impl DisjointSet for DisjointSetForest {
+    fn make_set(&mut self) -> usize {
+        DisjointSetForest::make_set(self)
+    }
+
+    fn find_set(&mut self, x: usize) -> usize {
+        DisjointSetForest::find_set(self, x)
+    }
+
+    fn union(&mut self, x: usize, y: usize) -> bool {
+        DisjointSetForest::union(self, x, y)
+    }
+
+    fn len(&self) -> usize {
+        DisjointSetForest::len(self)
+    }
+
+    fn pointer_ops(&self) -> u64 {
+        DisjointSetForest::pointer_ops(self)
+    }
+}
+
+impl DisjointSet for DisjointSetList {
+    fn make_set(&mut self) -> usize {
+        DisjointSetList::make_set(self)
+    }
+
+    fn find_set(&mut self, x: usize) -> usize {
+        DisjointSetList::find_set(self, x)
+    }
+
+    fn union(&mut self, x: usize, y: usize) -> bool {
+        DisjointSetList::union(self, x, y)
+    }
+
+    fn len(&self) -> usize {
+        DisjointSetList::len(self)
+    }
+
+    fn pointer_ops(&self) -> u64 {
+        DisjointSetList::pointer_ops(self)
+    }
+}
@@ -0,0 +1,203 @@
+/// Entry of the CLRS `b` table: which subproblem an optimal solution uses.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Arrow {
+    /// `x_i = y_j`, both are part of the LCS ("↖")
+    UpLeft,
+    /// Drop `x_i` ("↑")
+    Up,
+    /// Drop `y_j` ("←")
+    Left,
+}
+
+/// The `c` and `b` tables computed by [`lcs_length`].
+///
+/// Both are `(m + 1) × (n + 1)`; row 0 and column 0 belong to the empty
+/// prefixes, so `b[0][*]` and `b[*][0]` are `None`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LcsTables {
+    pub c: Vec<Vec<usize>>,
+    pub b: Vec<Vec<Option<Arrow>>>,
+}
+
+impl LcsTables {
+    /// Length of an LCS of the full sequences, `c[m, n]`.
+    pub fn length(&self) -> usize {
+        self.c
+            .last()
+            .and_then(|row| row.last())
+            .copied()
+            .unwrap_or(0)
+    }
+
+    /// Cells `(i, j)` visited by [`print_lcs`] from `(m, n)`, in that order.
+    pub fn path(&self) -> Vec<(usize, usize)> {
+        let mut i = self.b.len() - 1;
+        let mut j = self.b[0].len() - 1;
+        let mut path = Vec::new();
+        while let Some(arrow) = self.b[i][j] {
+            path.push((i, j));
+            match arrow {
+                Arrow::UpLeft => {
+                    i -= 1;
+                    j -= 1;
+                }
+                Arrow::Up => i -= 1,
+                Arrow::Left => j -= 1,
+            }
+        }
+        path
+    }
+}
+
+/// # LCS-Length
+///
+/// Computes the length of a longest common subsequence of `x` and `y`
+/// bottom-up (CLRS Section 14.4). Works on any sequence of comparable
+/// items: characters, bytes or whole tokens.
+///
+/// # Pseudocode (CLRS)
+///
+/// ```text
+/// LCS-LENGTH(X, Y, m, n)
+/// let b[1 : m, 1 : n] and c[0 : m, 0 : n] be new tables
+/// for i = 1 to m
+///     c[i, 0] = 0
+/// for j = 0 to n
+///     c[0, j] = 0
+/// for i = 1 to m                  // compute table entries in row-major order
+///     for j = 1 to n
+///         if x_i == y_j
+///             c[i, j] = c[i - 1, j - 1] + 1
+///             b[i, j] = "↖"
+///         elseif c[i - 1, j] >= c[i, j - 1]
+///             c[i, j] = c[i - 1, j]
+///             b[i, j] = "↑"
+///         else c[i, j] = c[i, j - 1]
+///             b[i, j] = "←"
+/// return c and b
+/// ```
+///
+/// # Example
+///
+/// ```rust
+/// use algorithms::dp::lcs::{lcs_length, print_lcs};
+///
+/// let x: Vec<char> = "ABCBDAB".chars().collect();
+/// let y: Vec<char> = "BDCABA".chars().collect();
+/// let tables = lcs_length(&x, &y);
+///
+/// assert_eq!(tables.length(), 4);
+/// let lcs: String = print_lcs(&tables, &x, x.len(), y.len()).into_iter().collect();
+/// assert_eq!(lcs, "BCBA");
+/// ```
+///
+/// # Notes
+///
+/// * Runs in **Θ(mn)** time and space.
+pub fn lcs_length<T: Eq>(x: &[T], y: &[T]) -> LcsTables {
+    let (m, n) = (x.len(), y.len());
+    let mut c = vec![vec![0; n + 1]; m + 1];
+    let mut b = vec![vec![None; n + 1]; m + 1];
+
+    for i in 1..=m {
+        for j in 1..=n {
+            if x[i - 1] == y[j - 1] {
+                c[i][j] = c[i - 1][j - 1] + 1;
+                b[i][j] = Some(Arrow::UpLeft);
+            } else if c[i - 1][j] >= c[i][j - 1] {
+                c[i][j] = c[i - 1][j];
+                b[i][j] = Some(Arrow::Up);
+            } else {
+                c[i][j] = c[i][j - 1];
+                b[i][j] = Some(Arrow::Left);
+            }
+        }
+    }
+
+    LcsTables { c, b }
+}
+
+/// # Print-LCS
+///
+/// Follows the arrows of `b` from `(i, j)` back to the start and returns the
+/// elements of an LCS of `x[1..=i]` and `y[1..=j]` in order. CLRS prints
+/// them; here they are collected instead.
+///
+/// # Pseudocode (CLRS)
+///
+/// ```text
+/// PRINT-LCS(b, X, i, j)
+/// if i == 0 or j == 0
+///     return                      // the LCS has length 0
+/// if b[i, j] == "↖"
+///     PRINT-LCS(b, X, i - 1, j - 1)
+///     print x_i                   // same as y_j
+/// elseif b[i, j] == "↑"
+///     PRINT-LCS(b, X, i - 1, j)
+/// else PRINT-LCS(b, X, i, j - 1)
+/// ```
+pub fn print_lcs<'a, T>(tables: &LcsTables, x: &'a [T], i: usize, j: usize) -> Vec<&'a T> {
+    let mut out = Vec::new();
+    collect_lcs(&tables.b, x, i, j, &mut out);
+    out
+}
+
+/// Convenience wrapper: an LCS of `x` and `y` as an owned vector.
+pub fn lcs<T: Eq + Clone>(x: &[T], y: &[T]) -> Vec<T> {
+    let tables = lcs_length(x, y);
+    print_lcs(&tables, x, x.len(), y.len())
+        .into_iter()
+        .cloned()
+        .collect()
+}
+
+/// # LCS Length in Linear Space (CLRS Exercise 14.4-4)
+///
+/// Computes only the LCS length, keeping two rows of `c` over the shorter
+/// sequence: **Θ(mn)** time and **O(min(m, n))** extra space.
+pub fn lcs_length_two_rows<T: Eq>(x: &[T], y: &[T]) -> usize {
+    // Let the rows run over the shorter sequence.
+    let (long, short) = if x.len() >= y.len() { (x, y) } else { (y, x) };
+    let mut previous = vec![0; short.len() + 1];
+    let mut current = vec![0; short.len() + 1];
+
+    for a in long {
+        for (j, b) in short.iter().enumerate() {
+            current[j + 1] = if a == b {
+                previous[j] + 1
+            } else {
+                previous[j + 1].max(current[j])
+            };
+        }
+        std::mem::swap(&mut previous, &mut current);
+    }
+
+    previous[short.len()]
+}
+
+fn collect_lcs<'a, T>(
+    b: &[Vec<Option<Arrow>>],
+    x: &'a [T],
+    i: usize,
+    j: usize,
+    out: &mut Vec<&'a T>,
+) {
+    if i == 0 || j == 0 {
+        return;
+    }
+    match b[i][j] {
+        Some(Arrow::UpLeft) => {
+            collect_lcs(b, x, i - 1, j - 1, out);
+            out.push(&x[i - 1]);
+        }
+        Some(Arrow::Up) => collect_lcs(b, x, i - 1, j, out),
+        _ => collect_lcs(b, x, i, j - 1, out),
+    }
+}
+
+/// A longest common subsequence of the characters of `x` and `y`
+pub(crate) fn describe(x: &str, y: &str) -> String {
+    let x: Vec<char> = x.chars().collect();
+    let y: Vec<char> = y.chars().collect();
+    lcs(&x, &y).into_iter().collect()
+}
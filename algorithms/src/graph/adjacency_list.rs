@@ -0,0 +1,180 @@
+use super::representation::{Graph, Weight, default_labels};
+
+/// # Adjacency List
+///
+/// `G.Adj[u]` lists every neighbor of `u` together with the edge weight
+/// (CLRS Section 20.1). An undirected edge `{u, v}` appears in both
+/// `Adj[u]` and `Adj[v]`. Needs **Θ(V + E)** space.
+///
+/// # Example
+///
+/// ```rust
+/// use algorithms::graph::adjacency_list::AdjacencyList;
+/// use algorithms::graph::representation::Graph;
+///
+/// let mut g = AdjacencyList::directed(3);
+/// g.add_edge(0, 1);
+/// g.add_edge(1, 2);
+///
+/// assert!(g.has_edge(0, 1));
+/// assert!(!g.has_edge(1, 0));
+/// assert!(g.transpose().has_edge(1, 0));
+/// assert!(g.square().has_edge(0, 2));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AdjacencyList {
+    directed: bool,
+    weighted: bool,
+    adj: Vec<Vec<(usize, Weight)>>,
+    labels: Vec<String>,
+}
+
+impl AdjacencyList {
+    /// Directed graph on the vertices `0..n` without edges.
+    pub fn directed(n: usize) -> Self {
+        Self::with_vertices(n, true)
+    }
+
+    /// Undirected graph on the vertices `0..n` without edges.
+    pub fn undirected(n: usize) -> Self {
+        Self::with_vertices(n, false)
+    }
+
+    /// Same vertices, labels and edges as `graph`.
+    pub fn from_graph<G: Graph>(graph: &G) -> Self {
+        let mut list = Self::with_vertices(graph.vertex_count(), graph.is_directed());
+        list.weighted = graph.is_weighted();
+        for v in graph.vertices() {
+            list.labels[v] = graph.label(v).to_string();
+        }
+        for u in graph.vertices() {
+            list.adj[u].extend(graph.neighbors(u));
+        }
+        list
+    }
+
+    /// Add a new isolated vertex and return its index.
+    pub fn add_vertex(&mut self, label: &str) -> usize {
+        self.adj.push(Vec::new());
+        self.labels.push(label.to_string());
+        self.adj.len() - 1
+    }
+
+    pub fn set_label(&mut self, v: usize, label: &str) {
+        self.labels[v] = label.to_string();
+    }
+
+    /// Add the edge `(u, v)` with weight `1`.
+    pub fn add_edge(&mut self, u: usize, v: usize) {
+        self.insert(u, v, 1);
+    }
+
+    /// Add the edge `(u, v)` with weight `w` and mark the graph weighted.
+    pub fn add_weighted_edge(&mut self, u: usize, v: usize, w: Weight) {
+        self.weighted = true;
+        self.insert(u, v, w);
+    }
+
+    /// # Transpose (CLRS Exercise 20.1-3)
+    ///
+    /// `G^T = (V, {(v, u) : (u, v) ∈ E})` in **Θ(V + E)** time. The
+    /// transpose of an undirected graph is the graph itself.
+    pub fn transpose(&self) -> Self {
+        if !self.directed {
+            return self.clone();
+        }
+        let mut transposed = Self {
+            adj: vec![Vec::new(); self.adj.len()],
+            ..self.clone()
+        };
+        for (u, neighbors) in self.adj.iter().enumerate() {
+            for &(v, w) in neighbors {
+                transposed.adj[v].push((u, w));
+            }
+        }
+        transposed
+    }
+
+    /// # Square (CLRS Exercise 20.1-5)
+    ///
+    /// `G²` has an edge `(u, w)` whenever `G` has a path with at most two
+    /// edges from `u` to `w`. Paths `u → v → u` do not add self-loops. Runs
+    /// in **O(V + E · max-degree)** time; the result is unweighted.
+    pub fn square(&self) -> Self {
+        let n = self.adj.len();
+        let mut square = Self {
+            weighted: false,
+            adj: vec![Vec::new(); n],
+            ..self.clone()
+        };
+        // mark[w] == u + 1 iff (u, w) is already in Adj²[u]
+        let mut mark = vec![0; n];
+        for u in 0..n {
+            for &(v, _) in &self.adj[u] {
+                let reachable = std::iter::once(v)
+                    .chain(self.adj[v].iter().map(|&(w, _)| w).filter(|&w| w != u));
+                for w in reachable {
+                    if mark[w] != u + 1 {
+                        mark[w] = u + 1;
+                        square.adj[u].push((w, 1));
+                    }
+                }
+            }
+        }
+        square
+    }
+
+    fn with_vertices(n: usize, directed: bool) -> Self {
+        Self {
+            directed,
+            weighted: false,
+            adj: vec![Vec::new(); n],
+            labels: default_labels(n),
+        }
+    }
+
+    fn insert(&mut self, u: usize, v: usize, w: Weight) {
+        let n = self.adj.len();
+        assert!(
+            u < n && v < n,
+            "edge ({}, {}) has a vertex outside 0..{}",
+            u,
+            v,
+            n
+        );
+        self.adj[u].push((v, w));
+        if !self.directed && u != v {
+            self.adj[v].push((u, w));
+        }
+    }
+}
+
+impl Graph for AdjacencyList {
+    fn vertex_count(&self) -> usize {
+        self.adj.len()
+    }
+
+    fn is_directed(&self) -> bool {
+        self.directed
+    }
+
+    fn is_weighted(&self) -> bool {
+        self.weighted
+    }
+
+    fn neighbors(&self, u: usize) -> impl Iterator<Item = (usize, Weight)> + '_ {
+        self.adj[u].iter().copied()
+    }
+
+    fn weight(&self, u: usize, v: usize) -> Option<Weight> {
+        self.adj[u].iter().find(|(x, _)| *x == v).map(|(_, w)| *w)
+    }
+
+    fn label(&self, v: usize) -> &str {
+        &self.labels[v]
+    }
+
+    fn degree(&self, u: usize) -> usize {
+        self.adj[u].len()
+    }
+}
@@ -0,0 +1,163 @@
+use super::representation::{Graph, Weight, default_labels};
+
+/// # Adjacency Matrix
+///
+/// `|V| × |V|` matrix `A` with `a[u][v] = Some(w(u, v))` if `(u, v) ∈ E`
+/// and `None` otherwise (CLRS Section 20.1). The matrix of an undirected
+/// graph is symmetric. Needs **Θ(V²)** space but answers "is `(u, v)` an
+/// edge?" in **O(1)** time. Parallel edges collapse into one; the last
+/// weight wins.
+///
+/// # Example
+///
+/// ```rust
+/// use algorithms::graph::adjacency_matrix::AdjacencyMatrix;
+/// use algorithms::graph::representation::Graph;
+///
+/// let mut g = AdjacencyMatrix::undirected(3);
+/// g.add_weighted_edge(0, 2, 7);
+///
+/// assert_eq!(g.weight(2, 0), Some(7));
+/// assert_eq!(g.weight(0, 1), None);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AdjacencyMatrix {
+    directed: bool,
+    weighted: bool,
+    matrix: Vec<Vec<Option<Weight>>>,
+    labels: Vec<String>,
+}
+
+impl AdjacencyMatrix {
+    /// Directed graph on the vertices `0..n` without edges.
+    pub fn directed(n: usize) -> Self {
+        Self::with_vertices(n, true)
+    }
+
+    /// Undirected graph on the vertices `0..n` without edges.
+    pub fn undirected(n: usize) -> Self {
+        Self::with_vertices(n, false)
+    }
+
+    /// Same vertices, labels and edges as `graph`.
+    pub fn from_graph<G: Graph>(graph: &G) -> Self {
+        let mut matrix = Self::with_vertices(graph.vertex_count(), graph.is_directed());
+        matrix.weighted = graph.is_weighted();
+        for u in graph.vertices() {
+            matrix.labels[u] = graph.label(u).to_string();
+            for (v, w) in graph.neighbors(u) {
+                matrix.matrix[u][v] = Some(w);
+            }
+        }
+        matrix
+    }
+
+    pub fn set_label(&mut self, v: usize, label: &str) {
+        self.labels[v] = label.to_string();
+    }
+
+    /// Add the edge `(u, v)` with weight `1`.
+    pub fn add_edge(&mut self, u: usize, v: usize) {
+        self.insert(u, v, 1);
+    }
+
+    /// Add the edge `(u, v)` with weight `w` and mark the graph weighted.
+    pub fn add_weighted_edge(&mut self, u: usize, v: usize, w: Weight) {
+        self.weighted = true;
+        self.insert(u, v, w);
+    }
+
+    /// Row `u` of the matrix.
+    pub fn row(&self, u: usize) -> &[Option<Weight>] {
+        &self.matrix[u]
+    }
+
+    /// # Transpose (CLRS Exercise 20.1-3)
+    ///
+    /// `A^T` in **Θ(V²)** time.
+    pub fn transpose(&self) -> Self {
+        let n = self.matrix.len();
+        let mut transposed = self.clone();
+        for u in 0..n {
+            for v in 0..n {
+                transposed.matrix[u][v] = self.matrix[v][u];
+            }
+        }
+        transposed
+    }
+
+    /// # Square (CLRS Exercise 20.1-5)
+    ///
+    /// `G²` from the boolean product `A · A` plus `A`, in **Θ(V³)** time.
+    /// Paths `u → v → u` do not add self-loops; the result is unweighted.
+    pub fn square(&self) -> Self {
+        let n = self.matrix.len();
+        let mut square = Self {
+            weighted: false,
+            ..self.clone()
+        };
+        for u in 0..n {
+            for w in 0..n {
+                let reachable = self.matrix[u][w].is_some()
+                    || (u != w
+                        && (0..n)
+                            .any(|v| self.matrix[u][v].is_some() && self.matrix[v][w].is_some()));
+                square.matrix[u][w] = reachable.then_some(1);
+            }
+        }
+        square
+    }
+
+    fn with_vertices(n: usize, directed: bool) -> Self {
+        Self {
+            directed,
+            weighted: false,
+            matrix: vec![vec![None; n]; n],
+            labels: default_labels(n),
+        }
+    }
+
+    fn insert(&mut self, u: usize, v: usize, w: Weight) {
+        let n = self.matrix.len();
+        assert!(
+            u < n && v < n,
+            "edge ({}, {}) has a vertex outside 0..{}",
+            u,
+            v,
+            n
+        );
+        self.matrix[u][v] = Some(w);
+        if !self.directed {
+            self.matrix[v][u] = Some(w);
+        }
+    }
+}
+
+impl Graph for AdjacencyMatrix {
+    fn vertex_count(&self) -> usize {
+        self.matrix.len()
+    }
+
+    fn is_directed(&self) -> bool {
+        self.directed
+    }
+
+    fn is_weighted(&self) -> bool {
+        self.weighted
+    }
+
+    fn neighbors(&self, u: usize) -> impl Iterator<Item = (usize, Weight)> + '_ {
+        self.matrix[u]
+            .iter()
+            .enumerate()
+            .filter_map(|(v, w)| w.map(|w| (v, w)))
+    }
+
+    fn weight(&self, u: usize, v: usize) -> Option<Weight> {
+        self.matrix[u][v]
+    }
+
+    fn label(&self, v: usize) -> &str {
+        &self.labels[v]
+    }
+}
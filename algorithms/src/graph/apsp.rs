@@ -0,0 +1,542 @@
+//! # All-Pairs Shortest Paths
+//!
+//! Shortest paths between every pair of vertices (CLRS Chapter 23): by
+//! repeated min-plus "multiplication" of the weight matrix, by the
+//! Floyd-Warshall algorithm and, for sparse graphs, by Johnson's algorithm,
+//! which reweights the edges so that Dijkstra's algorithm can run from
+//! every vertex. The transitive closure is Floyd-Warshall over `(∨, ∧)`.
+//!
+//! Matrices are indexed by vertex, with `None` for ∞ or NIL.
+
+use std::fmt::Write;
+
+use super::adjacency_list::AdjacencyList;
+use super::representation::{Graph, Weight, labels};
+use super::sssp::{NegativeCycle, bellman_ford, dijkstra};
+use crate::heap::priority_queue::MinPriorityQueue;
+
+/// `n × n` matrix of path weights, `None` for ∞.
+pub type DistanceMatrix = Vec<Vec<Option<Weight>>>;
+
+/// `n × n` predecessor matrix `Π`, `None` for NIL.
+pub type PredecessorMatrix = Vec<Vec<Option<usize>>>;
+
+/// Shortest-path weights `D` and the predecessor matrix `Π`: `π_ij` is the
+/// vertex before `j` on a shortest path from `i`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AllPairs {
+    pub d: DistanceMatrix,
+    pub pi: PredecessorMatrix,
+}
+
+impl AllPairs {
+    /// # Print-All-Pairs-Shortest-Path
+    ///
+    /// The vertices on the shortest path from `i` to `j` read off row `i`
+    /// of `Π`, or `None` if no path from `i` to `j` exists.
+    ///
+    /// # Pseudocode (CLRS)
+    ///
+    /// ```text
+    /// PRINT-ALL-PAIRS-SHORTEST-PATH(Π, i, j)
+    /// if i == j
+    ///     print i
+    /// elseif π_ij == NIL
+    ///     print "no path from" i "to" j "exists"
+    /// else PRINT-ALL-PAIRS-SHORTEST-PATH(Π, i, π_ij)
+    ///     print j
+    /// ```
+    pub fn path(&self, i: usize, j: usize) -> Option<Vec<usize>> {
+        let mut path = vec![j];
+        let mut current = j;
+        while current != i {
+            current = self.pi[i][current]?;
+            // A predecessor cycle (negative-weight cycle) never reaches i.
+            if path.len() > self.pi.len() {
+                return None;
+            }
+            path.push(current);
+        }
+        path.reverse();
+        Some(path)
+    }
+
+    /// Whether some `d_ii < 0`, i.e. `G` has a negative-weight cycle.
+    pub fn has_negative_cycle(&self) -> bool {
+        (0..self.d.len()).any(|i| self.d[i][i].is_some_and(|d| d < 0))
+    }
+}
+
+/// # Weight Matrix
+///
+/// `W = (w_ij)` with `w_ii = 0`, `w_ij = w(i, j)` for edges and ∞
+/// otherwise (CLRS Section 23.1). Of parallel edges the lightest
+/// counts; a negative self-loop keeps its weight.
+pub fn weight_matrix<G: Graph>(g: &G) -> DistanceMatrix {
+    let n = g.vertex_count();
+    let mut w = vec![vec![None; n]; n];
+    for i in g.vertices() {
+        w[i][i] = Some(0);
+        for (j, weight) in g.neighbors(i) {
+            if shorter(Some(weight), w[i][j]) {
+                w[i][j] = Some(weight);
+            }
+        }
+    }
+    w
+}
+
+/// # Extend-Shortest-Paths
+///
+/// The min-plus product `L · W`: `+` takes the place of `·` and `min` the
+/// place of `+` (CLRS Section 23.1). If `L` holds the weights of shortest
+/// paths with at most `r` edges and `W` those with at most `s`, the product
+/// holds those with at most `r + s`.
+///
+/// # Pseudocode (CLRS)
+///
+/// ```text
+/// EXTEND-SHORTEST-PATHS(L, W, L', n)
+/// // Assume that the elements of L' are initialized to ∞.
+/// for i = 1 to n
+///     for j = 1 to n
+///         for k = 1 to n
+///             l'_ij = min {l'_ij, l_ik + w_kj}
+/// ```
+///
+/// # Notes
+///
+/// * Runs in **Θ(n³)** time.
+///
+/// # Panics
+///
+/// Panics if `l` and `w` are not square matrices of the same size.
+pub fn extend_shortest_paths(l: &DistanceMatrix, w: &DistanceMatrix) -> DistanceMatrix {
+    let n = l.len();
+    assert!(
+        w.len() == n && l.iter().chain(w).all(|row| row.len() == n),
+        "min-plus product needs two n × n matrices"
+    );
+    let mut product = vec![vec![None; n]; n];
+    for i in 0..n {
+        for j in 0..n {
+            for k in 0..n {
+                let through_k = add(l[i][k], w[k][j]);
+                if shorter(through_k, product[i][j]) {
+                    product[i][j] = through_k;
+                }
+            }
+        }
+    }
+    product
+}
+
+/// # Slow-APSP
+///
+/// `L^(n-1) = W^(n-1)` by `n - 2` min-plus products with `W`: shortest
+/// paths have at most `n - 1` edges (CLRS Section 23.1).
+///
+/// # Pseudocode (CLRS)
+///
+/// ```text
+/// SLOW-APSP(W, L^(0), n)
+/// let L = (l_ij) and M = (m_ij) be new n × n matrices
+/// L = L^(0)
+/// for r = 1 to n - 1
+///     M = ∞                               // initialize M
+///     EXTEND-SHORTEST-PATHS(L, W, M, n)   // compute the matrix "product" M = L · W
+///     L = M
+/// return L
+/// ```
+///
+/// # Example
+///
+/// ```rust
+/// use algorithms::graph::apsp::slow_all_pairs;
+/// use algorithms::graph::parse::parse_edge_list;
+///
+/// let g = parse_edge_list("a b 3\nb c -1\na c 4\n").unwrap();
+/// let l = slow_all_pairs(&g);
+///
+/// assert_eq!(l[0], [Some(0), Some(3), Some(2)]);
+/// assert_eq!(l[2][0], None);
+/// ```
+///
+/// # Notes
+///
+/// * Runs in **Θ(n⁴)** time.
+/// * With a negative-weight cycle the result is not well defined; some
+///   diagonal entry is negative, though.
+pub fn slow_all_pairs<G: Graph>(g: &G) -> DistanceMatrix {
+    let w = weight_matrix(g);
+    // L^(1) = W
+    let mut l = w.clone();
+    for _ in 2..g.vertex_count() {
+        l = extend_shortest_paths(&l, &w);
+    }
+    l
+}
+
+/// # Faster-APSP
+///
+/// `L^(n-1)` by repeated squaring, `L^(2r) = L^(r) · L^(r)`, until
+/// `2r ≥ n - 1`. Overshooting does no harm: without negative-weight cycles,
+/// paths of more than `n - 1` edges are no shorter (CLRS Section 23.1).
+///
+/// # Pseudocode (CLRS)
+///
+/// ```text
+/// FASTER-APSP(W, n)
+/// let L and M be new n × n matrices
+/// L = W
+/// r = 1
+/// while r < n - 1
+///     M = ∞                               // initialize M
+///     EXTEND-SHORTEST-PATHS(L, L, M, n)   // compute M = L²
+///     r = 2r
+///     L = M                               // ready for the next iteration
+/// return L
+/// ```
+///
+/// # Example
+///
+/// ```rust
+/// use algorithms::graph::apsp::{faster_all_pairs, slow_all_pairs};
+/// use algorithms::graph::parse::parse_edge_list;
+///
+/// let g = parse_edge_list("a b 1\nb c 1\nc d 1\nd e 1\ne a -3\n").unwrap();
+///
+/// assert_eq!(faster_all_pairs(&g), slow_all_pairs(&g));
+/// assert_eq!(faster_all_pairs(&g)[4][3], Some(0));
+/// ```
+///
+/// # Notes
+///
+/// * Runs in **Θ(n³ lg n)** time.
+pub fn faster_all_pairs<G: Graph>(g: &G) -> DistanceMatrix {
+    let n = g.vertex_count();
+    let mut l = weight_matrix(g);
+    let mut r = 1;
+    while r + 1 < n {
+        l = extend_shortest_paths(&l, &l);
+        r *= 2;
+    }
+    l
+}
+
+/// # Floyd-Warshall
+///
+/// Shortest paths between all pairs in `Θ(n³)` time (CLRS Section 23.2).
+/// `D^(k)` holds the weights of shortest paths whose intermediate vertices
+/// all lie in `{0, ..., k - 1}`: a shortest such path either avoids vertex
+/// `k - 1` or goes through it exactly once. `Π` is maintained alongside.
+///
+/// # Pseudocode (CLRS)
+///
+/// ```text
+/// FLOYD-WARSHALL(W, n)
+/// D^(0) = W
+/// for k = 1 to n
+///     let D^(k) = (d^(k)_ij) be a new n × n matrix
+///     for i = 1 to n
+///         for j = 1 to n
+///             d^(k)_ij = min {d^(k-1)_ij, d^(k-1)_ik + d^(k-1)_kj}
+/// return D^(n)
+/// ```
+///
+/// # Example
+///
+/// ```rust
+/// use algorithms::graph::apsp::floyd_warshall;
+/// use algorithms::graph::parse::parse_edge_list;
+///
+/// let g = parse_edge_list("a b 3\nb c -1\na c 4\nc a 2\n").unwrap();
+/// let result = floyd_warshall(&g);
+///
+/// assert_eq!(result.d[1], [Some(1), Some(0), Some(-1)]);
+/// assert_eq!(result.path(1, 0), Some(vec![1, 2, 0]));
+/// assert!(!result.has_negative_cycle());
+/// ```
+///
+/// # Notes
+///
+/// * Runs in **Θ(n³)** time and, updating `D` in place, **Θ(n²)** space.
+/// * `G` has a negative-weight cycle exactly if some `d_ii < 0`; see
+///   [`AllPairs::has_negative_cycle`].
+pub fn floyd_warshall<G: Graph>(g: &G) -> AllPairs {
+    let mut current = initial_predecessors(g);
+    for k in g.vertices() {
+        floyd_warshall_step(&mut current, k);
+    }
+    current
+}
+
+/// # Floyd-Warshall (every step)
+///
+/// `D^(0), D^(1), ..., D^(n)` with `Π^(0), ..., Π^(n)`, as tabulated in
+/// CLRS Figure 23.4. Needs **Θ(n³)** space.
+pub fn floyd_warshall_steps<G: Graph>(g: &G) -> Vec<AllPairs> {
+    let mut steps = vec![initial_predecessors(g)];
+    for k in g.vertices() {
+        let mut next = steps[k].clone();
+        floyd_warshall_step(&mut next, k);
+        steps.push(next);
+    }
+    steps
+}
+
+/// # Transitive Closure
+///
+/// `t_ij = true` exactly if `G` has a path from `i` to `j`: Floyd-Warshall
+/// with logical or and and in place of `min` and `+` (CLRS Section 23.2).
+///
+/// # Pseudocode (CLRS)
+///
+/// ```text
+/// TRANSITIVE-CLOSURE(G, n)
+/// let T^(0) = (t^(0)_ij) be a new n × n matrix
+/// for i = 1 to n
+///     for j = 1 to n
+///         if i == j or (i, j) ∈ G.E
+///             t^(0)_ij = 1
+///         else t^(0)_ij = 0
+/// for k = 1 to n
+///     let T^(k) = (t^(k)_ij) be a new n × n matrix
+///     for i = 1 to n
+///         for j = 1 to n
+///             t^(k)_ij = t^(k-1)_ij ∨ (t^(k-1)_ik ∧ t^(k-1)_kj)
+/// return T^(n)
+/// ```
+///
+/// # Example
+///
+/// ```rust
+/// use algorithms::graph::apsp::transitive_closure;
+/// use algorithms::graph::parse::parse_edge_list;
+///
+/// let g = parse_edge_list("a b\nb c\nd a\n").unwrap();
+/// let t = transitive_closure(&g);
+///
+/// assert!(t[3][2]);
+/// assert!(!t[2][0]);
+/// ```
+///
+/// # Notes
+///
+/// * Runs in **Θ(n³)** time.
+pub fn transitive_closure<G: Graph>(g: &G) -> Vec<Vec<bool>> {
+    let n = g.vertex_count();
+    let mut t = vec![vec![false; n]; n];
+    for i in g.vertices() {
+        t[i][i] = true;
+        for (j, _) in g.neighbors(i) {
+            t[i][j] = true;
+        }
+    }
+    for k in 0..n {
+        let through = t[k].clone();
+        for row in t.iter_mut().filter(|row| row[k]) {
+            for (reachable, &via_k) in row.iter_mut().zip(&through) {
+                *reachable |= via_k;
+            }
+        }
+    }
+    t
+}
+
+/// # Johnson
+///
+/// All-pairs shortest paths for sparse graphs (CLRS Section 23.3). A new
+/// vertex `s` with edges of weight `0` to every vertex gives, via
+/// Bellman-Ford, a potential `h(v) = δ(s, v)` such that the reweighted
+/// edges `ŵ(u, v) = w(u, v) + h(u) - h(v)` are nonnegative and shortest
+/// paths stay shortest. Dijkstra's algorithm from every vertex then
+/// finishes the job. A negative-weight cycle is returned instead.
+///
+/// # Pseudocode (CLRS)
+///
+/// ```text
+/// JOHNSON(G, w)
+/// compute G', where G'.V = G.V ∪ {s},
+///     G'.E = G.E ∪ {(s, v) : v ∈ G.V}, and
+///     w(s, v) = 0 for all v ∈ G.V
+/// if BELLMAN-FORD(G', w, s) == FALSE
+///     print "the input graph contains a negative-weight cycle"
+/// else for each vertex v ∈ G'.V
+///         set h(v) to the value of δ(s, v)
+///             computed by the Bellman-Ford algorithm
+///     for each edge (u, v) ∈ G'.E
+///         ŵ(u, v) = w(u, v) + h(u) - h(v)
+///     let D = (d_uv) be a new n × n matrix
+///     for each vertex u ∈ G.V
+///         run DIJKSTRA(G, ŵ, u) to compute δ̂(u, v) for all v ∈ G.V
+///         for each vertex v ∈ G.V
+///             d_uv = δ̂(u, v) + h(v) - h(u)
+///     return D
+/// ```
+///
+/// # Example
+///
+/// ```rust
+/// use algorithms::graph::apsp::{floyd_warshall, johnson};
+/// use algorithms::graph::parse::parse_edge_list;
+///
+/// let g = parse_edge_list("a b 3\nb c -1\na c 4\nc a 2\n").unwrap();
+/// assert_eq!(johnson(&g).unwrap().d, floyd_warshall(&g).d);
+///
+/// let cyclic = parse_edge_list("a b 1\nb a -2\n").unwrap();
+/// assert!(johnson(&cyclic).is_err());
+/// ```
+///
+/// # Notes
+///
+/// * Runs in **O(VE lg V)** time with the binary-heap
+///   [`MinPriorityQueue`]; a Fibonacci heap would give
+///   **O(V² lg V + VE)**.
+/// * Vertices of the cycle are those of `G`; `s` never lies on one.
+/// * A reweighted edge `ŵ(u, v)` or a distance that overflows [`Weight`]
+///   counts as ∞, as in [`relax`](super::sssp::relax).
+pub fn johnson<G: Graph>(g: &G) -> Result<AllPairs, NegativeCycle> {
+    let n = g.vertex_count();
+    let mut augmented = AdjacencyList::directed(n + 1);
+    for u in g.vertices() {
+        for (v, w) in g.neighbors(u) {
+            augmented.add_weighted_edge(u, v, w);
+        }
+        augmented.add_weighted_edge(n, u, 0);
+    }
+    let h: Vec<Weight> = bellman_ford(&augmented, n)?
+        .d
+        .into_iter()
+        .map(|d| d.expect("s reaches every vertex"))
+        .collect();
+
+    let mut reweighted = AdjacencyList::directed(n);
+    for u in g.vertices() {
+        for (v, w) in g.neighbors(u) {
+            if let Some(w) = w.checked_add(h[u]).and_then(|w| w.checked_sub(h[v])) {
+                reweighted.add_weighted_edge(u, v, w);
+            }
+        }
+    }
+
+    let mut d = Vec::with_capacity(n);
+    let mut pi = Vec::with_capacity(n);
+    for u in g.vertices() {
+        let paths = dijkstra(&reweighted, u, &mut MinPriorityQueue::new());
+        d.push(
+            g.vertices()
+                .map(|v| paths.d[v].and_then(|dv| dv.checked_add(h[v])?.checked_sub(h[u])))
+                .collect(),
+        );
+        pi.push(paths.pi);
+    }
+    Ok(AllPairs { d, pi })
+}
+
+/// `D^(0) = W` and `Π^(0)`: `π_ij = i` for every edge `(i, j)`, `i ≠ j`
+fn initial_predecessors<G: Graph>(g: &G) -> AllPairs {
+    let d = weight_matrix(g);
+    let pi = d
+        .iter()
+        .enumerate()
+        .map(|(i, row)| {
+            row.iter()
+                .enumerate()
+                .map(|(j, w)| (i != j && w.is_some()).then_some(i))
+                .collect()
+        })
+        .collect();
+    AllPairs { d, pi }
+}
+
+/// `D^(k+1)` and `Π^(k+1)` from `D^(k)` and `Π^(k)`, in place: unless
+/// `d_kk < 0`, row and column `k` do not change in this step
+fn floyd_warshall_step(current: &mut AllPairs, k: usize) {
+    let n = current.d.len();
+    for i in 0..n {
+        for j in 0..n {
+            let through_k = add(current.d[i][k], current.d[k][j]);
+            if shorter(through_k, current.d[i][j]) {
+                current.d[i][j] = through_k;
+                current.pi[i][j] = current.pi[k][j];
+            }
+        }
+    }
+}
+
+/// `a + b` with ∞ absorbing; a sum that overflows is ∞ as well
+fn add(a: Option<Weight>, b: Option<Weight>) -> Option<Weight> {
+    a?.checked_add(b?)
+}
+
+/// Whether `candidate < current` with ∞ as the largest weight
+fn shorter(candidate: Option<Weight>, current: Option<Weight>) -> bool {
+    candidate.is_some_and(|c| current.is_none_or(|d| c < d))
+}
+
+/// Distances found by SLOW-APSP
+pub(crate) fn describe_slow(g: &AdjacencyList, _: usize) -> String {
+    describe(g, &slow_all_pairs(g))
+}
+
+/// Distances found by FASTER-APSP
+pub(crate) fn describe_faster(g: &AdjacencyList, _: usize) -> String {
+    describe(g, &faster_all_pairs(g))
+}
+
+/// Distances found by FLOYD-WARSHALL, noting a negative-weight cycle
+pub(crate) fn describe_floyd_warshall(g: &AdjacencyList, _: usize) -> String {
+    let result = floyd_warshall(g);
+    let mut text = describe(g, &result.d);
+    if result.has_negative_cycle() {
+        text.push_str("Negative-weight cycle: some d_ii < 0\n");
+    }
+    text
+}
+
+/// The vertices reachable from every vertex
+pub(crate) fn describe_transitive_closure(g: &AdjacencyList, _: usize) -> String {
+    let mut text = String::new();
+    for (i, row) in transitive_closure(g).iter().enumerate() {
+        let reachable: Vec<usize> = g.vertices().filter(|&j| row[j]).collect();
+        let _ = writeln!(text, "{}: {}", g.label(i), labels(g, &reachable));
+    }
+    text
+}
+
+/// Distances found by JOHNSON, or the negative-weight cycle it detects
+pub(crate) fn describe_johnson(g: &AdjacencyList, _: usize) -> String {
+    match johnson(g) {
+        Ok(result) => describe(g, &result.d),
+        Err(cycle) => format!(
+            "Negative-weight cycle: {} {}\n",
+            labels(g, &cycle.vertices),
+            g.label(cycle.vertices[0])
+        ),
+    }
+}
+
+/// Distance matrix with vertex labels along both sides, ∞ for no path
+fn describe(g: &AdjacencyList, d: &DistanceMatrix) -> String {
+    let cell = |w: &Option<i64>| w.map_or("∞".to_string(), |w| w.to_string());
+    let width = g
+        .vertices()
+        .map(|v| g.label(v).chars().count())
+        .chain(d.iter().flatten().map(|w| cell(w).chars().count()))
+        .max()
+        .unwrap_or(1);
+    let mut text = format!("{:width$}", "");
+    for v in g.vertices() {
+        let _ = write!(text, " {:>width$}", g.label(v));
+    }
+    text.push('\n');
+    for (u, row) in d.iter().enumerate() {
+        let _ = write!(text, "{:width$}", g.label(u));
+        for w in row {
+            let _ = write!(text, " {:>width$}", cell(w));
+        }
+        text.push('\n');
+    }
+    text
+}
@@ -0,0 +1,193 @@
+use std::collections::VecDeque;
+use std::fmt::Write;
+
+use super::adjacency_list::AdjacencyList;
+use super::representation::{Graph, labels};
+
+/// Vertex colors of breadth-first and depth-first search.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Color {
+    /// Not yet discovered
+    White,
+    /// Discovered, but not all neighbors examined
+    Gray,
+    /// Finished
+    Black,
+}
+
+/// Result of [`bfs`]: distances, predecessors and the queue order.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BfsResult {
+    pub source: usize,
+    /// `v.d`, `None` for ∞ (unreachable)
+    pub d: Vec<Option<usize>>,
+    /// `v.π`
+    pub pi: Vec<Option<usize>>,
+    /// Vertices in the order they were enqueued (and dequeued)
+    pub order: Vec<usize>,
+}
+
+impl BfsResult {
+    /// Color of `v` after the first `steps` iterations of the while loop,
+    /// i.e. after `steps` vertices have been dequeued.
+    pub fn color_after(&self, v: usize, steps: usize) -> Color {
+        match self.order.iter().position(|&x| x == v) {
+            Some(i) if i < steps => Color::Black,
+            Some(i) if i < self.discovered_after(steps) => Color::Gray,
+            _ => Color::White,
+        }
+    }
+
+    /// Contents of `Q` after the first `steps` iterations, head first.
+    pub fn queue_after(&self, steps: usize) -> &[usize] {
+        let end = self.discovered_after(steps);
+        &self.order[steps.min(end)..end]
+    }
+
+    /// `s` plus every vertex discovered from one of the first `steps`
+    fn discovered_after(&self, steps: usize) -> usize {
+        let dequeued = &self.order[..steps.min(self.order.len())];
+        1 + self
+            .pi
+            .iter()
+            .filter(|p| p.is_some_and(|p| dequeued.contains(&p)))
+            .count()
+    }
+}
+
+/// # Breadth-First Search
+///
+/// Computes the distance `v.d` (fewest edges) from `s` to every vertex and
+/// the breadth-first tree `v.π` (CLRS Section 20.2).
+///
+/// # Pseudocode (CLRS)
+///
+/// ```text
+/// BFS(G, s)
+/// for each vertex u ∈ G.V - {s}
+///     u.color = WHITE
+///     u.d = ∞
+///     u.π = NIL
+/// s.color = GRAY
+/// s.d = 0
+/// s.π = NIL
+/// Q = ∅
+/// ENQUEUE(Q, s)
+/// while Q ≠ ∅
+///     u = DEQUEUE(Q)
+///     for each vertex v in G.Adj[u]   // search the neighbors of u
+///         if v.color == WHITE         // is v being discovered now?
+///             v.color = GRAY
+///             v.d = u.d + 1
+///             v.π = u
+///             ENQUEUE(Q, v)           // v is now on the frontier
+///     u.color = BLACK                 // u is now behind the frontier
+/// ```
+///
+/// # Example
+///
+/// ```rust
+/// use algorithms::graph::bfs::{bfs, print_path};
+/// use algorithms::graph::parse::parse_edge_list;
+///
+/// let g = parse_edge_list("undirected\ns r\ns w\nw t\nt u\n").unwrap();
+/// let result = bfs(&g, 0);
+///
+/// assert_eq!(result.d[4], Some(3));
+/// assert_eq!(print_path(&result.pi, 0, 4), Some(vec![0, 2, 3, 4]));
+/// ```
+///
+/// # Notes
+///
+/// * Runs in **O(V + E)** time with adjacency lists.
+///
+/// # Panics
+///
+/// Panics if `s` is not a vertex of `g`.
+pub fn bfs<G: Graph>(g: &G, s: usize) -> BfsResult {
+    let n = g.vertex_count();
+    assert!(s < n, "source {} is not a vertex of G", s);
+
+    let mut color = vec![Color::White; n];
+    let mut d = vec![None; n];
+    let mut pi = vec![None; n];
+    let mut order = vec![s];
+    color[s] = Color::Gray;
+    d[s] = Some(0);
+
+    let mut queue = VecDeque::from([s]);
+    while let Some(u) = queue.pop_front() {
+        for (v, _) in g.neighbors(u) {
+            if color[v] == Color::White {
+                color[v] = Color::Gray;
+                d[v] = d[u].map(|du| du + 1);
+                pi[v] = Some(u);
+                queue.push_back(v);
+                order.push(v);
+            }
+        }
+        color[u] = Color::Black;
+    }
+
+    BfsResult {
+        source: s,
+        d,
+        pi,
+        order,
+    }
+}
+
+/// # Print-Path
+///
+/// The vertices on the path from `s` to `v` in the predecessor tree `π`,
+/// or `None` if no path from `s` to `v` exists. Works for any predecessor
+/// array, e.g. from BFS, DFS or a shortest-paths algorithm.
+///
+/// # Pseudocode (CLRS)
+///
+/// ```text
+/// PRINT-PATH(G, s, v)
+/// if v == s
+///     print s
+/// elseif v.π == NIL
+///     print "no path from" s "to" v "exists"
+/// else PRINT-PATH(G, s, v.π)
+///     print v
+/// ```
+pub fn print_path(pi: &[Option<usize>], s: usize, v: usize) -> Option<Vec<usize>> {
+    let mut path = vec![v];
+    let mut current = v;
+    while current != s {
+        current = pi[current]?;
+        // A predecessor cycle never reaches s.
+        if path.len() > pi.len() {
+            return None;
+        }
+        path.push(current);
+    }
+    path.reverse();
+    Some(path)
+}
+
+/// Table of `v.d`, `v.π` and the path from `s` for every vertex
+pub(crate) fn describe(g: &AdjacencyList, s: usize) -> String {
+    let result = bfs(g, s);
+    let mut text = String::new();
+    for v in g.vertices() {
+        let d = result.d[v].map_or("∞".to_string(), |d| d.to_string());
+        let pi = result.pi[v].map_or("NIL", |p| g.label(p));
+        let path = match print_path(&result.pi, s, v) {
+            Some(path) => labels(g, &path),
+            None => format!("no path from {} to {} exists", g.label(s), g.label(v)),
+        };
+        let _ = writeln!(
+            text,
+            "{}: d = {}, π = {}, path: {}",
+            g.label(v),
+            d,
+            pi,
+            path
+        );
+    }
+    text
+}
@@ -0,0 +1,246 @@
+//! # Articulation Points, Bridges and Biconnected Components
+//!
+//! CLRS Problem 20-2 for a connected, undirected graph `G`: an
+//! *articulation point* is a vertex whose removal disconnects `G`, a
+//! *bridge* an edge whose removal disconnects `G`, and a *biconnected
+//! component* a maximal set of edges in which any two edges lie on a
+//! common simple cycle. All three follow from one depth-first search and
+//!
+//! ```text
+//! v.low = min { v.d, w.d : (u, w) is a back edge for some descendant u of v }
+//! ```
+//!
+//! * A nonroot vertex `v` is an articulation point iff it has a child `s`
+//!   with `s.low ≥ v.d`; the root iff it has at least two children.
+//! * A tree edge `(v, s)` is a bridge iff `s.low > v.d` (bridges lie on no
+//!   simple cycle, so no back edge ever is one).
+//! * The edges of a biconnected component are popped off an edge stack
+//!   whenever a child `s` of `v` with `s.low ≥ v.d` finishes.
+//!
+//! Disconnected graphs are handled one depth-first tree at a time.
+//! Self-loops lie on no simple cycle and are ignored.
+
+use std::fmt::Write;
+
+use super::adjacency_list::AdjacencyList;
+use super::representation::{Edge, Graph, Weight, labels};
+
+/// # Articulation Points
+///
+/// Cut vertices of `G` in increasing order (Problem 20-2 a–d).
+///
+/// # Example
+///
+/// ```rust
+/// use algorithms::graph::biconnected::articulation_points;
+/// use algorithms::graph::parse::parse_edge_list;
+///
+/// // Two triangles sharing vertex c
+/// let g = parse_edge_list("undirected\na b\nb c\nc a\nc d\nd e\ne c\n").unwrap();
+///
+/// assert_eq!(articulation_points(&g), [2]);
+/// ```
+///
+/// # Notes
+///
+/// * Runs in **O(V + E)** time.
+///
+/// # Panics
+///
+/// Panics if `g` is directed.
+pub fn articulation_points<G: Graph>(g: &G) -> Vec<usize> {
+    let search = LowSearch::run(g);
+    g.vertices().filter(|&v| search.articulation[v]).collect()
+}
+
+/// # Bridges
+///
+/// Edges of `G` that lie on no simple cycle, as `(v.π, v)` tree edges in
+/// order of discovery (Problem 20-2 e–f).
+///
+/// # Example
+///
+/// ```rust
+/// use algorithms::graph::biconnected::bridges;
+/// use algorithms::graph::parse::parse_edge_list;
+///
+/// let g = parse_edge_list("undirected\na b\nb c\nc a\nc d\n").unwrap();
+/// let bridges = bridges(&g);
+///
+/// assert_eq!(bridges.len(), 1);
+/// assert_eq!((bridges[0].from, bridges[0].to), (2, 3));
+/// ```
+///
+/// # Notes
+///
+/// * Runs in **O(V + E)** time.
+///
+/// # Panics
+///
+/// Panics if `g` is directed.
+pub fn bridges<G: Graph>(g: &G) -> Vec<Edge> {
+    LowSearch::run(g).bridges
+}
+
+/// # Biconnected Components
+///
+/// Partitions the nonloop edges of `G` into biconnected components, in the
+/// order the search completes them (Problem 20-2 g–h). A bridge forms a
+/// component on its own.
+///
+/// # Example
+///
+/// ```rust
+/// use algorithms::graph::biconnected::biconnected_components;
+/// use algorithms::graph::parse::parse_edge_list;
+///
+/// let g = parse_edge_list("undirected\na b\nb c\nc a\nc d\nd e\ne c\n").unwrap();
+/// let components = biconnected_components(&g);
+///
+/// assert_eq!(components.len(), 2);
+/// assert!(components.iter().all(|edges| edges.len() == 3));
+/// ```
+///
+/// # Notes
+///
+/// * Runs in **O(V + E)** time.
+///
+/// # Panics
+///
+/// Panics if `g` is directed.
+pub fn biconnected_components<G: Graph>(g: &G) -> Vec<Vec<Edge>> {
+    LowSearch::run(g).components
+}
+
+/// Depth-first search computing `v.low` and everything derived from it
+struct LowSearch {
+    d: Vec<usize>,
+    low: Vec<usize>,
+    time: usize,
+    edge_stack: Vec<Edge>,
+    articulation: Vec<bool>,
+    bridges: Vec<Edge>,
+    components: Vec<Vec<Edge>>,
+}
+
+/// Vertex `u` of the search, its parent and remaining neighbors
+struct Frame {
+    u: usize,
+    parent: Option<usize>,
+    neighbors: Vec<(usize, Weight)>,
+    next: usize,
+    /// Whether the tree edge back to the parent has been skipped
+    parent_skipped: bool,
+    children: usize,
+}
+
+impl LowSearch {
+    fn run<G: Graph>(g: &G) -> Self {
+        assert!(!g.is_directed(), "biconnectivity needs an undirected graph");
+        let n = g.vertex_count();
+        let mut search = Self {
+            d: vec![0; n],
+            low: vec![0; n],
+            time: 0,
+            edge_stack: Vec::new(),
+            articulation: vec![false; n],
+            bridges: Vec::new(),
+            components: Vec::new(),
+        };
+        for root in g.vertices() {
+            if search.d[root] == 0 {
+                search.visit(g, root);
+            }
+        }
+        search
+    }
+
+    fn visit<G: Graph>(&mut self, g: &G, root: usize) {
+        let mut frames = vec![self.discover(g, root, None)];
+
+        while let Some(frame) = frames.last_mut() {
+            let u = frame.u;
+            if let Some(&(v, w)) = frame.neighbors.get(frame.next) {
+                frame.next += 1;
+                if v == u {
+                    continue;
+                }
+                if self.d[v] == 0 {
+                    frame.children += 1;
+                    self.edge_stack.push(Edge::new(u, v, w));
+                    frames.push(self.discover(g, v, Some(u)));
+                } else if Some(v) == frame.parent && !frame.parent_skipped {
+                    frame.parent_skipped = true;
+                } else if self.d[v] < self.d[u] {
+                    // Back edge to an ancestor; seen again later from v's side
+                    self.edge_stack.push(Edge::new(u, v, w));
+                    self.low[u] = self.low[u].min(self.d[v]);
+                }
+                continue;
+            }
+
+            let finished = frames.pop().unwrap();
+            match frames.last() {
+                Some(parent) => self.finish_child(parent.u, parent.parent.is_none(), finished.u),
+                None => self.articulation[u] = finished.children >= 2,
+            }
+        }
+    }
+
+    /// Update `v` once its child `s` has finished
+    fn finish_child(&mut self, v: usize, v_is_root: bool, s: usize) {
+        self.low[v] = self.low[v].min(self.low[s]);
+        if self.low[s] < self.d[v] {
+            return;
+        }
+        // Root articulation is decided by its number of children
+        if !v_is_root {
+            self.articulation[v] = true;
+        }
+        let mut component = Vec::new();
+        while let Some(edge) = self.edge_stack.pop() {
+            component.push(edge);
+            if (edge.from, edge.to) == (v, s) {
+                break;
+            }
+        }
+        if self.low[s] > self.d[v] {
+            // Nothing below s reaches back to v: (v, s) is alone
+            self.bridges.push(component[0]);
+        }
+        self.components.push(component);
+    }
+
+    fn discover<G: Graph>(&mut self, g: &G, u: usize, parent: Option<usize>) -> Frame {
+        self.time += 1;
+        self.d[u] = self.time;
+        self.low[u] = self.time;
+        Frame {
+            u,
+            parent,
+            neighbors: g.neighbors(u).collect(),
+            next: 0,
+            parent_skipped: false,
+            children: 0,
+        }
+    }
+}
+
+/// Articulation points, bridges and the edges of each biconnected component
+pub(crate) fn describe(g: &AdjacencyList, _: usize) -> String {
+    if g.is_directed() {
+        return "biconnected components need an undirected graph\n".to_string();
+    }
+    let edge = |e: &Edge| format!("({}, {})", g.label(e.from), g.label(e.to));
+    let bridges: Vec<String> = bridges(g).iter().map(edge).collect();
+    let mut text = format!(
+        "Articulation points: {}\nBridges: {}\n",
+        labels(g, &articulation_points(g)),
+        bridges.join(" ")
+    );
+    for (i, component) in biconnected_components(g).iter().enumerate() {
+        let edges: Vec<String> = component.iter().map(edge).collect();
+        let _ = writeln!(text, "Component {}: {}", i + 1, edges.join(" "));
+    }
+    text
+}
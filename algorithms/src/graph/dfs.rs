@@ -0,0 +1,257 @@
+use std::fmt::Write;
+
+use super::adjacency_list::AdjacencyList;
+use super::bfs::Color;
+use super::representation::{Edge, Graph, Weight};
+
+/// Edge types of a depth-first forest (CLRS Section 20.3).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EdgeClass {
+    /// Edge of the depth-first forest
+    Tree,
+    /// To an ancestor (including self-loops)
+    Back,
+    /// Nontree edge to a descendant
+    Forward,
+    /// Any other edge
+    Cross,
+}
+
+impl EdgeClass {
+    /// One-letter label as in CLRS Figure 20.5: `T`, `B`, `F` or `C`.
+    pub fn letter(self) -> char {
+        match self {
+            EdgeClass::Tree => 'T',
+            EdgeClass::Back => 'B',
+            EdgeClass::Forward => 'F',
+            EdgeClass::Cross => 'C',
+        }
+    }
+}
+
+/// Result of [`dfs`]: timestamps, predecessor forest and edge classes.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DfsResult {
+    /// Discovery time `v.d`, from `1` to `2|V|`
+    pub d: Vec<usize>,
+    /// Finishing time `v.f`
+    pub f: Vec<usize>,
+    /// `v.π` in the depth-first forest
+    pub pi: Vec<Option<usize>>,
+    /// Every edge in the order it was explored, with its class. An
+    /// undirected edge is classified once, from the side explored first.
+    pub edges: Vec<(Edge, EdgeClass)>,
+}
+
+impl DfsResult {
+    /// Color of `v` right after time `time` (`0` is before the search).
+    pub fn color_at(&self, v: usize, time: usize) -> Color {
+        if time < self.d[v] {
+            Color::White
+        } else if time < self.f[v] {
+            Color::Gray
+        } else {
+            Color::Black
+        }
+    }
+
+    /// Vertices by decreasing finishing time.
+    pub fn by_decreasing_finish(&self) -> Vec<usize> {
+        let mut order: Vec<usize> = (0..self.f.len()).collect();
+        order.sort_by_key(|&v| std::cmp::Reverse(self.f[v]));
+        order
+    }
+
+    /// Roots of the depth-first trees, in discovery order.
+    pub fn roots(&self) -> Vec<usize> {
+        let mut roots: Vec<usize> = (0..self.pi.len())
+            .filter(|&v| self.pi[v].is_none())
+            .collect();
+        roots.sort_by_key(|&v| self.d[v]);
+        roots
+    }
+
+    /// Class of the explored edge `(u, v)`.
+    pub fn class_of(&self, u: usize, v: usize) -> Option<EdgeClass> {
+        self.edges
+            .iter()
+            .find(|(e, _)| (e.from, e.to) == (u, v))
+            .map(|(_, class)| *class)
+    }
+}
+
+/// # Depth-First Search
+///
+/// Searches the whole graph, restarting from every still-white vertex in
+/// the order `0, 1, ...` (CLRS Section 20.3), and classifies every edge.
+///
+/// # Pseudocode (CLRS)
+///
+/// ```text
+/// DFS(G)
+/// for each vertex u ∈ G.V
+///     u.color = WHITE
+///     u.π = NIL
+/// time = 0
+/// for each vertex u ∈ G.V
+///     if u.color == WHITE
+///         DFS-VISIT(G, u)
+///
+/// DFS-VISIT(G, u)
+/// time = time + 1                 // white vertex u has just been discovered
+/// u.d = time
+/// u.color = GRAY
+/// for each vertex v in G.Adj[u]   // explore each edge (u, v)
+///     if v.color == WHITE
+///         v.π = u
+///         DFS-VISIT(G, v)
+/// time = time + 1
+/// u.f = time
+/// u.color = BLACK                 // blacken u; it is finished
+/// ```
+///
+/// # Example
+///
+/// ```rust
+/// use algorithms::graph::dfs::{EdgeClass, dfs};
+/// use algorithms::graph::parse::parse_edge_list;
+///
+/// let g = parse_edge_list("u v\nv x\nx u\nu x\n").unwrap();
+/// let result = dfs(&g);
+///
+/// assert_eq!((result.d[0], result.f[0]), (1, 6));
+/// assert_eq!(result.class_of(2, 0), Some(EdgeClass::Back));
+/// assert_eq!(result.class_of(0, 2), Some(EdgeClass::Forward));
+/// ```
+///
+/// # Notes
+///
+/// * Runs in **Θ(V + E)** time.
+/// * `DFS-VISIT` is implemented with an explicit stack, so deep graphs do
+///   not overflow the call stack.
+pub fn dfs<G: Graph>(g: &G) -> DfsResult {
+    let order: Vec<usize> = g.vertices().collect();
+    dfs_in_order(g, &order)
+}
+
+/// # Depth-First Search (given root order)
+///
+/// Same as [`dfs`], but the main loop considers the vertices in `order`,
+/// as the second search of `STRONGLY-CONNECTED-COMPONENTS` requires.
+pub fn dfs_in_order<G: Graph>(g: &G, order: &[usize]) -> DfsResult {
+    let n = g.vertex_count();
+    let mut search = Search {
+        directed: g.is_directed(),
+        color: vec![Color::White; n],
+        time: 0,
+        result: DfsResult {
+            d: vec![0; n],
+            f: vec![0; n],
+            pi: vec![None; n],
+            edges: Vec::new(),
+        },
+    };
+    for &u in order {
+        if search.color[u] == Color::White {
+            search.visit(g, u);
+        }
+    }
+    search.result
+}
+
+/// State shared by all calls of `DFS-VISIT`
+struct Search {
+    directed: bool,
+    color: Vec<Color>,
+    time: usize,
+    result: DfsResult,
+}
+
+/// Activation record of `DFS-VISIT(G, u)`
+struct Frame {
+    u: usize,
+    neighbors: Vec<(usize, Weight)>,
+    next: usize,
+    /// Whether the tree edge back to `u.π` has been skipped (undirected)
+    parent_skipped: bool,
+}
+
+impl Search {
+    fn visit<G: Graph>(&mut self, g: &G, root: usize) {
+        let mut stack = vec![self.discover(g, root)];
+
+        while let Some(frame) = stack.last_mut() {
+            let u = frame.u;
+            let Some(&(v, w)) = frame.neighbors.get(frame.next) else {
+                self.time += 1;
+                self.result.f[u] = self.time;
+                self.color[u] = Color::Black;
+                stack.pop();
+                continue;
+            };
+            frame.next += 1;
+
+            let class = match self.color[v] {
+                Color::White => EdgeClass::Tree,
+                Color::Gray if self.directed => EdgeClass::Back,
+                Color::Gray => {
+                    // The tree edge {π, u} seen again from u's side
+                    if Some(v) == self.result.pi[u] && !frame.parent_skipped {
+                        frame.parent_skipped = true;
+                        continue;
+                    }
+                    EdgeClass::Back
+                }
+                // Undirected: already classified from v's side
+                Color::Black if !self.directed => continue,
+                Color::Black if self.result.d[u] < self.result.d[v] => EdgeClass::Forward,
+                Color::Black => EdgeClass::Cross,
+            };
+            self.result.edges.push((Edge::new(u, v, w), class));
+
+            if class == EdgeClass::Tree {
+                self.result.pi[v] = Some(u);
+                stack.push(self.discover(g, v));
+            }
+        }
+    }
+
+    fn discover<G: Graph>(&mut self, g: &G, u: usize) -> Frame {
+        self.time += 1;
+        self.result.d[u] = self.time;
+        self.color[u] = Color::Gray;
+        Frame {
+            u,
+            neighbors: g.neighbors(u).collect(),
+            next: 0,
+            parent_skipped: false,
+        }
+    }
+}
+
+/// Timestamps, predecessors and edge classes of a depth-first search
+pub(crate) fn describe(g: &AdjacencyList, _: usize) -> String {
+    let result = dfs(g);
+    let mut text = String::new();
+    for v in g.vertices() {
+        let pi = result.pi[v].map_or("NIL", |p| g.label(p));
+        let _ = writeln!(
+            text,
+            "{}: d/f = {}/{}, π = {}",
+            g.label(v),
+            result.d[v],
+            result.f[v],
+            pi
+        );
+    }
+    for (edge, class) in &result.edges {
+        let _ = writeln!(
+            text,
+            "({}, {}): {:?}",
+            g.label(edge.from),
+            g.label(edge.to),
+            class
+        );
+    }
+    text
+}
@@ -0,0 +1,257 @@
+use std::collections::VecDeque;
+
+use super::network::{FlowNetwork, describe_max_flow};
+use crate::graph::adjacency_list::AdjacencyList;
+use crate::graph::representation::{Graph, Weight};
+use crate::trace::NoopTracer;
+
+/// One iteration of the while loop of FORD-FULKERSON.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Augmentation {
+    /// Vertices of the augmenting path `p`, from `s` to `t`
+    pub path: Vec<usize>,
+    /// `c_f(p)`
+    pub amount: Weight,
+}
+
+/// Result of [`edmonds_karp`]: `|f|` and every augmentation on the way.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MaxFlow {
+    pub value: Weight,
+    pub augmentations: Vec<Augmentation>,
+}
+
+/// Observer for the augmentations of the Ford-Fulkerson method.
+///
+/// `net` still holds the flow from before `augmentation`, so
+/// `net.residual_network()` is the `G_f` its path was found in. Closures of
+/// the form `FnMut(&Augmentation, &FlowNetwork)` are tracers as well.
+pub trait AugmentationTracer {
+    fn record(&mut self, augmentation: &Augmentation, net: &FlowNetwork);
+}
+
+impl<F> AugmentationTracer for F
+where
+    F: FnMut(&Augmentation, &FlowNetwork),
+{
+    fn record(&mut self, augmentation: &Augmentation, net: &FlowNetwork) {
+        self(augmentation, net)
+    }
+}
+
+impl AugmentationTracer for NoopTracer {
+    #[inline(always)]
+    fn record(&mut self, _augmentation: &Augmentation, _net: &FlowNetwork) {}
+}
+
+/// A cut `(S, T)` and the edges crossing it from `S` to `T`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MinCut {
+    pub s_side: Vec<usize>,
+    pub t_side: Vec<usize>,
+    /// Indices into [`FlowNetwork::flow_edges`]
+    pub edges: Vec<usize>,
+    /// `c(S, T)`
+    pub capacity: Weight,
+}
+
+/// # Edmonds-Karp
+///
+/// The Ford-Fulkerson method with every augmenting path found by
+/// breadth-first search in the residual network, i.e. a shortest one
+/// (CLRS Section 24.2). Leaves a maximum flow in `net` and returns its
+/// value together with the path and amount of every augmentation.
+///
+/// # Pseudocode (CLRS)
+///
+/// ```text
+/// FORD-FULKERSON(G, s, t)
+/// for each edge (u, v) ∈ G.E
+///     (u, v).f = 0
+/// while there exists a path p from s to t in the residual network G_f
+///     c_f(p) = min {c_f(u, v) : (u, v) is in p}
+///     for each edge (u, v) in p
+///         if (u, v) ∈ G.E
+///             (u, v).f = (u, v).f + c_f(p)
+///         else (v, u).f = (v, u).f - c_f(p)
+/// ```
+///
+/// # Example
+///
+/// ```rust
+/// use algorithms::graph::flow::ford_fulkerson::edmonds_karp;
+/// use algorithms::graph::flow::network::FlowNetwork;
+///
+/// let mut net = FlowNetwork::new(4);
+/// net.add_edge(0, 1, 3);
+/// net.add_edge(0, 2, 2);
+/// net.add_edge(1, 2, 5);
+/// net.add_edge(1, 3, 2);
+/// net.add_edge(2, 3, 3);
+///
+/// let result = edmonds_karp(&mut net, 0, 3);
+/// assert_eq!(result.value, 5);
+/// assert_eq!(result.augmentations[0].path, [0, 1, 3]);
+/// assert_eq!(net.value(0), 5);
+/// ```
+///
+/// # Notes
+///
+/// * Runs in **O(VE²)** time: at most `O(VE)` augmentations of `O(E)` each.
+///
+/// # Panics
+///
+/// Panics if `s == t` or either is not a vertex of `net`.
+pub fn edmonds_karp(net: &mut FlowNetwork, s: usize, t: usize) -> MaxFlow {
+    edmonds_karp_traced(net, s, t, &mut NoopTracer)
+}
+
+/// # Edmonds-Karp (traced)
+///
+/// Same as [`edmonds_karp`], but reports every augmentation to `tracer`
+/// before pushing flow along its path, for tracing in the style of CLRS
+/// Figure 24.6.
+///
+/// # Example
+///
+/// ```rust
+/// use algorithms::graph::flow::ford_fulkerson::{Augmentation, edmonds_karp_traced};
+/// use algorithms::graph::flow::network::FlowNetwork;
+/// use algorithms::graph::representation::Graph;
+///
+/// let mut net = FlowNetwork::new(3);
+/// net.add_edge(0, 1, 4);
+/// net.add_edge(1, 2, 1);
+///
+/// let mut residuals = Vec::new();
+/// edmonds_karp_traced(&mut net, 0, 2, &mut |_: &Augmentation, net: &FlowNetwork| {
+///     residuals.push(net.residual_network())
+/// });
+/// assert_eq!(residuals.len(), 1);
+/// assert_eq!(residuals[0].weight(0, 1), Some(4));
+/// ```
+pub fn edmonds_karp_traced<R>(net: &mut FlowNetwork, s: usize, t: usize, tracer: &mut R) -> MaxFlow
+where
+    R: AugmentationTracer + ?Sized,
+{
+    check_terminals(net, s, t);
+    net.clear_flow();
+
+    let mut augmentations = Vec::new();
+    while let Some(arcs) = shortest_augmenting_path(net, s, t) {
+        let amount = arcs
+            .iter()
+            .map(|&arc| net.residual_capacity(arc))
+            .min()
+            .expect("a path from s ≠ t has an arc");
+        let mut path = vec![s];
+        path.extend(arcs.iter().map(|&arc| net.head(arc)));
+        let augmentation = Augmentation { path, amount };
+        tracer.record(&augmentation, net);
+        augmentations.push(augmentation);
+        for arc in arcs {
+            net.push(arc, amount);
+        }
+    }
+
+    MaxFlow {
+        value: net.value(s),
+        augmentations,
+    }
+}
+
+/// # Minimum Cut
+///
+/// For a maximum flow in `net`, the cut with `S` the vertices reachable
+/// from `s` in `G_f`: by the max-flow min-cut theorem (CLRS Theorem 24.6)
+/// every edge from `S` to `T` is saturated, so `c(S, T) = |f|` is minimum.
+///
+/// # Example
+///
+/// ```rust
+/// use algorithms::graph::flow::ford_fulkerson::{edmonds_karp, min_cut};
+/// use algorithms::graph::flow::network::FlowNetwork;
+///
+/// let mut net = FlowNetwork::new(3);
+/// net.add_edge(0, 1, 4);
+/// net.add_edge(1, 2, 1);
+/// edmonds_karp(&mut net, 0, 2);
+///
+/// let cut = min_cut(&net, 0);
+/// assert_eq!(cut.s_side, [0, 1]);
+/// assert_eq!(cut.edges, [1]);
+/// assert_eq!(cut.capacity, 1);
+/// ```
+///
+/// # Notes
+///
+/// * Runs in **O(V + E)** time. If the flow is not maximum, `t` lies in `S`.
+pub fn min_cut(net: &FlowNetwork, s: usize) -> MinCut {
+    let reachable = residual_search(net, s).0;
+    let (s_side, t_side): (Vec<usize>, Vec<usize>) = net.vertices().partition(|&v| reachable[v]);
+    let edges: Vec<usize> = net
+        .flow_edges()
+        .iter()
+        .enumerate()
+        .filter(|(_, e)| reachable[e.from] && !reachable[e.to])
+        .map(|(i, _)| i)
+        .collect();
+    let capacity = edges.iter().map(|&i| net.flow_edges()[i].capacity).sum();
+    MinCut {
+        s_side,
+        t_side,
+        edges,
+        capacity,
+    }
+}
+
+/// Arcs of a shortest path from `s` to `t` in `G_f`
+fn shortest_augmenting_path(net: &FlowNetwork, s: usize, t: usize) -> Option<Vec<usize>> {
+    let (reached, parent_arc) = residual_search(net, s);
+    if !reached[t] {
+        return None;
+    }
+    let mut arcs = Vec::new();
+    let mut v = t;
+    while v != s {
+        let arc = parent_arc[v].expect("reached vertices other than s have a parent");
+        arcs.push(arc);
+        v = net.tail(arc);
+    }
+    arcs.reverse();
+    Some(arcs)
+}
+
+/// Breadth-first search of `G_f` from `s`: reached vertices and the arc
+/// each was reached by
+fn residual_search(net: &FlowNetwork, s: usize) -> (Vec<bool>, Vec<Option<usize>>) {
+    let n = net.vertex_count();
+    let mut reached = vec![false; n];
+    let mut parent_arc = vec![None; n];
+    reached[s] = true;
+    let mut queue = VecDeque::from([s]);
+    while let Some(u) = queue.pop_front() {
+        for &arc in net.arcs(u) {
+            let v = net.head(arc);
+            if !reached[v] && net.residual_capacity(arc) > 0 {
+                reached[v] = true;
+                parent_arc[v] = Some(arc);
+                queue.push_back(v);
+            }
+        }
+    }
+    (reached, parent_arc)
+}
+
+/// Source and sink must be two distinct vertices
+pub(super) fn check_terminals(net: &FlowNetwork, s: usize, t: usize) {
+    let n = net.vertex_count();
+    assert!(s < n, "source {} is not a vertex of G", s);
+    assert!(t < n, "sink {} is not a vertex of G", t);
+    assert!(s != t, "source and sink must differ");
+}
+
+/// Maximum flow from `s` found by the Edmonds-Karp algorithm
+pub(crate) fn describe(g: &AdjacencyList, s: usize) -> String {
+    describe_max_flow(g, s, |net, s, t| edmonds_karp(net, s, t).value)
+}
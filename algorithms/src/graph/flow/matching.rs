@@ -0,0 +1,325 @@
+//! # Bipartite Matching
+//!
+//! Maximum matchings in a bipartite graph `G = (V, E)` with `V = L ∪ R`:
+//! once through a unit-capacity flow network (CLRS Section 24.3) and once
+//! with the Hopcroft-Karp algorithm (CLRS Section 25.1). A graph is taken
+//! as undirected; directed edges simply count in both directions.
+
+use std::collections::VecDeque;
+use std::fmt::Write;
+
+use super::ford_fulkerson::edmonds_karp;
+use super::network::FlowNetwork;
+use crate::graph::adjacency_list::AdjacencyList;
+use crate::graph::representation::Graph;
+
+/// A matching `M` as the mate of every vertex, `None` if unmatched.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Matching {
+    pub mate: Vec<Option<usize>>,
+}
+
+impl Matching {
+    /// `|M|`.
+    pub fn size(&self) -> usize {
+        self.edges().len()
+    }
+
+    /// The edges of `M` as pairs `(u, v)` with `u < v`, ordered by `u`.
+    pub fn edges(&self) -> Vec<(usize, usize)> {
+        self.mate
+            .iter()
+            .enumerate()
+            .filter_map(|(u, &v)| v.filter(|&v| u < v).map(|v| (u, v)))
+            .collect()
+    }
+}
+
+/// # Bipartition
+///
+/// Split `V` into `L` and `R` so that every edge runs between them, by
+/// 2-coloring each component with breadth-first search. Returns whether
+/// each vertex lies in `R`, or `None` if `G` has an odd cycle.
+///
+/// # Example
+///
+/// ```rust
+/// use algorithms::graph::flow::matching::bipartition;
+/// use algorithms::graph::parse::parse_edge_list;
+///
+/// let path = parse_edge_list("a b\nb c\n").unwrap();
+/// let triangle = parse_edge_list("a b\nb c\nc a\n").unwrap();
+///
+/// assert_eq!(bipartition(&path), Some(vec![false, true, false]));
+/// assert_eq!(bipartition(&triangle), None);
+/// ```
+///
+/// # Notes
+///
+/// * Runs in **O(V + E)** time; the first vertex of every component goes to `L`.
+pub fn bipartition<G: Graph>(g: &G) -> Option<Vec<bool>> {
+    let adj = undirected_neighbors(g);
+    let mut side: Vec<Option<bool>> = vec![None; g.vertex_count()];
+    for root in g.vertices() {
+        if side[root].is_some() {
+            continue;
+        }
+        side[root] = Some(false);
+        let mut queue = VecDeque::from([root]);
+        while let Some(u) = queue.pop_front() {
+            for &v in &adj[u] {
+                match side[v] {
+                    None => {
+                        side[v] = side[u].map(|right| !right);
+                        queue.push_back(v);
+                    }
+                    Some(right) if Some(right) == side[u] => return None,
+                    Some(_) => {}
+                }
+            }
+        }
+    }
+    side.into_iter().collect()
+}
+
+/// # Maximum Bipartite Matching
+///
+/// Adds a source `s` with an edge to every vertex of `L` and a sink `t`
+/// with an edge from every vertex of `R`, directs `E` from `L` to `R`, gives
+/// every edge capacity 1 and runs [`edmonds_karp`]. By the integrality
+/// theorem the maximum flow is integer-valued, and the edges from `L` to
+/// `R` that carry flow form a maximum matching (CLRS Section 24.3).
+///
+/// # Example
+///
+/// ```rust
+/// use algorithms::graph::flow::matching::bipartite_matching;
+/// use algorithms::graph::parse::parse_edge_list;
+///
+/// let g = parse_edge_list("l1 r1\nl1 r2\nl2 r1\n").unwrap();
+/// let m = bipartite_matching(&g);
+///
+/// assert_eq!(m.size(), 2);
+/// assert_eq!(m.edges(), [(0, 2), (1, 3)]);
+/// ```
+///
+/// # Notes
+///
+/// * Runs in **O(VE)** time: `|f| ≤ |V| / 2` augmentations of `O(E)` each.
+///
+/// # Panics
+///
+/// Panics if `g` is not bipartite.
+pub fn bipartite_matching<G: Graph>(g: &G) -> Matching {
+    let right = bipartition(g).expect("graph is not bipartite");
+    let n = g.vertex_count();
+    let (s, t) = (n, n + 1);
+
+    let adj = undirected_neighbors(g);
+    let mut net = FlowNetwork::new(n + 2);
+    net.set_label(s, "s");
+    net.set_label(t, "t");
+    for u in g.vertices() {
+        if right[u] {
+            net.add_edge(u, t, 1);
+        } else {
+            net.add_edge(s, u, 1);
+            for &v in &adj[u] {
+                net.add_edge(u, v, 1);
+            }
+        }
+    }
+    edmonds_karp(&mut net, s, t);
+
+    let mut mate = vec![None; n];
+    for e in net.flow_edges() {
+        if e.flow > 0 && e.from < n && e.to < n {
+            mate[e.from] = Some(e.to);
+            mate[e.to] = Some(e.from);
+        }
+    }
+    Matching { mate }
+}
+
+/// # Hopcroft-Karp
+///
+/// Grows a matching in phases. Each phase runs a breadth-first search from
+/// the unmatched vertices of `L` along alternating paths to find the
+/// length of a shortest augmenting path, then augments `M` by a maximal set
+/// of vertex-disjoint shortest augmenting paths found by depth-first search
+/// through the layers (CLRS Section 25.1).
+///
+/// # Pseudocode (CLRS)
+///
+/// ```text
+/// HOPCROFT-KARP(G)
+/// M = ∅
+/// repeat
+///     let P = {P_1, P_2, ..., P_k} be a maximal set of vertex-disjoint
+///         shortest M-augmenting paths
+///     M = M ⊕ (P_1 ∪ P_2 ∪ ... ∪ P_k)
+/// until P == ∅
+/// return M
+/// ```
+///
+/// # Example
+///
+/// ```rust
+/// use algorithms::graph::flow::matching::hopcroft_karp;
+/// use algorithms::graph::parse::parse_edge_list;
+///
+/// let g = parse_edge_list("l1 r1\nl1 r2\nl2 r1\n").unwrap();
+/// let m = hopcroft_karp(&g);
+///
+/// assert_eq!(m.size(), 2);
+/// assert_eq!(m.mate[0], Some(2));
+/// ```
+///
+/// # Notes
+///
+/// * At most **O(√V)** phases of `O(E)` each: **O(E√V)** time.
+///
+/// # Panics
+///
+/// Panics if `g` is not bipartite.
+pub fn hopcroft_karp<G: Graph>(g: &G) -> Matching {
+    let right = bipartition(g).expect("graph is not bipartite");
+    let adj = undirected_neighbors(g);
+    let left: Vec<usize> = g.vertices().filter(|&u| !right[u]).collect();
+    let mut mate = vec![None; g.vertex_count()];
+
+    while let Some(layers) = alternating_layers(&adj, &left, &mate) {
+        // a position in adj[u], so each edge is tried once per phase
+        let mut current = vec![0; g.vertex_count()];
+        for &u in &left {
+            if mate[u].is_none() {
+                augment(&adj, &layers, &mut current, &mut mate, u);
+            }
+        }
+    }
+    Matching { mate }
+}
+
+/// Layers of the vertices of `L` on alternating paths from the free ones,
+/// and the last layer, which has an edge to a free vertex of `R`
+struct Layers {
+    layer: Vec<Option<usize>>,
+    last: usize,
+}
+
+/// Breadth-first search from the free vertices of `L`, at layer 0, that
+/// puts `v.mate` one layer past `u` for every edge `(u, v)`; stops at the
+/// first layer with an edge to a free vertex, `None` if there is none
+fn alternating_layers(
+    adj: &[Vec<usize>],
+    left: &[usize],
+    mate: &[Option<usize>],
+) -> Option<Layers> {
+    let mut layer: Vec<Option<usize>> = vec![None; adj.len()];
+    let mut queue = VecDeque::new();
+    for &u in left {
+        if mate[u].is_none() {
+            layer[u] = Some(0);
+            queue.push_back((u, 0));
+        }
+    }
+    let mut last = None;
+    while let Some((u, d)) = queue.pop_front() {
+        if last.is_some_and(|last| d > last) {
+            break;
+        }
+        for &v in &adj[u] {
+            match mate[v] {
+                None => last = Some(d),
+                Some(w) if layer[w].is_none() => {
+                    layer[w] = Some(d + 1);
+                    queue.push_back((w, d + 1));
+                }
+                Some(_) => {}
+            }
+        }
+    }
+    last.map(|last| Layers { layer, last })
+}
+
+/// Depth-first search for a shortest augmenting path from `root ∈ L` that
+/// follows the layers; flips the path into `mate` if one is found
+fn augment(
+    adj: &[Vec<usize>],
+    layers: &Layers,
+    current: &mut [usize],
+    mate: &mut [Option<usize>],
+    root: usize,
+) -> bool {
+    if layers.layer[root].is_none() {
+        return false;
+    }
+    // The vertices of L on the path so far; each one below the top left
+    // through its edge adj[u][current[u] - 1]
+    let mut stack = vec![root];
+
+    while let Some(&u) = stack.last() {
+        let d = layers.layer[u].expect("only layered vertices are pushed");
+        let Some(&v) = adj[u].get(current[u]) else {
+            // No augmenting path leaves u in this phase
+            stack.pop();
+            continue;
+        };
+        current[u] += 1;
+
+        match mate[v] {
+            None if d == layers.last => {
+                // Match every vertex on the path to the one it left through
+                for &x in &stack {
+                    let y = adj[x][current[x] - 1];
+                    mate[x] = Some(y);
+                    mate[y] = Some(x);
+                }
+                return true;
+            }
+            Some(w) if d < layers.last && layers.layer[w] == Some(d + 1) => stack.push(w),
+            _ => {}
+        }
+    }
+    false
+}
+
+/// Neighbors of every vertex with each edge taken in both directions
+fn undirected_neighbors<G: Graph>(g: &G) -> Vec<Vec<usize>> {
+    let mut adj = vec![Vec::new(); g.vertex_count()];
+    for u in g.vertices() {
+        for (v, _) in g.neighbors(u) {
+            if !adj[u].contains(&v) {
+                adj[u].push(v);
+            }
+            if !adj[v].contains(&u) {
+                adj[v].push(u);
+            }
+        }
+    }
+    adj
+}
+
+/// Matched edges and `|M|` of the matching found with a maximum flow
+pub(crate) fn describe_bipartite_matching(g: &AdjacencyList, _: usize) -> String {
+    describe(g, bipartite_matching)
+}
+
+/// Matched edges and `|M|` of the matching found by HOPCROFT-KARP
+pub(crate) fn describe_hopcroft_karp(g: &AdjacencyList, _: usize) -> String {
+    describe(g, hopcroft_karp)
+}
+
+/// Matched edges and `|M|`
+fn describe(g: &AdjacencyList, matching: impl Fn(&AdjacencyList) -> Matching) -> String {
+    if bipartition(g).is_none() {
+        return "a bipartite matching needs a bipartite graph\n".to_string();
+    }
+    let m = matching(g);
+    let mut text = String::new();
+    for (u, v) in m.edges() {
+        let _ = writeln!(text, "({}, {})", g.label(u), g.label(v));
+    }
+    let _ = writeln!(text, "Matching size: {}", m.size());
+    text
+}
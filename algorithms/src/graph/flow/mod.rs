@@ -0,0 +1,4 @@
+pub mod ford_fulkerson;
+pub mod matching;
+pub mod network;
+pub mod push_relabel;
@@ -0,0 +1,280 @@
+use std::collections::BTreeMap;
+use std::fmt::Write;
+
+use super::ford_fulkerson::min_cut;
+use crate::graph::adjacency_list::AdjacencyList;
+use crate::graph::representation::{Graph, Weight, default_labels, labels};
+
+/// An edge `(u, v)` of a flow network with capacity `c(u, v)` and flow
+/// `f(u, v)`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FlowEdge {
+    pub from: usize,
+    pub to: usize,
+    pub capacity: Weight,
+    pub flow: Weight,
+}
+
+/// # Flow Network
+///
+/// Directed graph `G = (V, E)` with a nonnegative capacity on every edge
+/// and a flow `f` (CLRS Section 24.1). Every edge `(u, v)` doubles as two
+/// arcs of the residual network `G_f`: `(u, v)` with residual capacity
+/// `c(u, v) - f(u, v)` and the reversal `(v, u)` with residual capacity
+/// `f(u, v)`. Antiparallel and parallel edges need no special treatment.
+///
+/// As a [`Graph`], the network has the edges `E` weighted by capacity.
+///
+/// # Example
+///
+/// ```rust
+/// use algorithms::graph::flow::network::FlowNetwork;
+/// use algorithms::graph::representation::Graph;
+///
+/// let mut net = FlowNetwork::new(3);
+/// net.add_edge(0, 1, 5);
+/// net.add_edge(1, 2, 3);
+///
+/// assert_eq!(net.weight(0, 1), Some(5));
+/// assert_eq!(net.value(0), 0);
+/// assert!(net.residual_network().has_edge(1, 2));
+/// assert!(!net.residual_network().has_edge(1, 0));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FlowNetwork {
+    edges: Vec<FlowEdge>,
+    /// Residual arcs leaving each vertex: arc `2i` is edge `i`, `2i + 1`
+    /// its reversal
+    arcs: Vec<Vec<usize>>,
+    labels: Vec<String>,
+}
+
+impl FlowNetwork {
+    /// Network on the vertices `0..n` without edges.
+    pub fn new(n: usize) -> Self {
+        Self {
+            edges: Vec::new(),
+            arcs: vec![Vec::new(); n],
+            labels: default_labels(n),
+        }
+    }
+
+    /// Network with the vertices, labels and edges of `g`, the edge
+    /// weights as capacities.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `g` is undirected or has a negative weight.
+    pub fn from_graph<G: Graph>(g: &G) -> Self {
+        assert!(g.is_directed(), "a flow network needs a directed graph");
+        let mut net = Self::new(g.vertex_count());
+        for u in g.vertices() {
+            net.labels[u] = g.label(u).to_string();
+            for (v, c) in g.neighbors(u) {
+                net.add_edge(u, v, c);
+            }
+        }
+        net
+    }
+
+    pub fn set_label(&mut self, v: usize, label: &str) {
+        self.labels[v] = label.to_string();
+    }
+
+    /// Add the edge `(u, v)` with capacity `c` and no flow; returns its
+    /// index into [`flow_edges`](Self::flow_edges).
+    ///
+    /// # Panics
+    ///
+    /// Panics if `c < 0` or a vertex is out of range.
+    pub fn add_edge(&mut self, u: usize, v: usize, c: Weight) -> usize {
+        let n = self.arcs.len();
+        assert!(
+            u < n && v < n,
+            "edge ({}, {}) has a vertex outside 0..{}",
+            u,
+            v,
+            n
+        );
+        assert!(c >= 0, "capacity {} of ({}, {}) is negative", c, u, v);
+        let i = self.edges.len();
+        self.edges.push(FlowEdge {
+            from: u,
+            to: v,
+            capacity: c,
+            flow: 0,
+        });
+        self.arcs[u].push(2 * i);
+        self.arcs[v].push(2 * i + 1);
+        i
+    }
+
+    /// `E` with capacities and the current flow.
+    pub fn flow_edges(&self) -> &[FlowEdge] {
+        &self.edges
+    }
+
+    /// `|f|`: the net flow out of `s`.
+    pub fn value(&self, s: usize) -> Weight {
+        self.edges
+            .iter()
+            .map(|e| match (e.from == s, e.to == s) {
+                (true, false) => e.flow,
+                (false, true) => -e.flow,
+                _ => 0,
+            })
+            .sum()
+    }
+
+    /// Set `f(u, v) = 0` on every edge.
+    pub fn clear_flow(&mut self) {
+        for edge in &mut self.edges {
+            edge.flow = 0;
+        }
+    }
+
+    /// # Residual Network
+    ///
+    /// `G_f` with the residual capacities `c_f(u, v) > 0` as weights;
+    /// capacities of parallel arcs add up.
+    pub fn residual_network(&self) -> AdjacencyList {
+        let mut capacities: BTreeMap<(usize, usize), Weight> = BTreeMap::new();
+        for arc in 0..2 * self.edges.len() {
+            let c = self.residual_capacity(arc);
+            if c > 0 {
+                *capacities
+                    .entry((self.tail(arc), self.head(arc)))
+                    .or_default() += c;
+            }
+        }
+        let mut residual = AdjacencyList::directed(self.vertex_count());
+        for v in self.vertices() {
+            residual.set_label(v, self.label(v));
+        }
+        for ((u, v), c) in capacities {
+            residual.add_weighted_edge(u, v, c);
+        }
+        residual
+    }
+
+    /// Residual arcs leaving `u`
+    pub(crate) fn arcs(&self, u: usize) -> &[usize] {
+        &self.arcs[u]
+    }
+
+    /// Vertex the residual arc leaves
+    pub(crate) fn tail(&self, arc: usize) -> usize {
+        let edge = &self.edges[arc / 2];
+        if arc.is_multiple_of(2) {
+            edge.from
+        } else {
+            edge.to
+        }
+    }
+
+    /// Vertex the residual arc enters
+    pub(crate) fn head(&self, arc: usize) -> usize {
+        let edge = &self.edges[arc / 2];
+        if arc.is_multiple_of(2) {
+            edge.to
+        } else {
+            edge.from
+        }
+    }
+
+    /// `c_f` of a residual arc
+    pub(crate) fn residual_capacity(&self, arc: usize) -> Weight {
+        let edge = &self.edges[arc / 2];
+        if arc.is_multiple_of(2) {
+            edge.capacity - edge.flow
+        } else {
+            edge.flow
+        }
+    }
+
+    /// Send `amount` along a residual arc: more flow on an edge or less on
+    /// its reversal
+    pub(crate) fn push(&mut self, arc: usize, amount: Weight) {
+        let edge = &mut self.edges[arc / 2];
+        if arc.is_multiple_of(2) {
+            edge.flow += amount;
+        } else {
+            edge.flow -= amount;
+        }
+    }
+}
+
+impl Graph for FlowNetwork {
+    fn vertex_count(&self) -> usize {
+        self.arcs.len()
+    }
+
+    fn is_directed(&self) -> bool {
+        true
+    }
+
+    fn is_weighted(&self) -> bool {
+        true
+    }
+
+    fn neighbors(&self, u: usize) -> impl Iterator<Item = (usize, Weight)> + '_ {
+        self.arcs[u]
+            .iter()
+            .filter(|&&arc| arc.is_multiple_of(2))
+            .map(|&arc| (self.edges[arc / 2].to, self.edges[arc / 2].capacity))
+    }
+
+    fn weight(&self, u: usize, v: usize) -> Option<Weight> {
+        self.neighbors(u).find(|(x, _)| *x == v).map(|(_, c)| c)
+    }
+
+    fn label(&self, v: usize) -> &str {
+        &self.labels[v]
+    }
+}
+
+/// Flow `f/c` on every edge, `|f|` and a minimum cut, with the vertex
+/// labeled `t` as the sink, or the last vertex if there is none
+pub(crate) fn describe_max_flow(
+    g: &AdjacencyList,
+    s: usize,
+    max_flow: impl Fn(&mut FlowNetwork, usize, usize) -> i64,
+) -> String {
+    if !g.is_directed() {
+        return "a flow network needs a directed graph\n".to_string();
+    }
+    if g.edges().iter().any(|e| e.weight < 0) {
+        return "a flow network needs nonnegative capacities\n".to_string();
+    }
+    let t = g
+        .vertex_named("t")
+        .unwrap_or(g.vertex_count().saturating_sub(1));
+    if s == t {
+        return format!("source and sink are both {}\n", g.label(s));
+    }
+    let mut net = FlowNetwork::from_graph(g);
+    let value = max_flow(&mut net, s, t);
+    let mut text = String::new();
+    for e in net.flow_edges() {
+        let _ = writeln!(
+            text,
+            "({}, {}): {}/{}",
+            g.label(e.from),
+            g.label(e.to),
+            e.flow,
+            e.capacity
+        );
+    }
+    let cut = min_cut(&net, s);
+    let _ = writeln!(
+        text,
+        "Maximum flow from {} to {}: {}\nMinimum cut: S = {{{}}}, T = {{{}}}, c(S, T) = {}",
+        g.label(s),
+        g.label(t),
+        value,
+        labels(g, &cut.s_side),
+        labels(g, &cut.t_side),
+        cut.capacity
+    );
+    text
+}
@@ -0,0 +1,285 @@
+//! # Push-Relabel
+//!
+//! Maximum flow by pushing excess along admissible arcs and relabeling
+//! vertices that have none (CLRS 3rd edition, Sections 26.4-26.5). Instead
+//! of a flow, these algorithms maintain a preflow, which may leave excess
+//! `u.e > 0` at a vertex; heights `u.h` decide where it goes. When no
+//! vertex but `s` and `t` has excess, the preflow is a maximum flow.
+
+use std::collections::VecDeque;
+
+use super::ford_fulkerson::check_terminals;
+use super::network::{FlowNetwork, describe_max_flow};
+use crate::graph::adjacency_list::AdjacencyList;
+use crate::graph::representation::{Graph, Weight};
+
+/// Value of the maximum flow and the number of basic operations performed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PushRelabel {
+    pub value: Weight,
+    pub pushes: usize,
+    pub relabels: usize,
+}
+
+/// # Generic Push-Relabel
+///
+/// Applies a push or relabel operation to some vertex with excess until
+/// none applies (CLRS 3rd edition, Section 26.4). Overflowing vertices wait
+/// in a FIFO queue; each turn performs a single operation on the vertex at
+/// the front. Leaves a maximum flow in `net`.
+///
+/// # Pseudocode (CLRS)
+///
+/// ```text
+/// GENERIC-PUSH-RELABEL(G)
+/// INITIALIZE-PREFLOW(G, s)
+/// while there exists an applicable push or relabel operation
+///     select an applicable push or relabel operation and perform it
+///
+/// PUSH(u, v)
+/// // Applies when: u is overflowing, c_f(u, v) > 0, and u.h = v.h + 1.
+/// Δ_f(u, v) = min(u.e, c_f(u, v))
+/// if (u, v) ∈ E
+///     (u, v).f = (u, v).f + Δ_f(u, v)
+/// else (v, u).f = (v, u).f - Δ_f(u, v)
+/// u.e = u.e - Δ_f(u, v)
+/// v.e = v.e + Δ_f(u, v)
+///
+/// RELABEL(u)
+/// // Applies when: u is overflowing and for all v ∈ V such that
+/// //     (u, v) ∈ E_f, we have u.h ≤ v.h.
+/// u.h = 1 + min {v.h : (u, v) ∈ E_f}
+/// ```
+///
+/// # Example
+///
+/// ```rust
+/// use algorithms::graph::flow::network::FlowNetwork;
+/// use algorithms::graph::flow::push_relabel::generic_push_relabel;
+///
+/// let mut net = FlowNetwork::new(4);
+/// net.add_edge(0, 1, 3);
+/// net.add_edge(0, 2, 2);
+/// net.add_edge(1, 2, 5);
+/// net.add_edge(1, 3, 2);
+/// net.add_edge(2, 3, 3);
+///
+/// assert_eq!(generic_push_relabel(&mut net, 0, 3).value, 5);
+/// assert_eq!(net.value(0), 5);
+/// ```
+///
+/// # Notes
+///
+/// * Performs **O(V²E)** basic operations.
+///
+/// # Panics
+///
+/// Panics if `s == t` or either is not a vertex of `net`.
+pub fn generic_push_relabel(net: &mut FlowNetwork, s: usize, t: usize) -> PushRelabel {
+    let mut state = Preflow::new(net, s, t);
+    let mut overflowing: VecDeque<usize> = net
+        .vertices()
+        .filter(|&v| state.is_overflowing(v))
+        .collect();
+
+    while let Some(&u) = overflowing.front() {
+        let admissible = net
+            .arcs(u)
+            .iter()
+            .copied()
+            .find(|&arc| state.is_admissible(net, arc));
+        match admissible {
+            Some(arc) => {
+                let v = net.head(arc);
+                let was_overflowing = state.is_overflowing(v);
+                state.push(net, arc);
+                if !was_overflowing && state.is_overflowing(v) {
+                    overflowing.push_back(v);
+                }
+            }
+            None => state.relabel(net, u),
+        }
+        if !state.is_overflowing(u) {
+            overflowing.pop_front();
+        }
+    }
+    state.finish(net)
+}
+
+/// # Relabel-to-Front
+///
+/// Push-relabel that discharges vertices in the order of a list `L` of
+/// `V - {s, t}`, moving a vertex to the front whenever discharging it
+/// raised its height (CLRS 3rd edition, Section 26.5). `L` stays a
+/// topological sort of the admissible network, so one pass without
+/// relabels finishes. Leaves a maximum flow in `net`.
+///
+/// # Pseudocode (CLRS)
+///
+/// ```text
+/// RELABEL-TO-FRONT(G, s, t)
+/// INITIALIZE-PREFLOW(G, s)
+/// L = G.V - {s, t}, in any order
+/// for each vertex u ∈ G.V - {s, t}
+///     u.current = u.N.head
+/// u = L.head
+/// while u ≠ NIL
+///     old-height = u.h
+///     DISCHARGE(u)
+///     if u.h > old-height
+///         move u to the front of list L
+///     u = u.next
+///
+/// DISCHARGE(u)
+/// while u.e > 0
+///     v = u.current
+///     if v == NIL
+///         RELABEL(u)
+///         u.current = u.N.head
+///     elseif c_f(u, v) > 0 and u.h == v.h + 1
+///         PUSH(u, v)
+///     else u.current = v.next-neighbor
+/// ```
+///
+/// # Example
+///
+/// ```rust
+/// use algorithms::graph::flow::network::FlowNetwork;
+/// use algorithms::graph::flow::push_relabel::relabel_to_front;
+///
+/// let mut net = FlowNetwork::new(4);
+/// net.add_edge(0, 1, 3);
+/// net.add_edge(0, 2, 2);
+/// net.add_edge(1, 2, 5);
+/// net.add_edge(1, 3, 2);
+/// net.add_edge(2, 3, 3);
+///
+/// assert_eq!(relabel_to_front(&mut net, 0, 3).value, 5);
+/// ```
+///
+/// # Notes
+///
+/// * Runs in **O(V³)** time.
+/// * The neighbor list `u.N` is the list of residual arcs at `u`, in the
+///   order the edges were added.
+///
+/// # Panics
+///
+/// Panics if `s == t` or either is not a vertex of `net`.
+pub fn relabel_to_front(net: &mut FlowNetwork, s: usize, t: usize) -> PushRelabel {
+    let mut state = Preflow::new(net, s, t);
+    let mut list: Vec<usize> = net.vertices().filter(|&v| v != s && v != t).collect();
+    // u.current as a position in net.arcs(u)
+    let mut current = vec![0; net.vertex_count()];
+
+    let mut i = 0;
+    while i < list.len() {
+        let u = list[i];
+        let old_height = state.height[u];
+        state.discharge(net, u, &mut current[u]);
+        if state.height[u] > old_height {
+            list[..=i].rotate_right(1);
+            i = 0;
+        }
+        i += 1;
+    }
+    state.finish(net)
+}
+
+/// Heights, excesses and operation counts of a preflow
+struct Preflow {
+    s: usize,
+    t: usize,
+    height: Vec<usize>,
+    excess: Vec<Weight>,
+    pushes: usize,
+    relabels: usize,
+}
+
+impl Preflow {
+    /// `INITIALIZE-PREFLOW(G, s)`: saturate every edge leaving `s`
+    fn new(net: &mut FlowNetwork, s: usize, t: usize) -> Self {
+        check_terminals(net, s, t);
+        net.clear_flow();
+        let n = net.vertex_count();
+        let mut state = Self {
+            s,
+            t,
+            height: vec![0; n],
+            excess: vec![0; n],
+            pushes: 0,
+            relabels: 0,
+        };
+        state.height[s] = n;
+        for arc in net.arcs(s).to_vec() {
+            let c = net.residual_capacity(arc);
+            if arc.is_multiple_of(2) && c > 0 {
+                net.push(arc, c);
+                state.excess[net.head(arc)] += c;
+                state.excess[s] -= c;
+            }
+        }
+        state
+    }
+
+    /// `u.e > 0` for `u ∉ {s, t}`
+    fn is_overflowing(&self, u: usize) -> bool {
+        u != self.s && u != self.t && self.excess[u] > 0
+    }
+
+    /// `c_f(u, v) > 0` and `u.h == v.h + 1`
+    fn is_admissible(&self, net: &FlowNetwork, arc: usize) -> bool {
+        net.residual_capacity(arc) > 0
+            && self.height[net.tail(arc)] == self.height[net.head(arc)] + 1
+    }
+
+    /// `PUSH(u, v)` along an admissible arc
+    fn push(&mut self, net: &mut FlowNetwork, arc: usize) {
+        let (u, v) = (net.tail(arc), net.head(arc));
+        let delta = self.excess[u].min(net.residual_capacity(arc));
+        net.push(arc, delta);
+        self.excess[u] -= delta;
+        self.excess[v] += delta;
+        self.pushes += 1;
+    }
+
+    /// `RELABEL(u)`
+    fn relabel(&mut self, net: &FlowNetwork, u: usize) {
+        let lowest = net
+            .arcs(u)
+            .iter()
+            .filter(|&&arc| net.residual_capacity(arc) > 0)
+            .map(|&arc| self.height[net.head(arc)])
+            .min()
+            .expect("an overflowing vertex has a residual arc");
+        self.height[u] = lowest + 1;
+        self.relabels += 1;
+    }
+
+    /// `DISCHARGE(u)`; `current` is the position of `u.current` in `u.N`
+    fn discharge(&mut self, net: &mut FlowNetwork, u: usize, current: &mut usize) {
+        while self.excess[u] > 0 {
+            match net.arcs(u).get(*current).copied() {
+                None => {
+                    self.relabel(net, u);
+                    *current = 0;
+                }
+                Some(arc) if self.is_admissible(net, arc) => self.push(net, arc),
+                Some(_) => *current += 1,
+            }
+        }
+    }
+
+    fn finish(self, net: &FlowNetwork) -> PushRelabel {
+        PushRelabel {
+            value: net.value(self.s),
+            pushes: self.pushes,
+            relabels: self.relabels,
+        }
+    }
+}
+
+/// Maximum flow from `s` found by RELABEL-TO-FRONT
+pub(crate) fn describe(g: &AdjacencyList, s: usize) -> String {
+    describe_max_flow(g, s, |net, s, t| relabel_to_front(net, s, t).value)
+}
@@ -0,0 +1,13 @@
+pub mod adjacency_list;
+pub mod adjacency_matrix;
+pub mod apsp;
+pub mod bfs;
+pub mod biconnected;
+pub mod dfs;
+pub mod flow;
+pub mod mst;
+pub mod parse;
+pub mod representation;
+pub mod scc;
+pub mod sssp;
+pub mod topological_sort;
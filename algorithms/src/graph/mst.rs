@@ -0,0 +1,326 @@
+//! # Minimum Spanning Trees
+//!
+//! Kruskal's and Prim's algorithms (CLRS Chapter 21) and the second-best
+//! minimum spanning tree of Problem 21-1. All of them take a connected,
+//! undirected, weighted graph; on a disconnected graph they return a
+//! minimum spanning forest.
+
+use std::fmt::Write;
+
+use super::adjacency_list::AdjacencyList;
+use super::representation::{Edge, Graph, Weight};
+use crate::disjoint_set::forest::DisjointSetForest;
+use crate::heap::min_queue::MinQueue;
+use crate::heap::priority_queue::MinPriorityQueue;
+
+/// Edges of a spanning tree and their total weight `w(T)`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SpanningTree {
+    pub edges: Vec<Edge>,
+    pub weight: Weight,
+}
+
+impl SpanningTree {
+    fn from_edges(edges: Vec<Edge>) -> Self {
+        let weight = edges.iter().map(|e| e.weight).sum();
+        Self { edges, weight }
+    }
+
+    /// Whether `{u, v}` is a tree edge.
+    pub fn contains(&self, u: usize, v: usize) -> bool {
+        self.edges
+            .iter()
+            .any(|e| (e.from, e.to) == (u, v) || (e.from, e.to) == (v, u))
+    }
+}
+
+/// # MST-Kruskal
+///
+/// Grows a forest by adding the lightest edge that connects two different
+/// trees, tracking the trees in a disjoint-set forest (CLRS Section 21.2).
+///
+/// # Pseudocode (CLRS)
+///
+/// ```text
+/// MST-KRUSKAL(G, w)
+/// A = ∅
+/// for each vertex v ∈ G.V
+///     MAKE-SET(v)
+/// create a single list of the edges in G.E
+/// sort the list of edges into monotonically increasing order by weight w
+/// for each edge (u, v) taken from the sorted list in order
+///     if FIND-SET(u) ≠ FIND-SET(v)
+///         A = A ∪ {(u, v)}
+///         UNION(u, v)
+/// return A
+/// ```
+///
+/// # Example
+///
+/// ```rust
+/// use algorithms::graph::mst::mst_kruskal;
+/// use algorithms::graph::parse::parse_edge_list;
+///
+/// let g = parse_edge_list("undirected\na b 4\nb c 2\na c 3\nc d 5\n").unwrap();
+/// let tree = mst_kruskal(&g);
+///
+/// assert_eq!(tree.weight, 10);
+/// assert!(!tree.contains(0, 1));
+/// ```
+///
+/// # Notes
+///
+/// * Runs in **O(E lg V)** time, dominated by sorting the edges.
+/// * Edges of equal weight are taken in the order of [`Graph::edges`].
+///
+/// # Panics
+///
+/// Panics if `g` is directed.
+pub fn mst_kruskal<G: Graph>(g: &G) -> SpanningTree {
+    assert!(
+        !g.is_directed(),
+        "a spanning tree needs an undirected graph"
+    );
+    let edges = sorted_edges(g);
+    let in_tree = kruskal(g.vertex_count(), &edges);
+    SpanningTree::from_edges(
+        edges
+            .into_iter()
+            .zip(in_tree)
+            .filter_map(|(edge, taken)| taken.then_some(edge))
+            .collect(),
+    )
+}
+
+/// # MST-Prim
+///
+/// Grows a single tree from the root `r`, always adding the lightest edge
+/// leaving it (CLRS Section 21.2). The vertices not yet in the tree wait in
+/// `queue` keyed by the weight of their lightest edge to the tree; any
+/// [`MinQueue`] works, and the choice sets the running time.
+///
+/// # Pseudocode (CLRS)
+///
+/// ```text
+/// MST-PRIM(G, w, r)
+/// for each vertex u ∈ G.V
+///     u.key = ∞
+///     u.π = NIL
+/// r.key = 0
+/// Q = ∅
+/// for each vertex u ∈ G.V
+///     INSERT(Q, u)
+/// while Q ≠ ∅
+///     u = EXTRACT-MIN(Q)          // add u to the tree
+///     for each vertex v in G.Adj[u]   // update keys of u's non-tree neighbors
+///         if v ∈ Q and w(u, v) < v.key
+///             v.π = u
+///             v.key = w(u, v)
+///             DECREASE-KEY(Q, v, w(u, v))
+/// ```
+///
+/// # Example
+///
+/// ```rust
+/// use algorithms::graph::mst::mst_prim;
+/// use algorithms::graph::parse::parse_edge_list;
+/// use algorithms::heap::fibonacci_heap::FibonacciHeap;
+/// use algorithms::heap::min_queue::ArrayQueue;
+/// use algorithms::heap::priority_queue::MinPriorityQueue;
+///
+/// let g = parse_edge_list("undirected\na b 4\nb c 2\na c 3\nc d 5\n").unwrap();
+///
+/// assert_eq!(mst_prim(&g, 0, &mut MinPriorityQueue::new()).weight, 10);
+/// assert_eq!(mst_prim(&g, 0, &mut FibonacciHeap::new()).weight, 10);
+/// assert_eq!(mst_prim(&g, 0, &mut ArrayQueue::new()).weight, 10);
+/// ```
+///
+/// # Notes
+///
+/// * Binary heap: **O(E lg V)**; Fibonacci heap: **O(E + V lg V)**;
+///   unsorted array: **O(V²)**, the best choice for dense graphs.
+/// * Tree edges are `(v.π, v)` in order of `v`.
+///
+/// # Panics
+///
+/// Panics if `g` is directed, if `r` is not a vertex or if `queue` is not
+/// empty.
+pub fn mst_prim<G, Q>(g: &G, r: usize, queue: &mut Q) -> SpanningTree
+where
+    G: Graph,
+    Q: MinQueue<Weight, usize>,
+{
+    assert!(
+        !g.is_directed(),
+        "a spanning tree needs an undirected graph"
+    );
+    assert!(r < g.vertex_count(), "root {} is not a vertex of G", r);
+    assert!(queue.is_empty(), "queue must start empty");
+
+    let mut key = vec![Weight::MAX; g.vertex_count()];
+    let mut pi = vec![None; g.vertex_count()];
+    key[r] = 0;
+    let handles: Vec<_> = g.vertices().map(|u| queue.insert(key[u], u)).collect();
+
+    while let Some((_, u)) = queue.extract_min() {
+        for (v, w) in g.neighbors(u) {
+            if queue.contains(handles[v]) && w < key[v] {
+                pi[v] = Some(u);
+                key[v] = w;
+                queue.decrease_key(handles[v], w);
+            }
+        }
+    }
+
+    SpanningTree::from_edges(
+        g.vertices()
+            .filter_map(|v| pi[v].map(|u| Edge::new(u, v, key[v])))
+            .collect(),
+    )
+}
+
+/// # Second-Best Minimum Spanning Tree (CLRS Problem 21-1)
+///
+/// A spanning tree of least weight among all except one minimum spanning
+/// tree `T`. It differs from `T` in a single swap: add the nontree edge
+/// `(u, v)` and drop `max[u, v]`, the heaviest edge on the path from `u` to
+/// `v` in `T`, choosing the pair that adds the least weight. Returns `None`
+/// if `G` has no nontree edge, i.e. is itself a forest.
+///
+/// # Example
+///
+/// ```rust
+/// use algorithms::graph::mst::second_best_mst;
+/// use algorithms::graph::parse::parse_edge_list;
+///
+/// let g = parse_edge_list("undirected\na b 4\nb c 2\na c 3\nc d 5\n").unwrap();
+/// let second = second_best_mst(&g).unwrap();
+///
+/// assert_eq!(second.weight, 11);
+/// assert!(second.contains(0, 1));
+/// ```
+///
+/// # Notes
+///
+/// * Runs in **O(V²)** time: `max[u, v]` for all pairs comes from one
+///   search of `T` per vertex (Problem 21-1 c).
+///
+/// # Panics
+///
+/// Panics if `g` is directed.
+pub fn second_best_mst<G: Graph>(g: &G) -> Option<SpanningTree> {
+    assert!(
+        !g.is_directed(),
+        "a spanning tree needs an undirected graph"
+    );
+    let n = g.vertex_count();
+    let edges = sorted_edges(g);
+    let in_tree = kruskal(n, &edges);
+
+    let mut tree = AdjacencyList::undirected(n);
+    for (edge, _) in edges.iter().zip(&in_tree).filter(|(_, taken)| **taken) {
+        tree.add_weighted_edge(edge.from, edge.to, edge.weight);
+    }
+    let max = heaviest_tree_edges(&tree);
+
+    // (weight gained, nontree edge to add, tree edge to drop)
+    let (_, add, drop) = edges
+        .iter()
+        .zip(&in_tree)
+        .filter(|(edge, taken)| !**taken && edge.from != edge.to)
+        .filter_map(|(edge, _)| {
+            let heaviest = max[edge.from][edge.to]?;
+            Some((edge.weight - heaviest.weight, *edge, heaviest))
+        })
+        .min_by_key(|(gain, _, _)| *gain)?;
+
+    let mut result: Vec<Edge> = edges
+        .into_iter()
+        .zip(in_tree)
+        .filter(|(edge, taken)| *taken && !same_edge(edge, &drop))
+        .map(|(edge, _)| edge)
+        .collect();
+    result.push(add);
+    Some(SpanningTree::from_edges(result))
+}
+
+/// `max[u, v]` for every pair of vertices in the same tree of the forest
+fn heaviest_tree_edges(tree: &AdjacencyList) -> Vec<Vec<Option<Edge>>> {
+    let n = tree.vertex_count();
+    let mut max: Vec<Vec<Option<Edge>>> = vec![vec![None; n]; n];
+    for u in tree.vertices() {
+        let mut visited = vec![false; n];
+        visited[u] = true;
+        let mut stack = vec![u];
+        while let Some(x) = stack.pop() {
+            for (y, w) in tree.neighbors(x) {
+                if visited[y] {
+                    continue;
+                }
+                visited[y] = true;
+                let edge = Edge::new(x, y, w);
+                max[u][y] = match max[u][x] {
+                    Some(heaviest) if heaviest.weight >= w => Some(heaviest),
+                    _ => Some(edge),
+                };
+                stack.push(y);
+            }
+        }
+    }
+    max
+}
+
+/// Lines 4-9 of MST-KRUSKAL: which of the sorted `edges` join the tree
+fn kruskal(n: usize, edges: &[Edge]) -> Vec<bool> {
+    let mut sets = DisjointSetForest::new();
+    for _ in 0..n {
+        sets.make_set();
+    }
+    edges
+        .iter()
+        .map(|edge| sets.union(edge.from, edge.to))
+        .collect()
+}
+
+/// `E` by increasing weight, stable
+fn sorted_edges<G: Graph>(g: &G) -> Vec<Edge> {
+    let mut edges = g.edges();
+    edges.sort_by_key(|e| e.weight);
+    edges
+}
+
+/// Whether two undirected edges have the same endpoints and weight
+fn same_edge(a: &Edge, b: &Edge) -> bool {
+    a.weight == b.weight
+        && (a.from.min(a.to), a.from.max(a.to)) == (b.from.min(b.to), b.from.max(b.to))
+}
+
+/// Tree edges and total weight of the spanning tree MST-KRUSKAL grows
+pub(crate) fn describe_kruskal(g: &AdjacencyList, _: usize) -> String {
+    describe(g, mst_kruskal)
+}
+
+/// Tree edges and total weight of the spanning tree MST-PRIM grows from `r`
+pub(crate) fn describe_prim(g: &AdjacencyList, r: usize) -> String {
+    describe(g, |g| mst_prim(g, r, &mut MinPriorityQueue::new()))
+}
+
+/// Tree edges and total weight of a minimum spanning tree
+fn describe(g: &AdjacencyList, mst: impl Fn(&AdjacencyList) -> SpanningTree) -> String {
+    if g.is_directed() {
+        return "a spanning tree needs an undirected graph\n".to_string();
+    }
+    let tree = mst(g);
+    let mut text = String::new();
+    for edge in &tree.edges {
+        let _ = writeln!(
+            text,
+            "({}, {}): {}",
+            g.label(edge.from),
+            g.label(edge.to),
+            edge.weight
+        );
+    }
+    let _ = writeln!(text, "Total weight: {}", tree.weight);
+    text
+}
@@ -0,0 +1,442 @@
+//! # Graph Loaders
+//!
+//! Read graphs from two text formats into an [`AdjacencyList`]. Vertices
+//! are numbered in order of first appearance and keep their names as labels.
+//!
+//! **Edge list**: one edge `u v` or weighted edge `u v w` per line, a lone
+//! `u` declares an isolated vertex. An optional first line `directed` or
+//! `undirected` sets the kind (default: directed); `#` starts a comment.
+//!
+//! ```text
+//! undirected
+//! # Figure 20.3
+//! r s
+//! s w 4
+//! ```
+//!
+//! **DOT**: the subset of Graphviz `graph { a -- b }` / `digraph { a -> b }`
+//! without subgraphs. Edge chains `a -> b -> c` are allowed; a numeric
+//! `weight` (or else `label`) attribute becomes the edge weight. Other
+//! attributes and attribute statements are ignored.
+
+use std::collections::HashMap;
+use std::error::Error;
+use std::fmt;
+
+use super::adjacency_list::AdjacencyList;
+use super::representation::Weight;
+
+/// Error of a graph loader, with the 1-based line it occurred on.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    pub line: usize,
+    pub message: String,
+}
+
+impl ParseError {
+    fn new(line: usize, message: impl Into<String>) -> Self {
+        Self {
+            line,
+            message: message.into(),
+        }
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "line {}: {}", self.line, self.message)
+    }
+}
+
+impl Error for ParseError {}
+
+/// # Parse Edge List
+///
+/// Reads the edge-list format described in the [module docs](self).
+///
+/// # Example
+///
+/// ```rust
+/// use algorithms::graph::parse::parse_edge_list;
+/// use algorithms::graph::representation::Graph;
+///
+/// let g = parse_edge_list("undirected\nu v\nv w 3\n").unwrap();
+///
+/// assert_eq!(g.vertex_count(), 3);
+/// assert_eq!(g.weight(2, 1), Some(3));
+/// assert_eq!(g.label(2), "w");
+/// ```
+pub fn parse_edge_list(text: &str) -> Result<AdjacencyList, ParseError> {
+    let mut lines = text
+        .lines()
+        .enumerate()
+        .map(|(i, line)| (i + 1, line.split('#').next().unwrap_or("").trim()))
+        .filter(|(_, line)| !line.is_empty())
+        .peekable();
+
+    let directed = !matches!(lines.peek(), Some((_, "undirected")));
+    if matches!(lines.peek(), Some((_, "directed" | "undirected"))) {
+        lines.next();
+    }
+
+    let mut builder = Builder::new(directed);
+    for (number, line) in lines {
+        let fields: Vec<&str> = line.split_whitespace().collect();
+        match fields.as_slice() {
+            [u] => {
+                builder.vertex(u);
+            }
+            [u, v] => builder.edge(u, v, None),
+            [u, v, w] => {
+                let w = w
+                    .parse::<Weight>()
+                    .map_err(|_| ParseError::new(number, format!("invalid weight '{}'", w)))?;
+                builder.edge(u, v, Some(w));
+            }
+            _ => {
+                return Err(ParseError::new(
+                    number,
+                    "expected 'u', 'u v' or 'u v weight'",
+                ));
+            }
+        }
+    }
+    Ok(builder.graph)
+}
+
+/// # Parse DOT
+///
+/// Reads the DOT subset described in the [module docs](self).
+///
+/// # Example
+///
+/// ```rust
+/// use algorithms::graph::parse::parse_dot;
+/// use algorithms::graph::representation::Graph;
+///
+/// let g = parse_dot("digraph { s -> t [weight=10]; t -> x -> s }").unwrap();
+///
+/// assert!(g.is_directed());
+/// assert_eq!(g.weight(0, 1), Some(10));
+/// assert!(g.has_edge(2, 0));
+/// ```
+pub fn parse_dot(text: &str) -> Result<AdjacencyList, ParseError> {
+    let tokens = tokenize_dot(text)?;
+    let mut parser = DotParser { tokens, pos: 0 };
+
+    if parser.peek_is("strict") {
+        parser.pos += 1;
+    }
+    let directed = match parser.next()? {
+        (_, Token::Id(kind)) if kind == "digraph" => true,
+        (_, Token::Id(kind)) if kind == "graph" => false,
+        (line, _) => return Err(ParseError::new(line, "expected 'graph' or 'digraph'")),
+    };
+    if let Some((_, Token::Id(_))) = parser.peek() {
+        parser.pos += 1; // graph name
+    }
+    parser.expect(Token::Punct("{"))?;
+
+    let mut builder = Builder::new(directed);
+    let edge_op = if directed { "->" } else { "--" };
+    loop {
+        let (line, token) = parser.next()?;
+        let id = match token {
+            Token::Punct("}") => break,
+            Token::Punct(";" | ",") => continue,
+            Token::Id(id) => id,
+            Token::Punct(p) => {
+                return Err(ParseError::new(line, format!("unexpected '{}'", p)));
+            }
+        };
+
+        // Attribute statements: `graph [...]`, `node [...]`, `edge [...]`, `a = b`
+        if matches!(id.as_str(), "graph" | "node" | "edge") {
+            parser.attributes()?;
+            continue;
+        }
+        if id == "subgraph" {
+            return Err(ParseError::new(line, "subgraphs are not supported"));
+        }
+        if parser.peek_is_punct("=") {
+            parser.pos += 1;
+            parser.id()?;
+            continue;
+        }
+
+        let mut chain = vec![id];
+        while let Some((line, Token::Punct(op))) = parser.peek() {
+            if *op == "->" || *op == "--" {
+                if *op != edge_op {
+                    let line = *line;
+                    return Err(ParseError::new(
+                        line,
+                        format!("'{}' is not allowed here, use '{}'", op, edge_op),
+                    ));
+                }
+                parser.pos += 1;
+                chain.push(parser.id()?);
+            } else {
+                break;
+            }
+        }
+        let attributes = parser.attributes()?;
+
+        if chain.len() == 1 {
+            builder.vertex(&chain[0]);
+            continue;
+        }
+        let weight = match attributes.get("weight").or_else(|| attributes.get("label")) {
+            Some((line, w)) => Some(
+                w.parse::<Weight>()
+                    .map_err(|_| ParseError::new(*line, format!("invalid weight '{}'", w)))?,
+            ),
+            None => None,
+        };
+        for pair in chain.windows(2) {
+            builder.edge(&pair[0], &pair[1], weight);
+        }
+    }
+
+    if let Some((line, _)) = parser.peek() {
+        return Err(ParseError::new(*line, "unexpected input after '}'"));
+    }
+    Ok(builder.graph)
+}
+
+/// # Parse Graph
+///
+/// Reads DOT if the first word is `graph`, `digraph` or `strict`, and an
+/// edge list otherwise.
+pub fn parse_graph(text: &str) -> Result<AdjacencyList, ParseError> {
+    let first_word = text
+        .lines()
+        .map(str::trim)
+        .find(|line| !line.is_empty() && !line.starts_with('#') && !line.starts_with("//"))
+        .and_then(|line| line.split_whitespace().next())
+        .map(|word| word.trim_end_matches('{'));
+    match first_word {
+        Some("graph" | "digraph" | "strict") => parse_dot(text),
+        _ => parse_edge_list(text),
+    }
+}
+
+/// Adds named vertices and edges to a growing graph
+struct Builder {
+    graph: AdjacencyList,
+    index: HashMap<String, usize>,
+}
+
+impl Builder {
+    fn new(directed: bool) -> Self {
+        let graph = if directed {
+            AdjacencyList::directed(0)
+        } else {
+            AdjacencyList::undirected(0)
+        };
+        Self {
+            graph,
+            index: HashMap::new(),
+        }
+    }
+
+    fn vertex(&mut self, name: &str) -> usize {
+        if let Some(&v) = self.index.get(name) {
+            return v;
+        }
+        let v = self.graph.add_vertex(name);
+        self.index.insert(name.to_string(), v);
+        v
+    }
+
+    fn edge(&mut self, u: &str, v: &str, weight: Option<Weight>) {
+        let (u, v) = (self.vertex(u), self.vertex(v));
+        match weight {
+            Some(w) => self.graph.add_weighted_edge(u, v, w),
+            None => self.graph.add_edge(u, v),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Token {
+    Id(String),
+    Punct(&'static str),
+}
+
+/// Split DOT source into identifiers and punctuation, dropping comments
+fn tokenize_dot(text: &str) -> Result<Vec<(usize, Token)>, ParseError> {
+    let chars: Vec<char> = text.chars().collect();
+    let mut tokens = Vec::new();
+    let mut line = 1;
+    let mut i = 0;
+
+    while i < chars.len() {
+        let c = chars[i];
+        let next = chars.get(i + 1).copied();
+        match c {
+            '\n' => {
+                line += 1;
+                i += 1;
+            }
+            c if c.is_whitespace() => i += 1,
+            '#' if is_line_start(&chars, i) => {
+                while i < chars.len() && chars[i] != '\n' {
+                    i += 1;
+                }
+            }
+            '/' if next == Some('/') => {
+                while i < chars.len() && chars[i] != '\n' {
+                    i += 1;
+                }
+            }
+            '/' if next == Some('*') => {
+                i += 2;
+                while i < chars.len() && !(chars[i] == '*' && chars.get(i + 1) == Some(&'/')) {
+                    if chars[i] == '\n' {
+                        line += 1;
+                    }
+                    i += 1;
+                }
+                i += 2;
+            }
+            '-' if next == Some('>') || next == Some('-') => {
+                tokens.push((
+                    line,
+                    Token::Punct(if next == Some('>') { "->" } else { "--" }),
+                ));
+                i += 2;
+            }
+            '{' | '}' | '[' | ']' | ';' | ',' | '=' => {
+                let punct = match c {
+                    '{' => "{",
+                    '}' => "}",
+                    '[' => "[",
+                    ']' => "]",
+                    ';' => ";",
+                    ',' => ",",
+                    _ => "=",
+                };
+                tokens.push((line, Token::Punct(punct)));
+                i += 1;
+            }
+            '"' => {
+                let start_line = line;
+                let mut id = String::new();
+                i += 1;
+                loop {
+                    match chars.get(i) {
+                        None => return Err(ParseError::new(start_line, "unterminated string")),
+                        Some('"') => break,
+                        Some('\\') if chars.get(i + 1) == Some(&'"') => {
+                            id.push('"');
+                            i += 1;
+                        }
+                        Some(&ch) => {
+                            if ch == '\n' {
+                                line += 1;
+                            }
+                            id.push(ch);
+                        }
+                    }
+                    i += 1;
+                }
+                tokens.push((start_line, Token::Id(id)));
+                i += 1;
+            }
+            c if c.is_alphanumeric() || c == '_' || c == '.' || c == '-' => {
+                let start = i;
+                i += 1;
+                while i < chars.len()
+                    && (chars[i].is_alphanumeric() || chars[i] == '_' || chars[i] == '.')
+                {
+                    i += 1;
+                }
+                tokens.push((line, Token::Id(chars[start..i].iter().collect())));
+            }
+            other => return Err(ParseError::new(line, format!("unexpected '{}'", other))),
+        }
+    }
+    Ok(tokens)
+}
+
+/// Whether only whitespace precedes position `i` on its line
+fn is_line_start(chars: &[char], i: usize) -> bool {
+    chars[..i]
+        .iter()
+        .rev()
+        .take_while(|&&c| c != '\n')
+        .all(|c| c.is_whitespace())
+}
+
+struct DotParser {
+    tokens: Vec<(usize, Token)>,
+    pos: usize,
+}
+
+impl DotParser {
+    fn peek(&self) -> Option<&(usize, Token)> {
+        self.tokens.get(self.pos)
+    }
+
+    fn peek_is(&self, id: &str) -> bool {
+        matches!(self.peek(), Some((_, Token::Id(s))) if s == id)
+    }
+
+    fn peek_is_punct(&self, punct: &str) -> bool {
+        matches!(self.peek(), Some((_, Token::Punct(p))) if *p == punct)
+    }
+
+    fn last_line(&self) -> usize {
+        self.tokens.last().map_or(1, |(line, _)| *line)
+    }
+
+    fn next(&mut self) -> Result<(usize, Token), ParseError> {
+        let token = self
+            .tokens
+            .get(self.pos)
+            .cloned()
+            .ok_or_else(|| ParseError::new(self.last_line(), "unexpected end of input"))?;
+        self.pos += 1;
+        Ok(token)
+    }
+
+    fn expect(&mut self, expected: Token) -> Result<(), ParseError> {
+        let (line, token) = self.next()?;
+        if token != expected {
+            return Err(ParseError::new(line, format!("expected {:?}", expected)));
+        }
+        Ok(())
+    }
+
+    fn id(&mut self) -> Result<String, ParseError> {
+        match self.next()? {
+            (_, Token::Id(id)) => Ok(id),
+            (line, _) => Err(ParseError::new(line, "expected an identifier")),
+        }
+    }
+
+    /// Optional `[a = b, c = d; ...]` lists, keyed by attribute name
+    fn attributes(&mut self) -> Result<HashMap<String, (usize, String)>, ParseError> {
+        let mut attributes = HashMap::new();
+        while self.peek_is_punct("[") {
+            self.pos += 1;
+            loop {
+                let (line, token) = self.next()?;
+                match token {
+                    Token::Punct("]") => break,
+                    Token::Punct(";" | ",") => continue,
+                    Token::Id(key) => {
+                        self.expect(Token::Punct("="))?;
+                        let value = self.id()?;
+                        attributes.insert(key, (line, value));
+                    }
+                    Token::Punct(p) => {
+                        return Err(ParseError::new(line, format!("unexpected '{}'", p)));
+                    }
+                }
+            }
+        }
+        Ok(attributes)
+    }
+}
@@ -0,0 +1,98 @@
+//! # Graph Representations
+//!
+//! The [`Graph`] trait shared by [`AdjacencyList`](super::adjacency_list::AdjacencyList)
+//! and [`AdjacencyMatrix`](super::adjacency_matrix::AdjacencyMatrix)
+//! (CLRS Section 20.1). Vertices are the zero-based indices
+//! `0..vertex_count()`; every vertex also carries a label for printing.
+//! Unweighted graphs store weight `1` on every edge.
+
+/// Weight of an edge; `1` for every edge of an unweighted graph.
+pub type Weight = i64;
+
+/// Edge `(from, to)` with its weight.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Edge {
+    pub from: usize,
+    pub to: usize,
+    pub weight: Weight,
+}
+
+impl Edge {
+    pub fn new(from: usize, to: usize, weight: Weight) -> Self {
+        Self { from, to, weight }
+    }
+}
+
+/// # Graph
+///
+/// Read-only view of a graph `G = (V, E)` common to both representations.
+pub trait Graph {
+    /// `|V|`
+    fn vertex_count(&self) -> usize;
+
+    /// Whether edges are ordered pairs.
+    fn is_directed(&self) -> bool;
+
+    /// Whether edge weights are meaningful.
+    fn is_weighted(&self) -> bool;
+
+    /// `G.Adj[u]`: every `(v, w(u, v))` with `(u, v) ∈ E`.
+    fn neighbors(&self, u: usize) -> impl Iterator<Item = (usize, Weight)> + '_;
+
+    /// `w(u, v)`, or `None` if `(u, v) ∉ E`.
+    fn weight(&self, u: usize, v: usize) -> Option<Weight>;
+
+    /// Printable name of vertex `v`.
+    fn label(&self, v: usize) -> &str;
+
+    /// `V` as indices.
+    fn vertices(&self) -> std::ops::Range<usize> {
+        0..self.vertex_count()
+    }
+
+    fn has_edge(&self, u: usize, v: usize) -> bool {
+        self.weight(u, v).is_some()
+    }
+
+    /// Out-degree (directed) or degree (undirected) of `u`.
+    fn degree(&self, u: usize) -> usize {
+        self.neighbors(u).count()
+    }
+
+    /// `E`, ordered by source vertex. An undirected edge `{u, v}` is listed
+    /// once, as `(min(u, v), max(u, v))`.
+    fn edges(&self) -> Vec<Edge> {
+        let directed = self.is_directed();
+        self.vertices()
+            .flat_map(|u| {
+                self.neighbors(u)
+                    .filter(move |&(v, _)| directed || u <= v)
+                    .map(move |(v, w)| Edge::new(u, v, w))
+            })
+            .collect()
+    }
+
+    /// `|E|`
+    fn edge_count(&self) -> usize {
+        self.edges().len()
+    }
+
+    /// Index of the vertex with label `label`.
+    fn vertex_named(&self, label: &str) -> Option<usize> {
+        self.vertices().find(|&v| self.label(v) == label)
+    }
+}
+
+/// Labels `"0"`, `"1"`, ... for a graph without names
+pub(crate) fn default_labels(n: usize) -> Vec<String> {
+    (0..n).map(|v| v.to_string()).collect()
+}
+
+/// Labels of `vertices` joined by spaces
+pub(crate) fn labels<G: Graph>(g: &G, vertices: &[usize]) -> String {
+    vertices
+        .iter()
+        .map(|&v| g.label(v))
+        .collect::<Vec<_>>()
+        .join(" ")
+}
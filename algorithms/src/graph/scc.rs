@@ -0,0 +1,282 @@
+use std::fmt::Write;
+
+use super::adjacency_list::AdjacencyList;
+use super::dfs::{dfs, dfs_in_order};
+use super::representation::{Graph, labels};
+
+/// # Strongly Connected Components (Kosaraju)
+///
+/// Decomposes a directed graph into its strongly connected components with
+/// two depth-first searches, the second on `G^T` in order of decreasing
+/// finishing time (CLRS Section 20.5). Every tree of the second forest is
+/// one component.
+///
+/// # Pseudocode (CLRS)
+///
+/// ```text
+/// STRONGLY-CONNECTED-COMPONENTS(G)
+/// call DFS(G) to compute finish times u.f for each vertex u
+/// create G^T
+/// call DFS(G^T), but in the main loop of DFS, consider the vertices in
+///     order of decreasing u.f (as computed in line 1)
+/// output the vertices of each tree in the depth-first forest formed in
+///     line 3 as a separate strongly connected component
+/// ```
+///
+/// # Example
+///
+/// ```rust
+/// use algorithms::graph::parse::parse_edge_list;
+/// use algorithms::graph::scc::strongly_connected_components;
+///
+/// let g = parse_edge_list("a b\nb a\nb c\nc d\nd c\n").unwrap();
+///
+/// assert_eq!(strongly_connected_components(&g), [vec![0, 1], vec![2, 3]]);
+/// ```
+///
+/// # Notes
+///
+/// * Runs in **Θ(V + E)** time.
+/// * Components come in topological order of the component graph and
+///   list their vertices in increasing order.
+pub fn strongly_connected_components<G: Graph>(g: &G) -> Vec<Vec<usize>> {
+    let first = dfs(g);
+    let transpose = AdjacencyList::from_graph(g).transpose();
+    let second = dfs_in_order(&transpose, &first.by_decreasing_finish());
+
+    // Parents are discovered before their children; times are distinct
+    let mut by_discovery = vec![None; 2 * g.vertex_count() + 1];
+    for v in g.vertices() {
+        by_discovery[second.d[v]] = Some(v);
+    }
+    let mut component_of = vec![0; g.vertex_count()];
+    let mut count = 0;
+    for v in by_discovery.into_iter().flatten() {
+        component_of[v] = match second.pi[v] {
+            Some(p) => component_of[p],
+            None => {
+                count += 1;
+                count - 1
+            }
+        };
+    }
+
+    let mut components = vec![Vec::new(); count];
+    for v in g.vertices() {
+        components[component_of[v]].push(v);
+    }
+    components
+}
+
+/// # Strongly Connected Components (Tarjan)
+///
+/// Finds the same components as [`strongly_connected_components`] with a
+/// single depth-first search. This is Tarjan's algorithm, which CLRS does
+/// not cover; it does not implement STRONGLY-CONNECTED-COMPONENTS. Every
+/// vertex keeps `low`, the smallest discovery time reachable through its
+/// subtree and one more edge to a vertex still on the stack; a vertex with
+/// `low == d` is the root of a component, which is then popped off the
+/// stack.
+///
+/// # Example
+///
+/// ```rust
+/// use algorithms::graph::parse::parse_edge_list;
+/// use algorithms::graph::scc::tarjan_scc;
+///
+/// let g = parse_edge_list("a b\nb a\nb c\nc d\nd c\n").unwrap();
+///
+/// assert_eq!(tarjan_scc(&g), [vec![2, 3], vec![0, 1]]);
+/// ```
+///
+/// # Notes
+///
+/// * Runs in **Θ(V + E)** time without building `G^T`.
+/// * Components come in reverse topological order of the component graph.
+pub fn tarjan_scc<G: Graph>(g: &G) -> Vec<Vec<usize>> {
+    let n = g.vertex_count();
+    let mut search = Tarjan {
+        d: vec![0; n],
+        low: vec![0; n],
+        on_stack: vec![false; n],
+        stack: Vec::new(),
+        time: 0,
+        components: Vec::new(),
+    };
+    for root in g.vertices() {
+        if search.d[root] == 0 {
+            search.visit(g, root);
+        }
+    }
+    search.components
+}
+
+/// State of Tarjan's search; `d[v] == 0` means undiscovered
+struct Tarjan {
+    d: Vec<usize>,
+    low: Vec<usize>,
+    on_stack: Vec<bool>,
+    stack: Vec<usize>,
+    time: usize,
+    components: Vec<Vec<usize>>,
+}
+
+/// Vertex `u` whose neighbors from `next` on are still to be explored
+struct Frame {
+    u: usize,
+    neighbors: Vec<usize>,
+    next: usize,
+}
+
+impl Tarjan {
+    fn visit<G: Graph>(&mut self, g: &G, root: usize) {
+        let mut frames = vec![self.discover(g, root)];
+
+        while let Some(frame) = frames.last_mut() {
+            let u = frame.u;
+            if let Some(&v) = frame.neighbors.get(frame.next) {
+                frame.next += 1;
+                if self.d[v] == 0 {
+                    frames.push(self.discover(g, v));
+                } else if self.on_stack[v] {
+                    self.low[u] = self.low[u].min(self.d[v]);
+                }
+                continue;
+            }
+
+            frames.pop();
+            if let Some(parent) = frames.last() {
+                self.low[parent.u] = self.low[parent.u].min(self.low[u]);
+            }
+            if self.low[u] == self.d[u] {
+                let mut component = Vec::new();
+                while let Some(v) = self.stack.pop() {
+                    self.on_stack[v] = false;
+                    component.push(v);
+                    if v == u {
+                        break;
+                    }
+                }
+                component.sort_unstable();
+                self.components.push(component);
+            }
+        }
+    }
+
+    fn discover<G: Graph>(&mut self, g: &G, u: usize) -> Frame {
+        self.time += 1;
+        self.d[u] = self.time;
+        self.low[u] = self.time;
+        self.stack.push(u);
+        self.on_stack[u] = true;
+        Frame {
+            u,
+            neighbors: g.neighbors(u).map(|(v, _)| v).collect(),
+            next: 0,
+        }
+    }
+}
+
+/// Strongly connected components of `G` and the component graph `G^SCC`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ComponentGraph {
+    /// Vertices of every component, as from [`strongly_connected_components`]
+    pub components: Vec<Vec<usize>>,
+    /// Index of the component containing each vertex of `G`
+    pub component_of: Vec<usize>,
+    /// `G^SCC`: one vertex per component, labeled with the labels of its
+    /// members, and an edge `(i, j)` if some edge of `G` leads from
+    /// component `i` to component `j ≠ i`
+    pub graph: AdjacencyList,
+}
+
+/// # Component Graph
+///
+/// Contracts every strongly connected component of `G` to a single vertex
+/// (CLRS Section 20.5). `G^SCC` is a dag and its vertices are numbered in
+/// topological order.
+///
+/// # Example
+///
+/// ```rust
+/// use algorithms::graph::parse::parse_edge_list;
+/// use algorithms::graph::representation::Graph;
+/// use algorithms::graph::scc::component_graph;
+///
+/// let g = parse_edge_list("a b\nb a\nb c\nc d\nd c\na d\n").unwrap();
+/// let scc = component_graph(&g);
+///
+/// assert_eq!(scc.component_of, [0, 0, 1, 1]);
+/// assert_eq!(scc.graph.label(1), "cd");
+/// assert_eq!(scc.graph.edge_count(), 1);
+/// ```
+///
+/// # Notes
+///
+/// * Runs in **Θ(V + E)** time; parallel edges between two components are
+///   merged into one.
+pub fn component_graph<G: Graph>(g: &G) -> ComponentGraph {
+    let components = strongly_connected_components(g);
+    let mut component_of = vec![0; g.vertex_count()];
+    for (i, component) in components.iter().enumerate() {
+        for &v in component {
+            component_of[v] = i;
+        }
+    }
+
+    let mut graph = AdjacencyList::directed(components.len());
+    for (i, component) in components.iter().enumerate() {
+        let labels: Vec<&str> = component.iter().map(|&v| g.label(v)).collect();
+        let separator = if labels.iter().all(|l| l.chars().count() == 1) {
+            ""
+        } else {
+            ","
+        };
+        graph.set_label(i, &labels.join(separator));
+    }
+
+    let mut seen = vec![usize::MAX; components.len()];
+    for (i, component) in components.iter().enumerate() {
+        for &u in component {
+            for (v, _) in g.neighbors(u) {
+                let j = component_of[v];
+                if j != i && seen[j] != i {
+                    seen[j] = i;
+                    graph.add_edge(i, j);
+                }
+            }
+        }
+    }
+
+    ComponentGraph {
+        components,
+        component_of,
+        graph,
+    }
+}
+
+/// Strongly connected components and the edges of `G^SCC`
+pub(crate) fn describe_component_graph(g: &AdjacencyList, _: usize) -> String {
+    let scc = component_graph(g);
+    let mut text = String::new();
+    for component in &scc.components {
+        let _ = writeln!(text, "{{{}}}", labels(g, component));
+    }
+    let edges: Vec<String> = scc
+        .graph
+        .edges()
+        .iter()
+        .map(|e| format!("({}, {})", scc.graph.label(e.from), scc.graph.label(e.to)))
+        .collect();
+    let _ = writeln!(text, "Component graph: {}", edges.join(" "));
+    text
+}
+
+/// Strongly connected components in the order Tarjan's algorithm finds them
+pub(crate) fn describe_tarjan(g: &AdjacencyList, _: usize) -> String {
+    let mut text = String::new();
+    for component in tarjan_scc(g) {
+        let _ = writeln!(text, "{{{}}}", labels(g, &component));
+    }
+    text
+}
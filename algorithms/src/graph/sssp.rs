@@ -0,0 +1,632 @@
+//! # Single-Source Shortest Paths
+//!
+//! The Bellman-Ford algorithm, shortest paths in a dag and Dijkstra's
+//! algorithm (CLRS Chapter 22). All three start with
+//! [`initialize_single_source`] and change `v.d` and `v.π` only through
+//! [`relax`]; they differ in the order in which they relax the edges. The
+//! `_traced` variants report every step to a [`PathTracer`], which is how
+//! the report draws figures in the style of CLRS Figure 22.4. Systems of
+//! difference constraints (Section 22.4) are solved on top of Bellman-Ford.
+
+use std::error::Error;
+use std::fmt::{self, Write};
+
+use super::adjacency_list::AdjacencyList;
+use super::bfs::print_path;
+use super::representation::{Graph, Weight, labels};
+use super::topological_sort::{Cycle, topological_sort};
+use crate::heap::min_queue::MinQueue;
+use crate::heap::priority_queue::MinPriorityQueue;
+use crate::trace::NoopTracer;
+
+/// Shortest-path estimates `v.d` and predecessors `v.π` from `source`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ShortestPaths {
+    pub source: usize,
+    /// `v.d`, `None` for ∞ (unreachable)
+    pub d: Vec<Option<Weight>>,
+    /// `v.π`
+    pub pi: Vec<Option<usize>>,
+}
+
+impl ShortestPaths {
+    /// Vertices on the shortest path from the source to `v`, or `None` if
+    /// `v` is unreachable.
+    pub fn path(&self, v: usize) -> Option<Vec<usize>> {
+        print_path(&self.pi, self.source, v)
+    }
+}
+
+/// A single step of a shortest-paths algorithm.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PathEvent {
+    /// `INITIALIZE-SINGLE-SOURCE(G, s)` has run.
+    Initialize,
+    /// `RELAX(u, v, w)`; `improved` if it lowered `v.d`.
+    Relax {
+        u: usize,
+        v: usize,
+        weight: Weight,
+        improved: bool,
+    },
+    /// Pass `pass` (1-based) of Bellman-Ford over all edges is complete.
+    PassComplete { pass: usize },
+    /// Every edge leaving `u` has been relaxed (DAG-SHORTEST-PATHS,
+    /// DIJKSTRA).
+    VertexComplete { u: usize },
+}
+
+/// Observer for the steps of a shortest-paths algorithm.
+///
+/// `paths` is the state *after* `event` took effect. Closures of the form
+/// `FnMut(&PathEvent, &ShortestPaths)` are tracers as well.
+pub trait PathTracer {
+    fn record(&mut self, event: PathEvent, paths: &ShortestPaths);
+}
+
+impl<F> PathTracer for F
+where
+    F: FnMut(&PathEvent, &ShortestPaths),
+{
+    fn record(&mut self, event: PathEvent, paths: &ShortestPaths) {
+        self(&event, paths)
+    }
+}
+
+impl PathTracer for NoopTracer {
+    #[inline(always)]
+    fn record(&mut self, _event: PathEvent, _paths: &ShortestPaths) {}
+}
+
+/// Error of [`bellman_ford`]: a negative-weight cycle is reachable from the
+/// source, so shortest paths are not well defined.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NegativeCycle {
+    /// Vertices `v₀, v₁, ..., vₖ` of the cycle; `(vₖ, v₀)` closes it
+    pub vertices: Vec<usize>,
+}
+
+impl fmt::Display for NegativeCycle {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let vertices: Vec<String> = self.vertices.iter().map(|v| v.to_string()).collect();
+        write!(
+            f,
+            "graph has a negative-weight cycle through {}",
+            vertices.join(" -> ")
+        )
+    }
+}
+
+impl Error for NegativeCycle {}
+
+/// # Initialize-Single-Source
+///
+/// `v.d = ∞` and `v.π = NIL` for all `n` vertices, then `s.d = 0`.
+///
+/// # Pseudocode (CLRS)
+///
+/// ```text
+/// INITIALIZE-SINGLE-SOURCE(G, s)
+/// for each vertex v ∈ G.V
+///     v.d = ∞
+///     v.π = NIL
+/// s.d = 0
+/// ```
+///
+/// # Panics
+///
+/// Panics if `s >= n`.
+pub fn initialize_single_source(n: usize, s: usize) -> ShortestPaths {
+    assert!(s < n, "source {} is not a vertex of G", s);
+    let mut d = vec![None; n];
+    d[s] = Some(0);
+    ShortestPaths {
+        source: s,
+        d,
+        pi: vec![None; n],
+    }
+}
+
+/// # Relax
+///
+/// Tests whether the path to `v` through the edge `(u, v)` of weight `w`
+/// is shorter than the best one found so far and, if so, takes it.
+/// Returns whether `v.d` was lowered.
+///
+/// # Pseudocode (CLRS)
+///
+/// ```text
+/// RELAX(u, v, w)
+/// if v.d > u.d + w(u, v)
+///     v.d = u.d + w(u, v)
+///     v.π = u
+/// ```
+///
+/// # Example
+///
+/// ```rust
+/// use algorithms::graph::sssp::{initialize_single_source, relax};
+///
+/// let mut paths = initialize_single_source(3, 0);
+/// assert!(relax(&mut paths, 0, 1, 5));
+/// assert!(!relax(&mut paths, 2, 1, 1)); // 2.d = ∞
+/// assert!(relax(&mut paths, 0, 1, 3));
+///
+/// assert_eq!(paths.d, [Some(0), Some(3), None]);
+/// assert_eq!(paths.pi[1], Some(0));
+/// ```
+///
+/// # Notes
+///
+/// * A path weight `u.d + w(u, v)` that overflows [`Weight`] counts as ∞,
+///   so it never replaces `v.d`.
+pub fn relax(paths: &mut ShortestPaths, u: usize, v: usize, w: Weight) -> bool {
+    let Some(through_u) = paths.d[u].and_then(|du| du.checked_add(w)) else {
+        return false;
+    };
+    if paths.d[v].is_some_and(|dv| dv <= through_u) {
+        return false;
+    }
+    paths.d[v] = Some(through_u);
+    paths.pi[v] = Some(u);
+    true
+}
+
+/// # Bellman-Ford
+///
+/// Shortest paths from `s` with arbitrary, possibly negative, edge weights
+/// (CLRS Section 22.1). Relaxes every edge `|V| - 1` times; if an edge can
+/// still be relaxed afterwards, a negative-weight cycle is reachable from
+/// `s` and is returned instead.
+///
+/// # Pseudocode (CLRS)
+///
+/// ```text
+/// BELLMAN-FORD(G, w, s)
+/// INITIALIZE-SINGLE-SOURCE(G, s)
+/// for i = 1 to |G.V| - 1
+///     for each edge (u, v) ∈ G.E
+///         RELAX(u, v, w)
+/// for each edge (u, v) ∈ G.E
+///     if v.d > u.d + w(u, v)
+///         return FALSE
+/// return TRUE
+/// ```
+///
+/// # Example
+///
+/// ```rust
+/// use algorithms::graph::parse::parse_edge_list;
+/// use algorithms::graph::sssp::bellman_ford;
+///
+/// let g = parse_edge_list("s a 4\ns b 5\nb a -3\na c 1\n").unwrap();
+/// let paths = bellman_ford(&g, 0).unwrap();
+/// assert_eq!(paths.d, [Some(0), Some(2), Some(5), Some(3)]);
+/// assert_eq!(paths.path(3), Some(vec![0, 2, 1, 3]));
+///
+/// let cyclic = parse_edge_list("s a 1\na b 2\nb a -3\n").unwrap();
+/// assert_eq!(bellman_ford(&cyclic, 0).unwrap_err().vertices, [1, 2]);
+/// ```
+///
+/// # Notes
+///
+/// * Runs in **Θ(VE)** time.
+/// * Edges are relaxed in the order of `G.Adj`; an undirected edge counts
+///   as one edge in each direction, so a negative one is a cycle.
+/// * The cycle is found by relaxing once more and following `π` back `|V|`
+///   times from a vertex whose estimate dropped.
+///
+/// # Panics
+///
+/// Panics if `s` is not a vertex of `g`.
+pub fn bellman_ford<G: Graph>(g: &G, s: usize) -> Result<ShortestPaths, NegativeCycle> {
+    bellman_ford_traced(g, s, &mut NoopTracer)
+}
+
+/// # Bellman-Ford (traced)
+///
+/// Same as [`bellman_ford`], but reports initialization, every relaxation
+/// and the end of every pass to `tracer`.
+pub fn bellman_ford_traced<G, R>(
+    g: &G,
+    s: usize,
+    tracer: &mut R,
+) -> Result<ShortestPaths, NegativeCycle>
+where
+    G: Graph,
+    R: PathTracer + ?Sized,
+{
+    let n = g.vertex_count();
+    let mut paths = initialize_single_source(n, s);
+    tracer.record(PathEvent::Initialize, &paths);
+
+    for pass in 1..n {
+        for u in g.vertices() {
+            for (v, w) in g.neighbors(u) {
+                traced_relax(&mut paths, u, v, w, tracer);
+            }
+        }
+        tracer.record(PathEvent::PassComplete { pass }, &paths);
+    }
+
+    let violated = g.vertices().any(|u| {
+        g.neighbors(u)
+            .any(|(v, w)| match paths.d[u].and_then(|du| du.checked_add(w)) {
+                Some(through_u) => paths.d[v].is_none_or(|dv| dv > through_u),
+                None => false,
+            })
+    });
+    if violated {
+        return Err(negative_cycle(g, paths));
+    }
+    Ok(paths)
+}
+
+/// # DAG-Shortest-Paths
+///
+/// Shortest paths from `s` in a directed acyclic graph (CLRS Section 22.2):
+/// relaxing the edges leaving each vertex in topologically sorted order
+/// relaxes the edges of every path in order, so one pass suffices. Negative
+/// weights are allowed since a dag has no cycles at all; a graph that has
+/// one is rejected with that cycle.
+///
+/// # Pseudocode (CLRS)
+///
+/// ```text
+/// DAG-SHORTEST-PATHS(G, w, s)
+/// topologically sort the vertices of G
+/// INITIALIZE-SINGLE-SOURCE(G, s)
+/// for each vertex u ∈ G.V, taken in topologically sorted order
+///     for each vertex v in G.Adj[u]
+///         RELAX(u, v, w)
+/// ```
+///
+/// # Example
+///
+/// ```rust
+/// use algorithms::graph::parse::parse_edge_list;
+/// use algorithms::graph::sssp::dag_shortest_paths;
+///
+/// let g = parse_edge_list("r s 5\ns t 2\nt x -7\nr x 3\n").unwrap();
+/// let paths = dag_shortest_paths(&g, 1).unwrap();
+///
+/// assert_eq!(paths.d, [None, Some(0), Some(2), Some(-5)]);
+/// ```
+///
+/// # Notes
+///
+/// * Runs in **Θ(V + E)** time.
+///
+/// # Panics
+///
+/// Panics if `g` is undirected or `s` is not a vertex of `g`.
+pub fn dag_shortest_paths<G: Graph>(g: &G, s: usize) -> Result<ShortestPaths, Cycle> {
+    dag_shortest_paths_traced(g, s, &mut NoopTracer)
+}
+
+/// # DAG-Shortest-Paths (traced)
+///
+/// Same as [`dag_shortest_paths`], but reports initialization, every
+/// relaxation and every finished vertex to `tracer`.
+pub fn dag_shortest_paths_traced<G, R>(
+    g: &G,
+    s: usize,
+    tracer: &mut R,
+) -> Result<ShortestPaths, Cycle>
+where
+    G: Graph,
+    R: PathTracer + ?Sized,
+{
+    let order = topological_sort(g)?;
+    let mut paths = initialize_single_source(g.vertex_count(), s);
+    tracer.record(PathEvent::Initialize, &paths);
+
+    for u in order {
+        for (v, w) in g.neighbors(u) {
+            traced_relax(&mut paths, u, v, w, tracer);
+        }
+        tracer.record(PathEvent::VertexComplete { u }, &paths);
+    }
+    Ok(paths)
+}
+
+/// # Dijkstra
+///
+/// Shortest paths from `s` when no edge weight is negative (CLRS Section
+/// 22.3). Repeatedly takes the vertex `u` with the smallest estimate out of
+/// `queue`, whose `u.d` is then final, and relaxes the edges leaving it;
+/// every improved estimate becomes a `DECREASE-KEY`. Any [`MinQueue`]
+/// works, and the choice sets the running time.
+///
+/// # Pseudocode (CLRS)
+///
+/// ```text
+/// DIJKSTRA(G, w, s)
+/// INITIALIZE-SINGLE-SOURCE(G, s)
+/// S = ∅
+/// Q = ∅
+/// for each vertex u ∈ G.V
+///     INSERT(Q, u)
+/// while Q ≠ ∅
+///     u = EXTRACT-MIN(Q)
+///     S = S ∪ {u}
+///     for each vertex v in G.Adj[u]
+///         RELAX(u, v, w)
+///         if the call of RELAX decreased v.d
+///             DECREASE-KEY(Q, v, v.d)
+/// ```
+///
+/// # Example
+///
+/// ```rust
+/// use algorithms::graph::parse::parse_edge_list;
+/// use algorithms::graph::sssp::dijkstra;
+/// use algorithms::heap::fibonacci_heap::FibonacciHeap;
+/// use algorithms::heap::priority_queue::MinPriorityQueue;
+///
+/// let g = parse_edge_list("s t 10\ns y 5\ny t 3\nt x 1\ny x 9\n").unwrap();
+///
+/// let paths = dijkstra(&g, 0, &mut MinPriorityQueue::new());
+/// assert_eq!(paths.d, [Some(0), Some(8), Some(5), Some(9)]);
+/// assert_eq!(dijkstra(&g, 0, &mut FibonacciHeap::new()), paths);
+/// ```
+///
+/// # Notes
+///
+/// * Binary heap: **O((V + E) lg V)**; Fibonacci heap: **O(V lg V + E)**;
+///   unsorted array: **O(V²)**.
+///
+/// # Panics
+///
+/// Panics if `g` has a negative edge weight, if `s` is not a vertex or if
+/// `queue` is not empty.
+pub fn dijkstra<G, Q>(g: &G, s: usize, queue: &mut Q) -> ShortestPaths
+where
+    G: Graph,
+    Q: MinQueue<Weight, usize>,
+{
+    dijkstra_traced(g, s, queue, &mut NoopTracer)
+}
+
+/// # Dijkstra (traced)
+///
+/// Same as [`dijkstra`], but reports initialization, every relaxation and
+/// every vertex added to `S` to `tracer`.
+pub fn dijkstra_traced<G, Q, R>(g: &G, s: usize, queue: &mut Q, tracer: &mut R) -> ShortestPaths
+where
+    G: Graph,
+    Q: MinQueue<Weight, usize>,
+    R: PathTracer + ?Sized,
+{
+    assert!(
+        g.vertices().all(|u| g.neighbors(u).all(|(_, w)| w >= 0)),
+        "Dijkstra's algorithm needs nonnegative edge weights"
+    );
+    assert!(queue.is_empty(), "queue must start empty");
+
+    let mut paths = initialize_single_source(g.vertex_count(), s);
+    tracer.record(PathEvent::Initialize, &paths);
+    let key = |d: Option<Weight>| d.unwrap_or(Weight::MAX);
+    let handles: Vec<_> = g
+        .vertices()
+        .map(|u| queue.insert(key(paths.d[u]), u))
+        .collect();
+
+    while let Some((_, u)) = queue.extract_min() {
+        for (v, w) in g.neighbors(u) {
+            if traced_relax(&mut paths, u, v, w, tracer) {
+                queue.decrease_key(handles[v], key(paths.d[v]));
+            }
+        }
+        tracer.record(PathEvent::VertexComplete { u }, &paths);
+    }
+    paths
+}
+
+/// A constraint `x_j - x_i ≤ b` of a system of difference constraints.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DifferenceConstraint {
+    pub j: usize,
+    pub i: usize,
+    pub b: Weight,
+}
+
+impl DifferenceConstraint {
+    /// `x_j - x_i ≤ b`
+    pub fn new(j: usize, i: usize, b: Weight) -> Self {
+        Self { j, i, b }
+    }
+}
+
+/// # Constraint Graph
+///
+/// The constraint graph of a system of difference constraints on the
+/// variables `x_0, ..., x_{n-1}` (CLRS Section 22.4): vertex `k` stands for
+/// `x_k`, each constraint `x_j - x_i ≤ b` is an edge `(i, j)` of weight
+/// `b`, and an extra source vertex `n` has an edge of weight `0` to every
+/// other vertex.
+///
+/// # Panics
+///
+/// Panics if a constraint refers to a variable `≥ n`.
+pub fn constraint_graph(n: usize, constraints: &[DifferenceConstraint]) -> AdjacencyList {
+    let mut g = AdjacencyList::directed(n + 1);
+    for k in 0..n {
+        g.set_label(k, &format!("x{}", k));
+    }
+    g.set_label(n, "s");
+    for k in 0..n {
+        g.add_weighted_edge(n, k, 0);
+    }
+    for c in constraints {
+        assert!(
+            c.i < n && c.j < n,
+            "constraint x{} - x{} ≤ {} has no such variable",
+            c.j,
+            c.i,
+            c.b
+        );
+        g.add_weighted_edge(c.i, c.j, c.b);
+    }
+    g
+}
+
+/// # Difference Constraints
+///
+/// A feasible solution `x` of the constraints `x_j - x_i ≤ b` on `n`
+/// variables: `x_k = δ(s, v_k)` in the [`constraint_graph`] (Theorem
+/// 22.9). If the graph has a negative-weight cycle, the system has no
+/// solution and the cycle, as variable indices, is returned; summing its
+/// constraints gives the contradiction `0 ≤ b₁ + ... + bₖ < 0`.
+///
+/// # Example
+///
+/// ```rust
+/// use algorithms::graph::sssp::{DifferenceConstraint, solve_difference_constraints};
+///
+/// let c = DifferenceConstraint::new;
+/// let x = solve_difference_constraints(3, &[c(0, 1, 0), c(1, 2, -1), c(2, 0, 3)]).unwrap();
+/// assert_eq!(x, [-1, -1, 0]);
+///
+/// let infeasible = solve_difference_constraints(2, &[c(0, 1, -1), c(1, 0, 0)]);
+/// assert_eq!(infeasible.unwrap_err().vertices, [0, 1]);
+/// ```
+///
+/// # Notes
+///
+/// * Runs in **O(n² + nm)** time for `m` constraints (Bellman-Ford on
+///   `n + 1` vertices and `n + m` edges).
+/// * Any solution plus a constant is a solution as well (Lemma 22.8); this
+///   one has `x_k ≤ 0` for all `k`, as `s` reaches every `v_k` by an edge
+///   of weight `0`.
+///
+/// # Panics
+///
+/// Panics if a constraint refers to a variable `≥ n`.
+pub fn solve_difference_constraints(
+    n: usize,
+    constraints: &[DifferenceConstraint],
+) -> Result<Vec<Weight>, NegativeCycle> {
+    let g = constraint_graph(n, constraints);
+    let paths = bellman_ford(&g, n)?;
+    Ok(paths.d[..n]
+        .iter()
+        .map(|d| d.expect("every vertex is reachable from s"))
+        .collect())
+}
+
+/// `RELAX(u, v, w)`, reported to `tracer`
+fn traced_relax<R: PathTracer + ?Sized>(
+    paths: &mut ShortestPaths,
+    u: usize,
+    v: usize,
+    w: Weight,
+    tracer: &mut R,
+) -> bool {
+    let improved = relax(paths, u, v, w);
+    tracer.record(
+        PathEvent::Relax {
+            u,
+            v,
+            weight: w,
+            improved,
+        },
+        paths,
+    );
+    improved
+}
+
+/// A negative-weight cycle, given that `paths` still has a relaxable edge
+fn negative_cycle<G: Graph>(g: &G, mut paths: ShortestPaths) -> NegativeCycle {
+    let mut last = None;
+    for u in g.vertices() {
+        for (v, w) in g.neighbors(u) {
+            if relax(&mut paths, u, v, w) {
+                last = Some(v);
+            }
+        }
+    }
+
+    // |V| steps back along π from a lowered estimate end on the cycle
+    let predecessor = |v: usize| paths.pi[v].expect("lowered estimates have a predecessor");
+    let mut start = last.expect("an edge can still be relaxed");
+    for _ in g.vertices() {
+        start = predecessor(start);
+    }
+    let mut vertices = vec![start];
+    let mut current = predecessor(start);
+    while current != start {
+        vertices.push(current);
+        current = predecessor(current);
+    }
+    vertices.reverse();
+
+    let first = (0..vertices.len())
+        .min_by_key(|&i| vertices[i])
+        .unwrap_or(0);
+    vertices.rotate_left(first);
+    NegativeCycle { vertices }
+}
+
+/// Shortest paths from `s` found by BELLMAN-FORD, or the negative-weight
+/// cycle it detects
+pub(crate) fn describe_bellman_ford(g: &AdjacencyList, s: usize) -> String {
+    match bellman_ford(g, s) {
+        Ok(paths) => describe(g, &paths),
+        Err(cycle) => format!(
+            "Negative-weight cycle: {} {}\n",
+            labels(g, &cycle.vertices),
+            g.label(cycle.vertices[0])
+        ),
+    }
+}
+
+/// Shortest paths from `s` found by DAG-SHORTEST-PATHS, or the cycle that
+/// rules it out
+pub(crate) fn describe_dag(g: &AdjacencyList, s: usize) -> String {
+    if !g.is_directed() {
+        return "a dag needs a directed graph\n".to_string();
+    }
+    match dag_shortest_paths(g, s) {
+        Ok(paths) => describe(g, &paths),
+        Err(cycle) => format!(
+            "Not acyclic, cycle: {} {}\n",
+            labels(g, &cycle.vertices),
+            g.label(cycle.vertices[0])
+        ),
+    }
+}
+
+/// Shortest paths from `s` found by DIJKSTRA
+pub(crate) fn describe_dijkstra(g: &AdjacencyList, s: usize) -> String {
+    if g.edges().iter().any(|e| e.weight < 0) {
+        return "Dijkstra's algorithm needs nonnegative edge weights\n".to_string();
+    }
+    describe(g, &dijkstra(g, s, &mut MinPriorityQueue::new()))
+}
+
+/// Table of `v.d`, `v.π` and the shortest path for every vertex
+fn describe(g: &AdjacencyList, paths: &ShortestPaths) -> String {
+    let mut text = String::new();
+    for v in g.vertices() {
+        let d = paths.d[v].map_or("∞".to_string(), |d| d.to_string());
+        let pi = paths.pi[v].map_or("NIL", |p| g.label(p));
+        let path = match paths.path(v) {
+            Some(path) => labels(g, &path),
+            None => format!(
+                "no path from {} to {} exists",
+                g.label(paths.source),
+                g.label(v)
+            ),
+        };
+        let _ = writeln!(
+            text,
+            "{}: d = {}, π = {}, path: {}",
+            g.label(v),
+            d,
+            pi,
+            path
+        );
+    }
+    text
+}
@@ -0,0 +1,96 @@
+use std::error::Error;
+use std::fmt;
+
+use super::adjacency_list::AdjacencyList;
+use super::dfs::{EdgeClass, dfs};
+use super::representation::{Graph, labels};
+
+/// Error of [`topological_sort`]: the graph is not acyclic.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Cycle {
+    /// Vertices `v₀, v₁, ..., vₖ` of a cycle; `(vₖ, v₀)` closes it
+    pub vertices: Vec<usize>,
+}
+
+impl fmt::Display for Cycle {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let vertices: Vec<String> = self.vertices.iter().map(|v| v.to_string()).collect();
+        write!(f, "graph has a cycle through {}", vertices.join(" -> "))
+    }
+}
+
+impl Error for Cycle {}
+
+/// # Topological Sort
+///
+/// Orders the vertices of a directed acyclic graph so that every edge
+/// `(u, v)` goes from left to right (CLRS Section 20.4). A graph with a
+/// back edge is not acyclic (Lemma 20.11); the cycle that edge closes is
+/// returned instead.
+///
+/// # Pseudocode (CLRS)
+///
+/// ```text
+/// TOPOLOGICAL-SORT(G)
+/// call DFS(G) to compute finish times v.f for each vertex v
+/// as each vertex is finished, insert it onto the front of a linked list
+/// return the linked list of vertices
+/// ```
+///
+/// # Example
+///
+/// ```rust
+/// use algorithms::graph::parse::parse_edge_list;
+/// use algorithms::graph::topological_sort::topological_sort;
+///
+/// let dag = parse_edge_list("shirt tie\ntie jacket\nshirt belt\nbelt jacket\n").unwrap();
+/// assert_eq!(topological_sort(&dag), Ok(vec![0, 3, 1, 2]));
+///
+/// let cyclic = parse_edge_list("a b\nb c\nc a\n").unwrap();
+/// assert_eq!(topological_sort(&cyclic).unwrap_err().vertices, [0, 1, 2]);
+/// ```
+///
+/// # Notes
+///
+/// * Runs in **Θ(V + E)** time.
+///
+/// # Panics
+///
+/// Panics if `g` is undirected.
+pub fn topological_sort<G: Graph>(g: &G) -> Result<Vec<usize>, Cycle> {
+    assert!(g.is_directed(), "topological sort needs a directed graph");
+
+    let result = dfs(g);
+    let back_edge = result
+        .edges
+        .iter()
+        .find(|(_, class)| *class == EdgeClass::Back);
+    if let Some((edge, _)) = back_edge {
+        // v is an ancestor of u: walk up the tree from u to v
+        let mut vertices = vec![edge.from];
+        let mut current = edge.from;
+        while current != edge.to {
+            current = result.pi[current].expect("back edge leads to an ancestor");
+            vertices.push(current);
+        }
+        vertices.reverse();
+        return Err(Cycle { vertices });
+    }
+
+    Ok(result.by_decreasing_finish())
+}
+
+/// Topological order, or the cycle that prevents one
+pub(crate) fn describe(g: &AdjacencyList, _: usize) -> String {
+    if !g.is_directed() {
+        return "topological sort needs a directed graph\n".to_string();
+    }
+    match topological_sort(g) {
+        Ok(order) => format!("Order: {}\n", labels(g, &order)),
+        Err(cycle) => format!(
+            "Not acyclic, cycle: {} {}\n",
+            labels(g, &cycle.vertices),
+            g.label(cycle.vertices[0])
+        ),
+    }
+}
@@ -0,0 +1,161 @@
+use std::cell::Cell;
+use std::fmt::Write;
+
+use super::hash_function::{Division, HashFunction, natural_keys, table_size};
+use super::stats::{ProbeStats, describe_load};
+
+/// # Hash Table with Chaining
+///
+/// Every slot `T[j]` holds a list of the elements whose keys hash to `j`
+/// (CLRS Section 11.2). New elements go to the head of their list.
+/// Searches record how many list elements they examined in
+/// [`stats`](Self::stats).
+///
+/// # Pseudocode (CLRS)
+///
+/// ```text
+/// CHAINED-HASH-INSERT(T, x)
+/// LIST-PREPEND(T[h(x.key)], x)
+///
+/// CHAINED-HASH-SEARCH(T, k)
+/// return LIST-SEARCH(T[h(k)], k)
+///
+/// CHAINED-HASH-DELETE(T, x)
+/// LIST-DELETE(T[h(x.key)], x)
+/// ```
+///
+/// # Example
+///
+/// ```rust
+/// use algorithms::hashing::chained::ChainedHashTable;
+/// use algorithms::hashing::hash_function::Division;
+///
+/// let mut table = ChainedHashTable::new(Division::new(9));
+/// for k in [5, 28, 19, 15, 20, 33, 12, 17, 10] {
+///     table.insert(k, k * 10);
+/// }
+///
+/// // 28, 19 and 10 all hash to slot 1, the latest first
+/// assert_eq!(table.chain(1), [10, 19, 28]);
+/// assert_eq!(table.search(19), Some(&190));
+/// assert_eq!(table.delete(19), Some(190));
+/// assert_eq!(table.search(19), None);
+/// ```
+///
+/// # Notes
+///
+/// * Under simple uniform hashing a search takes **Θ(1 + α)** expected
+///   time; insertion takes **O(1)** after the search for a duplicate key.
+#[derive(Debug, Clone)]
+pub struct ChainedHashTable<V, H: HashFunction> {
+    h: H,
+    /// Chains with their head at the end
+    chains: Vec<Vec<(u64, V)>>,
+    len: usize,
+    stats: Cell<ProbeStats>,
+}
+
+impl<V, H: HashFunction> ChainedHashTable<V, H> {
+    /// Empty table with one slot per value of `h`.
+    pub fn new(h: H) -> Self {
+        let chains = (0..h.slots()).map(|_| Vec::new()).collect();
+        Self {
+            h,
+            chains,
+            len: 0,
+            stats: Cell::new(ProbeStats::default()),
+        }
+    }
+
+    /// Number of stored elements `n`.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// `α = n/m`.
+    pub fn load_factor(&self) -> f64 {
+        self.len as f64 / self.chains.len() as f64
+    }
+
+    /// Insert `value` under `k`; returns the value it replaces, if any.
+    pub fn insert(&mut self, k: u64, value: V) -> Option<V> {
+        let j = self.h.hash(k);
+        if let Some(i) = self.position(j, k) {
+            return Some(std::mem::replace(&mut self.chains[j][i].1, value));
+        }
+        self.chains[j].push((k, value));
+        self.len += 1;
+        None
+    }
+
+    /// The value stored under `k`.
+    pub fn search(&self, k: u64) -> Option<&V> {
+        let j = self.h.hash(k);
+        self.position(j, k).map(|i| &self.chains[j][i].1)
+    }
+
+    /// Remove `k`; returns its value if it was present.
+    pub fn delete(&mut self, k: u64) -> Option<V> {
+        let j = self.h.hash(k);
+        let i = self.position(j, k)?;
+        self.len -= 1;
+        Some(self.chains[j].remove(i).1)
+    }
+
+    /// Keys in `T[j]`, from the head of the list.
+    pub fn chain(&self, j: usize) -> Vec<u64> {
+        self.chains[j].iter().rev().map(|(k, _)| *k).collect()
+    }
+
+    /// Length of every chain.
+    pub fn chain_lengths(&self) -> Vec<usize> {
+        self.chains.iter().map(Vec::len).collect()
+    }
+
+    /// Probes of all searches so far, including those made by `insert` and
+    /// `delete`.
+    pub fn stats(&self) -> ProbeStats {
+        self.stats.get()
+    }
+
+    pub fn reset_stats(&self) {
+        self.stats.set(ProbeStats::default());
+    }
+
+    /// `LIST-SEARCH(T[j], k)` from the head, recording the probes
+    fn position(&self, j: usize, k: u64) -> Option<usize> {
+        let chain = &self.chains[j];
+        let found = chain.iter().rposition(|(key, _)| *key == k);
+        let probes = found.map_or(chain.len(), |i| chain.len() - i);
+        let mut stats = self.stats.get();
+        stats.record(found.is_some(), probes);
+        self.stats.set(stats);
+        found
+    }
+}
+
+/// Chains and probe counts of a table with `h(k) = k mod m` built from `keys`
+pub(crate) fn describe(keys: &[i64]) -> String {
+    let Some(keys) = natural_keys(keys) else {
+        return "hash tables need nonnegative keys\n".to_string();
+    };
+    let m = table_size(keys.len());
+    let mut table = ChainedHashTable::new(Division::new(m));
+    for &k in &keys {
+        table.insert(k, ());
+    }
+    let mut text = format!("m = {}, h(k) = k mod {}\n", m, m);
+    for j in 0..m {
+        let chain: String = table.chain(j).iter().map(|k| format!(" {}", k)).collect();
+        let _ = writeln!(text, "{}:{}", j, chain);
+    }
+    table.reset_stats();
+    for &k in &keys {
+        table.search(k);
+    }
+    text + &describe_load(table.load_factor(), table.stats().mean_successful())
+}
@@ -0,0 +1,203 @@
+//! # Hash Functions
+//!
+//! Hash functions `h : U → {0, 1, ..., m - 1}` on the universe `U` of
+//! 64-bit natural numbers (CLRS Section 11.3). The tables take any
+//! [`HashFunction`], so the same table runs with a static function or one
+//! drawn at random from a universal family.
+
+use crate::random::RandomSource;
+
+/// `2^61 - 1`, the Mersenne prime used by [`Universal`]
+pub const MERSENNE_61: u64 = (1 << 61) - 1;
+
+/// Knuth's suggestion `A = (√5 - 1) / 2` as a 64-bit binary fraction
+const GOLDEN_FRACTION: u64 = 0x9E37_79B9_7F4A_7C15;
+
+/// A hash function with a fixed number of slots `m`.
+pub trait HashFunction {
+    /// `h(k)`, a slot in `0..m`.
+    fn hash(&self, k: u64) -> usize;
+
+    /// `m`, the number of slots.
+    fn slots(&self) -> usize;
+}
+
+/// # Division Method
+///
+/// `h(k) = k mod m` (CLRS Section 11.3.1). Works best when `m` is a prime
+/// not too close to a power of 2.
+///
+/// # Example
+///
+/// ```rust
+/// use algorithms::hashing::hash_function::{Division, HashFunction};
+///
+/// let h = Division::new(701);
+/// assert_eq!(h.hash(1000), 299);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Division {
+    m: usize,
+}
+
+impl Division {
+    /// # Panics
+    ///
+    /// Panics if `m == 0`.
+    pub fn new(m: usize) -> Self {
+        assert!(m > 0, "a hash function needs at least one slot");
+        Self { m }
+    }
+}
+
+impl HashFunction for Division {
+    fn hash(&self, k: u64) -> usize {
+        (k % self.m as u64) as usize
+    }
+
+    fn slots(&self) -> usize {
+        self.m
+    }
+}
+
+/// # Multiplication Method
+///
+/// `h(k) = ⌊m (kA mod 1)⌋` for a constant `0 < A < 1` (CLRS Section
+/// 11.3.2). `A` is kept as a 64-bit binary fraction `s / 2^64`, so `kA mod
+/// 1` is the low word of `k · s` and multiplying by `m` keeps the high
+/// word; with `m = 2^ℓ` this is the multiply-shift method. Unlike the
+/// division method, the choice of `m` is not critical.
+///
+/// # Example
+///
+/// ```rust
+/// use algorithms::hashing::hash_function::{HashFunction, Multiplication};
+///
+/// // m = 2^14, A = 2654435769 / 2^32
+/// let h = Multiplication::with_constant(1 << 14, 2654435769.0 / 4294967296.0);
+/// assert_eq!(h.hash(123456), 67);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Multiplication {
+    m: usize,
+    /// `A · 2^64`
+    s: u64,
+}
+
+impl Multiplication {
+    /// With Knuth's `A = (√5 - 1) / 2 ≈ 0.618`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `m == 0`.
+    pub fn new(m: usize) -> Self {
+        assert!(m > 0, "a hash function needs at least one slot");
+        Self {
+            m,
+            s: GOLDEN_FRACTION,
+        }
+    }
+
+    /// # Panics
+    ///
+    /// Panics if `m == 0` or `a` is not strictly between 0 and 1.
+    pub fn with_constant(m: usize, a: f64) -> Self {
+        assert!(0.0 < a && a < 1.0, "A = {} is not in (0, 1)", a);
+        Self {
+            s: (a * 2f64.powi(64)) as u64,
+            ..Self::new(m)
+        }
+    }
+}
+
+impl HashFunction for Multiplication {
+    fn hash(&self, k: u64) -> usize {
+        let fraction = k.wrapping_mul(self.s);
+        ((fraction as u128 * self.m as u128) >> 64) as usize
+    }
+
+    fn slots(&self) -> usize {
+        self.m
+    }
+}
+
+/// # Universal Hashing
+///
+/// A member `h_{ab}(k) = ((ak + b) mod p) mod m` of the universal family
+/// `H_{pm}` (CLRS Section 11.3.4), with the prime `p = 2^61 - 1`. Drawing
+/// `a` and `b` at random when the table is created guarantees that any two
+/// distinct keys collide with probability at most `1/m`, whatever keys an
+/// adversary picks. Keys are taken mod `p`.
+///
+/// # Example
+///
+/// ```rust
+/// use algorithms::hashing::hash_function::{HashFunction, Universal};
+/// use algorithms::random::XorShift64;
+///
+/// let h = Universal::random(100, &mut XorShift64::new(7));
+/// let g = Universal::random(100, &mut XorShift64::new(7));
+///
+/// // The same seed draws the same function
+/// assert_eq!(h, g);
+/// assert!(h.hash(12345) < 100);
+/// assert_eq!(Universal::new(6, 3, 4).hash(8), 4);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Universal {
+    m: usize,
+    a: u64,
+    b: u64,
+}
+
+impl Universal {
+    /// `h_{ab}` with `a ∈ {1, ..., p - 1}` and `b ∈ {0, ..., p - 1}`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `m == 0` or `a` or `b` is out of range.
+    pub fn new(m: usize, a: u64, b: u64) -> Self {
+        assert!(m > 0, "a hash function needs at least one slot");
+        assert!(
+            (1..MERSENNE_61).contains(&a) && b < MERSENNE_61,
+            "a = {} or b = {} is outside Z_p",
+            a,
+            b
+        );
+        Self { m, a, b }
+    }
+
+    /// Draw `h_{ab}` uniformly at random from `H_{pm}`.
+    pub fn random<R: RandomSource>(m: usize, rng: &mut R) -> Self {
+        let a = 1 + rng.next_u64() % (MERSENNE_61 - 1);
+        let b = rng.next_u64() % MERSENNE_61;
+        Self::new(m, a, b)
+    }
+}
+
+impl HashFunction for Universal {
+    fn hash(&self, k: u64) -> usize {
+        let p = MERSENNE_61 as u128;
+        let ak_b = (self.a as u128 * (k as u128 % p) + self.b as u128) % p;
+        (ak_b % self.m as u128) as usize
+    }
+
+    fn slots(&self) -> usize {
+        self.m
+    }
+}
+
+/// Keys as natural numbers, `None` if one is negative
+pub(crate) fn natural_keys(keys: &[i64]) -> Option<Vec<u64>> {
+    keys.iter().map(|&k| u64::try_from(k).ok()).collect()
+}
+
+/// Smallest prime `m ≥ 2n`, at least 2, for a table of `n` keys
+pub(crate) fn table_size(n: usize) -> usize {
+    let is_prime = |m: usize| {
+        (2..)
+            .take_while(|d| d * d <= m)
+            .all(|d| !m.is_multiple_of(d))
+    };
+    (2 * n.max(1)..).find(|&m| is_prime(m)).unwrap()
+}
@@ -0,0 +1,4 @@
+pub mod chained;
+pub mod hash_function;
+pub mod open_addressing;
+pub mod stats;
@@ -0,0 +1,474 @@
+//! # Open Addressing
+//!
+//! All elements live in the table itself; a key `k` is looked for along
+//! its probe sequence `⟨h(k, 0), h(k, 1), ..., h(k, m - 1)⟩` until it or an
+//! empty slot turns up (CLRS Section 11.4). The probe sequence is a
+//! [`ProbeSequence`], so one table implements linear probing, quadratic
+//! probing and double hashing.
+
+use std::cell::Cell;
+use std::error::Error;
+use std::fmt::{self, Write};
+
+use super::hash_function::{Division, HashFunction, natural_keys, table_size};
+use super::stats::{ProbeStats, describe_load};
+
+/// A probe sequence `h(k, i)` over `m` slots.
+pub trait ProbeSequence {
+    /// `h(k, i)` for `0 ≤ i < m`.
+    fn probe(&self, k: u64, i: usize) -> usize;
+
+    /// `m`, the number of slots.
+    fn slots(&self) -> usize;
+}
+
+/// # Linear Probing
+///
+/// `h(k, i) = (h'(k) + i) mod m`: the slots after `h'(k)`, one by one.
+/// Suffers from primary clustering, but is the only sequence that allows
+/// deletion without tombstones
+/// ([`linear_probing_delete`](OpenAddressTable::linear_probing_delete)).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LinearProbing<H: HashFunction> {
+    h: H,
+}
+
+impl<H: HashFunction> LinearProbing<H> {
+    pub fn new(h: H) -> Self {
+        Self { h }
+    }
+}
+
+impl<H: HashFunction> ProbeSequence for LinearProbing<H> {
+    fn probe(&self, k: u64, i: usize) -> usize {
+        (self.h.hash(k) + i) % self.h.slots()
+    }
+
+    fn slots(&self) -> usize {
+        self.h.slots()
+    }
+}
+
+/// # Quadratic Probing
+///
+/// `h(k, i) = (h'(k) + c₁i + c₂i²) mod m` with `c₁ = c₂ = 1/2`, i.e. the
+/// offsets are the triangular numbers `i(i + 1)/2`. When `m` is a power of
+/// 2 the sequence visits every slot. Avoids primary clustering, but keys
+/// with the same `h'(k)` still share their whole sequence.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct QuadraticProbing<H: HashFunction> {
+    h: H,
+}
+
+impl<H: HashFunction> QuadraticProbing<H> {
+    pub fn new(h: H) -> Self {
+        Self { h }
+    }
+}
+
+impl<H: HashFunction> ProbeSequence for QuadraticProbing<H> {
+    fn probe(&self, k: u64, i: usize) -> usize {
+        let m = self.h.slots() as u128;
+        let offset = (i as u128 * (i as u128 + 1) / 2) % m;
+        ((self.h.hash(k) as u128 + offset) % m) as usize
+    }
+
+    fn slots(&self) -> usize {
+        self.h.slots()
+    }
+}
+
+/// # Double Hashing
+///
+/// `h(k, i) = (h₁(k) + i h₂(k)) mod m` with `h₂(k) = 1 + h'₂(k)`, where
+/// `h'₂` has fewer slots than `h₁`. For the sequence to reach every slot,
+/// `h₂(k)` must be relatively prime to `m`; taking `m` prime and `h'₂` over
+/// `m - 1` slots guarantees it. Each pair `(h₁(k), h₂(k))` yields its own
+/// sequence, which makes double hashing the closest to uniform hashing.
+///
+/// # Example
+///
+/// ```rust
+/// use algorithms::hashing::hash_function::Division;
+/// use algorithms::hashing::open_addressing::{DoubleHashing, ProbeSequence};
+///
+/// // h₁(k) = k mod 13, h₂(k) = 1 + (k mod 11)
+/// let probing = DoubleHashing::new(Division::new(13), Division::new(11));
+/// let sequence: Vec<usize> = (0..3).map(|i| probing.probe(14, i)).collect();
+/// assert_eq!(sequence, [1, 5, 9]);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DoubleHashing<H1: HashFunction, H2: HashFunction> {
+    h1: H1,
+    h2: H2,
+}
+
+impl<H1: HashFunction, H2: HashFunction> DoubleHashing<H1, H2> {
+    /// # Panics
+    ///
+    /// Panics unless `h2` has fewer slots than `h1`.
+    pub fn new(h1: H1, h2: H2) -> Self {
+        assert!(
+            h2.slots() < h1.slots(),
+            "h'₂ needs fewer than {} slots",
+            h1.slots()
+        );
+        Self { h1, h2 }
+    }
+}
+
+impl<H1: HashFunction, H2: HashFunction> ProbeSequence for DoubleHashing<H1, H2> {
+    fn probe(&self, k: u64, i: usize) -> usize {
+        let m = self.h1.slots() as u128;
+        let step = 1 + self.h2.hash(k) as u128;
+        ((self.h1.hash(k) as u128 + i as u128 * step) % m) as usize
+    }
+
+    fn slots(&self) -> usize {
+        self.h1.slots()
+    }
+}
+
+/// Error of an insertion into a table whose probe sequence for the key
+/// holds no free slot.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Overflow {
+    pub key: u64,
+}
+
+impl fmt::Display for Overflow {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "hash table overflow inserting {}", self.key)
+    }
+}
+
+impl Error for Overflow {}
+
+/// Content of one slot of an open-address table.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Slot<V> {
+    Empty,
+    /// Tombstone `DELETED` left behind by [`OpenAddressTable::delete`]
+    Deleted,
+    Occupied(u64, V),
+}
+
+/// # Open-Address Hash Table
+///
+/// Hash table that resolves collisions by probing along `probing`.
+/// Deletion either leaves a tombstone, which searches skip and insertions
+/// reuse, or — with linear probing — moves later keys back instead.
+/// Searches record their probes in [`stats`](Self::stats).
+///
+/// # Pseudocode (CLRS)
+///
+/// ```text
+/// HASH-INSERT(T, k)
+/// i = 0
+/// repeat
+///     q = h(k, i)
+///     if T[q] == NIL
+///         T[q] = k
+///         return q
+///     else i = i + 1
+/// until i == m
+/// error "hash table overflow"
+///
+/// HASH-SEARCH(T, k)
+/// i = 0
+/// repeat
+///     q = h(k, i)
+///     if T[q] == k
+///         return q
+///     i = i + 1
+/// until T[q] == NIL or i == m
+/// return NIL
+/// ```
+///
+/// # Example
+///
+/// ```rust
+/// use algorithms::hashing::hash_function::Division;
+/// use algorithms::hashing::open_addressing::{LinearProbing, OpenAddressTable};
+///
+/// let mut table = OpenAddressTable::new(LinearProbing::new(Division::new(11)));
+/// for k in [10, 22, 31, 4, 15, 28, 17, 88, 59] {
+///     table.insert(k, ()).unwrap();
+/// }
+///
+/// let expected = [22, 88, 0, 0, 4, 15, 28, 17, 59, 31, 10];
+/// let keys: Vec<u64> = table.keys().iter().map(|k| k.unwrap_or(0)).collect();
+/// assert_eq!(keys, expected);
+/// assert!(table.search(59).is_some());
+/// assert!(table.search(5).is_none());
+/// ```
+///
+/// # Notes
+///
+/// * Under uniform hashing with `α < 1` an unsuccessful search makes at
+///   most **1/(1 - α)** expected probes and a successful one at most
+///   **(1/α) ln(1/(1 - α))**.
+/// * Tombstones count as occupied for these bounds, so after many
+///   deletions searches slow down although `α` is small.
+#[derive(Debug, Clone)]
+pub struct OpenAddressTable<V, P: ProbeSequence> {
+    probing: P,
+    slots: Vec<Slot<V>>,
+    len: usize,
+    stats: Cell<ProbeStats>,
+}
+
+impl<V, P: ProbeSequence> OpenAddressTable<V, P> {
+    /// Empty table with one slot per value of the probe sequence.
+    pub fn new(probing: P) -> Self {
+        let slots = (0..probing.slots()).map(|_| Slot::Empty).collect();
+        Self {
+            probing,
+            slots,
+            len: 0,
+            stats: Cell::new(ProbeStats::default()),
+        }
+    }
+
+    /// Number of stored elements `n`.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// `α = n/m`.
+    pub fn load_factor(&self) -> f64 {
+        self.len as f64 / self.slots.len() as f64
+    }
+
+    /// `HASH-INSERT(T, k)` with `value`; a present key gets the new value,
+    /// which returns the old one. Otherwise the key takes the first
+    /// tombstone or empty slot of its probe sequence.
+    ///
+    /// # Errors
+    ///
+    /// [`Overflow`] if the probe sequence of `k` has no free slot.
+    pub fn insert(&mut self, k: u64, value: V) -> Result<Option<V>, Overflow> {
+        let (found, free) = self.find(k);
+        if let Some(q) = found {
+            let Slot::Occupied(_, old) = std::mem::replace(&mut self.slots[q], Slot::Empty) else {
+                unreachable!("find returns an occupied slot");
+            };
+            self.slots[q] = Slot::Occupied(k, value);
+            return Ok(Some(old));
+        }
+        let q = free.ok_or(Overflow { key: k })?;
+        self.slots[q] = Slot::Occupied(k, value);
+        self.len += 1;
+        Ok(None)
+    }
+
+    /// `HASH-SEARCH(T, k)`: the value stored under `k`.
+    pub fn search(&self, k: u64) -> Option<&V> {
+        match &self.slots[self.find(k).0?] {
+            Slot::Occupied(_, value) => Some(value),
+            _ => None,
+        }
+    }
+
+    /// Remove `k` by marking its slot `DELETED`; returns its value if it
+    /// was present.
+    pub fn delete(&mut self, k: u64) -> Option<V> {
+        let q = self.find(k).0?;
+        self.len -= 1;
+        match std::mem::replace(&mut self.slots[q], Slot::Deleted) {
+            Slot::Occupied(_, value) => Some(value),
+            _ => unreachable!("find returns an occupied slot"),
+        }
+    }
+
+    /// Key in every slot, `None` for empty slots and tombstones.
+    pub fn keys(&self) -> Vec<Option<u64>> {
+        self.slots
+            .iter()
+            .map(|slot| match slot {
+                Slot::Occupied(k, _) => Some(*k),
+                _ => None,
+            })
+            .collect()
+    }
+
+    /// The slots themselves.
+    pub fn slots(&self) -> &[Slot<V>] {
+        &self.slots
+    }
+
+    /// Number of tombstones.
+    pub fn tombstones(&self) -> usize {
+        self.slots
+            .iter()
+            .filter(|slot| matches!(slot, Slot::Deleted))
+            .count()
+    }
+
+    /// Probes of all searches so far, including those made by `insert` and
+    /// `delete`.
+    pub fn stats(&self) -> ProbeStats {
+        self.stats.get()
+    }
+
+    pub fn reset_stats(&self) {
+        self.stats.set(ProbeStats::default());
+    }
+
+    /// Follow the probe sequence of `k` to the slot holding it or to an
+    /// empty slot, recording the probes; also returns the first free slot
+    /// seen, tombstone or empty
+    fn find(&self, k: u64) -> (Option<usize>, Option<usize>) {
+        let mut free = None;
+        let mut probes = 0;
+        let mut found = None;
+        for i in 0..self.slots.len() {
+            let q = self.probing.probe(k, i);
+            probes += 1;
+            match &self.slots[q] {
+                Slot::Occupied(key, _) if *key == k => {
+                    found = Some(q);
+                    break;
+                }
+                Slot::Occupied(..) => {}
+                Slot::Deleted => {
+                    free.get_or_insert(q);
+                }
+                Slot::Empty => {
+                    free.get_or_insert(q);
+                    break;
+                }
+            }
+        }
+        let mut stats = self.stats.get();
+        stats.record(found.is_some(), probes);
+        self.stats.set(stats);
+        (found, free)
+    }
+}
+
+impl<V, H: HashFunction> OpenAddressTable<V, LinearProbing<H>> {
+    /// # Linear-Probing Deletion
+    ///
+    /// Removes `k` without a tombstone: empties its slot `q`, then scans
+    /// on for a key `k'` whose probe sequence passed `q` before reaching its
+    /// own slot `q'`, moves it into `q` and repeats with `q'` as the hole,
+    /// until an empty slot ends the cluster (CLRS Section 11.5.1). Returns
+    /// the value of `k` if it was present. Tombstones left by
+    /// [`delete`](Self::delete) are never moved.
+    ///
+    /// # Pseudocode (CLRS)
+    ///
+    /// ```text
+    /// LINEAR-PROBING-HASH-DELETE(T, q)
+    /// while TRUE
+    ///     T[q] = NIL
+    ///     q' = q
+    ///     repeat
+    ///         q' = (q' + 1) mod m
+    ///         k' = T[q']
+    ///         if k' == NIL
+    ///             return
+    ///     until g(k', q) < g(k', q')
+    ///     T[q] = k'
+    ///     q = q'
+    /// ```
+    ///
+    /// where `g(k, q) = (q - h(k)) mod m` is the probe number at which `k`
+    /// reaches slot `q`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use algorithms::hashing::hash_function::Division;
+    /// use algorithms::hashing::open_addressing::{LinearProbing, OpenAddressTable};
+    ///
+    /// let mut table = OpenAddressTable::new(LinearProbing::new(Division::new(7)));
+    /// for k in [0, 7, 1, 14] {
+    ///     table.insert(k, ()).unwrap();
+    /// }
+    /// // 0 7 1 14 in slots 0..4
+    /// table.linear_probing_delete(7);
+    ///
+    /// // 1 moves back home, 14 follows into the hole 1 leaves
+    /// assert_eq!(table.keys()[..4], [Some(0), Some(1), Some(14), None]);
+    /// assert_eq!(table.tombstones(), 0);
+    /// ```
+    pub fn linear_probing_delete(&mut self, k: u64) -> Option<V> {
+        let mut q = self.find(k).0?;
+        self.len -= 1;
+        let m = self.slots.len();
+        let h = &self.probing.h;
+        let g = |k: u64, q: usize| (q + m - h.hash(k)) % m;
+
+        let Slot::Occupied(_, value) = std::mem::replace(&mut self.slots[q], Slot::Empty) else {
+            unreachable!("find returns an occupied slot");
+        };
+        let mut next = q;
+        loop {
+            next = (next + 1) % m;
+            match self.slots[next] {
+                Slot::Empty => return Some(value),
+                Slot::Occupied(k2, _) if g(k2, q) < g(k2, next) => {
+                    self.slots.swap(q, next);
+                    q = next;
+                }
+                _ => {}
+            }
+        }
+    }
+}
+
+/// Slots and probe counts of a linear-probing table built from `keys`
+pub(crate) fn describe_linear(keys: &[i64]) -> String {
+    let m = table_size(keys.len());
+    describe(keys, LinearProbing::new(Division::new(m)), "h(k) = k mod m")
+}
+
+/// Slots and probe counts of a quadratic-probing table built from `keys`
+pub(crate) fn describe_quadratic(keys: &[i64]) -> String {
+    let m = table_size(keys.len());
+    describe(
+        keys,
+        QuadraticProbing::new(Division::new(m)),
+        "h(k) = k mod m, offsets i(i + 1)/2",
+    )
+}
+
+/// Slots and probe counts of a double-hashing table built from `keys`
+pub(crate) fn describe_double(keys: &[i64]) -> String {
+    let m = table_size(keys.len());
+    describe(
+        keys,
+        DoubleHashing::new(Division::new(m), Division::new(m - 1)),
+        "h1(k) = k mod m, h2(k) = 1 + (k mod (m - 1))",
+    )
+}
+
+/// Slots and probe counts of an open-address table built from `keys`
+fn describe<P: ProbeSequence>(keys: &[i64], probing: P, hash: &str) -> String {
+    let Some(keys) = natural_keys(keys) else {
+        return "hash tables need nonnegative keys\n".to_string();
+    };
+    let m = probing.slots();
+    let mut table = OpenAddressTable::new(probing);
+    let mut text = format!("m = {}, {}\n", m, hash);
+    for &k in &keys {
+        if let Err(overflow) = table.insert(k, ()) {
+            let _ = writeln!(text, "{}", overflow);
+        }
+    }
+    for (q, key) in table.keys().iter().enumerate() {
+        let key = key.map_or(String::new(), |k| format!(" {}", k));
+        let _ = writeln!(text, "{}:{}", q, key);
+    }
+    table.reset_stats();
+    for &k in &keys {
+        table.search(k);
+    }
+    text + &describe_load(table.load_factor(), table.stats().mean_successful())
+}
@@ -0,0 +1,52 @@
+/// Searches and the probes they made, split by outcome.
+///
+/// A probe examines one slot of an open-address table or one element of a
+/// chain. The means are the quantities the analysis of CLRS Sections 11.2
+/// and 11.4 bounds in terms of the load factor `α = n/m`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ProbeStats {
+    pub successful: u64,
+    pub successful_probes: u64,
+    pub unsuccessful: u64,
+    pub unsuccessful_probes: u64,
+}
+
+impl ProbeStats {
+    /// Count one search that made `probes` probes.
+    pub fn record(&mut self, found: bool, probes: usize) {
+        if found {
+            self.successful += 1;
+            self.successful_probes += probes as u64;
+        } else {
+            self.unsuccessful += 1;
+            self.unsuccessful_probes += probes as u64;
+        }
+    }
+
+    /// Mean probes of a successful search, 0 if there was none.
+    pub fn mean_successful(&self) -> f64 {
+        mean(self.successful_probes, self.successful)
+    }
+
+    /// Mean probes of an unsuccessful search, 0 if there was none.
+    pub fn mean_unsuccessful(&self) -> f64 {
+        mean(self.unsuccessful_probes, self.unsuccessful)
+    }
+}
+
+/// `total / count`, 0 for no searches
+fn mean(total: u64, count: u64) -> f64 {
+    if count == 0 {
+        0.0
+    } else {
+        total as f64 / count as f64
+    }
+}
+
+/// Load factor and mean probes of a successful search
+pub(crate) fn describe_load(alpha: f64, probes: f64) -> String {
+    format!(
+        "Load factor: {:.2}\nProbes per successful search: {:.2}\n",
+        alpha, probes
+    )
+}
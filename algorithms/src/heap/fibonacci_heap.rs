@@ -0,0 +1,312 @@
+use super::min_queue::MinQueue;
+use super::priority_queue::Handle;
+
+/// Node of the heap; `left`/`right` link its circular sibling list
+#[derive(Debug, Clone)]
+struct Node<K, V> {
+    key: K,
+    value: V,
+    parent: Option<usize>,
+    child: Option<usize>,
+    left: usize,
+    right: usize,
+    degree: usize,
+    mark: bool,
+}
+
+/// # Fibonacci Heap
+///
+/// Min-heap as a collection of heap-ordered trees in a circular root list
+/// (CLRS 3rd edition, Chapter 19). `INSERT` and `DECREASE-KEY` take
+/// **O(1)** amortized time, `EXTRACT-MIN` **O(lg n)** amortized: trees of
+/// equal degree are only linked when the minimum is extracted, and
+/// `DECREASE-KEY` cuts a node loose, cascading up through marked parents.
+/// Nodes live in an arena indexed by their [`Handle`].
+///
+/// # Pseudocode (CLRS)
+///
+/// ```text
+/// FIB-HEAP-EXTRACT-MIN(H)
+/// z = H.min
+/// if z ≠ NIL
+///     for each child x of z
+///         add x to the root list of H
+///         x.p = NIL
+///     remove z from the root list of H
+///     if z == z.right
+///         H.min = NIL
+///     else H.min = z.right
+///         CONSOLIDATE(H)
+///     H.n = H.n - 1
+/// return z
+///
+/// FIB-HEAP-DECREASE-KEY(H, x, k)
+/// if k > x.key
+///     error "new key is greater than current key"
+/// x.key = k
+/// y = x.p
+/// if y ≠ NIL and x.key < y.key
+///     CUT(H, x, y)
+///     CASCADING-CUT(H, y)
+/// if x.key < H.min.key
+///     H.min = x
+/// ```
+///
+/// # Example
+///
+/// ```rust
+/// use algorithms::heap::fibonacci_heap::FibonacciHeap;
+/// use algorithms::heap::min_queue::MinQueue;
+///
+/// let mut heap = FibonacciHeap::new();
+/// let handles: Vec<_> = [23, 7, 21, 3, 18].iter().map(|&k| heap.insert(k, k)).collect();
+/// assert_eq!(heap.extract_min(), Some((3, 3)));
+///
+/// heap.decrease_key(handles[2], 1);
+/// assert_eq!(heap.extract_min(), Some((1, 21)));
+/// assert_eq!(heap.extract_min(), Some((7, 7)));
+/// ```
+#[derive(Debug, Clone)]
+pub struct FibonacciHeap<K, V> {
+    nodes: Vec<Option<Node<K, V>>>,
+    min: Option<usize>,
+    n: usize,
+    comparisons: u64,
+}
+
+impl<K: Ord, V> FibonacciHeap<K, V> {
+    /// `MAKE-FIB-HEAP()`
+    pub fn new() -> Self {
+        Self {
+            nodes: Vec::new(),
+            min: None,
+            n: 0,
+            comparisons: 0,
+        }
+    }
+
+    /// `H.min` without removing it.
+    pub fn minimum(&self) -> Option<(&K, &V)> {
+        let node = self.node(self.min?);
+        Some((&node.key, &node.value))
+    }
+
+    /// Current key of a queued element.
+    pub fn key(&self, handle: Handle) -> Option<&K> {
+        let node = self.nodes.get(handle.index())?.as_ref()?;
+        Some(&node.key)
+    }
+
+    /// Number of trees in the root list, `t(H)` in the potential function.
+    pub fn root_count(&self) -> usize {
+        self.min.map_or(0, |min| self.siblings(min).len())
+    }
+
+    /// `CONSOLIDATE(H)`: link roots of equal degree until all differ
+    fn consolidate(&mut self, start: usize) {
+        // D(n) ≤ ⌊log_φ n⌋ < 2 lg n + 2
+        let max_degree = 2 * (usize::BITS - self.n.leading_zeros()) as usize + 2;
+        let mut by_degree: Vec<Option<usize>> = vec![None; max_degree + 1];
+
+        for w in self.siblings(start) {
+            let mut x = w;
+            let mut d = self.node(x).degree;
+            while let Some(mut y) = by_degree[d].take() {
+                if self.less(y, x) {
+                    std::mem::swap(&mut x, &mut y);
+                }
+                self.link(y, x);
+                d += 1;
+            }
+            by_degree[d] = Some(x);
+        }
+
+        self.min = None;
+        for x in by_degree.into_iter().flatten() {
+            self.make_singleton(x);
+            self.add_root(x);
+        }
+    }
+
+    /// `FIB-HEAP-LINK(H, y, x)`: make root `y` a child of root `x`
+    fn link(&mut self, y: usize, x: usize) {
+        self.unlink(y);
+        match self.node(x).child {
+            Some(child) => self.splice(child, y),
+            None => self.node_mut(x).child = Some(y),
+        }
+        let node = self.node_mut(y);
+        node.parent = Some(x);
+        node.mark = false;
+        self.node_mut(x).degree += 1;
+    }
+
+    /// `CUT(H, x, y)`: move child `x` of `y` to the root list
+    fn cut(&mut self, x: usize, y: usize) {
+        if self.node(y).child == Some(x) {
+            let right = self.node(x).right;
+            self.node_mut(y).child = (right != x).then_some(right);
+        }
+        self.unlink(x);
+        self.node_mut(y).degree -= 1;
+        let node = self.node_mut(x);
+        node.parent = None;
+        node.mark = false;
+        self.add_root(x);
+    }
+
+    /// `CASCADING-CUT(H, y)`
+    fn cascading_cut(&mut self, mut y: usize) {
+        while let Some(z) = self.node(y).parent {
+            if !self.node(y).mark {
+                self.node_mut(y).mark = true;
+                return;
+            }
+            self.cut(y, z);
+            y = z;
+        }
+    }
+
+    /// Insert singleton `x` into the root list and update `H.min`
+    fn add_root(&mut self, x: usize) {
+        match self.min {
+            None => self.min = Some(x),
+            Some(min) => {
+                self.splice(min, x);
+                if self.less(x, min) {
+                    self.min = Some(x);
+                }
+            }
+        }
+    }
+
+    /// Insert singleton `x` to the right of `a` in `a`'s circular list
+    fn splice(&mut self, a: usize, x: usize) {
+        let right = self.node(a).right;
+        self.node_mut(x).left = a;
+        self.node_mut(x).right = right;
+        self.node_mut(a).right = x;
+        self.node_mut(right).left = x;
+    }
+
+    /// Remove `x` from its circular list, leaving it a singleton
+    fn unlink(&mut self, x: usize) {
+        let (left, right) = (self.node(x).left, self.node(x).right);
+        self.node_mut(left).right = right;
+        self.node_mut(right).left = left;
+        self.make_singleton(x);
+    }
+
+    fn make_singleton(&mut self, x: usize) {
+        let node = self.node_mut(x);
+        node.left = x;
+        node.right = x;
+    }
+
+    /// `x` and its siblings, starting at `x`
+    fn siblings(&self, x: usize) -> Vec<usize> {
+        let mut list = vec![x];
+        let mut current = self.node(x).right;
+        while current != x {
+            list.push(current);
+            current = self.node(current).right;
+        }
+        list
+    }
+
+    fn less(&mut self, a: usize, b: usize) -> bool {
+        self.comparisons += 1;
+        self.node(a).key < self.node(b).key
+    }
+
+    fn node(&self, x: usize) -> &Node<K, V> {
+        self.nodes[x].as_ref().expect("node is in the heap")
+    }
+
+    fn node_mut(&mut self, x: usize) -> &mut Node<K, V> {
+        self.nodes[x].as_mut().expect("node is in the heap")
+    }
+}
+
+impl<K: Ord, V> Default for FibonacciHeap<K, V> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<K: Ord, V> MinQueue<K, V> for FibonacciHeap<K, V> {
+    /// `FIB-HEAP-INSERT(H, x)`
+    fn insert(&mut self, key: K, value: V) -> Handle {
+        let x = self.nodes.len();
+        self.nodes.push(Some(Node {
+            key,
+            value,
+            parent: None,
+            child: None,
+            left: x,
+            right: x,
+            degree: 0,
+            mark: false,
+        }));
+        self.add_root(x);
+        self.n += 1;
+        Handle::new(x)
+    }
+
+    fn extract_min(&mut self) -> Option<(K, V)> {
+        let z = self.min?;
+        if let Some(child) = self.node(z).child {
+            for x in self.siblings(child) {
+                self.make_singleton(x);
+                self.node_mut(x).parent = None;
+                self.splice(z, x);
+            }
+        }
+        let right = self.node(z).right;
+        self.unlink(z);
+        if right == z {
+            self.min = None;
+        } else {
+            self.consolidate(right);
+        }
+        self.n -= 1;
+
+        let node = self.nodes[z].take()?;
+        Some((node.key, node.value))
+    }
+
+    fn decrease_key(&mut self, handle: Handle, key: K) {
+        let x = handle.index();
+        let node = self.nodes[x]
+            .as_mut()
+            .expect("handle is not in the priority queue");
+        self.comparisons += 1;
+        assert!(key <= node.key, "new key is larger than current key");
+        node.key = key;
+
+        if let Some(y) = self.node(x).parent
+            && self.less(x, y)
+        {
+            self.cut(x, y);
+            self.cascading_cut(y);
+        }
+        if let Some(min) = self.min
+            && min != x
+            && self.less(x, min)
+        {
+            self.min = Some(x);
+        }
+    }
+
+    fn contains(&self, handle: Handle) -> bool {
+        self.key(handle).is_some()
+    }
+
+    fn len(&self) -> usize {
+        self.n
+    }
+
+    fn comparisons(&self) -> u64 {
+        self.comparisons
+    }
+}
@@ -0,0 +1,178 @@
+use crate::trace::{NoopTracer, TraceEvent, Tracer};
+
+/// `PARENT(i)` for a **1-based** heap index `i`.
+pub fn parent(i: usize) -> usize {
+    i / 2
+}
+
+/// `LEFT(i)` for a **1-based** heap index `i`.
+pub fn left(i: usize) -> usize {
+    2 * i
+}
+
+/// `RIGHT(i)` for a **1-based** heap index `i`.
+pub fn right(i: usize) -> usize {
+    2 * i + 1
+}
+
+/// # Max-Heapify
+///
+/// Lets the value at **1-based** index `i` float down so that the subtree
+/// rooted at `i` obeys the max-heap property, assuming the subtrees rooted
+/// at `LEFT(i)` and `RIGHT(i)` already do. The heap is the whole slice,
+/// i.e. `A.heap-size = arr.len()`.
+///
+/// # Pseudocode (CLRS)
+///
+/// ```text
+/// MAX-HEAPIFY(A, i)
+/// l = LEFT(i)
+/// r = RIGHT(i)
+/// if l <= A.heap-size and A[l] > A[i]
+///     largest = l
+/// else largest = i
+/// if r <= A.heap-size and A[r] > A[largest]
+///     largest = r
+/// if largest != i
+///     exchange A[i] with A[largest]
+///     MAX-HEAPIFY(A, largest)
+/// ```
+pub fn max_heapify<T: Ord>(arr: &mut [T], i: usize) {
+    max_heapify_traced(arr, i, &mut NoopTracer);
+}
+
+/// # Build-Max-Heap
+///
+/// Turns an arbitrary slice into a max-heap in **O(n)** time.
+///
+/// # Pseudocode (CLRS)
+///
+/// ```text
+/// BUILD-MAX-HEAP(A, n)
+/// A.heap-size = n
+/// for i = floor(n / 2) downto 1
+///     MAX-HEAPIFY(A, i)
+/// ```
+pub fn build_max_heap<T: Ord>(arr: &mut [T]) {
+    build_max_heap_traced(arr, &mut NoopTracer);
+}
+
+/// # Heapsort
+///
+/// Sorts a mutable slice **in-place** using a max-heap.
+///
+/// # Pseudocode (CLRS)
+///
+/// ```text
+/// HEAPSORT(A, n)
+/// BUILD-MAX-HEAP(A, n)
+/// for i = n downto 2
+///     exchange A[1] with A[i]
+///     A.heap-size = A.heap-size - 1
+///     MAX-HEAPIFY(A, 1)
+/// ```
+///
+/// # Example
+///
+/// ```rust
+/// use algorithms::heap::heapsort::heapsort;
+///
+/// let mut data = [16, 4, 10, 14, 7, 9, 3, 2, 8, 1];
+/// heapsort(&mut data);
+/// assert_eq!(data, [1, 2, 3, 4, 7, 8, 9, 10, 14, 16]);
+/// ```
+///
+/// # Notes
+///
+/// * The algorithm runs in **O(n log n)** time.
+/// * It sorts in place but is **not stable**.
+pub fn heapsort<T: Ord>(arr: &mut [T]) {
+    heapsort_traced(arr, &mut NoopTracer);
+}
+
+/// # Max-Heapify (traced)
+///
+/// Same as [`max_heapify`], but reports comparisons and exchanges
+/// (with zero-based indices) to `tracer`.
+pub fn max_heapify_traced<T, R>(arr: &mut [T], i: usize, tracer: &mut R)
+where
+    T: Ord,
+    R: Tracer<T> + ?Sized,
+{
+    let heap_size = arr.len();
+    heapify(arr, heap_size, i, tracer);
+}
+
+/// # Build-Max-Heap (traced)
+pub fn build_max_heap_traced<T, R>(arr: &mut [T], tracer: &mut R)
+where
+    T: Ord,
+    R: Tracer<T> + ?Sized,
+{
+    for i in (1..=parent(arr.len())).rev() {
+        max_heapify_traced(arr, i, tracer);
+    }
+}
+
+/// # Heapsort (traced)
+///
+/// Same as [`heapsort`], but reports every comparison and exchange to `tracer`.
+pub fn heapsort_traced<T, R>(arr: &mut [T], tracer: &mut R)
+where
+    T: Ord,
+    R: Tracer<T> + ?Sized,
+{
+    build_max_heap_traced(arr, tracer);
+    for i in (2..=arr.len()).rev() {
+        arr.swap(0, i - 1);
+        tracer.record(TraceEvent::Swap { i: 0, j: i - 1 }, arr);
+        heapify(arr, i - 1, 1, tracer);
+    }
+}
+
+/// `MAX-HEAPIFY` on the heap `A[1..=heap_size]` embedded in the full slice,
+/// so tracers always see the complete array.
+fn heapify<T, R>(arr: &mut [T], heap_size: usize, mut i: usize, tracer: &mut R)
+where
+    T: Ord,
+    R: Tracer<T> + ?Sized,
+{
+    // Iterative form of the tail-recursive CLRS procedure.
+    loop {
+        let l = left(i);
+        let r = right(i);
+        let mut largest = i;
+
+        if l <= heap_size {
+            tracer.record(TraceEvent::Compare { i: l - 1, j: i - 1 }, arr);
+            if arr[l - 1] > arr[i - 1] {
+                largest = l;
+            }
+        }
+        if r <= heap_size {
+            tracer.record(
+                TraceEvent::Compare {
+                    i: r - 1,
+                    j: largest - 1,
+                },
+                arr,
+            );
+            if arr[r - 1] > arr[largest - 1] {
+                largest = r;
+            }
+        }
+        if largest == i {
+            return;
+        }
+
+        arr.swap(i - 1, largest - 1);
+        tracer.record(
+            TraceEvent::Swap {
+                i: i - 1,
+                j: largest - 1,
+            },
+            arr,
+        );
+        i = largest;
+    }
+}
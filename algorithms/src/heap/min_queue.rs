@@ -0,0 +1,165 @@
+//! # Min-Priority Queues
+//!
+//! The operations MST-PRIM and DIJKSTRA need from their queue, as a trait
+//! so the same algorithm runs on any implementation (CLRS Sections 21.2,
+//! 22.3). The choice decides the running time:
+//!
+//! | Queue                                    | EXTRACT-MIN   | DECREASE-KEY  |
+//! |------------------------------------------|---------------|---------------|
+//! | [`MinPriorityQueue`] (binary heap)       | O(lg n)       | O(lg n)       |
+//! | [`FibonacciHeap`]                        | O(lg n) amort.| O(1) amort.   |
+//! | [`ArrayQueue`] (unsorted array)          | O(n)          | O(1)          |
+//!
+//! [`FibonacciHeap`]: super::fibonacci_heap::FibonacciHeap
+
+use super::priority_queue::{Handle, MinPriorityQueue};
+
+/// # Min Queue
+///
+/// Min-priority queue of `(key, value)` pairs whose keys can be decreased
+/// through the [`Handle`] returned by `insert`.
+pub trait MinQueue<K: Ord, V> {
+    /// `INSERT(Q, x, k)`
+    fn insert(&mut self, key: K, value: V) -> Handle;
+
+    /// `EXTRACT-MIN(Q)`
+    fn extract_min(&mut self) -> Option<(K, V)>;
+
+    /// `DECREASE-KEY(Q, x, k)`
+    ///
+    /// # Panics
+    ///
+    /// Panics if `handle` is no longer queued or if `key` is larger than
+    /// the current key.
+    fn decrease_key(&mut self, handle: Handle, key: K);
+
+    /// Whether the element behind `handle` is still in the queue.
+    fn contains(&self, handle: Handle) -> bool;
+
+    /// Number of queued elements.
+    fn len(&self) -> usize;
+
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Key comparisons made so far.
+    fn comparisons(&self) -> u64;
+}
+
+impl<K: Ord, V> MinQueue<K, V> for MinPriorityQueue<K, V> {
+    fn insert(&mut self, key: K, value: V) -> Handle {
+        MinPriorityQueue::insert(self, key, value)
+    }
+
+    fn extract_min(&mut self) -> Option<(K, V)> {
+        MinPriorityQueue::extract_min(self)
+    }
+
+    fn decrease_key(&mut self, handle: Handle, key: K) {
+        MinPriorityQueue::decrease_key(self, handle, key)
+    }
+
+    fn contains(&self, handle: Handle) -> bool {
+        MinPriorityQueue::contains(self, handle)
+    }
+
+    fn len(&self) -> usize {
+        MinPriorityQueue::len(self)
+    }
+
+    fn comparisons(&self) -> u64 {
+        MinPriorityQueue::comparisons(self)
+    }
+}
+
+/// # Array Queue
+///
+/// Unsorted array indexed by handle: `INSERT` and `DECREASE-KEY` write one
+/// slot, `EXTRACT-MIN` scans all of them. With `|V|` elements this gives
+/// MST-PRIM and DIJKSTRA **O(V²)** time, best for dense graphs.
+///
+/// # Example
+///
+/// ```rust
+/// use algorithms::heap::min_queue::{ArrayQueue, MinQueue};
+///
+/// let mut queue = ArrayQueue::new();
+/// let a = queue.insert(7, 'a');
+/// queue.insert(3, 'b');
+/// queue.decrease_key(a, 1);
+///
+/// assert_eq!(queue.extract_min(), Some((1, 'a')));
+/// assert_eq!(queue.extract_min(), Some((3, 'b')));
+/// assert_eq!(queue.extract_min(), None);
+/// ```
+#[derive(Debug, Clone)]
+pub struct ArrayQueue<K, V> {
+    slots: Vec<Option<(K, V)>>,
+    len: usize,
+    comparisons: u64,
+}
+
+impl<K: Ord, V> ArrayQueue<K, V> {
+    pub fn new() -> Self {
+        Self {
+            slots: Vec::new(),
+            len: 0,
+            comparisons: 0,
+        }
+    }
+}
+
+impl<K: Ord, V> Default for ArrayQueue<K, V> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<K: Ord, V> MinQueue<K, V> for ArrayQueue<K, V> {
+    fn insert(&mut self, key: K, value: V) -> Handle {
+        self.slots.push(Some((key, value)));
+        self.len += 1;
+        Handle::new(self.slots.len() - 1)
+    }
+
+    fn extract_min(&mut self) -> Option<(K, V)> {
+        let mut min: Option<usize> = None;
+        for (i, slot) in self.slots.iter().enumerate() {
+            let Some((key, _)) = slot else { continue };
+            if let Some(j) = min {
+                self.comparisons += 1;
+                if self.slots[j]
+                    .as_ref()
+                    .is_some_and(|(min_key, _)| key >= min_key)
+                {
+                    continue;
+                }
+            }
+            min = Some(i);
+        }
+        self.len -= usize::from(min.is_some());
+        self.slots[min?].take()
+    }
+
+    fn decrease_key(&mut self, handle: Handle, key: K) {
+        let (current, _) = self.slots[handle.index()]
+            .as_mut()
+            .expect("handle is not in the priority queue");
+        self.comparisons += 1;
+        assert!(key <= *current, "new key is larger than current key");
+        *current = key;
+    }
+
+    fn contains(&self, handle: Handle) -> bool {
+        self.slots.get(handle.index()).is_some_and(Option::is_some)
+    }
+
+    fn len(&self) -> usize {
+        self.len
+    }
+
+    fn comparisons(&self) -> u64 {
+        self.comparisons
+    }
+}
@@ -0,0 +1,4 @@
+pub mod fibonacci_heap;
+pub mod heapsort;
+pub mod min_queue;
+pub mod priority_queue;
@@ -0,0 +1,305 @@
+use std::marker::PhantomData;
+
+use super::heapsort::{left, parent, right};
+
+/// Stable reference to an element inserted into a [`PriorityQueue`].
+///
+/// CLRS keeps a "handle" in every application object so that the heap
+/// position of the object can be found when its key changes; this is that
+/// handle. It stays valid (but [`PriorityQueue::contains`] turns `false`)
+/// after the element has been extracted.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Handle(usize);
+
+impl Handle {
+    pub(crate) fn new(index: usize) -> Self {
+        Self(index)
+    }
+
+    /// Zero-based insertion number of the element.
+    pub fn index(self) -> usize {
+        self.0
+    }
+}
+
+/// Decides which of two keys belongs closer to the root.
+pub trait HeapOrder {
+    fn above<K: Ord>(a: &K, b: &K) -> bool;
+}
+
+/// Largest key at the root.
+#[derive(Debug, Clone, Copy)]
+pub struct MaxOrder;
+
+/// Smallest key at the root.
+#[derive(Debug, Clone, Copy)]
+pub struct MinOrder;
+
+impl HeapOrder for MaxOrder {
+    fn above<K: Ord>(a: &K, b: &K) -> bool {
+        a > b
+    }
+}
+
+impl HeapOrder for MinOrder {
+    fn above<K: Ord>(a: &K, b: &K) -> bool {
+        a < b
+    }
+}
+
+/// Max-priority queue: `insert`, `maximum`, `extract_max`, `increase_key`.
+pub type MaxPriorityQueue<K, V> = PriorityQueue<K, V, MaxOrder>;
+
+/// Min-priority queue: `insert`, `minimum`, `extract_min`, `decrease_key`.
+pub type MinPriorityQueue<K, V> = PriorityQueue<K, V, MinOrder>;
+
+#[derive(Debug, Clone)]
+struct Entry<K, V> {
+    key: K,
+    value: V,
+    /// Zero-based position in `heap`, `None` once extracted
+    position: Option<usize>,
+}
+
+/// # Priority Queue
+///
+/// Binary heap of `(key, value)` pairs with handle-based key updates
+/// (CLRS Section 6.5). The heap stores handles; every entry remembers its
+/// heap position so a key can be changed in **O(log n)** time.
+///
+/// # Example
+///
+/// ```rust
+/// use algorithms::heap::priority_queue::MinPriorityQueue;
+///
+/// let mut queue = MinPriorityQueue::new();
+/// let a = queue.insert(7, 'a');
+/// queue.insert(3, 'b');
+/// queue.decrease_key(a, 1);
+///
+/// assert_eq!(queue.extract_min(), Some((1, 'a')));
+/// assert_eq!(queue.extract_min(), Some((3, 'b')));
+/// assert_eq!(queue.extract_min(), None);
+/// ```
+#[derive(Debug, Clone)]
+pub struct PriorityQueue<K, V, O> {
+    heap: Vec<usize>,
+    entries: Vec<Option<Entry<K, V>>>,
+    comparisons: u64,
+    order: PhantomData<O>,
+}
+
+impl<K: Ord, V, O: HeapOrder> PriorityQueue<K, V, O> {
+    pub fn new() -> Self {
+        Self {
+            heap: Vec::new(),
+            entries: Vec::new(),
+            comparisons: 0,
+            order: PhantomData,
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.heap.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.heap.is_empty()
+    }
+
+    /// Key comparisons made so far.
+    pub fn comparisons(&self) -> u64 {
+        self.comparisons
+    }
+
+    /// Whether the element behind `handle` is still in the queue.
+    pub fn contains(&self, handle: Handle) -> bool {
+        self.entry(handle).is_some()
+    }
+
+    /// Current key of a queued element.
+    pub fn key(&self, handle: Handle) -> Option<&K> {
+        self.entry(handle).map(|e| &e.key)
+    }
+
+    /// Value of a queued element.
+    pub fn value(&self, handle: Handle) -> Option<&V> {
+        self.entry(handle).map(|e| &e.value)
+    }
+
+    /// `INSERT(S, x, k)`: add `value` with `key` and return its handle.
+    pub fn insert(&mut self, key: K, value: V) -> Handle {
+        let id = self.entries.len();
+        self.entries.push(Some(Entry {
+            key,
+            value,
+            position: Some(self.heap.len()),
+        }));
+        self.heap.push(id);
+        self.sift_up(self.heap.len() - 1);
+        Handle(id)
+    }
+
+    /// Root element without removing it.
+    pub fn peek(&self) -> Option<(&K, &V)> {
+        let id = *self.heap.first()?;
+        let entry = self.entries[id].as_ref()?;
+        Some((&entry.key, &entry.value))
+    }
+
+    /// Remove and return the root element.
+    pub fn pop(&mut self) -> Option<(K, V)> {
+        self.pop_with_handle().map(|(_, key, value)| (key, value))
+    }
+
+    /// Remove the root element and also return its handle.
+    pub fn pop_with_handle(&mut self) -> Option<(Handle, K, V)> {
+        if self.heap.is_empty() {
+            return None;
+        }
+        let last = self.heap.len() - 1;
+        self.swap(0, last);
+        let id = self.heap.pop()?;
+        if !self.heap.is_empty() {
+            self.sift_down(0);
+        }
+        let entry = self.entries[id].take()?;
+        Some((Handle(id), entry.key, entry.value))
+    }
+
+    /// Change the key of a queued element in either direction.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `handle` is no longer in the queue.
+    pub fn update_key(&mut self, handle: Handle, key: K) {
+        let entry = self.entries[handle.0]
+            .as_mut()
+            .expect("handle is not in the priority queue");
+        let position = entry.position.expect("queued entry has a heap position");
+        let moves_up = O::above(&key, &entry.key);
+        entry.key = key;
+        self.comparisons += 1;
+        if moves_up {
+            self.sift_up(position);
+        } else {
+            self.sift_down(position);
+        }
+    }
+
+    fn entry(&self, handle: Handle) -> Option<&Entry<K, V>> {
+        self.entries.get(handle.0)?.as_ref()
+    }
+
+    fn key_at(&self, position: usize) -> &K {
+        &self.entries[self.heap[position]]
+            .as_ref()
+            .expect("heap only holds queued entries")
+            .key
+    }
+
+    /// Whether the key at heap position `a` belongs above the one at `b`
+    fn above(&mut self, a: usize, b: usize) -> bool {
+        self.comparisons += 1;
+        O::above(self.key_at(a), self.key_at(b))
+    }
+
+    /// Exchange two heap slots and keep the stored positions in sync
+    fn swap(&mut self, a: usize, b: usize) {
+        self.heap.swap(a, b);
+        for position in [a, b] {
+            if let Some(entry) = self.entries[self.heap[position]].as_mut() {
+                entry.position = Some(position);
+            }
+        }
+    }
+
+    /// Loop of `INCREASE-KEY`: move the element at `position` towards the root
+    fn sift_up(&mut self, position: usize) {
+        // Work with 1-based indices like CLRS.
+        let mut i = position + 1;
+        while i > 1 && self.above(i - 1, parent(i) - 1) {
+            self.swap(i - 1, parent(i) - 1);
+            i = parent(i);
+        }
+    }
+
+    /// `MAX-HEAPIFY` / `MIN-HEAPIFY` starting at `position`
+    fn sift_down(&mut self, position: usize) {
+        let heap_size = self.heap.len();
+        let mut i = position + 1;
+        loop {
+            let (l, r) = (left(i), right(i));
+            let mut top = i;
+            if l <= heap_size && self.above(l - 1, top - 1) {
+                top = l;
+            }
+            if r <= heap_size && self.above(r - 1, top - 1) {
+                top = r;
+            }
+            if top == i {
+                return;
+            }
+            self.swap(i - 1, top - 1);
+            i = top;
+        }
+    }
+}
+
+impl<K: Ord, V, O: HeapOrder> Default for PriorityQueue<K, V, O> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<K: Ord, V> PriorityQueue<K, V, MaxOrder> {
+    /// `MAXIMUM(S)`
+    pub fn maximum(&self) -> Option<(&K, &V)> {
+        self.peek()
+    }
+
+    /// `EXTRACT-MAX(S)`
+    pub fn extract_max(&mut self) -> Option<(K, V)> {
+        self.pop()
+    }
+
+    /// `INCREASE-KEY(S, x, k)`
+    ///
+    /// # Panics
+    ///
+    /// Panics if `handle` is no longer queued or if `key` is smaller than
+    /// the current key ("new key is smaller than current key").
+    pub fn increase_key(&mut self, handle: Handle, key: K) {
+        let current = self
+            .key(handle)
+            .expect("handle is not in the priority queue");
+        assert!(key >= *current, "new key is smaller than current key");
+        self.update_key(handle, key);
+    }
+}
+
+impl<K: Ord, V> PriorityQueue<K, V, MinOrder> {
+    /// `MINIMUM(S)`
+    pub fn minimum(&self) -> Option<(&K, &V)> {
+        self.peek()
+    }
+
+    /// `EXTRACT-MIN(S)`
+    pub fn extract_min(&mut self) -> Option<(K, V)> {
+        self.pop()
+    }
+
+    /// `DECREASE-KEY(S, x, k)`
+    ///
+    /// # Panics
+    ///
+    /// Panics if `handle` is no longer queued or if `key` is larger than
+    /// the current key ("new key is larger than current key").
+    pub fn decrease_key(&mut self, handle: Handle, key: K) {
+        let current = self
+            .key(handle)
+            .expect("handle is not in the priority queue");
+        assert!(key <= *current, "new key is larger than current key");
+        self.update_key(handle, key);
+    }
+}
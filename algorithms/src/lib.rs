@@ -1 +1,19 @@
-pub mod sorting;
\ No newline at end of file
+//! # CLRS Algorithms
+//!
+//! Implementations of the algorithms from *Introduction to Algorithms*
+//! (CLRS, 4th edition), one module per topic. Every runnable algorithm is
+//! listed in the [`registry`]; [`prelude`] re-exports the common entry points.
+
+pub mod analysis;
+pub mod disjoint_set;
+pub mod dp;
+pub mod graph;
+pub mod hashing;
+pub mod heap;
+pub mod prelude;
+pub mod random;
+pub mod registry;
+pub mod selection;
+pub mod sorting;
+pub mod trace;
+pub mod trees;
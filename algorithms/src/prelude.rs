@@ -0,0 +1,32 @@
+//! # Prelude
+//!
+//! The most common entry points in one import:
+//!
+//! ```rust
+//! use algorithms::prelude::*;
+//!
+//! let mut data = [31, 41, 59, 26, 41, 58];
+//! insertion_sort(&mut data);
+//! assert_eq!(data, [26, 31, 41, 41, 58, 59]);
+//! ```
+
+pub use crate::analysis::{Counts, InputCase, OpCounter};
+pub use crate::dp::lcs::{lcs, lcs_length, print_lcs};
+pub use crate::graph::adjacency_list::AdjacencyList;
+pub use crate::graph::adjacency_matrix::AdjacencyMatrix;
+pub use crate::graph::parse::{parse_dot, parse_edge_list};
+pub use crate::graph::representation::{Edge, Graph, Weight};
+pub use crate::heap::heapsort::heapsort;
+pub use crate::heap::priority_queue::{MaxPriorityQueue, MinPriorityQueue};
+pub use crate::random::{RandomSource, XorShift64};
+pub use crate::registry::{self, Algorithm, Entry};
+pub use crate::selection::minimum_maximum::minimum_maximum;
+pub use crate::selection::randomized_select::randomized_select;
+pub use crate::selection::select::select;
+pub use crate::sorting::bucket_sort::bucket_sort;
+pub use crate::sorting::counting_sort::counting_sort;
+pub use crate::sorting::insertion_sort::{insertion_sort, insertion_sort_by};
+pub use crate::sorting::merge_sort::{merge_sort, merge_sort_by};
+pub use crate::sorting::quicksort::{quicksort, randomized_quicksort};
+pub use crate::sorting::radix_sort::radix_sort;
+pub use crate::trace::{EventLog, NoopTracer, SnapshotCollector, TraceEvent, Tracer};
@@ -0,0 +1,59 @@
+//! # Random Sources
+//!
+//! Randomized algorithms and input generators take a [`RandomSource`]
+//! instead of reaching for a global generator, so every run can be
+//! reproduced from a seed.
+
+/// Source of pseudo-random numbers used throughout the crate.
+pub trait RandomSource {
+    /// Next raw 64-bit value.
+    fn next_u64(&mut self) -> u64;
+
+    /// Uniform value in `low..high` (`low < high`), CLRS `RANDOM(low, high - 1)`.
+    fn gen_range(&mut self, low: usize, high: usize) -> usize {
+        assert!(low < high, "empty range {}..{}", low, high);
+        low + (self.next_u64() % (high - low) as u64) as usize
+    }
+
+    /// Uniform value in `[0, 1)`.
+    fn next_f64(&mut self) -> f64 {
+        (self.next_u64() >> 11) as f64 / (1u64 << 53) as f64
+    }
+}
+
+/// Small, fast xorshift64* generator. Not cryptographically secure.
+#[derive(Debug, Clone)]
+pub struct XorShift64 {
+    state: u64,
+}
+
+impl XorShift64 {
+    /// Create a generator from `seed` (a zero seed is remapped, since
+    /// xorshift would otherwise only ever produce zeros).
+    pub fn new(seed: u64) -> Self {
+        Self {
+            state: if seed == 0 {
+                0x9E37_79B9_7F4A_7C15
+            } else {
+                seed
+            },
+        }
+    }
+}
+
+impl RandomSource for XorShift64 {
+    fn next_u64(&mut self) -> u64 {
+        self.state ^= self.state >> 12;
+        self.state ^= self.state << 25;
+        self.state ^= self.state >> 27;
+        self.state.wrapping_mul(0x2545_F491_4F6C_DD1D)
+    }
+}
+
+/// Fisher-Yates shuffle (CLRS `RANDOMIZE-IN-PLACE`).
+pub fn shuffle<T, R: RandomSource>(arr: &mut [T], rng: &mut R) {
+    for i in (1..arr.len()).rev() {
+        let j = rng.gen_range(0, i + 1);
+        arr.swap(i, j);
+    }
+}
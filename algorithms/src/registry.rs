@@ -0,0 +1,733 @@
+//! # Algorithm Registry
+//!
+//! One [`Algorithm`] entry per runnable algorithm: its ID, where it lives in
+//! CLRS, the procedure it implements and a type-erased entry point. The CLI
+//! and the LaTeX generator enumerate this table instead of hard-coding
+//! algorithm names, so registering a new algorithm here is enough to make it
+//! runnable and documentable.
+//!
+//! # Example
+//!
+//! ```rust
+//! use algorithms::registry;
+//!
+//! let algorithm = registry::find("insertion_sort").unwrap();
+//! assert_eq!(algorithm.chapter, 2);
+//! assert_eq!(algorithm.pseudocode, "INSERTION-SORT");
+//!
+//! let mut data = [5, 2, 4, 6, 1, 3];
+//! assert!(algorithm.run_on_array(&mut data));
+//! assert_eq!(data, [1, 2, 3, 4, 5, 6]);
+//! ```
+
+use crate::analysis::{
+    Counts, InputCase, OpCounter, merge_sort_worst_input, quicksort_best_input, random_input,
+    reversed_input, sorted_input,
+};
+use crate::disjoint_set::{components, lca};
+use crate::dp::lcs;
+use crate::graph::adjacency_list::AdjacencyList;
+use crate::graph::flow::{ford_fulkerson, matching, push_relabel};
+use crate::graph::{apsp, bfs, biconnected, dfs, mst, scc, sssp, topological_sort};
+use crate::hashing::{chained, open_addressing};
+use crate::heap::heapsort::heapsort_traced;
+use crate::random::XorShift64;
+use crate::selection::randomized_select::randomized_select_traced;
+use crate::selection::select::select_traced;
+use crate::sorting::insertion_sort::insertion_sort_traced;
+use crate::sorting::merge_sort::merge_sort_traced;
+use crate::sorting::quicksort::{
+    quicksort_hoare, quicksort_median_of_three, quicksort_three_way, quicksort_traced,
+    randomized_quicksort_traced, tail_recursive_quicksort,
+};
+use crate::sorting::{bucket_sort, counting_sort, radix_sort};
+use crate::trace::{NoopTracer, Tracer};
+use crate::trees::{bst, btree, interval, order_statistic, red_black};
+
+/// Seed used when an entry is run without an explicit random source
+const DEFAULT_SEED: u64 = 42;
+
+/// Type-erased entry point of a registered algorithm.
+///
+/// Randomized algorithms draw from the given [`XorShift64`]; deterministic
+/// ones ignore it. Untraced implementations ignore the tracer.
+#[derive(Clone, Copy)]
+pub enum Entry {
+    /// Sorts an integer array in place
+    Sort(fn(&mut [i64], &mut XorShift64, &mut dyn Tracer<i64>)),
+    /// Sorts an array of natural numbers in place, or explains why it
+    /// cannot, leaving the array unchanged
+    SortNaturals(fn(&mut [i64]) -> Result<(), String>),
+    /// Sorts an array of reals from `[0, 1)` in place, or explains why it
+    /// cannot, leaving the array unchanged
+    SortReals(fn(&mut [f64]) -> Result<(), String>),
+    /// Returns the element of the given 1-based rank, permuting the array
+    Select(fn(&mut [i64], usize, &mut XorShift64, &mut dyn Tracer<i64>) -> i64),
+    /// Combines two strings into a result string
+    Strings(fn(&str, &str) -> String),
+    /// Runs on a graph from a source vertex and describes the result
+    Graph(fn(&AdjacencyList, usize) -> String),
+    /// Inserts the keys in order into a dictionary and describes it
+    Keys(fn(&[i64]) -> String),
+}
+
+/// Input generators for the best and worst case of an experiment; the
+/// average case is always a random permutation. A case is left out when
+/// no input of a given size is known to be the best or the worst.
+#[derive(Clone, Copy)]
+pub struct CaseInputs {
+    pub best: Option<fn(usize) -> Vec<i64>>,
+    pub worst: Option<fn(usize) -> Vec<i64>>,
+}
+
+/// Description of one registered algorithm.
+#[derive(Clone, Copy)]
+pub struct Algorithm {
+    /// Stable identifier used by the CLI and `report.yml`
+    pub id: &'static str,
+    /// Human-readable name
+    pub name: &'static str,
+    /// CLRS (4th edition) chapter
+    pub chapter: u8,
+    /// CLRS section, exercise or problem the algorithm comes from
+    pub section: &'static str,
+    /// Name of the CLRS procedure implemented
+    pub pseudocode: &'static str,
+    /// Implementing source file, relative to `algorithms/src`
+    pub source: &'static str,
+    pub entry: Entry,
+    /// Present if operation counts can be measured
+    pub inputs: Option<CaseInputs>,
+}
+
+impl Algorithm {
+    /// Run an integer-array entry without tracing.
+    ///
+    /// Sorting entries sort `data`; selection entries select the lower
+    /// median. Returns `false` for entries that take other input.
+    pub fn run_on_array(&self, data: &mut [i64]) -> bool {
+        self.run_on_array_traced(data, &mut XorShift64::new(DEFAULT_SEED), &mut NoopTracer)
+    }
+
+    /// Same as [`run_on_array`](Self::run_on_array), but with an explicit
+    /// random source and a tracer that sees every step.
+    pub fn run_on_array_traced(
+        &self,
+        data: &mut [i64],
+        rng: &mut XorShift64,
+        tracer: &mut dyn Tracer<i64>,
+    ) -> bool {
+        match self.entry {
+            Entry::Sort(sort) => sort(data, rng, tracer),
+            Entry::SortNaturals(sort) => return sort(data).is_ok(),
+            Entry::Select(select) if !data.is_empty() => {
+                select(data, data.len().div_ceil(2), rng, tracer);
+            }
+            Entry::Select(_) => {}
+            Entry::SortReals(_) | Entry::Strings(_) | Entry::Graph(_) | Entry::Keys(_) => {
+                return false;
+            }
+        }
+        true
+    }
+
+    /// Count comparisons and writes on one input of size `n`.
+    ///
+    /// Returns `None` if the algorithm has no [`CaseInputs`] or no input
+    /// generator for `case`.
+    pub fn count_operations(
+        &self,
+        case: InputCase,
+        n: usize,
+        rng: &mut XorShift64,
+    ) -> Option<Counts> {
+        let inputs = self.inputs?;
+        let mut data = match case {
+            InputCase::Best => (inputs.best?)(n),
+            InputCase::Average => random_input(n, rng),
+            InputCase::Worst => (inputs.worst?)(n),
+        };
+        let mut counter = OpCounter::new();
+        self.run_on_array_traced(&mut data, rng, &mut counter)
+            .then(|| counter.counts())
+    }
+}
+
+/// Title of a CLRS (4th edition) chapter.
+pub fn chapter_title(chapter: u8) -> &'static str {
+    match chapter {
+        2 => "Getting Started",
+        6 => "Heapsort",
+        7 => "Quicksort",
+        8 => "Sorting in Linear Time",
+        9 => "Medians and Order Statistics",
+        11 => "Hash Tables",
+        12 => "Binary Search Trees",
+        13 => "Red-Black Trees",
+        14 => "Dynamic Programming",
+        17 => "Augmenting Data Structures",
+        18 => "B-Trees",
+        19 => "Data Structures for Disjoint Sets",
+        20 => "Elementary Graph Algorithms",
+        21 => "Minimum Spanning Trees",
+        22 => "Single-Source Shortest Paths",
+        23 => "All-Pairs Shortest Paths",
+        24 => "Maximum Flow",
+        25 => "Matchings in Bipartite Graphs",
+        _ => "Other",
+    }
+}
+
+/// All registered algorithms, ordered by chapter.
+pub fn all() -> &'static [Algorithm] {
+    ALGORITHMS
+}
+
+/// Look up an algorithm by its ID.
+pub fn find(id: &str) -> Option<&'static Algorithm> {
+    ALGORITHMS.iter().find(|a| a.id == id)
+}
+
+/// Registered algorithms grouped by CLRS chapter, in chapter order.
+pub fn by_chapter() -> Vec<(u8, Vec<&'static Algorithm>)> {
+    let mut groups: Vec<(u8, Vec<&'static Algorithm>)> = Vec::new();
+    for algorithm in ALGORITHMS {
+        match groups
+            .iter_mut()
+            .find(|(chapter, _)| *chapter == algorithm.chapter)
+        {
+            Some((_, members)) => members.push(algorithm),
+            None => groups.push((algorithm.chapter, vec![algorithm])),
+        }
+    }
+    groups.sort_by_key(|(chapter, _)| *chapter);
+    groups
+}
+
+/// Best case sorted, worst case reversed
+const SORTED_OR_REVERSED: Option<CaseInputs> = Some(CaseInputs {
+    best: Some(sorted_input),
+    worst: Some(reversed_input),
+});
+
+/// Only random permutations, for algorithms without a known best or worst
+/// input
+const AVERAGE_ONLY: Option<CaseInputs> = Some(CaseInputs {
+    best: None,
+    worst: None,
+});
+
+static ALGORITHMS: &[Algorithm] = &[
+    Algorithm {
+        id: "insertion_sort",
+        name: "Insertion Sort",
+        chapter: 2,
+        section: "2.1",
+        pseudocode: "INSERTION-SORT",
+        source: "sorting/insertion_sort.rs",
+        entry: Entry::Sort(|a, _, t| insertion_sort_traced(a, t)),
+        inputs: SORTED_OR_REVERSED,
+    },
+    Algorithm {
+        id: "merge_sort",
+        name: "Merge Sort",
+        chapter: 2,
+        section: "2.3",
+        pseudocode: "MERGE-SORT",
+        source: "sorting/merge_sort.rs",
+        entry: Entry::Sort(|a, _, t| merge_sort_traced(a, t)),
+        inputs: Some(CaseInputs {
+            best: Some(sorted_input),
+            worst: Some(merge_sort_worst_input),
+        }),
+    },
+    Algorithm {
+        id: "heapsort",
+        name: "Heapsort",
+        chapter: 6,
+        section: "6.4",
+        pseudocode: "HEAPSORT",
+        source: "heap/heapsort.rs",
+        entry: Entry::Sort(|a, _, t| heapsort_traced(a, t)),
+        // Sorted and reversed input are not its extremes: a reversed array
+        // is already a max-heap
+        inputs: AVERAGE_ONLY,
+    },
+    Algorithm {
+        id: "quicksort",
+        name: "Quicksort",
+        chapter: 7,
+        section: "7.1",
+        pseudocode: "QUICKSORT",
+        source: "sorting/quicksort.rs",
+        entry: Entry::Sort(|a, _, t| quicksort_traced(a, t)),
+        inputs: Some(CaseInputs {
+            best: Some(quicksort_best_input),
+            worst: Some(sorted_input),
+        }),
+    },
+    Algorithm {
+        id: "randomized_quicksort",
+        name: "Randomized Quicksort",
+        chapter: 7,
+        section: "7.3",
+        pseudocode: "RANDOMIZED-QUICKSORT",
+        source: "sorting/quicksort.rs",
+        entry: Entry::Sort(|a, rng, t| randomized_quicksort_traced(a, rng, t)),
+        // The random pivots make every input an average case
+        inputs: AVERAGE_ONLY,
+    },
+    Algorithm {
+        id: "quicksort_hoare",
+        name: "Quicksort with Hoare Partition",
+        chapter: 7,
+        section: "Problem 7-1",
+        pseudocode: "HOARE-PARTITION",
+        source: "sorting/quicksort.rs",
+        entry: Entry::Sort(|a, _, _| quicksort_hoare(a)),
+        inputs: None,
+    },
+    Algorithm {
+        id: "quicksort_three_way",
+        name: "Quicksort with Equal Elements",
+        chapter: 7,
+        section: "Problem 7-2",
+        pseudocode: "PARTITION'",
+        source: "sorting/quicksort.rs",
+        entry: Entry::Sort(|a, _, _| quicksort_three_way(a)),
+        inputs: None,
+    },
+    Algorithm {
+        id: "tail_recursive_quicksort",
+        name: "Tail-Recursive Quicksort",
+        chapter: 7,
+        section: "Problem 7-4",
+        pseudocode: "TRE-QUICKSORT",
+        source: "sorting/quicksort.rs",
+        entry: Entry::Sort(|a, _, _| tail_recursive_quicksort(a)),
+        inputs: None,
+    },
+    Algorithm {
+        id: "quicksort_median_of_three",
+        name: "Median-of-3 Quicksort",
+        chapter: 7,
+        section: "Problem 7-5",
+        pseudocode: "QUICKSORT",
+        source: "sorting/quicksort.rs",
+        entry: Entry::Sort(|a, _, _| quicksort_median_of_three(a)),
+        inputs: None,
+    },
+    Algorithm {
+        id: "counting_sort",
+        name: "Counting Sort",
+        chapter: 8,
+        section: "8.2",
+        pseudocode: "COUNTING-SORT",
+        source: "sorting/counting_sort.rs",
+        entry: Entry::SortNaturals(counting_sort::sort_naturals),
+        inputs: None,
+    },
+    Algorithm {
+        id: "radix_sort",
+        name: "Radix Sort",
+        chapter: 8,
+        section: "8.3",
+        pseudocode: "RADIX-SORT",
+        source: "sorting/radix_sort.rs",
+        entry: Entry::SortNaturals(radix_sort::sort_naturals),
+        inputs: None,
+    },
+    Algorithm {
+        id: "bucket_sort",
+        name: "Bucket Sort",
+        chapter: 8,
+        section: "8.4",
+        pseudocode: "BUCKET-SORT",
+        source: "sorting/bucket_sort.rs",
+        entry: Entry::SortReals(bucket_sort::sort_unit_interval),
+        inputs: None,
+    },
+    Algorithm {
+        id: "randomized_select",
+        name: "Randomized Select",
+        chapter: 9,
+        section: "9.2",
+        pseudocode: "RANDOMIZED-SELECT",
+        source: "selection/randomized_select.rs",
+        entry: Entry::Select(|a, i, rng, t| *randomized_select_traced(a, i, rng, t)),
+        // The random pivots make every input an average case
+        inputs: AVERAGE_ONLY,
+    },
+    Algorithm {
+        id: "select",
+        name: "Select (Median of Medians)",
+        chapter: 9,
+        section: "9.3",
+        pseudocode: "SELECT",
+        source: "selection/select.rs",
+        entry: Entry::Select(|a, i, _, t| *select_traced(a, i, t)),
+        // Linear in the worst case, with no known input as best or worst
+        inputs: AVERAGE_ONLY,
+    },
+    Algorithm {
+        id: "chained_hash_table",
+        name: "Hash Table with Chaining",
+        chapter: 11,
+        section: "11.2",
+        pseudocode: "CHAINED-HASH-INSERT",
+        source: "hashing/chained.rs",
+        entry: Entry::Keys(chained::describe),
+        inputs: None,
+    },
+    Algorithm {
+        id: "linear_probing",
+        name: "Open Addressing with Linear Probing",
+        chapter: 11,
+        section: "11.4",
+        pseudocode: "HASH-INSERT",
+        source: "hashing/open_addressing.rs",
+        entry: Entry::Keys(open_addressing::describe_linear),
+        inputs: None,
+    },
+    Algorithm {
+        id: "quadratic_probing",
+        name: "Open Addressing with Quadratic Probing",
+        chapter: 11,
+        section: "11.4",
+        pseudocode: "HASH-INSERT",
+        source: "hashing/open_addressing.rs",
+        entry: Entry::Keys(open_addressing::describe_quadratic),
+        inputs: None,
+    },
+    Algorithm {
+        id: "double_hashing",
+        name: "Open Addressing with Double Hashing",
+        chapter: 11,
+        section: "11.4",
+        pseudocode: "HASH-INSERT",
+        source: "hashing/open_addressing.rs",
+        entry: Entry::Keys(open_addressing::describe_double),
+        inputs: None,
+    },
+    Algorithm {
+        id: "inorder_tree_walk",
+        name: "Tree Walks",
+        chapter: 12,
+        section: "12.1",
+        pseudocode: "INORDER-TREE-WALK",
+        source: "trees/bst.rs",
+        entry: Entry::Keys(bst::describe_walks),
+        inputs: None,
+    },
+    Algorithm {
+        id: "tree_insert",
+        name: "Binary Search Tree Insertion",
+        chapter: 12,
+        section: "12.3",
+        pseudocode: "TREE-INSERT",
+        source: "trees/bst.rs",
+        entry: Entry::Keys(bst::describe_insert),
+        inputs: None,
+    },
+    Algorithm {
+        id: "tree_delete",
+        name: "Binary Search Tree Deletion",
+        chapter: 12,
+        section: "12.3",
+        pseudocode: "TREE-DELETE",
+        source: "trees/bst.rs",
+        entry: Entry::Keys(bst::describe_delete),
+        inputs: None,
+    },
+    Algorithm {
+        id: "rb_insert",
+        name: "Red-Black Tree Insertion",
+        chapter: 13,
+        section: "13.3",
+        pseudocode: "RB-INSERT",
+        source: "trees/red_black.rs",
+        entry: Entry::Keys(red_black::describe_insert),
+        inputs: None,
+    },
+    Algorithm {
+        id: "rb_delete",
+        name: "Red-Black Tree Deletion",
+        chapter: 13,
+        section: "13.4",
+        pseudocode: "RB-DELETE",
+        source: "trees/red_black.rs",
+        entry: Entry::Keys(red_black::describe_delete),
+        inputs: None,
+    },
+    Algorithm {
+        id: "lcs",
+        name: "Longest Common Subsequence",
+        chapter: 14,
+        section: "14.4",
+        pseudocode: "LCS-LENGTH",
+        source: "dp/lcs.rs",
+        entry: Entry::Strings(lcs::describe),
+        inputs: None,
+    },
+    Algorithm {
+        id: "os_select",
+        name: "Order-Statistic Tree",
+        chapter: 17,
+        section: "17.1",
+        pseudocode: "OS-SELECT",
+        source: "trees/order_statistic.rs",
+        entry: Entry::Keys(order_statistic::describe),
+        inputs: None,
+    },
+    Algorithm {
+        id: "interval_search",
+        name: "Interval Tree",
+        chapter: 17,
+        section: "17.3",
+        pseudocode: "INTERVAL-SEARCH",
+        source: "trees/interval.rs",
+        entry: Entry::Keys(interval::describe),
+        inputs: None,
+    },
+    Algorithm {
+        id: "b_tree_insert",
+        name: "B-Tree Insertion",
+        chapter: 18,
+        section: "18.2",
+        pseudocode: "B-TREE-INSERT",
+        source: "trees/btree/tree.rs",
+        entry: Entry::Keys(btree::tree::describe_insert),
+        inputs: None,
+    },
+    Algorithm {
+        id: "b_tree_delete",
+        name: "B-Tree Deletion",
+        chapter: 18,
+        section: "18.3",
+        pseudocode: "B-TREE-DELETE",
+        source: "trees/btree/tree.rs",
+        entry: Entry::Keys(btree::tree::describe_delete),
+        inputs: None,
+    },
+    Algorithm {
+        id: "connected_components",
+        name: "Connected Components",
+        chapter: 19,
+        section: "19.1",
+        pseudocode: "CONNECTED-COMPONENTS",
+        source: "disjoint_set/components.rs",
+        entry: Entry::Graph(components::describe),
+        inputs: None,
+    },
+    Algorithm {
+        id: "offline_lca",
+        name: "Tarjan's Offline Least-Common-Ancestors",
+        chapter: 19,
+        section: "Problem 19-3",
+        pseudocode: "LCA",
+        source: "disjoint_set/lca.rs",
+        entry: Entry::Graph(lca::describe),
+        inputs: None,
+    },
+    Algorithm {
+        id: "bfs",
+        name: "Breadth-First Search",
+        chapter: 20,
+        section: "20.2",
+        pseudocode: "BFS",
+        source: "graph/bfs.rs",
+        entry: Entry::Graph(bfs::describe),
+        inputs: None,
+    },
+    Algorithm {
+        id: "dfs",
+        name: "Depth-First Search",
+        chapter: 20,
+        section: "20.3",
+        pseudocode: "DFS",
+        source: "graph/dfs.rs",
+        entry: Entry::Graph(dfs::describe),
+        inputs: None,
+    },
+    Algorithm {
+        id: "topological_sort",
+        name: "Topological Sort",
+        chapter: 20,
+        section: "20.4",
+        pseudocode: "TOPOLOGICAL-SORT",
+        source: "graph/topological_sort.rs",
+        entry: Entry::Graph(topological_sort::describe),
+        inputs: None,
+    },
+    Algorithm {
+        id: "strongly_connected_components",
+        name: "Strongly Connected Components",
+        chapter: 20,
+        section: "20.5",
+        pseudocode: "STRONGLY-CONNECTED-COMPONENTS",
+        source: "graph/scc.rs",
+        entry: Entry::Graph(scc::describe_component_graph),
+        inputs: None,
+    },
+    Algorithm {
+        id: "tarjan_scc",
+        name: "Tarjan's Strongly Connected Components",
+        chapter: 20,
+        // A one-pass variant of the two-pass procedure of Section 20.5
+        section: "20.5",
+        pseudocode: "TARJAN-SCC (not in CLRS)",
+        source: "graph/scc.rs",
+        entry: Entry::Graph(scc::describe_tarjan),
+        inputs: None,
+    },
+    Algorithm {
+        id: "biconnected_components",
+        name: "Biconnected Components",
+        chapter: 20,
+        section: "Problem 20-2",
+        pseudocode: "DFS",
+        source: "graph/biconnected.rs",
+        entry: Entry::Graph(biconnected::describe),
+        inputs: None,
+    },
+    Algorithm {
+        id: "mst_kruskal",
+        name: "Kruskal's Algorithm",
+        chapter: 21,
+        section: "21.2",
+        pseudocode: "MST-KRUSKAL",
+        source: "graph/mst.rs",
+        entry: Entry::Graph(mst::describe_kruskal),
+        inputs: None,
+    },
+    Algorithm {
+        id: "mst_prim",
+        name: "Prim's Algorithm",
+        chapter: 21,
+        section: "21.2",
+        pseudocode: "MST-PRIM",
+        source: "graph/mst.rs",
+        entry: Entry::Graph(mst::describe_prim),
+        inputs: None,
+    },
+    Algorithm {
+        id: "bellman_ford",
+        name: "Bellman-Ford Algorithm",
+        chapter: 22,
+        section: "22.1",
+        pseudocode: "BELLMAN-FORD",
+        source: "graph/sssp.rs",
+        entry: Entry::Graph(sssp::describe_bellman_ford),
+        inputs: None,
+    },
+    Algorithm {
+        id: "dag_shortest_paths",
+        name: "Shortest Paths in a DAG",
+        chapter: 22,
+        section: "22.2",
+        pseudocode: "DAG-SHORTEST-PATHS",
+        source: "graph/sssp.rs",
+        entry: Entry::Graph(sssp::describe_dag),
+        inputs: None,
+    },
+    Algorithm {
+        id: "dijkstra",
+        name: "Dijkstra's Algorithm",
+        chapter: 22,
+        section: "22.3",
+        pseudocode: "DIJKSTRA",
+        source: "graph/sssp.rs",
+        entry: Entry::Graph(sssp::describe_dijkstra),
+        inputs: None,
+    },
+    Algorithm {
+        id: "slow_all_pairs",
+        name: "Shortest Paths by Matrix Multiplication",
+        chapter: 23,
+        section: "23.1",
+        pseudocode: "SLOW-APSP",
+        source: "graph/apsp.rs",
+        entry: Entry::Graph(apsp::describe_slow),
+        inputs: None,
+    },
+    Algorithm {
+        id: "faster_all_pairs",
+        name: "Shortest Paths by Repeated Squaring",
+        chapter: 23,
+        section: "23.1",
+        pseudocode: "FASTER-APSP",
+        source: "graph/apsp.rs",
+        entry: Entry::Graph(apsp::describe_faster),
+        inputs: None,
+    },
+    Algorithm {
+        id: "floyd_warshall",
+        name: "Floyd-Warshall Algorithm",
+        chapter: 23,
+        section: "23.2",
+        pseudocode: "FLOYD-WARSHALL",
+        source: "graph/apsp.rs",
+        entry: Entry::Graph(apsp::describe_floyd_warshall),
+        inputs: None,
+    },
+    Algorithm {
+        id: "transitive_closure",
+        name: "Transitive Closure",
+        chapter: 23,
+        section: "23.2",
+        pseudocode: "TRANSITIVE-CLOSURE",
+        source: "graph/apsp.rs",
+        entry: Entry::Graph(apsp::describe_transitive_closure),
+        inputs: None,
+    },
+    Algorithm {
+        id: "johnson",
+        name: "Johnson's Algorithm",
+        chapter: 23,
+        section: "23.3",
+        pseudocode: "JOHNSON",
+        source: "graph/apsp.rs",
+        entry: Entry::Graph(apsp::describe_johnson),
+        inputs: None,
+    },
+    Algorithm {
+        id: "edmonds_karp",
+        name: "Edmonds-Karp Algorithm",
+        chapter: 24,
+        section: "24.2",
+        pseudocode: "FORD-FULKERSON",
+        source: "graph/flow/ford_fulkerson.rs",
+        entry: Entry::Graph(ford_fulkerson::describe),
+        inputs: None,
+    },
+    Algorithm {
+        id: "relabel_to_front",
+        name: "Relabel-to-Front Algorithm",
+        chapter: 24,
+        section: "26.5 (3rd ed.)",
+        pseudocode: "RELABEL-TO-FRONT",
+        source: "graph/flow/push_relabel.rs",
+        entry: Entry::Graph(push_relabel::describe),
+        inputs: None,
+    },
+    Algorithm {
+        id: "bipartite_matching",
+        name: "Maximum Bipartite Matching",
+        chapter: 24,
+        section: "24.3",
+        pseudocode: "FORD-FULKERSON",
+        source: "graph/flow/matching.rs",
+        entry: Entry::Graph(matching::describe_bipartite_matching),
+        inputs: None,
+    },
+    Algorithm {
+        id: "hopcroft_karp",
+        name: "Hopcroft-Karp Algorithm",
+        chapter: 25,
+        section: "25.1",
+        pseudocode: "HOPCROFT-KARP",
+        source: "graph/flow/matching.rs",
+        entry: Entry::Graph(matching::describe_hopcroft_karp),
+        inputs: None,
+    },
+];
@@ -0,0 +1,81 @@
+use crate::trace::{NoopTracer, TraceEvent, Tracer};
+
+/// # Simultaneous Minimum and Maximum
+///
+/// Finds both the smallest and the largest element with at most
+/// **3⌊n/2⌋** comparisons (CLRS Section 9.1): elements are processed in
+/// pairs, the smaller of a pair is compared only with the current minimum
+/// and the larger only with the current maximum.
+///
+/// # Pseudocode
+///
+/// ```text
+/// MINIMUM-MAXIMUM(A, n)
+/// if n is odd
+///     min = max = A[1]
+///     start = 2
+/// else if A[1] < A[2]
+///         min = A[1]; max = A[2]
+///     else min = A[2]; max = A[1]
+///     start = 3
+/// for i = start to n - 1 by 2
+///     if A[i] < A[i + 1]
+///         small = A[i]; large = A[i + 1]
+///     else small = A[i + 1]; large = A[i]
+///     if small < min
+///         min = small
+///     if large > max
+///         max = large
+/// return (min, max)
+/// ```
+///
+/// # Example
+///
+/// ```rust
+/// use algorithms::selection::minimum_maximum::minimum_maximum;
+///
+/// let data = [3, 9, 1, 7, 4, 8];
+/// assert_eq!(minimum_maximum(&data), Some((&1, &9)));
+/// assert_eq!(minimum_maximum::<i32>(&[]), None);
+/// ```
+pub fn minimum_maximum<T: Ord>(arr: &[T]) -> Option<(&T, &T)> {
+    minimum_maximum_traced(arr, &mut NoopTracer)
+}
+
+/// # Simultaneous Minimum and Maximum (traced)
+///
+/// Same as [`minimum_maximum`], but reports every comparison to `tracer`.
+pub fn minimum_maximum_traced<'a, T, R>(arr: &'a [T], tracer: &mut R) -> Option<(&'a T, &'a T)>
+where
+    T: Ord,
+    R: Tracer<T> + ?Sized,
+{
+    let n = arr.len();
+    let mut compare = |i: usize, j: usize| {
+        tracer.record(TraceEvent::Compare { i, j }, arr);
+        arr[i] < arr[j]
+    };
+
+    let (mut min, mut max, start) = match n {
+        0 => return None,
+        _ if n % 2 == 1 => (0, 0, 1),
+        _ if compare(0, 1) => (0, 1, 2),
+        _ => (1, 0, 2),
+    };
+
+    for i in (start..n).step_by(2) {
+        let (small, large) = if compare(i, i + 1) {
+            (i, i + 1)
+        } else {
+            (i + 1, i)
+        };
+        if compare(small, min) {
+            min = small;
+        }
+        if compare(max, large) {
+            max = large;
+        }
+    }
+
+    Some((&arr[min], &arr[max]))
+}
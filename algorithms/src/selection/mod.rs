@@ -0,0 +1,3 @@
+pub mod minimum_maximum;
+pub mod randomized_select;
+pub mod select;
@@ -0,0 +1,94 @@
+use crate::random::RandomSource;
+use crate::sorting::quicksort::partition_three_way_traced;
+use crate::trace::{NoopTracer, TraceEvent, Tracer};
+
+/// # Randomized Select
+///
+/// Returns the `i`-th smallest element (**1-based** rank, as in CLRS) of
+/// `arr` in expected **Θ(n)** time. Works like randomized quicksort but
+/// only descends into the side that contains the wanted order statistic.
+/// The slice is partially reordered in place.
+///
+/// Unlike CLRS, the partition is three-way, as in
+/// [`partition_three_way`](crate::sorting::quicksort::partition_three_way):
+/// the search stops once the rank falls among the keys equal to the pivot,
+/// so many duplicate keys do not make it quadratic.
+///
+/// # Pseudocode (CLRS)
+///
+/// ```text
+/// RANDOMIZED-SELECT(A, p, r, i)
+/// if p == r
+///     return A[p]             // 1 <= i <= r - p + 1 when p == r means that i = 1
+/// q = RANDOMIZED-PARTITION(A, p, r)
+/// k = q - p + 1
+/// if i == k
+///     return A[q]             // the pivot value is the answer
+/// elseif i < k
+///     return RANDOMIZED-SELECT(A, p, q - 1, i)
+/// else return RANDOMIZED-SELECT(A, q + 1, r, i - k)
+/// ```
+///
+/// # Example
+///
+/// ```rust
+/// use algorithms::random::XorShift64;
+/// use algorithms::selection::randomized_select::randomized_select;
+///
+/// let mut data = [3, 2, 9, 0, 7, 5, 4, 8, 6, 1];
+/// let mut rng = XorShift64::new(1);
+/// assert_eq!(*randomized_select(&mut data, 5, &mut rng), 4);
+/// ```
+///
+/// # Panics
+///
+/// Panics unless `1 <= i <= arr.len()`.
+pub fn randomized_select<'a, T, G>(arr: &'a mut [T], i: usize, rng: &mut G) -> &'a T
+where
+    T: Ord + Clone,
+    G: RandomSource,
+{
+    randomized_select_traced(arr, i, rng, &mut NoopTracer)
+}
+
+/// # Randomized Select (traced)
+///
+/// Same as [`randomized_select`], but reports every comparison and exchange
+/// to `tracer`.
+pub fn randomized_select_traced<'a, T, G, R>(
+    arr: &'a mut [T],
+    i: usize,
+    rng: &mut G,
+    tracer: &mut R,
+) -> &'a T
+where
+    T: Ord + Clone,
+    G: RandomSource,
+    R: Tracer<T> + ?Sized,
+{
+    assert!(
+        (1..=arr.len()).contains(&i),
+        "rank {} is outside 1..={}",
+        i,
+        arr.len()
+    );
+    let (mut p, mut r, mut i) = (0, arr.len() - 1, i);
+
+    // Iterative form: each recursive call only narrows [p, r].
+    while p < r {
+        let pivot = rng.gen_range(p, r + 1);
+        arr.swap(r, pivot);
+        tracer.record(TraceEvent::Swap { i: r, j: pivot }, arr);
+        // A[p : lt - 1] < x, A[lt : gt] = x and A[gt + 1 : r] > x
+        let (lt, gt) = partition_three_way_traced(arr, p, r, tracer);
+        if i <= lt - p {
+            r = lt - 1;
+        } else if i <= gt - p + 1 {
+            return &arr[p + i - 1];
+        } else {
+            i -= gt - p + 1;
+            p = gt + 1;
+        }
+    }
+    &arr[p]
+}
@@ -0,0 +1,139 @@
+use crate::sorting::quicksort::partition_three_way_traced;
+use crate::trace::{NoopTracer, TraceEvent, Tracer};
+
+/// # Select (Median of Medians)
+///
+/// Returns the `i`-th smallest element (**1-based** rank) of `arr` in
+/// **worst-case Θ(n)** time (CLRS, 4th edition, Section 9.3). The pivot is
+/// the median of the medians of groups of five, which guarantees that a
+/// constant fraction of the elements is discarded in every round. The
+/// slice is reordered in place.
+///
+/// Unlike CLRS, the partition around the pivot is three-way, as in
+/// [`partition_three_way`](crate::sorting::quicksort::partition_three_way):
+/// the search stops once the rank falls among the keys equal to the pivot,
+/// so many duplicate keys do not make it quadratic.
+///
+/// # Pseudocode (CLRS)
+///
+/// ```text
+/// SELECT(A, p, r, i)
+/// while (r - p + 1) mod 5 != 0
+///     for j = p + 1 to r              // put the minimum into A[p]
+///         if A[p] > A[j]
+///             exchange A[p] with A[j]
+///     if i == 1                       // if we want the minimum of A[p : r],
+///         return A[p]                 //   we're done
+///     p = p + 1                       // otherwise, we want the (i - 1)st
+///     i = i - 1                       //   element of A[p + 1 : r]
+/// g = (r - p + 1) / 5                 // number of 5-element groups
+/// for j = p to p + g - 1              // sort each group
+///     sort <A[j], A[j + g], A[j + 2g], A[j + 3g], A[j + 4g]> in place
+/// // All group medians now lie in the middle fifth of A[p : r].
+/// // Find the pivot x recursively as the median of the group medians.
+/// x = SELECT(A, p + 2g, p + 3g - 1, ceil(g / 2))
+/// q = PARTITION-AROUND(A, p, r, x)    // partition around the pivot
+/// // The rest is just like lines 3-9 of RANDOMIZED-SELECT.
+/// k = q - p + 1
+/// if i == k
+///     return A[q]                     // the pivot value is the answer
+/// elseif i < k
+///     return SELECT(A, p, q - 1, i)
+/// else return SELECT(A, q + 1, r, i - k)
+/// ```
+///
+/// # Example
+///
+/// ```rust
+/// use algorithms::selection::select::select;
+///
+/// let mut data = [3, 2, 9, 0, 7, 5, 4, 8, 6, 1];
+/// assert_eq!(*select(&mut data, 1), 0);
+/// assert_eq!(*select(&mut data, 10), 9);
+/// ```
+///
+/// # Panics
+///
+/// Panics unless `1 <= i <= arr.len()`.
+pub fn select<T>(arr: &mut [T], i: usize) -> &T
+where
+    T: Ord + Clone,
+{
+    select_traced(arr, i, &mut NoopTracer)
+}
+
+/// # Select (traced)
+///
+/// Same as [`select`], but reports every comparison and exchange to `tracer`.
+pub fn select_traced<'a, T, R>(arr: &'a mut [T], i: usize, tracer: &mut R) -> &'a T
+where
+    T: Ord + Clone,
+    R: Tracer<T> + ?Sized,
+{
+    assert!(
+        (1..=arr.len()).contains(&i),
+        "rank {} is outside 1..={}",
+        i,
+        arr.len()
+    );
+    let q = select_index(arr, 0, arr.len() - 1, i, tracer);
+    &arr[q]
+}
+
+/// `SELECT(A, p, r, i)` returning the index of the answer
+fn select_index<T, R>(arr: &mut [T], mut p: usize, r: usize, mut i: usize, tracer: &mut R) -> usize
+where
+    T: Ord + Clone,
+    R: Tracer<T> + ?Sized,
+{
+    while !(r - p + 1).is_multiple_of(5) {
+        // Put the minimum into A[p].
+        for j in p + 1..=r {
+            tracer.record(TraceEvent::Compare { i: p, j }, arr);
+            if arr[p] > arr[j] {
+                arr.swap(p, j);
+                tracer.record(TraceEvent::Swap { i: p, j }, arr);
+            }
+        }
+        if i == 1 {
+            return p;
+        }
+        p += 1;
+        i -= 1;
+    }
+
+    // Sort each group <A[j], A[j + g], ..., A[j + 4g]> with insertion sort.
+    let g = (r - p + 1) / 5;
+    for j in p..p + g {
+        for a in 1..5 {
+            let mut b = a;
+            while b > 0 {
+                let (lo, hi) = (j + (b - 1) * g, j + b * g);
+                tracer.record(TraceEvent::Compare { i: lo, j: hi }, arr);
+                if arr[lo] <= arr[hi] {
+                    break;
+                }
+                arr.swap(lo, hi);
+                tracer.record(TraceEvent::Swap { i: lo, j: hi }, arr);
+                b -= 1;
+            }
+        }
+    }
+
+    // Median of the group medians, which lie in A[p + 2g : p + 3g - 1].
+    let x = select_index(arr, p + 2 * g, p + 3 * g - 1, g.div_ceil(2), tracer);
+
+    // PARTITION-AROUND(A, p, r, x): move the pivot to A[r], then split
+    // A[p : r] into the keys < x, = x and > x.
+    arr.swap(x, r);
+    tracer.record(TraceEvent::Swap { i: x, j: r }, arr);
+    let (lt, gt) = partition_three_way_traced(arr, p, r, tracer);
+
+    if i <= lt - p {
+        select_index(arr, p, lt - 1, i, tracer)
+    } else if i <= gt - p + 1 {
+        p + i - 1
+    } else {
+        select_index(arr, gt + 1, r, i - (gt - p + 1), tracer)
+    }
+}
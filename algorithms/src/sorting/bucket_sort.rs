@@ -0,0 +1,66 @@
+use super::insertion_sort::insertion_sort_by;
+
+/// # Bucket Sort
+///
+/// Sorts values drawn from `[0, 1)` by distributing them into `n` equal-sized
+/// buckets, sorting each bucket with insertion sort and concatenating the
+/// buckets (CLRS Section 8.4).
+///
+/// # Pseudocode (CLRS)
+///
+/// ```text
+/// BUCKET-SORT(A, n)
+/// let B[0 : n - 1] be a new array
+/// for i = 0 to n - 1
+///     make B[i] an empty list
+/// for i = 1 to n
+///     insert A[i] into list B[floor(n * A[i])]
+/// for i = 0 to n - 1
+///     sort list B[i] with insertion sort
+/// concatenate the lists B[0], B[1], ..., B[n - 1] together in order
+/// return the concatenated lists
+/// ```
+///
+/// # Example
+///
+/// ```rust
+/// use algorithms::sorting::bucket_sort::bucket_sort;
+///
+/// let mut data = [0.78, 0.17, 0.39, 0.26, 0.72, 0.94, 0.21, 0.12, 0.23, 0.68];
+/// bucket_sort(&mut data);
+/// assert_eq!(data, [0.12, 0.17, 0.21, 0.23, 0.26, 0.39, 0.68, 0.72, 0.78, 0.94]);
+/// ```
+///
+/// # Notes
+///
+/// * Runs in expected **Θ(n)** time when the input is uniformly distributed.
+///
+/// # Panics
+///
+/// Panics if a value lies outside `[0, 1)`.
+pub fn bucket_sort(arr: &mut [f64]) {
+    let n = arr.len();
+    let mut buckets: Vec<Vec<f64>> = vec![Vec::new(); n];
+
+    for &x in arr.iter() {
+        assert!((0.0..1.0).contains(&x), "value {} is not in [0, 1)", x);
+        buckets[(n as f64 * x) as usize].push(x);
+    }
+
+    for bucket in &mut buckets {
+        insertion_sort_by(bucket, f64::total_cmp);
+    }
+
+    for (slot, x) in arr.iter_mut().zip(buckets.into_iter().flatten()) {
+        *slot = x;
+    }
+}
+
+/// BUCKET-SORT, after checking that every value lies in `[0, 1)`
+pub(crate) fn sort_unit_interval(arr: &mut [f64]) -> Result<(), String> {
+    if let Some(x) = arr.iter().find(|x| !(0.0..1.0).contains(*x)) {
+        return Err(format!("value {} is not in [0, 1)", x));
+    }
+    bucket_sort(arr);
+    Ok(())
+}
@@ -0,0 +1,97 @@
+/// # Counting Sort
+///
+/// Sorts `input` by an integer key in `0..=k` without comparing elements,
+/// following *"Introduction to Algorithms" (CLRS)*, Section 8.2. The key is
+/// extracted by a closure, so the same routine sorts plain integers, records
+/// by one field, or a single digit of a number (as used by radix sort).
+///
+/// # Pseudocode (CLRS)
+///
+/// ```text
+/// COUNTING-SORT(A, n, k)
+/// let B[1 : n] and C[0 : k] be new arrays
+/// for i = 0 to k
+///     C[i] = 0
+/// for j = 1 to n
+///     C[A[j]] = C[A[j]] + 1
+/// // C[i] now contains the number of elements equal to i.
+/// for i = 1 to k
+///     C[i] = C[i] + C[i - 1]
+/// // C[i] now contains the number of elements less than or equal to i.
+/// // Copy A to B, starting from the end of A.
+/// for j = n downto 1
+///     B[C[A[j]]] = A[j]
+///     C[A[j]] = C[A[j]] - 1   // to handle duplicate values
+/// return B
+/// ```
+///
+/// # Example
+///
+/// ```rust
+/// use algorithms::sorting::counting_sort::counting_sort;
+///
+/// let data = [2, 5, 3, 0, 2, 3, 0, 3];
+/// let sorted = counting_sort(&data, 5, |&x| x);
+/// assert_eq!(sorted, [0, 0, 2, 2, 3, 3, 3, 5]);
+/// ```
+///
+/// # Notes
+///
+/// * The algorithm runs in **Θ(n + k)** time.
+/// * It is **stable**, which is what makes it usable inside radix sort.
+///
+/// # Panics
+///
+/// Panics if a key is larger than `k`.
+pub fn counting_sort<T, F>(input: &[T], k: usize, key: F) -> Vec<T>
+where
+    T: Clone,
+    F: Fn(&T) -> usize,
+{
+    let mut count = vec![0usize; k + 1];
+    for x in input {
+        let digit = key(x);
+        assert!(digit <= k, "key {} is larger than k = {}", digit, k);
+        count[digit] += 1;
+    }
+
+    // count[i] = number of elements with key <= i
+    for i in 1..=k {
+        count[i] += count[i - 1];
+    }
+
+    // Walk backwards so equal keys keep their relative order.
+    let mut output: Vec<Option<T>> = vec![None; input.len()];
+    for x in input.iter().rev() {
+        let digit = key(x);
+        count[digit] -= 1;
+        output[count[digit]] = Some(x.clone());
+    }
+
+    output.into_iter().flatten().collect()
+}
+
+/// Largest `k` that [`sort_naturals`] allocates `C[0 : k]` for
+const MAX_KEY: i64 = 1 << 20;
+
+/// COUNTING-SORT on the keys themselves; `k` is the largest element
+pub(crate) fn sort_naturals(arr: &mut [i64]) -> Result<(), String> {
+    let k = natural_keys(arr)?.max().unwrap_or(0);
+    if k > MAX_KEY {
+        return Err(format!(
+            "key {} is larger than the supported k = {}",
+            k, MAX_KEY
+        ));
+    }
+    let sorted = counting_sort(arr, k as usize, |x| *x as usize);
+    arr.copy_from_slice(&sorted);
+    Ok(())
+}
+
+/// The elements, after checking that none is negative
+pub(crate) fn natural_keys(arr: &[i64]) -> Result<impl Iterator<Item = i64> + '_, String> {
+    match arr.iter().find(|&&x| x < 0) {
+        Some(x) => Err(format!("key {} is negative", x)),
+        None => Ok(arr.iter().copied()),
+    }
+}
@@ -1,3 +1,7 @@
+use std::cmp::Ordering;
+
+use crate::trace::{NoopTracer, TraceEvent, Tracer};
+
 /// # Insertion Sort
 ///
 /// Sorts a mutable slice **in-place** using the *Insertion Sort* algorithm.
@@ -22,11 +26,11 @@
 /// # Example
 ///
 /// ```rust
-/// use clrs::insertion_sort::insertion_sort;
+/// use algorithms::sorting::insertion_sort::insertion_sort;
 ///
-/// let mut data = [3.2, 1.1, 4.5, 2.0];
+/// let mut data = [5, 2, 4, 6, 1, 3];
 /// insertion_sort(&mut data);
-/// assert_eq!(data, [1.1, 2.0, 3.2, 4.5]);
+/// assert_eq!(data, [1, 2, 3, 4, 5, 6]);
 /// ```
 ///
 /// # Notes
@@ -37,17 +41,67 @@
 pub fn insertion_sort<T>(arr: &mut [T])
 where
     T: Ord + Clone,
+{
+    insertion_sort_by_traced(arr, T::cmp, &mut NoopTracer);
+}
+
+/// # Insertion Sort (custom order)
+///
+/// Same as [`insertion_sort`], but orders elements with the comparator
+/// `compare`. Passing `|a, b| b.cmp(a)` sorts into decreasing order
+/// (CLRS Exercise 2.1-3).
+pub fn insertion_sort_by<T, F>(arr: &mut [T], compare: F)
+where
+    T: Clone,
+    F: FnMut(&T, &T) -> Ordering,
+{
+    insertion_sort_by_traced(arr, compare, &mut NoopTracer);
+}
+
+/// # Insertion Sort (traced)
+///
+/// Same as [`insertion_sort`], but reports every key selection, comparison,
+/// shift and insertion to `tracer`.
+pub fn insertion_sort_traced<T, R>(arr: &mut [T], tracer: &mut R)
+where
+    T: Ord + Clone,
+    R: Tracer<T> + ?Sized,
+{
+    insertion_sort_by_traced(arr, T::cmp, tracer);
+}
+
+/// # Insertion Sort (custom order, traced)
+///
+/// The single implementation behind all insertion sort entry points.
+pub fn insertion_sort_by_traced<T, F, R>(arr: &mut [T], mut compare: F, tracer: &mut R)
+where
+    T: Clone,
+    F: FnMut(&T, &T) -> Ordering,
+    R: Tracer<T> + ?Sized,
 {
     for i in 1..arr.len() {
         let key = arr[i].clone();
+        tracer.record(
+            TraceEvent::SelectKey {
+                index: i,
+                key: key.clone(),
+            },
+            arr,
+        );
         let mut j = i;
 
         // Move elements greater than key one position ahead.
-        while j > 0 && arr[j - 1] > key {
+        while j > 0 {
+            tracer.record(TraceEvent::CompareKey { index: j - 1 }, arr);
+            if compare(&arr[j - 1], &key) != Ordering::Greater {
+                break;
+            }
             arr[j] = arr[j - 1].clone();
+            tracer.record(TraceEvent::Shift { from: j - 1, to: j }, arr);
             j -= 1;
         }
 
         arr[j] = key;
+        tracer.record(TraceEvent::Insert { index: j }, arr);
     }
 }
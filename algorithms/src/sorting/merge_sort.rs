@@ -0,0 +1,299 @@
+use std::cmp::Ordering;
+
+use crate::trace::{NoopTracer, TraceEvent, Tracer};
+
+/// # Sentinel
+///
+/// Types that have a largest value usable as the ∞ sentinel of the
+/// 3rd-edition `MERGE` procedure.
+///
+/// The sentinel must be **strictly greater** than every element being
+/// sorted; `T::MAX` itself must therefore not occur in the input.
+pub trait Sentinel {
+    const INFINITY: Self;
+}
+
+macro_rules! impl_sentinel {
+    ($($t:ty),*) => {
+        $(impl Sentinel for $t {
+            const INFINITY: Self = <$t>::MAX;
+        })*
+    };
+}
+
+impl_sentinel!(
+    i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize, char
+);
+
+/// # Merge Sort
+///
+/// Sorts a mutable slice using the divide-and-conquer *Merge Sort*
+/// algorithm from *"Introduction to Algorithms" (CLRS)*, Section 2.3.
+///
+/// # Pseudocode (CLRS, 4th edition)
+///
+/// ```text
+/// MERGE-SORT(A, p, r)
+/// if p >= r
+///     return
+/// q = floor((p + r) / 2)
+/// MERGE-SORT(A, p, q)
+/// MERGE-SORT(A, q + 1, r)
+/// MERGE(A, p, q, r)
+/// ```
+///
+/// # Example
+///
+/// ```rust
+/// use algorithms::sorting::merge_sort::merge_sort;
+///
+/// let mut data = [12, 3, 7, 9, 14, 6, 11, 2];
+/// merge_sort(&mut data);
+/// assert_eq!(data, [2, 3, 6, 7, 9, 11, 12, 14]);
+/// ```
+///
+/// # Notes
+///
+/// * The algorithm runs in **Θ(n log n)** time in every case.
+/// * It needs **Θ(n)** extra space for the temporary arrays `L` and `R`.
+/// * It is **stable**.
+pub fn merge_sort<T>(arr: &mut [T])
+where
+    T: Ord + Clone,
+{
+    merge_sort_by_traced(arr, T::cmp, &mut NoopTracer);
+}
+
+/// # Merge Sort (custom order)
+///
+/// Same as [`merge_sort`], but orders elements with the comparator `compare`.
+pub fn merge_sort_by<T, F>(arr: &mut [T], compare: F)
+where
+    T: Clone,
+    F: FnMut(&T, &T) -> Ordering,
+{
+    merge_sort_by_traced(arr, compare, &mut NoopTracer);
+}
+
+/// # Merge Sort (traced)
+///
+/// Same as [`merge_sort`], but reports every comparison and write to `tracer`.
+pub fn merge_sort_traced<T, R>(arr: &mut [T], tracer: &mut R)
+where
+    T: Ord + Clone,
+    R: Tracer<T> + ?Sized,
+{
+    merge_sort_by_traced(arr, T::cmp, tracer);
+}
+
+/// # Merge Sort (custom order, traced)
+///
+/// The single implementation behind all merge sort entry points.
+pub fn merge_sort_by_traced<T, F, R>(arr: &mut [T], mut compare: F, tracer: &mut R)
+where
+    T: Clone,
+    F: FnMut(&T, &T) -> Ordering,
+    R: Tracer<T> + ?Sized,
+{
+    if arr.len() > 1 {
+        sort_range(arr, 0, arr.len() - 1, &mut compare, tracer);
+    }
+}
+
+/// # Merge Sort with Sentinels
+///
+/// Merge sort built on [`merge_with_sentinels`] (CLRS, 3rd edition).
+/// All elements must be strictly less than [`Sentinel::INFINITY`].
+///
+/// # Panics
+///
+/// Panics if an element equals [`Sentinel::INFINITY`].
+pub fn merge_sort_with_sentinels<T>(arr: &mut [T])
+where
+    T: Ord + Clone + Sentinel,
+{
+    fn sort<T: Ord + Clone + Sentinel>(arr: &mut [T], p: usize, r: usize) {
+        if p >= r {
+            return;
+        }
+        let q = p + (r - p) / 2;
+        sort(arr, p, q);
+        sort(arr, q + 1, r);
+        merge_with_sentinels(arr, p, q, r);
+    }
+
+    if arr.len() > 1 {
+        sort(arr, 0, arr.len() - 1);
+    }
+}
+
+/// # Merge (CLRS, 4th edition)
+///
+/// Merges the sorted subarrays `A[p..=q]` and `A[q+1..=r]` into the sorted
+/// subarray `A[p..=r]`. Indices are zero-based and inclusive, as in CLRS.
+///
+/// # Pseudocode
+///
+/// ```text
+/// MERGE(A, p, q, r)
+/// nL = q - p + 1
+/// nR = r - q
+/// let L[0 : nL - 1] and R[0 : nR - 1] be new arrays
+/// for i = 0 to nL - 1
+///     L[i] = A[p + i]
+/// for j = 0 to nR - 1
+///     R[j] = A[q + j + 1]
+/// i = 0
+/// j = 0
+/// k = p
+/// while i < nL and j < nR
+///     if L[i] <= R[j]
+///         A[k] = L[i]
+///         i = i + 1
+///     else A[k] = R[j]
+///         j = j + 1
+///     k = k + 1
+/// while i < nL
+///     A[k] = L[i]
+///     i = i + 1
+///     k = k + 1
+/// while j < nR
+///     A[k] = R[j]
+///     j = j + 1
+///     k = k + 1
+/// ```
+pub fn merge<T>(arr: &mut [T], p: usize, q: usize, r: usize)
+where
+    T: Ord + Clone,
+{
+    merge_by_traced(arr, p, q, r, &mut T::cmp, &mut NoopTracer);
+}
+
+/// # Merge with Sentinels (CLRS, 3rd edition)
+///
+/// Same contract as [`merge`], but appends ∞ to both `L` and `R` so the
+/// main loop never has to check whether either array is exhausted.
+///
+/// # Pseudocode
+///
+/// ```text
+/// MERGE(A, p, q, r)
+/// n1 = q - p + 1
+/// n2 = r - q
+/// let L[1 .. n1 + 1] and R[1 .. n2 + 1] be new arrays
+/// for i = 1 to n1
+///     L[i] = A[p + i - 1]
+/// for j = 1 to n2
+///     R[j] = A[q + j]
+/// L[n1 + 1] = ∞
+/// R[n2 + 1] = ∞
+/// i = 1
+/// j = 1
+/// for k = p to r
+///     if L[i] <= R[j]
+///         A[k] = L[i]
+///         i = i + 1
+///     else A[k] = R[j]
+///         j = j + 1
+/// ```
+///
+/// # Panics
+///
+/// Panics if an element of `A[p..=r]` equals [`Sentinel::INFINITY`].
+pub fn merge_with_sentinels<T>(arr: &mut [T], p: usize, q: usize, r: usize)
+where
+    T: Ord + Clone + Sentinel,
+{
+    let mut left = arr[p..=q].to_vec();
+    let mut right = arr[q + 1..=r].to_vec();
+    // An element equal to ∞ would let the loop run past the sentinel
+    assert!(
+        left.iter().chain(right.iter()).all(|x| *x < T::INFINITY),
+        "elements must be strictly less than the sentinel"
+    );
+    left.push(T::INFINITY);
+    right.push(T::INFINITY);
+
+    let (mut i, mut j) = (0, 0);
+    for slot in &mut arr[p..=r] {
+        if left[i] <= right[j] {
+            *slot = left[i].clone();
+            i += 1;
+        } else {
+            *slot = right[j].clone();
+            j += 1;
+        }
+    }
+}
+
+/// Recursive `MERGE-SORT(A, p, r)` on zero-based inclusive bounds
+fn sort_range<T, F, R>(arr: &mut [T], p: usize, r: usize, compare: &mut F, tracer: &mut R)
+where
+    T: Clone,
+    F: FnMut(&T, &T) -> Ordering,
+    R: Tracer<T> + ?Sized,
+{
+    if p >= r {
+        return;
+    }
+    let q = p + (r - p) / 2;
+    sort_range(arr, p, q, compare, tracer);
+    sort_range(arr, q + 1, r, compare, tracer);
+    merge_by_traced(arr, p, q, r, compare, tracer);
+}
+
+/// `MERGE` with a comparator and tracing. Compare events refer to the
+/// positions the two candidates occupied before the merge started.
+fn merge_by_traced<T, F, R>(
+    arr: &mut [T],
+    p: usize,
+    q: usize,
+    r: usize,
+    compare: &mut F,
+    tracer: &mut R,
+) where
+    T: Clone,
+    F: FnMut(&T, &T) -> Ordering,
+    R: Tracer<T> + ?Sized,
+{
+    let left = arr[p..=q].to_vec();
+    let right = arr[q + 1..=r].to_vec();
+    let (n_left, n_right) = (left.len(), right.len());
+    let (mut i, mut j, mut k) = (0, 0, p);
+
+    // As long as both L and R contain an uncopied element,
+    // copy the smallest uncopied element back into A[p..=r].
+    while i < n_left && j < n_right {
+        tracer.record(
+            TraceEvent::Compare {
+                i: p + i,
+                j: q + 1 + j,
+            },
+            arr,
+        );
+        if compare(&left[i], &right[j]) != Ordering::Greater {
+            arr[k] = left[i].clone();
+            i += 1;
+        } else {
+            arr[k] = right[j].clone();
+            j += 1;
+        }
+        tracer.record(TraceEvent::Write { index: k }, arr);
+        k += 1;
+    }
+
+    // Having gone through one of L and R entirely,
+    // copy the remainder of the other to the end of A[p..=r].
+    while i < n_left {
+        arr[k] = left[i].clone();
+        tracer.record(TraceEvent::Write { index: k }, arr);
+        i += 1;
+        k += 1;
+    }
+    while j < n_right {
+        arr[k] = right[j].clone();
+        tracer.record(TraceEvent::Write { index: k }, arr);
+        j += 1;
+        k += 1;
+    }
+}
@@ -1 +1,6 @@
-pub mod insertion_sort;
\ No newline at end of file
+pub mod bucket_sort;
+pub mod counting_sort;
+pub mod insertion_sort;
+pub mod merge_sort;
+pub mod quicksort;
+pub mod radix_sort;
@@ -0,0 +1,448 @@
+use std::cmp::Ordering;
+
+use crate::random::RandomSource;
+use crate::trace::{NoopTracer, TraceEvent, Tracer};
+
+/// # Quicksort
+///
+/// Sorts a mutable slice **in-place** using *Quicksort* with the Lomuto
+/// partition from *"Introduction to Algorithms" (CLRS)*, Chapter 7.
+///
+/// # Pseudocode (CLRS)
+///
+/// ```text
+/// QUICKSORT(A, p, r)
+/// if p < r
+///     // Partition the subarray around the pivot, which ends up in A[q].
+///     q = PARTITION(A, p, r)
+///     QUICKSORT(A, p, q - 1)      // recursively sort the low side
+///     QUICKSORT(A, q + 1, r)      // recursively sort the high side
+/// ```
+///
+/// # Example
+///
+/// ```rust
+/// use algorithms::sorting::quicksort::quicksort;
+///
+/// let mut data = [2, 8, 7, 1, 3, 5, 6, 4];
+/// quicksort(&mut data);
+/// assert_eq!(data, [1, 2, 3, 4, 5, 6, 7, 8]);
+/// ```
+///
+/// # Notes
+///
+/// * Expected **Θ(n log n)** time, but **Θ(n²)** on already sorted input.
+/// * It sorts in place but is **not stable**.
+/// * Both sides are sorted recursively, as in CLRS, so the recursion is
+///   **Θ(n)** deep on sorted input and a large enough array overflows the
+///   stack; [`tail_recursive_quicksort`] keeps it at **O(log n)**.
+pub fn quicksort<T>(arr: &mut [T])
+where
+    T: Ord + Clone,
+{
+    quicksort_traced(arr, &mut NoopTracer);
+}
+
+/// # Quicksort (traced)
+///
+/// Same as [`quicksort`], but reports every comparison and exchange to `tracer`.
+pub fn quicksort_traced<T, R>(arr: &mut [T], tracer: &mut R)
+where
+    T: Ord + Clone,
+    R: Tracer<T> + ?Sized,
+{
+    if arr.len() > 1 {
+        sort_range(arr, 0, arr.len() - 1, &mut |_: &mut [T], _, _| {}, tracer);
+    }
+}
+
+/// # Partition (Lomuto)
+///
+/// Rearranges `A[p..=r]` around the pivot `x = A[r]` and returns its final
+/// index `q`: afterwards `A[p..q] <= x < A[q+1..=r]`. Indices are zero-based
+/// and inclusive, as in CLRS.
+///
+/// # Pseudocode (CLRS)
+///
+/// ```text
+/// PARTITION(A, p, r)
+/// x = A[r]                        // the pivot
+/// i = p - 1                       // highest index into the low side
+/// for j = p to r - 1              // process each element other than the pivot
+///     if A[j] <= x                // does this element belong on the low side?
+///         i = i + 1               // index of a new slot in the low side
+///         exchange A[i] with A[j] // put this element there
+/// exchange A[i + 1] with A[r]     // pivot goes just to the right of the low side
+/// return i + 1                    // new index of the pivot
+/// ```
+pub fn partition<T>(arr: &mut [T], p: usize, r: usize) -> usize
+where
+    T: Ord + Clone,
+{
+    partition_traced(arr, p, r, &mut NoopTracer)
+}
+
+/// # Partition (traced)
+///
+/// Same as [`partition`], but reports comparisons against the pivot and
+/// exchanges to `tracer`.
+pub fn partition_traced<T, R>(arr: &mut [T], p: usize, r: usize, tracer: &mut R) -> usize
+where
+    T: Ord + Clone,
+    R: Tracer<T> + ?Sized,
+{
+    let x = arr[r].clone();
+    // `i` is one past the highest index of the low side (CLRS i + 1).
+    let mut i = p;
+    for j in p..r {
+        tracer.record(TraceEvent::Compare { i: j, j: r }, arr);
+        if arr[j] <= x {
+            arr.swap(i, j);
+            tracer.record(TraceEvent::Swap { i, j }, arr);
+            i += 1;
+        }
+    }
+    arr.swap(i, r);
+    tracer.record(TraceEvent::Swap { i, j: r }, arr);
+    i
+}
+
+/// # Hoare Partition (CLRS Problem 7-1)
+///
+/// Partitions `A[p..=r]` around `x = A[p]` and returns an index `j` with
+/// `p <= j < r` such that every element of `A[p..=j]` is `<= x` and every
+/// element of `A[j+1..=r]` is `>= x`. Unlike [`partition`], the pivot does
+/// not necessarily end up at `j`.
+///
+/// # Pseudocode (CLRS)
+///
+/// ```text
+/// HOARE-PARTITION(A, p, r)
+/// x = A[p]
+/// i = p - 1
+/// j = r + 1
+/// while TRUE
+///     repeat
+///         j = j - 1
+///     until A[j] <= x
+///     repeat
+///         i = i + 1
+///     until A[i] >= x
+///     if i < j
+///         exchange A[i] with A[j]
+///     else return j
+/// ```
+pub fn hoare_partition<T>(arr: &mut [T], p: usize, r: usize) -> usize
+where
+    T: Ord + Clone,
+{
+    let x = arr[p].clone();
+    // `i` and `j` point at the next candidates, which avoids p - 1 underflowing.
+    let (mut i, mut j) = (p, r);
+    loop {
+        while arr[j] > x {
+            j -= 1;
+        }
+        while arr[i] < x {
+            i += 1;
+        }
+        if i < j {
+            arr.swap(i, j);
+            i += 1;
+            j -= 1;
+        } else {
+            return j;
+        }
+    }
+}
+
+/// # Quicksort with Hoare Partition
+///
+/// Quicksort built on [`hoare_partition`]. Of `A[p..=j]` and `A[j+1..=r]`,
+/// the smaller side is sorted recursively and the larger one in a loop, as
+/// in [`tail_recursive_quicksort`], so the stack depth is **O(log n)**.
+pub fn quicksort_hoare<T>(arr: &mut [T])
+where
+    T: Ord + Clone,
+{
+    fn sort<T: Ord + Clone>(arr: &mut [T], mut p: usize, mut r: usize) {
+        while p < r {
+            let j = hoare_partition(arr, p, r);
+            if j - p < r - j {
+                sort(arr, p, j);
+                p = j + 1;
+            } else {
+                sort(arr, j + 1, r);
+                r = j;
+            }
+        }
+    }
+
+    if arr.len() > 1 {
+        sort(arr, 0, arr.len() - 1);
+    }
+}
+
+/// # Randomized Quicksort
+///
+/// Quicksort whose pivot is chosen uniformly at random, which gives
+/// expected **O(n log n)** time on every input. The random source is
+/// injected so runs are reproducible from a seed.
+///
+/// # Pseudocode (CLRS)
+///
+/// ```text
+/// RANDOMIZED-PARTITION(A, p, r)
+/// i = RANDOM(p, r)
+/// exchange A[r] with A[i]
+/// return PARTITION(A, p, r)
+///
+/// RANDOMIZED-QUICKSORT(A, p, r)
+/// if p < r
+///     q = RANDOMIZED-PARTITION(A, p, r)
+///     RANDOMIZED-QUICKSORT(A, p, q - 1)
+///     RANDOMIZED-QUICKSORT(A, q + 1, r)
+/// ```
+///
+/// # Notes
+///
+/// * Keys equal to the pivot all go to the low side, so on an array of
+///   equal keys the recursion is still **Θ(n)** deep.
+pub fn randomized_quicksort<T, G>(arr: &mut [T], rng: &mut G)
+where
+    T: Ord + Clone,
+    G: RandomSource,
+{
+    randomized_quicksort_traced(arr, rng, &mut NoopTracer);
+}
+
+/// # Randomized Quicksort (traced)
+pub fn randomized_quicksort_traced<T, G, R>(arr: &mut [T], rng: &mut G, tracer: &mut R)
+where
+    T: Ord + Clone,
+    G: RandomSource,
+    R: Tracer<T> + ?Sized,
+{
+    if arr.len() > 1 {
+        let mut choose = |a: &mut [T], p: usize, r: usize| {
+            let i = rng.gen_range(p, r + 1);
+            a.swap(r, i);
+        };
+        sort_range(arr, 0, arr.len() - 1, &mut choose, tracer);
+    }
+}
+
+/// # Median-of-3 Partition
+///
+/// Moves the median of `A[p]`, `A[mid]` and `A[r]` into `A[r]` and then
+/// runs [`partition`], which avoids the quadratic behavior on sorted input.
+pub fn median_of_three_partition<T>(arr: &mut [T], p: usize, r: usize) -> usize
+where
+    T: Ord + Clone,
+{
+    move_median_to_end(arr, p, r);
+    partition(arr, p, r)
+}
+
+/// # Quicksort with Median-of-3 Pivot
+///
+/// # Notes
+///
+/// * Like [`quicksort`], it recurses on both sides, and an array of equal
+///   keys makes the recursion **Θ(n)** deep.
+pub fn quicksort_median_of_three<T>(arr: &mut [T])
+where
+    T: Ord + Clone,
+{
+    if arr.len() > 1 {
+        let mut choose = |a: &mut [T], p: usize, r: usize| move_median_to_end(a, p, r);
+        sort_range(arr, 0, arr.len() - 1, &mut choose, &mut NoopTracer);
+    }
+}
+
+/// # Three-Way Partition (CLRS Problem 7-2)
+///
+/// Partitions `A[p..=r]` around `x = A[r]` into three parts and returns
+/// `(q, t)` with `A[p..q] < x`, `A[q..=t] == x` and `A[t+1..=r] > x`.
+/// Elements equal to the pivot are never touched again, so quicksort on
+/// inputs with many duplicate keys stays fast.
+///
+/// # Pseudocode
+///
+/// ```text
+/// PARTITION'(A, p, r)
+/// x = A[r]
+/// lt = p          // A[p : lt - 1] < x
+/// i = p           // A[lt : i - 1] = x
+/// gt = r          // A[gt + 1 : r] > x
+/// while i <= gt
+///     if A[i] < x
+///         exchange A[lt] with A[i]
+///         lt = lt + 1
+///         i = i + 1
+///     elseif A[i] > x
+///         exchange A[i] with A[gt]
+///         gt = gt - 1
+///     else i = i + 1
+/// return (lt, gt)
+/// ```
+pub fn partition_three_way<T>(arr: &mut [T], p: usize, r: usize) -> (usize, usize)
+where
+    T: Ord + Clone,
+{
+    partition_three_way_traced(arr, p, r, &mut NoopTracer)
+}
+
+/// # Three-Way Partition (traced)
+///
+/// Same as [`partition_three_way`], but reports exchanges to `tracer`. The
+/// pivot is the current key, as it moves during the partition, so every
+/// comparison is one against the key.
+pub fn partition_three_way_traced<T, R>(
+    arr: &mut [T],
+    p: usize,
+    r: usize,
+    tracer: &mut R,
+) -> (usize, usize)
+where
+    T: Ord + Clone,
+    R: Tracer<T> + ?Sized,
+{
+    let x = arr[r].clone();
+    tracer.record(
+        TraceEvent::SelectKey {
+            index: r,
+            key: x.clone(),
+        },
+        arr,
+    );
+    let (mut lt, mut i, mut gt) = (p, p, r);
+    // `gt` cannot underflow: until `i` passes a copy of the pivot,
+    // that copy still lies in A[i..=gt].
+    while i <= gt {
+        tracer.record(TraceEvent::CompareKey { index: i }, arr);
+        match arr[i].cmp(&x) {
+            Ordering::Less => {
+                arr.swap(lt, i);
+                tracer.record(TraceEvent::Swap { i: lt, j: i }, arr);
+                lt += 1;
+                i += 1;
+            }
+            Ordering::Greater => {
+                arr.swap(i, gt);
+                tracer.record(TraceEvent::Swap { i, j: gt }, arr);
+                gt -= 1;
+            }
+            Ordering::Equal => i += 1,
+        }
+    }
+    (lt, gt)
+}
+
+/// # Quicksort with Three-Way Partition
+///
+/// Quicksort built on [`partition_three_way`]; only the `< x` and `> x`
+/// parts are sorted, the smaller one recursively and the larger one in a
+/// loop, so the stack depth is **O(log n)**.
+pub fn quicksort_three_way<T>(arr: &mut [T])
+where
+    T: Ord + Clone,
+{
+    fn sort<T: Ord + Clone>(arr: &mut [T], mut p: usize, mut r: usize) {
+        while p < r {
+            let (q, t) = partition_three_way(arr, p, r);
+            if q - p < r - t {
+                if q > p {
+                    sort(arr, p, q - 1);
+                }
+                p = t + 1;
+            } else {
+                sort(arr, t + 1, r);
+                if q == p {
+                    // Both sides are empty
+                    break;
+                }
+                r = q - 1;
+            }
+        }
+    }
+
+    if arr.len() > 1 {
+        sort(arr, 0, arr.len() - 1);
+    }
+}
+
+/// # Tail-Recursive Quicksort (CLRS Problem 7-4)
+///
+/// Replaces the second recursive call by a loop and always recurses on the
+/// smaller side, so the stack depth is **Θ(log n)** even in the worst case.
+///
+/// # Pseudocode
+///
+/// ```text
+/// TRE-QUICKSORT(A, p, r)
+/// while p < r
+///     q = PARTITION(A, p, r)
+///     if q - p < r - q
+///         TRE-QUICKSORT(A, p, q - 1)
+///         p = q + 1
+///     else TRE-QUICKSORT(A, q + 1, r)
+///         r = q - 1
+/// ```
+pub fn tail_recursive_quicksort<T>(arr: &mut [T])
+where
+    T: Ord + Clone,
+{
+    fn sort<T: Ord + Clone>(arr: &mut [T], mut p: usize, mut r: usize) {
+        while p < r {
+            let q = partition(arr, p, r);
+            if q - p < r - q {
+                if q > p {
+                    sort(arr, p, q - 1);
+                }
+                p = q + 1;
+            } else {
+                // Here q > p, so q - 1 cannot underflow.
+                sort(arr, q + 1, r);
+                r = q - 1;
+            }
+        }
+    }
+
+    if arr.len() > 1 {
+        sort(arr, 0, arr.len() - 1);
+    }
+}
+
+/// Lomuto quicksort on `A[p..=r]`; `choose_pivot` may move a different
+/// element into `A[r]` before each partition.
+fn sort_range<T, C, R>(arr: &mut [T], p: usize, r: usize, choose_pivot: &mut C, tracer: &mut R)
+where
+    T: Ord + Clone,
+    C: FnMut(&mut [T], usize, usize),
+    R: Tracer<T> + ?Sized,
+{
+    if p < r {
+        choose_pivot(arr, p, r);
+        let q = partition_traced(arr, p, r, tracer);
+        if q > p {
+            sort_range(arr, p, q - 1, choose_pivot, tracer);
+        }
+        sort_range(arr, q + 1, r, choose_pivot, tracer);
+    }
+}
+
+/// Put the median of `A[p]`, `A[mid]`, `A[r]` into `A[r]`
+fn move_median_to_end<T: Ord>(arr: &mut [T], p: usize, r: usize) {
+    let mid = p + (r - p) / 2;
+    if arr[mid] < arr[p] {
+        arr.swap(mid, p);
+    }
+    if arr[r] < arr[p] {
+        arr.swap(r, p);
+    }
+    // Now A[p] is the smallest of the three; the median is min(A[mid], A[r]).
+    if arr[mid] < arr[r] {
+        arr.swap(mid, r);
+    }
+}
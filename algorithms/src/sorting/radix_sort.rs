@@ -0,0 +1,115 @@
+use super::counting_sort::{counting_sort, natural_keys};
+
+/// # Radix Sort
+///
+/// Sorts unsigned integers digit by digit, least significant digit first,
+/// using the stable [`counting_sort`] for every pass (CLRS Section 8.3).
+/// A "digit" is a group of `digit_bits` bits, so `b`-bit keys need
+/// `ceil(b / digit_bits)` passes.
+///
+/// # Pseudocode (CLRS)
+///
+/// ```text
+/// RADIX-SORT(A, n, d)
+/// for i = 1 to d
+///     use a stable sort to sort array A[1 : n] on digit i
+/// ```
+///
+/// # Example
+///
+/// ```rust
+/// use algorithms::sorting::radix_sort::radix_sort;
+///
+/// let mut data: [u32; 7] = [329, 457, 657, 839, 436, 720, 355];
+/// radix_sort(&mut data, 4);
+/// assert_eq!(data, [329, 355, 436, 457, 657, 720, 839]);
+/// ```
+///
+/// # Notes
+///
+/// * With `r`-bit digits, `b`-bit keys sort in **Θ((b / r)(n + 2^r))** time
+///   (CLRS Lemma 8.4); `r ≈ lg n` is a good choice.
+///
+/// # Panics
+///
+/// Panics unless `1 <= digit_bits <= 16`.
+pub fn radix_sort<T>(arr: &mut [T], digit_bits: u32)
+where
+    T: Copy + Into<u64>,
+{
+    assert!(
+        (1..=16).contains(&digit_bits),
+        "digit size must be between 1 and 16 bits"
+    );
+    let key_bits = (size_of::<T>() * 8) as u32;
+    let mask = (1u64 << digit_bits) - 1;
+
+    let mut shift = 0;
+    while shift < key_bits {
+        let digit = |x: &T| (((*x).into() >> shift) & mask) as usize;
+        let sorted = counting_sort(arr, mask as usize, digit);
+        arr.copy_from_slice(&sorted);
+        shift += digit_bits;
+    }
+}
+
+/// # Radix Sort for Byte Strings
+///
+/// Sorts fixed-width byte strings (e.g. ASCII words of equal length)
+/// lexicographically. Each digit is `digit_bytes` consecutive bytes, taken
+/// from the right end of the strings first.
+///
+/// # Example
+///
+/// ```rust
+/// use algorithms::sorting::radix_sort::radix_sort_bytes;
+///
+/// let mut words = [*b"COW", *b"DOG", *b"SEA", *b"RUG", *b"ROW", *b"MOB"];
+/// radix_sort_bytes(&mut words, 1);
+/// assert_eq!(words, [*b"COW", *b"DOG", *b"MOB", *b"ROW", *b"RUG", *b"SEA"]);
+/// ```
+///
+/// # Panics
+///
+/// Panics if the strings differ in length or `digit_bytes` is not 1 or 2.
+pub fn radix_sort_bytes<S>(arr: &mut [S], digit_bytes: usize)
+where
+    S: AsRef<[u8]> + Clone,
+{
+    assert!(
+        digit_bytes == 1 || digit_bytes == 2,
+        "digit size must be 1 or 2 bytes"
+    );
+    let Some(width) = arr.first().map(|s| s.as_ref().len()) else {
+        return;
+    };
+    assert!(
+        arr.iter().all(|s| s.as_ref().len() == width),
+        "all strings must have the same width"
+    );
+    let k = (1usize << (8 * digit_bytes)) - 1;
+
+    // Digits are byte ranges [start, end), least significant (rightmost) first.
+    let mut end = width;
+    while end > 0 {
+        let start = end.saturating_sub(digit_bytes);
+        let digit = |s: &S| {
+            s.as_ref()[start..end]
+                .iter()
+                .fold(0usize, |acc, &b| (acc << 8) | b as usize)
+        };
+        let sorted = counting_sort(arr, k, digit);
+        arr.clone_from_slice(&sorted);
+        end = start;
+    }
+}
+
+/// RADIX-SORT with 8-bit digits on natural numbers
+pub(crate) fn sort_naturals(arr: &mut [i64]) -> Result<(), String> {
+    let mut keys: Vec<u64> = natural_keys(arr)?.map(|k| k as u64).collect();
+    radix_sort(&mut keys, 8);
+    for (slot, key) in arr.iter_mut().zip(keys) {
+        *slot = key as i64;
+    }
+    Ok(())
+}
@@ -0,0 +1,150 @@
+//! # Execution Traces
+//!
+//! Algorithms in this crate can report what they do, step by step, to a
+//! [`Tracer`]. Each step is a [`TraceEvent`] together with the state of the
+//! array *after* the step was applied.
+//!
+//! Collectors in this module record those events so that exercises, the CLI
+//! and the LaTeX generator can all share a single source of truth for traces
+//! instead of hand-rolled `println!` copies of each algorithm.
+//!
+//! # Example
+//!
+//! ```rust
+//! use algorithms::sorting::insertion_sort::insertion_sort_traced;
+//! use algorithms::trace::{SnapshotCollector, TraceEvent};
+//!
+//! let mut data = [5, 2, 4, 6, 1, 3];
+//! let mut collector = SnapshotCollector::new();
+//! insertion_sort_traced(&mut data, &mut collector);
+//!
+//! let passes: Vec<_> = collector
+//!     .snapshots()
+//!     .iter()
+//!     .filter(|s| matches!(s.event, TraceEvent::Insert { .. }))
+//!     .collect();
+//! assert_eq!(passes.len(), 5);
+//! assert_eq!(passes[0].array, [2, 5, 4, 6, 1, 3]);
+//! ```
+
+/// A single step performed by an algorithm. Indices are zero-based.
+#[derive(Debug, Clone, PartialEq)]
+pub enum TraceEvent<T> {
+    /// `A[index]` was taken out of the array as the current key.
+    SelectKey { index: usize, key: T },
+    /// `A[i]` was compared with `A[j]`.
+    Compare { i: usize, j: usize },
+    /// `A[index]` was compared with the current key.
+    CompareKey { index: usize },
+    /// `A[from]` was copied one step over to `A[to]`.
+    Shift { from: usize, to: usize },
+    /// `A[i]` and `A[j]` were exchanged.
+    Swap { i: usize, j: usize },
+    /// The current key was written to `A[index]`.
+    Insert { index: usize },
+    /// `A[index]` was overwritten from auxiliary storage (e.g. by `MERGE`).
+    Write { index: usize },
+}
+
+/// Observer for algorithm steps.
+///
+/// `array` is the state of the input *after* `event` took effect.
+/// Closures of the form `FnMut(&TraceEvent<T>, &[T])` are tracers as well.
+pub trait Tracer<T> {
+    fn record(&mut self, event: TraceEvent<T>, array: &[T]);
+}
+
+impl<T, F> Tracer<T> for F
+where
+    F: FnMut(&TraceEvent<T>, &[T]),
+{
+    fn record(&mut self, event: TraceEvent<T>, array: &[T]) {
+        self(&event, array)
+    }
+}
+
+/// Tracer that ignores every event. Untraced entry points use this,
+/// so tracing costs nothing when it is not requested.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct NoopTracer;
+
+impl<T> Tracer<T> for NoopTracer {
+    #[inline(always)]
+    fn record(&mut self, _event: TraceEvent<T>, _array: &[T]) {}
+}
+
+/// Records the sequence of events without array snapshots.
+#[derive(Debug, Clone)]
+pub struct EventLog<T> {
+    events: Vec<TraceEvent<T>>,
+}
+
+impl<T> EventLog<T> {
+    pub fn new() -> Self {
+        Self { events: Vec::new() }
+    }
+
+    pub fn events(&self) -> &[TraceEvent<T>] {
+        &self.events
+    }
+
+    pub fn into_events(self) -> Vec<TraceEvent<T>> {
+        self.events
+    }
+}
+
+impl<T> Default for EventLog<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> Tracer<T> for EventLog<T> {
+    fn record(&mut self, event: TraceEvent<T>, _array: &[T]) {
+        self.events.push(event);
+    }
+}
+
+/// One recorded step: the event and a copy of the array after it.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Snapshot<T> {
+    pub event: TraceEvent<T>,
+    pub array: Vec<T>,
+}
+
+/// Records every event together with a snapshot of the array.
+#[derive(Debug, Clone)]
+pub struct SnapshotCollector<T> {
+    snapshots: Vec<Snapshot<T>>,
+}
+
+impl<T> SnapshotCollector<T> {
+    pub fn new() -> Self {
+        Self {
+            snapshots: Vec::new(),
+        }
+    }
+
+    pub fn snapshots(&self) -> &[Snapshot<T>] {
+        &self.snapshots
+    }
+
+    pub fn into_snapshots(self) -> Vec<Snapshot<T>> {
+        self.snapshots
+    }
+}
+
+impl<T> Default for SnapshotCollector<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Clone> Tracer<T> for SnapshotCollector<T> {
+    fn record(&mut self, event: TraceEvent<T>, array: &[T]) {
+        self.snapshots.push(Snapshot {
+            event,
+            array: array.to_vec(),
+        });
+    }
+}
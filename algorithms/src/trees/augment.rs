@@ -0,0 +1,46 @@
+/// # Augmentation
+///
+/// Extra information stored in every node of a
+/// [`RedBlackTree`](super::red_black::RedBlackTree) and computed from the
+/// node and its children alone. By CLRS Theorem 17.1, such an attribute can
+/// be maintained during insertion and deletion without affecting their
+/// **O(lg n)** running time: the tree recomputes it along the path from a
+/// changed node up to the root and at both nodes of every rotation.
+///
+/// `()` is the empty augmentation of a plain red-black tree;
+/// [`Size`](super::order_statistic::Size) and
+/// [`MaxHigh`](super::interval::MaxHigh) are the augmentations of CLRS
+/// Sections 17.1 and 17.3.
+///
+/// # Example
+///
+/// ```rust
+/// use algorithms::trees::augment::Augmentation;
+/// use algorithms::trees::red_black::RedBlackTree;
+///
+/// /// Sum of the values in a subtree
+/// struct Total(i64);
+///
+/// impl<K> Augmentation<K, i64> for Total {
+///     fn compute(_key: &K, value: &i64, left: Option<&Self>, right: Option<&Self>) -> Self {
+///         Total(value + left.map_or(0, |l| l.0) + right.map_or(0, |r| r.0))
+///     }
+/// }
+///
+/// let mut tree: RedBlackTree<u32, i64, Total> = RedBlackTree::augmented();
+/// for k in 1..=10 {
+///     tree.insert(k, 10 * k as i64);
+/// }
+/// tree.delete(tree.search(&4).unwrap());
+/// assert_eq!(tree.augment(tree.root().unwrap()).0, 510);
+/// ```
+pub trait Augmentation<K, V>: Sized {
+    /// The attribute of a node with `key` and `value` whose children carry
+    /// `left` and `right`, `None` for `T.nil`.
+    fn compute(key: &K, value: &V, left: Option<&Self>, right: Option<&Self>) -> Self;
+}
+
+impl<K, V> Augmentation<K, V> for () {
+    #[inline(always)]
+    fn compute(_key: &K, _value: &V, _left: Option<&()>, _right: Option<&()>) {}
+}
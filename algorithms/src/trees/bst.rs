@@ -0,0 +1,545 @@
+//! # Binary Search Trees
+//!
+//! A binary search tree keeps its keys so that for every node `x`, the keys
+//! in the left subtree of `x` are at most `x.key` and the keys in its right
+//! subtree at least `x.key` (CLRS Chapter 12). Nodes live in an arena and
+//! are named by their index, which stays valid until the node is deleted;
+//! like `x.p` in CLRS, every node knows its parent.
+
+use std::fmt::Write;
+
+/// Node of the tree
+#[derive(Debug, Clone)]
+struct Node<K, V> {
+    key: K,
+    value: V,
+    parent: Option<usize>,
+    left: Option<usize>,
+    right: Option<usize>,
+}
+
+/// # Binary Search Tree
+///
+/// Dictionary operations in **O(h)** time on a tree of height `h` (CLRS
+/// Sections 12.2 and 12.3). `TREE-INSERT` walks down from the root to the
+/// leaf position of the new key; equal keys go right, so duplicates are
+/// kept. `TREE-DELETE` replaces a node with two children by its successor,
+/// moving subtrees with `TRANSPLANT`, so no other node changes its key.
+///
+/// # Pseudocode (CLRS)
+///
+/// ```text
+/// TREE-INSERT(T, z)
+/// x = T.root
+/// y = NIL
+/// while x ≠ NIL
+///     y = x
+///     if z.key < x.key
+///         x = x.left
+///     else x = x.right
+/// z.p = y
+/// if y == NIL
+///     T.root = z
+/// elseif z.key < y.key
+///     y.left = z
+/// else y.right = z
+///
+/// TRANSPLANT(T, u, v)
+/// if u.p == NIL
+///     T.root = v
+/// elseif u == u.p.left
+///     u.p.left = v
+/// else u.p.right = v
+/// if v ≠ NIL
+///     v.p = u.p
+///
+/// TREE-DELETE(T, z)
+/// if z.left == NIL
+///     TRANSPLANT(T, z, z.right)
+/// elseif z.right == NIL
+///     TRANSPLANT(T, z, z.left)
+/// else y = TREE-MINIMUM(z.right)
+///     if y ≠ z.right
+///         TRANSPLANT(T, y, y.right)
+///         y.right = z.right
+///         y.right.p = y
+///     TRANSPLANT(T, z, y)
+///     y.left = z.left
+///     y.left.p = y
+/// ```
+///
+/// # Example
+///
+/// ```rust
+/// use algorithms::trees::bst::BinarySearchTree;
+///
+/// // CLRS Figure 12.2
+/// let mut tree = BinarySearchTree::new();
+/// for k in [15, 6, 18, 3, 7, 17, 20, 2, 4, 13, 9] {
+///     tree.insert(k, ());
+/// }
+/// let x = tree.search(&13).unwrap();
+/// let successor = tree.successor(x).unwrap();
+/// assert_eq!(tree.key(successor), &15);
+///
+/// tree.delete(tree.search(&6).unwrap());
+/// let keys: Vec<i32> = tree.inorder().map(|x| *tree.key(x)).collect();
+/// assert_eq!(keys, [2, 3, 4, 7, 9, 13, 15, 17, 18, 20]);
+/// ```
+///
+/// # Notes
+///
+/// * Built from `n` distinct keys in random order, the tree has expected
+///   height **O(lg n)** (CLRS Theorem 12.4); inserted in sorted order, it
+///   degenerates into a path of height `n - 1`.
+/// * Nodes are never moved, so indices returned by `insert` remain valid
+///   across other insertions and deletions.
+#[derive(Debug, Clone)]
+pub struct BinarySearchTree<K, V> {
+    nodes: Vec<Option<Node<K, V>>>,
+    root: Option<usize>,
+    len: usize,
+}
+
+impl<K, V> BinarySearchTree<K, V> {
+    pub fn new() -> Self {
+        Self {
+            nodes: Vec::new(),
+            root: None,
+            len: 0,
+        }
+    }
+
+    /// `T.root`
+    pub fn root(&self) -> Option<usize> {
+        self.root
+    }
+
+    /// Number of nodes.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Whether `x` is a node of the tree.
+    pub fn contains(&self, x: usize) -> bool {
+        matches!(self.nodes.get(x), Some(Some(_)))
+    }
+
+    /// `x.key`
+    ///
+    /// # Panics
+    ///
+    /// Panics if `x` is not a node of the tree; so do all methods taking a
+    /// node.
+    pub fn key(&self, x: usize) -> &K {
+        &self.node(x).key
+    }
+
+    /// Value stored with `x.key`.
+    pub fn value(&self, x: usize) -> &V {
+        &self.node(x).value
+    }
+
+    pub fn value_mut(&mut self, x: usize) -> &mut V {
+        &mut self.node_mut(x).value
+    }
+
+    /// `x.p`
+    pub fn parent(&self, x: usize) -> Option<usize> {
+        self.node(x).parent
+    }
+
+    /// `x.left`
+    pub fn left(&self, x: usize) -> Option<usize> {
+        self.node(x).left
+    }
+
+    /// `x.right`
+    pub fn right(&self, x: usize) -> Option<usize> {
+        self.node(x).right
+    }
+
+    /// `TREE-MINIMUM(x)`: the node with the smallest key in the subtree of
+    /// `x`.
+    pub fn minimum(&self, mut x: usize) -> usize {
+        while let Some(left) = self.left(x) {
+            x = left;
+        }
+        x
+    }
+
+    /// `TREE-MAXIMUM(x)`: the node with the largest key in the subtree of
+    /// `x`.
+    pub fn maximum(&self, mut x: usize) -> usize {
+        while let Some(right) = self.right(x) {
+            x = right;
+        }
+        x
+    }
+
+    /// `TREE-SUCCESSOR(x)`: the next node of the inorder walk.
+    pub fn successor(&self, mut x: usize) -> Option<usize> {
+        if let Some(right) = self.right(x) {
+            return Some(self.minimum(right));
+        }
+        // Go up until x is a left child
+        let mut y = self.parent(x);
+        while let Some(p) = y
+            && self.right(p) == Some(x)
+        {
+            x = p;
+            y = self.parent(p);
+        }
+        y
+    }
+
+    /// `TREE-PREDECESSOR(x)`: the previous node of the inorder walk.
+    pub fn predecessor(&self, mut x: usize) -> Option<usize> {
+        if let Some(left) = self.left(x) {
+            return Some(self.maximum(left));
+        }
+        let mut y = self.parent(x);
+        while let Some(p) = y
+            && self.left(p) == Some(x)
+        {
+            x = p;
+            y = self.parent(p);
+        }
+        y
+    }
+
+    /// Number of edges from the root down to `x`.
+    pub fn depth(&self, mut x: usize) -> usize {
+        let mut depth = 0;
+        while let Some(p) = self.parent(x) {
+            depth += 1;
+            x = p;
+        }
+        depth
+    }
+
+    /// Number of edges on the longest path from the root down to a leaf, 0
+    /// for an empty tree.
+    pub fn height(&self) -> usize {
+        let mut height = 0;
+        let mut stack: Vec<(usize, usize)> = self.root.map(|r| (r, 0)).into_iter().collect();
+        while let Some((x, depth)) = stack.pop() {
+            height = height.max(depth);
+            for child in [self.left(x), self.right(x)].into_iter().flatten() {
+                stack.push((child, depth + 1));
+            }
+        }
+        height
+    }
+
+    /// `INORDER-TREE-WALK(T.root)`: nodes in sorted order of their keys,
+    /// with a stack of the nodes whose left subtree is being walked
+    /// (CLRS Exercise 12.1-3).
+    pub fn inorder(&self) -> Inorder<'_, K, V> {
+        Inorder {
+            tree: self,
+            stack: Vec::new(),
+            current: self.root,
+        }
+    }
+
+    /// The inorder walk without a stack: the node visited last tells
+    /// whether the walk came down to the current node or back up from one
+    /// of its subtrees (CLRS Exercise 12.1-3).
+    pub fn inorder_without_stack(&self) -> StacklessInorder<'_, K, V> {
+        StacklessInorder {
+            tree: self,
+            current: self.root,
+            previous: None,
+        }
+    }
+
+    /// `PREORDER-TREE-WALK(T.root)`: every node before its subtrees.
+    pub fn preorder(&self) -> Preorder<'_, K, V> {
+        Preorder {
+            tree: self,
+            stack: self.root.into_iter().collect(),
+        }
+    }
+
+    /// `POSTORDER-TREE-WALK(T.root)`: every node after its subtrees.
+    pub fn postorder(&self) -> Postorder<'_, K, V> {
+        Postorder {
+            tree: self,
+            stack: self.root.map(|r| (r, false)).into_iter().collect(),
+        }
+    }
+
+    /// `TREE-DELETE(T, z)`: remove node `z` and return its key and value.
+    pub fn delete(&mut self, z: usize) -> (K, V) {
+        let (left, right) = (self.left(z), self.right(z));
+        match (left, right) {
+            (None, _) => self.transplant(z, right),
+            (_, None) => self.transplant(z, left),
+            (Some(left), Some(right)) => {
+                let y = self.minimum(right);
+                if y != right {
+                    self.transplant(y, self.right(y));
+                    self.node_mut(y).right = Some(right);
+                    self.node_mut(right).parent = Some(y);
+                }
+                self.transplant(z, Some(y));
+                self.node_mut(y).left = Some(left);
+                self.node_mut(left).parent = Some(y);
+            }
+        }
+        self.len -= 1;
+        let node = self.nodes[z].take().expect("node is in the tree");
+        (node.key, node.value)
+    }
+
+    /// `TRANSPLANT(T, u, v)`: replace the subtree of `u` by that of `v`
+    fn transplant(&mut self, u: usize, v: Option<usize>) {
+        let parent = self.parent(u);
+        match parent {
+            None => self.root = v,
+            Some(p) if self.left(p) == Some(u) => self.node_mut(p).left = v,
+            Some(p) => self.node_mut(p).right = v,
+        }
+        if let Some(v) = v {
+            self.node_mut(v).parent = parent;
+        }
+    }
+
+    fn node(&self, x: usize) -> &Node<K, V> {
+        self.nodes[x].as_ref().expect("node is in the tree")
+    }
+
+    fn node_mut(&mut self, x: usize) -> &mut Node<K, V> {
+        self.nodes[x].as_mut().expect("node is in the tree")
+    }
+}
+
+impl<K: Ord, V> BinarySearchTree<K, V> {
+    /// `TREE-INSERT(T, z)` for a new node `z` with `key` and `value`;
+    /// returns `z`.
+    pub fn insert(&mut self, key: K, value: V) -> usize {
+        let mut y = None;
+        let mut x = self.root;
+        while let Some(node) = x {
+            y = x;
+            x = if key < *self.key(node) {
+                self.left(node)
+            } else {
+                self.right(node)
+            };
+        }
+
+        let z = self.nodes.len();
+        match y {
+            None => self.root = Some(z),
+            Some(y) if key < *self.key(y) => self.node_mut(y).left = Some(z),
+            Some(y) => self.node_mut(y).right = Some(z),
+        }
+        self.nodes.push(Some(Node {
+            key,
+            value,
+            parent: y,
+            left: None,
+            right: None,
+        }));
+        self.len += 1;
+        z
+    }
+
+    /// `ITERATIVE-TREE-SEARCH(T.root, k)`: a node with key `k`.
+    pub fn search(&self, k: &K) -> Option<usize> {
+        let mut x = self.root;
+        while let Some(node) = x {
+            let key = self.key(node);
+            if k == key {
+                break;
+            }
+            x = if k < key {
+                self.left(node)
+            } else {
+                self.right(node)
+            };
+        }
+        x
+    }
+}
+
+impl<K, V> Default for BinarySearchTree<K, V> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Iterator of [`BinarySearchTree::inorder`].
+#[derive(Debug, Clone)]
+pub struct Inorder<'a, K, V> {
+    tree: &'a BinarySearchTree<K, V>,
+    /// Nodes still to visit once their left subtree is done
+    stack: Vec<usize>,
+    /// Root of the subtree to walk next
+    current: Option<usize>,
+}
+
+impl<K, V> Iterator for Inorder<'_, K, V> {
+    type Item = usize;
+
+    fn next(&mut self) -> Option<usize> {
+        while let Some(x) = self.current {
+            self.stack.push(x);
+            self.current = self.tree.left(x);
+        }
+        let x = self.stack.pop()?;
+        self.current = self.tree.right(x);
+        Some(x)
+    }
+}
+
+/// Iterator of [`BinarySearchTree::inorder_without_stack`].
+#[derive(Debug, Clone)]
+pub struct StacklessInorder<'a, K, V> {
+    tree: &'a BinarySearchTree<K, V>,
+    current: Option<usize>,
+    previous: Option<usize>,
+}
+
+impl<K, V> Iterator for StacklessInorder<'_, K, V> {
+    type Item = usize;
+
+    fn next(&mut self) -> Option<usize> {
+        loop {
+            let x = self.current?;
+            let (parent, left, right) =
+                (self.tree.parent(x), self.tree.left(x), self.tree.right(x));
+            if self.previous == parent {
+                // Came down: walk the left subtree first
+                if left.is_some() {
+                    self.previous = Some(x);
+                    self.current = left;
+                    continue;
+                }
+            } else if self.previous != left {
+                // Came up from the right subtree: x is done
+                self.previous = Some(x);
+                self.current = parent;
+                continue;
+            }
+            // The left subtree is done: visit x, then walk its right subtree
+            self.previous = Some(x);
+            self.current = right.or(parent);
+            return Some(x);
+        }
+    }
+}
+
+/// Iterator of [`BinarySearchTree::preorder`].
+#[derive(Debug, Clone)]
+pub struct Preorder<'a, K, V> {
+    tree: &'a BinarySearchTree<K, V>,
+    stack: Vec<usize>,
+}
+
+impl<K, V> Iterator for Preorder<'_, K, V> {
+    type Item = usize;
+
+    fn next(&mut self) -> Option<usize> {
+        let x = self.stack.pop()?;
+        self.stack.extend(self.tree.right(x));
+        self.stack.extend(self.tree.left(x));
+        Some(x)
+    }
+}
+
+/// Iterator of [`BinarySearchTree::postorder`].
+#[derive(Debug, Clone)]
+pub struct Postorder<'a, K, V> {
+    tree: &'a BinarySearchTree<K, V>,
+    /// Nodes with whether their subtrees are already on the stack
+    stack: Vec<(usize, bool)>,
+}
+
+impl<K, V> Iterator for Postorder<'_, K, V> {
+    type Item = usize;
+
+    fn next(&mut self) -> Option<usize> {
+        loop {
+            let (x, expanded) = self.stack.pop()?;
+            if expanded {
+                return Some(x);
+            }
+            self.stack.push((x, true));
+            for child in [self.tree.right(x), self.tree.left(x)]
+                .into_iter()
+                .flatten()
+            {
+                self.stack.push((child, false));
+            }
+        }
+    }
+}
+
+/// Inorder, preorder and postorder walks of the tree built from `keys`
+pub(crate) fn describe_walks(keys: &[i64]) -> String {
+    let tree = from_keys(keys);
+    let walk = |nodes: &mut dyn Iterator<Item = usize>| {
+        nodes
+            .map(|x| tree.key(x).to_string())
+            .collect::<Vec<_>>()
+            .join(" ")
+    };
+    format!(
+        "Inorder: {}\nPreorder: {}\nPostorder: {}\n",
+        walk(&mut tree.inorder()),
+        walk(&mut tree.preorder()),
+        walk(&mut tree.postorder())
+    )
+}
+
+/// The tree built by inserting `keys` in order
+pub(crate) fn describe_insert(keys: &[i64]) -> String {
+    describe_tree(&from_keys(keys))
+}
+
+/// The tree built from `keys`, then the keys left after each deletion
+pub(crate) fn describe_delete(keys: &[i64]) -> String {
+    let mut tree = from_keys(keys);
+    let mut text = describe_tree(&tree);
+    for k in keys {
+        if let Some(z) = tree.search(k) {
+            tree.delete(z);
+            let rest: Vec<String> = tree.inorder().map(|x| tree.key(x).to_string()).collect();
+            let _ = writeln!(text, "Delete {}: {}", k, rest.join(" "));
+        }
+    }
+    text
+}
+
+/// Binary search tree built by inserting `keys` in order
+fn from_keys(keys: &[i64]) -> BinarySearchTree<i64, ()> {
+    let mut tree = BinarySearchTree::new();
+    for &k in keys {
+        tree.insert(k, ());
+    }
+    tree
+}
+
+/// Children of every node in preorder, and the height
+fn describe_tree(tree: &BinarySearchTree<i64, ()>) -> String {
+    let mut text = String::new();
+    let key = |x: Option<usize>| x.map_or("NIL".to_string(), |x| tree.key(x).to_string());
+    for x in tree.preorder() {
+        let _ = writeln!(
+            text,
+            "{}: left {}, right {}",
+            tree.key(x),
+            key(tree.left(x)),
+            key(tree.right(x))
+        );
+    }
+    let _ = writeln!(text, "Height: {}", tree.height());
+    text
+}
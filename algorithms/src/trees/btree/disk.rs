@@ -0,0 +1,353 @@
+//! # Disk Stores
+//!
+//! Secondary storage for the nodes of a [`BTree`](super::tree::BTree). A
+//! B-tree node fills one disk page, and CLRS Section 18.1 measures B-tree
+//! operations by the number of pages they transfer with `DISK-READ` and
+//! `DISK-WRITE`; every [`DiskStore`] counts both in its [`DiskStats`].
+
+use std::fs::{File, OpenOptions};
+use std::io::{self, Read, Seek, SeekFrom, Write};
+use std::path::Path;
+
+/// Bytes of the leaf flag on a page
+const FLAG_SIZE: usize = 1;
+/// Bytes of `x.n` and of each child pointer on a page
+const WORD_SIZE: usize = 8;
+
+/// Node of a B-tree as kept on one disk page: `x.n` keys in nondecreasing
+/// order and, for an internal node, the pages of its `x.n + 1` children.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BTreeNode<K> {
+    pub keys: Vec<K>,
+    pub children: Vec<usize>,
+    pub leaf: bool,
+}
+
+impl<K> BTreeNode<K> {
+    /// A leaf without keys.
+    pub fn empty_leaf() -> Self {
+        Self {
+            keys: Vec::new(),
+            children: Vec::new(),
+            leaf: true,
+        }
+    }
+
+    /// `x.n`, the number of keys.
+    pub fn n(&self) -> usize {
+        self.keys.len()
+    }
+}
+
+/// Pages transferred since the store was created or last reset.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct DiskStats {
+    pub reads: u64,
+    pub writes: u64,
+}
+
+impl DiskStats {
+    /// `DISK-READ` and `DISK-WRITE` operations together.
+    pub fn total(&self) -> u64 {
+        self.reads + self.writes
+    }
+}
+
+/// # Disk Store
+///
+/// Pages of B-tree nodes, named by page number. Only [`read`](Self::read)
+/// and [`write`](Self::write) are disk operations and counted in
+/// [`stats`](Self::stats); allocating and freeing a page only updates the
+/// bookkeeping in main memory.
+pub trait DiskStore<K> {
+    /// `ALLOCATE-NODE()`: a new page, to be filled by a `DISK-WRITE`.
+    fn allocate(&mut self) -> usize;
+
+    /// `DISK-READ(x)`: the node stored on page `x`.
+    ///
+    /// # Errors
+    ///
+    /// An I/O error if page `x` holds no node or cannot be read.
+    fn read(&mut self, x: usize) -> io::Result<BTreeNode<K>>;
+
+    /// `DISK-WRITE(x)`: store `node` on page `x`.
+    ///
+    /// # Errors
+    ///
+    /// An I/O error if page `x` was never allocated or cannot be written.
+    fn write(&mut self, x: usize, node: &BTreeNode<K>) -> io::Result<()>;
+
+    /// Give back page `x` of a node that no longer exists.
+    fn free(&mut self, x: usize);
+
+    fn stats(&self) -> DiskStats;
+
+    fn reset_stats(&mut self);
+}
+
+/// # Memory Store
+///
+/// Pages held in main memory, for counting the disk operations of a B-tree
+/// without a file.
+#[derive(Debug, Clone)]
+pub struct MemoryStore<K> {
+    /// `None` for pages allocated but not yet written, or freed
+    pages: Vec<Option<BTreeNode<K>>>,
+    stats: DiskStats,
+}
+
+impl<K> MemoryStore<K> {
+    pub fn new() -> Self {
+        Self {
+            pages: Vec::new(),
+            stats: DiskStats::default(),
+        }
+    }
+
+    /// Number of pages holding a node.
+    pub fn pages_in_use(&self) -> usize {
+        self.pages.iter().flatten().count()
+    }
+}
+
+impl<K> Default for MemoryStore<K> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<K: Clone> DiskStore<K> for MemoryStore<K> {
+    fn allocate(&mut self) -> usize {
+        self.pages.push(None);
+        self.pages.len() - 1
+    }
+
+    fn read(&mut self, x: usize) -> io::Result<BTreeNode<K>> {
+        self.stats.reads += 1;
+        self.pages
+            .get(x)
+            .and_then(Option::as_ref)
+            .cloned()
+            .ok_or_else(|| no_node(x))
+    }
+
+    fn write(&mut self, x: usize, node: &BTreeNode<K>) -> io::Result<()> {
+        self.stats.writes += 1;
+        let page = self.pages.get_mut(x).ok_or_else(|| no_node(x))?;
+        *page = Some(node.clone());
+        Ok(())
+    }
+
+    fn free(&mut self, x: usize) {
+        if let Some(page) = self.pages.get_mut(x) {
+            *page = None;
+        }
+    }
+
+    fn stats(&self) -> DiskStats {
+        self.stats
+    }
+
+    fn reset_stats(&mut self) {
+        self.stats = DiskStats::default();
+    }
+}
+
+/// # Record
+///
+/// Keys with an encoding of a fixed number of bytes, so that every node of
+/// a B-tree of minimum degree `t` fits the same page size.
+pub trait Record: Sized {
+    /// Bytes of one encoded key
+    const SIZE: usize;
+
+    /// Write the key into `bytes`, which are `SIZE` long.
+    fn encode(&self, bytes: &mut [u8]);
+
+    /// The key written into `bytes` by [`encode`](Self::encode).
+    fn decode(bytes: &[u8]) -> Self;
+}
+
+macro_rules! impl_record {
+    ($($t:ty),*) => {
+        $(impl Record for $t {
+            const SIZE: usize = std::mem::size_of::<$t>();
+
+            fn encode(&self, bytes: &mut [u8]) {
+                bytes.copy_from_slice(&self.to_le_bytes());
+            }
+
+            fn decode(bytes: &[u8]) -> Self {
+                <$t>::from_le_bytes(bytes.try_into().expect("a key is SIZE bytes"))
+            }
+        })*
+    };
+}
+
+impl_record!(
+    i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize
+);
+
+/// # File Store
+///
+/// Pages of equal size in one file, page `x` at offset `x` times the page
+/// size, so every `DISK-READ` and `DISK-WRITE` is one seek and one transfer
+/// of a whole page. A page holds the leaf flag, `x.n`, room for `2t - 1`
+/// keys and room for `2t` child pointers, all little-endian.
+///
+/// # Example
+///
+/// ```rust
+/// use algorithms::trees::btree::disk::{BTreeNode, DiskStore, FileStore};
+///
+/// let path = std::env::temp_dir().join("disk-store-example.pages");
+/// let mut store = FileStore::<u32>::create(&path, 2).unwrap();
+/// let x = store.allocate();
+/// let node = BTreeNode { keys: vec![4, 8], children: Vec::new(), leaf: true };
+/// store.write(x, &node).unwrap();
+/// assert_eq!(store.read(x).unwrap(), node);
+/// assert_eq!((store.stats().reads, store.stats().writes), (1, 1));
+/// # std::fs::remove_file(path).unwrap();
+/// ```
+#[derive(Debug)]
+pub struct FileStore<K> {
+    file: File,
+    /// `2t - 1`, the most keys a node holds
+    max_keys: usize,
+    page_size: usize,
+    pages: usize,
+    stats: DiskStats,
+    _keys: std::marker::PhantomData<K>,
+}
+
+impl<K: Record> FileStore<K> {
+    /// A store in a new file at `path`, replacing any file there, for a
+    /// B-tree of minimum degree `t`.
+    ///
+    /// # Errors
+    ///
+    /// An I/O error if the file cannot be created.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `t < 2`.
+    pub fn create(path: impl AsRef<Path>, t: usize) -> io::Result<Self> {
+        assert!(t >= 2, "a B-tree needs minimum degree t ≥ 2");
+        let file = OpenOptions::new()
+            .read(true)
+            .write(true)
+            .create(true)
+            .truncate(true)
+            .open(path)?;
+        Ok(Self {
+            file,
+            max_keys: 2 * t - 1,
+            page_size: FLAG_SIZE + WORD_SIZE + (2 * t - 1) * K::SIZE + 2 * t * WORD_SIZE,
+            pages: 0,
+            stats: DiskStats::default(),
+            _keys: std::marker::PhantomData,
+        })
+    }
+
+    /// Bytes of one page.
+    pub fn page_size(&self) -> usize {
+        self.page_size
+    }
+
+    /// Seek to the start of page `x`
+    fn seek(&mut self, x: usize) -> io::Result<()> {
+        if x >= self.pages {
+            return Err(no_node(x));
+        }
+        self.file
+            .seek(SeekFrom::Start((x * self.page_size) as u64))
+            .map(|_| ())
+    }
+}
+
+impl<K: Record> DiskStore<K> for FileStore<K> {
+    fn allocate(&mut self) -> usize {
+        self.pages += 1;
+        self.pages - 1
+    }
+
+    fn read(&mut self, x: usize) -> io::Result<BTreeNode<K>> {
+        self.stats.reads += 1;
+        self.seek(x)?;
+        let mut page = vec![0; self.page_size];
+        self.file.read_exact(&mut page)?;
+
+        let leaf = page[0] == 1;
+        let n = u64::from_le_bytes(page[FLAG_SIZE..FLAG_SIZE + WORD_SIZE].try_into().unwrap());
+        let n = n as usize;
+        if n > self.max_keys {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!("page {} claims {} keys", x, n),
+            ));
+        }
+        let keys_start = FLAG_SIZE + WORD_SIZE;
+        let keys = page[keys_start..]
+            .chunks_exact(K::SIZE)
+            .take(n)
+            .map(K::decode)
+            .collect();
+        let children_start = keys_start + self.max_keys * K::SIZE;
+        let children = if leaf {
+            Vec::new()
+        } else {
+            page[children_start..]
+                .chunks_exact(WORD_SIZE)
+                .take(n + 1)
+                .map(|word| u64::from_le_bytes(word.try_into().unwrap()) as usize)
+                .collect()
+        };
+        Ok(BTreeNode {
+            keys,
+            children,
+            leaf,
+        })
+    }
+
+    fn write(&mut self, x: usize, node: &BTreeNode<K>) -> io::Result<()> {
+        self.stats.writes += 1;
+        if node.n() > self.max_keys {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                format!("a node of {} keys does not fit a page", node.n()),
+            ));
+        }
+        let mut page = vec![0; self.page_size];
+        page[0] = node.leaf as u8;
+        page[FLAG_SIZE..FLAG_SIZE + WORD_SIZE].copy_from_slice(&(node.n() as u64).to_le_bytes());
+        let keys_start = FLAG_SIZE + WORD_SIZE;
+        for (bytes, key) in page[keys_start..].chunks_exact_mut(K::SIZE).zip(&node.keys) {
+            key.encode(bytes);
+        }
+        let children_start = keys_start + self.max_keys * K::SIZE;
+        for (bytes, &c) in page[children_start..]
+            .chunks_exact_mut(WORD_SIZE)
+            .zip(&node.children)
+        {
+            bytes.copy_from_slice(&(c as u64).to_le_bytes());
+        }
+        self.seek(x)?;
+        self.file.write_all(&page)
+    }
+
+    /// Freed pages stay in the file unused.
+    fn free(&mut self, _x: usize) {}
+
+    fn stats(&self) -> DiskStats {
+        self.stats
+    }
+
+    fn reset_stats(&mut self) {
+        self.stats = DiskStats::default();
+    }
+}
+
+/// The error for page `x` holding no node
+fn no_node(x: usize) -> io::Error {
+    io::Error::new(io::ErrorKind::NotFound, format!("page {} holds no node", x))
+}
@@ -0,0 +1,607 @@
+use std::fmt::Write;
+use std::io;
+
+use super::disk::{BTreeNode, DiskStats, DiskStore, MemoryStore};
+
+/// Key to delete from a subtree: a given key, or its largest or smallest
+enum Target<'a, K> {
+    Key(&'a K),
+    Max,
+    Min,
+}
+
+/// # B-Tree
+///
+/// A balanced search tree of minimum degree `t ≥ 2` whose nodes are disk
+/// pages (CLRS Chapter 18). Every node other than the root holds between
+/// `t - 1` and `2t - 1` keys, an internal node with `n` keys has `n + 1`
+/// children, and all leaves have the same depth. Nodes are read and
+/// written through a [`DiskStore`], which counts every `DISK-READ` and
+/// `DISK-WRITE`; the root stays in main memory, so only a change of the
+/// root costs a disk access.
+///
+/// Insertion and deletion make a single pass down the tree. On the way,
+/// `B-TREE-INSERT` splits every full node it meets, so a split never has
+/// to propagate back up, and `B-TREE-DELETE` makes sure every node it
+/// descends to has at least `t` keys, so removing one never needs to back
+/// up either.
+///
+/// # Pseudocode (CLRS)
+///
+/// ```text
+/// B-TREE-SPLIT-CHILD(x, i)
+/// y = x.c_i                           // full child to split
+/// z = ALLOCATE-NODE()                 // z takes half of y
+/// z.leaf = y.leaf
+/// z.n = t - 1
+/// for j = 1 to t - 1                  // z gets y's greatest keys ...
+///     z.key_j = y.key_{j+t}
+/// if not y.leaf
+///     for j = 1 to t                  // ... and its corresponding children
+///         z.c_j = y.c_{j+t}
+/// y.n = t - 1                         // y keeps t - 1 keys
+/// for j = x.n + 1 downto i + 1        // shift x's children to the right ...
+///     x.c_{j+1} = x.c_j
+/// x.c_{i+1} = z                       // ... to make room for z as a child
+/// for j = x.n downto i                // shift the corresponding keys in x
+///     x.key_{j+1} = x.key_j
+/// x.key_i = y.key_t                   // insert y's median key
+/// x.n = x.n + 1                       // x has gained a child
+/// DISK-WRITE(y)
+/// DISK-WRITE(z)
+/// DISK-WRITE(x)
+///
+/// B-TREE-INSERT(T, k)
+/// r = T.root
+/// if r.n == 2t - 1
+///     s = B-TREE-SPLIT-ROOT(T)
+///     B-TREE-INSERT-NONFULL(s, k)
+/// else B-TREE-INSERT-NONFULL(r, k)
+///
+/// B-TREE-SPLIT-ROOT(T)
+/// s = ALLOCATE-NODE()
+/// s.leaf = FALSE
+/// s.n = 0
+/// s.c_1 = T.root
+/// T.root = s
+/// B-TREE-SPLIT-CHILD(s, 1)
+/// return s
+///
+/// B-TREE-INSERT-NONFULL(x, k)
+/// i = x.n
+/// if x.leaf                           // inserting into a leaf?
+///     while i ≥ 1 and k < x.key_i     // shift keys in x to make room for k
+///         x.key_{i+1} = x.key_i
+///         i = i - 1
+///     x.key_{i+1} = k                 // insert key k in x
+///     x.n = x.n + 1                   // now x has 1 more key
+///     DISK-WRITE(x)
+/// else while i ≥ 1 and k < x.key_i    // find the child where k belongs
+///         i = i - 1
+///     i = i + 1
+///     DISK-READ(x.c_i)
+///     if x.c_i.n == 2t - 1            // split the child if it's full
+///         B-TREE-SPLIT-CHILD(x, i)
+///         if k > x.key_i              // does k go into x.c_i or x.c_{i+1}?
+///             i = i + 1
+///     B-TREE-INSERT-NONFULL(x.c_i, k)
+/// ```
+///
+/// # Example
+///
+/// ```rust
+/// use algorithms::trees::btree::disk::{DiskStore, MemoryStore};
+/// use algorithms::trees::btree::tree::BTree;
+///
+/// let mut tree = BTree::create(MemoryStore::new(), 2).unwrap();
+/// for k in "FSQKCLHTVWMRNPABXYDZE".chars() {
+///     tree.insert(k).unwrap();
+/// }
+/// assert_eq!(tree.height().unwrap(), 2);
+///
+/// tree.store_mut().reset_stats();
+/// let (x, i) = tree.search(&'P').unwrap().unwrap();
+/// // The root is in main memory, so the search read at most h pages
+/// assert!(tree.stats().reads <= 2);
+/// assert_eq!(tree.stats().writes, 0);
+/// assert_eq!(tree.node(x).unwrap().keys[i], 'P');
+///
+/// assert!(tree.delete(&'M').unwrap());
+/// assert_eq!(tree.search(&'M').unwrap(), None);
+/// ```
+///
+/// # Notes
+///
+/// * A B-tree with `n ≥ 1` keys has height `h ≤ log_t((n + 1)/2)`
+///   (CLRS Theorem 18.1).
+/// * Search, insertion and deletion access **O(h) = O(log_t n)** pages and
+///   take **O(t h)** CPU time.
+/// * Equal keys are kept; search and deletion find one of them.
+#[derive(Debug)]
+pub struct BTree<K, S> {
+    store: S,
+    t: usize,
+    root: usize,
+    /// Main-memory copy of `T.root`
+    root_node: BTreeNode<K>,
+}
+
+impl<K: Ord + Clone, S: DiskStore<K>> BTree<K, S> {
+    /// # B-TREE-CREATE
+    ///
+    /// An empty B-tree of minimum degree `t`, whose root is a leaf without
+    /// keys on a new page of `store`.
+    ///
+    /// # Pseudocode (CLRS)
+    ///
+    /// ```text
+    /// B-TREE-CREATE(T)
+    /// x = ALLOCATE-NODE()
+    /// x.leaf = TRUE
+    /// x.n = 0
+    /// DISK-WRITE(x)
+    /// T.root = x
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// An I/O error if the root cannot be written.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `t < 2`.
+    pub fn create(mut store: S, t: usize) -> io::Result<Self> {
+        assert!(t >= 2, "a B-tree needs minimum degree t ≥ 2");
+        let x = store.allocate();
+        let root_node = BTreeNode::empty_leaf();
+        store.write(x, &root_node)?;
+        Ok(Self {
+            store,
+            t,
+            root: x,
+            root_node,
+        })
+    }
+
+    /// The minimum degree `t`.
+    pub fn min_degree(&self) -> usize {
+        self.t
+    }
+
+    /// Page of `T.root`.
+    pub fn root(&self) -> usize {
+        self.root
+    }
+
+    pub fn store(&self) -> &S {
+        &self.store
+    }
+
+    pub fn store_mut(&mut self) -> &mut S {
+        &mut self.store
+    }
+
+    /// Disk operations of the store so far.
+    pub fn stats(&self) -> DiskStats {
+        self.store.stats()
+    }
+
+    /// The node on page `x`: `DISK-READ(x)`, or the root from main memory.
+    ///
+    /// # Errors
+    ///
+    /// An I/O error if page `x` cannot be read.
+    pub fn node(&mut self, x: usize) -> io::Result<BTreeNode<K>> {
+        self.disk_read(x)
+    }
+
+    /// Height `h`, found by reading the leftmost path down to a leaf.
+    ///
+    /// # Errors
+    ///
+    /// An I/O error if a page cannot be read.
+    pub fn height(&mut self) -> io::Result<usize> {
+        let mut node = self.root_node.clone();
+        let mut h = 0;
+        while !node.leaf {
+            node = self.store.read(node.children[0])?;
+            h += 1;
+        }
+        Ok(h)
+    }
+
+    /// # B-TREE-SEARCH
+    ///
+    /// The page `x` and index `i` with `x.keys[i] == k`, or `None` (CLRS
+    /// Section 18.2). At each node, a linear search finds the smallest `i`
+    /// with `k ≤ x.key_i`; unless that key is `k`, the search continues in
+    /// the child between `x.key_{i-1}` and `x.key_i`.
+    ///
+    /// # Pseudocode (CLRS)
+    ///
+    /// ```text
+    /// B-TREE-SEARCH(x, k)
+    /// i = 1
+    /// while i ≤ x.n and k > x.key_i
+    ///     i = i + 1
+    /// if i ≤ x.n and k == x.key_i
+    ///     return (x, i)
+    /// elseif x.leaf
+    ///     return NIL
+    /// else DISK-READ(x.c_i)
+    ///     return B-TREE-SEARCH(x.c_i, k)
+    /// ```
+    ///
+    /// # Notes
+    ///
+    /// * Reads at most **h** pages and takes **O(t h)** CPU time.
+    ///
+    /// # Errors
+    ///
+    /// An I/O error if a page cannot be read.
+    pub fn search(&mut self, k: &K) -> io::Result<Option<(usize, usize)>> {
+        let mut x = self.root;
+        let mut node = self.root_node.clone();
+        loop {
+            let i = first_at_least(&node, k);
+            if i < node.n() && node.keys[i] == *k {
+                return Ok(Some((x, i)));
+            } else if node.leaf {
+                return Ok(None);
+            }
+            x = node.children[i];
+            node = self.store.read(x)?;
+        }
+    }
+
+    /// `B-TREE-INSERT(T, k)`.
+    ///
+    /// # Errors
+    ///
+    /// An I/O error if a page cannot be read or written; the tree may then
+    /// be left inconsistent on disk.
+    pub fn insert(&mut self, k: K) -> io::Result<()> {
+        if self.root_node.n() == 2 * self.t - 1 {
+            self.split_root()?;
+        }
+        self.insert_nonfull(self.root, self.root_node.clone(), k)
+    }
+
+    /// # B-TREE-DELETE
+    ///
+    /// Delete one occurrence of `k`; returns whether `k` was found (CLRS
+    /// Section 18.3). Looking for `k` from the root down, at a node `x`:
+    ///
+    /// 1. If `k` is in `x` and `x` is a leaf, remove `k` from `x`.
+    /// 2. If `k` is in the internal node `x`, with children `y` before and
+    ///    `z` after it:
+    ///    * (a) if `y` has at least `t` keys, delete the predecessor `k'`
+    ///      of `k` from the subtree of `y` and replace `k` by `k'` in `x`;
+    ///    * (b) otherwise, if `z` has at least `t` keys, do the same with
+    ///      the successor of `k` in the subtree of `z`;
+    ///    * (c) otherwise, merge `k` and all of `z` into `y`, which then
+    ///      has `2t - 1` keys, free `z` and delete `k` from `y`.
+    /// 3. If `k` is not in the internal node `x`, it can only be in the
+    ///    subtree of the child `x.c_i` between the keys around `k`. If
+    ///    `x.c_i` has only `t - 1` keys:
+    ///    * (a) if an immediate sibling of `x.c_i` has at least `t` keys,
+    ///      move the key separating them down from `x` into `x.c_i`, the
+    ///      sibling's nearest key up into `x` and the sibling's nearest
+    ///      child over to `x.c_i`;
+    ///    * (b) otherwise, merge `x.c_i` with a sibling around the key of
+    ///      `x` separating them.
+    ///
+    ///    Then continue with `x.c_i`.
+    ///
+    /// A merge can take the last key of the root, which is then freed, and
+    /// its only child becomes the root; this is the only way a B-tree
+    /// shrinks in height.
+    ///
+    /// # Notes
+    ///
+    /// * Accesses **O(h)** pages and takes **O(t h)** CPU time; cases 2a
+    ///   and 2b find and delete `k'` in one more pass down a subtree.
+    ///
+    /// # Errors
+    ///
+    /// An I/O error if a page cannot be read or written; the tree may then
+    /// be left inconsistent on disk.
+    pub fn delete(&mut self, k: &K) -> io::Result<bool> {
+        let root = self.root_node.clone();
+        Ok(self.delete_from(self.root, root, Target::Key(k))?.is_some())
+    }
+
+    /// `B-TREE-SPLIT-ROOT(T)`
+    fn split_root(&mut self) -> io::Result<()> {
+        let s = self.store.allocate();
+        let mut s_node = BTreeNode {
+            keys: Vec::new(),
+            children: vec![self.root],
+            leaf: false,
+        };
+        let r_node = std::mem::replace(&mut self.root_node, s_node.clone());
+        self.root = s;
+        self.split_child(s, &mut s_node, 0, r_node)?;
+        Ok(())
+    }
+
+    /// `B-TREE-SPLIT-CHILD(x, i)` for the full child `y = x.c_i` read
+    /// before; returns `y` and its new sibling `z`
+    fn split_child(
+        &mut self,
+        x: usize,
+        x_node: &mut BTreeNode<K>,
+        i: usize,
+        mut y: BTreeNode<K>,
+    ) -> io::Result<(BTreeNode<K>, BTreeNode<K>)> {
+        let t = self.t;
+        let z = self.store.allocate();
+        let z_node = BTreeNode {
+            keys: y.keys.split_off(t),
+            children: if y.leaf {
+                Vec::new()
+            } else {
+                y.children.split_off(t)
+            },
+            leaf: y.leaf,
+        };
+        let median = y.keys.pop().expect("a full node has 2t - 1 keys");
+        x_node.children.insert(i + 1, z);
+        x_node.keys.insert(i, median);
+        self.disk_write(x_node.children[i], &y)?;
+        self.disk_write(z, &z_node)?;
+        self.disk_write(x, x_node)?;
+        Ok((y, z_node))
+    }
+
+    /// `B-TREE-INSERT-NONFULL(x, k)`
+    fn insert_nonfull(&mut self, mut x: usize, mut node: BTreeNode<K>, k: K) -> io::Result<()> {
+        loop {
+            // Past the last key that is at most k
+            let mut i = node
+                .keys
+                .iter()
+                .rposition(|key| *key <= k)
+                .map_or(0, |j| j + 1);
+            if node.leaf {
+                node.keys.insert(i, k);
+                return self.disk_write(x, &node);
+            }
+            let child = self.store.read(node.children[i])?;
+            (x, node) = if child.n() == 2 * self.t - 1 {
+                let (y, z) = self.split_child(x, &mut node, i, child)?;
+                if k > node.keys[i] {
+                    i += 1;
+                    (node.children[i], z)
+                } else {
+                    (node.children[i], y)
+                }
+            } else {
+                (node.children[i], child)
+            };
+        }
+    }
+
+    /// Remove `target` from the subtree of `x`, which has at least `t` keys
+    /// unless it is the root; returns the removed key
+    fn delete_from(
+        &mut self,
+        mut x: usize,
+        mut node: BTreeNode<K>,
+        target: Target<K>,
+    ) -> io::Result<Option<K>> {
+        let t = self.t;
+        loop {
+            // k is x.key_i if found, else in the subtree of x.c_i
+            let (i, found) = match target {
+                Target::Key(k) => {
+                    let i = first_at_least(&node, k);
+                    (i, i < node.n() && node.keys[i] == *k)
+                }
+                Target::Max if node.leaf => (node.n() - 1, true),
+                Target::Max => (node.n(), false),
+                Target::Min => (0, node.leaf),
+            };
+
+            if found && node.leaf {
+                // Case 1
+                let key = node.keys.remove(i);
+                self.disk_write(x, &node)?;
+                return Ok(Some(key));
+            }
+            if found {
+                let y = node.children[i];
+                let y_node = self.store.read(y)?;
+                if y_node.n() >= t {
+                    // Case 2a
+                    let pred = self.delete_from(y, y_node, Target::Max)?;
+                    let key = std::mem::replace(&mut node.keys[i], pred.expect("y has keys"));
+                    self.disk_write(x, &node)?;
+                    return Ok(Some(key));
+                }
+                let z = node.children[i + 1];
+                let z_node = self.store.read(z)?;
+                if z_node.n() >= t {
+                    // Case 2b
+                    let succ = self.delete_from(z, z_node, Target::Min)?;
+                    let key = std::mem::replace(&mut node.keys[i], succ.expect("z has keys"));
+                    self.disk_write(x, &node)?;
+                    return Ok(Some(key));
+                }
+                // Case 2c
+                node = self.merge(x, &mut node, i, y_node, z_node)?;
+                x = y;
+                continue;
+            }
+            if node.leaf {
+                return Ok(None);
+            }
+
+            let mut c = node.children[i];
+            let mut c_node = self.store.read(c)?;
+            if c_node.n() == t - 1 {
+                let left = match i {
+                    0 => None,
+                    _ => Some(self.store.read(node.children[i - 1])?),
+                };
+                // The right sibling is only read if the left one cannot help
+                let right = match left {
+                    Some(ref l_node) if l_node.n() >= t => None,
+                    _ if i == node.n() => None,
+                    _ => Some(self.store.read(node.children[i + 1])?),
+                };
+                match (left, right) {
+                    (Some(mut l_node), _) if l_node.n() >= t => {
+                        // Case 3a, from the left sibling
+                        let up = l_node.keys.pop().expect("the sibling has t keys");
+                        c_node
+                            .keys
+                            .insert(0, std::mem::replace(&mut node.keys[i - 1], up));
+                        if let Some(child) = l_node.children.pop() {
+                            c_node.children.insert(0, child);
+                        }
+                        self.disk_write(node.children[i - 1], &l_node)?;
+                        self.disk_write(c, &c_node)?;
+                        self.disk_write(x, &node)?;
+                    }
+                    (_, Some(mut r_node)) if r_node.n() >= t => {
+                        // Case 3a, from the right sibling
+                        let up = r_node.keys.remove(0);
+                        c_node.keys.push(std::mem::replace(&mut node.keys[i], up));
+                        if !r_node.leaf {
+                            c_node.children.push(r_node.children.remove(0));
+                        }
+                        self.disk_write(node.children[i + 1], &r_node)?;
+                        self.disk_write(c, &c_node)?;
+                        self.disk_write(x, &node)?;
+                    }
+                    (_, Some(r_node)) => {
+                        // Case 3b, with the right sibling
+                        c_node = self.merge(x, &mut node, i, c_node, r_node)?;
+                    }
+                    (Some(l_node), None) => {
+                        // Case 3b, with the left sibling
+                        c = node.children[i - 1];
+                        c_node = self.merge(x, &mut node, i - 1, l_node, c_node)?;
+                    }
+                    (None, None) => unreachable!("a child has a sibling"),
+                }
+            }
+            (x, node) = (c, c_node);
+        }
+    }
+
+    /// Merge `x.c_{i+1}` and the key `x.key_i` into `y = x.c_i`, each with
+    /// `t - 1` keys, and free `x.c_{i+1}`; if `x` is the root and loses its
+    /// last key, `y` becomes the root. Returns `y`
+    fn merge(
+        &mut self,
+        x: usize,
+        x_node: &mut BTreeNode<K>,
+        i: usize,
+        mut y_node: BTreeNode<K>,
+        z_node: BTreeNode<K>,
+    ) -> io::Result<BTreeNode<K>> {
+        let z = x_node.children.remove(i + 1);
+        y_node.keys.push(x_node.keys.remove(i));
+        y_node.keys.extend(z_node.keys);
+        y_node.children.extend(z_node.children);
+        self.store.free(z);
+        let y = x_node.children[i];
+        if x_node.n() == 0 {
+            // Only the root can run out of keys; the tree shrinks
+            self.store.free(x);
+            self.root = y;
+        } else {
+            self.disk_write(x, x_node)?;
+        }
+        self.disk_write(y, &y_node)?;
+        Ok(y_node)
+    }
+
+    /// `DISK-READ(x)`, unless `x` is the root in main memory
+    fn disk_read(&mut self, x: usize) -> io::Result<BTreeNode<K>> {
+        if x == self.root {
+            Ok(self.root_node.clone())
+        } else {
+            self.store.read(x)
+        }
+    }
+
+    /// `DISK-WRITE(x)`, keeping the copy of the root up to date
+    fn disk_write(&mut self, x: usize, node: &BTreeNode<K>) -> io::Result<()> {
+        if x == self.root {
+            self.root_node = node.clone();
+        }
+        self.store.write(x, node)
+    }
+}
+
+/// Smallest `i` with `k ≤ x.key_i`, or `x.n` if there is none
+fn first_at_least<K: Ord>(node: &BTreeNode<K>, k: &K) -> usize {
+    node.keys
+        .iter()
+        .position(|key| key >= k)
+        .unwrap_or(node.n())
+}
+
+/// The disk operations of inserting each of `keys`, then the tree
+pub(crate) fn describe_insert(keys: &[i64]) -> String {
+    let mut tree = from_keys(&[]);
+    let mut text = String::new();
+    for &k in keys {
+        tree.store_mut().reset_stats();
+        tree.insert(k).expect("memory pages are always readable");
+        let _ = writeln!(text, "Insert {}: {}", k, disk_accesses(&tree));
+    }
+    text + &describe_tree(&mut tree)
+}
+
+/// The tree built from `keys`, then the disk operations of deleting each
+pub(crate) fn describe_delete(keys: &[i64]) -> String {
+    let mut tree = from_keys(keys);
+    let mut text = describe_tree(&mut tree);
+    for k in keys {
+        tree.store_mut().reset_stats();
+        tree.delete(k).expect("memory pages are always readable");
+        let _ = writeln!(text, "Delete {}: {}", k, disk_accesses(&tree));
+    }
+    text
+}
+
+/// B-tree of minimum degree 2 in main memory holding `keys`
+fn from_keys(keys: &[i64]) -> BTree<i64, MemoryStore<i64>> {
+    let mut tree = BTree::create(MemoryStore::new(), 2).expect("memory pages are always writable");
+    for &k in keys {
+        tree.insert(k).expect("memory pages are always readable");
+    }
+    tree
+}
+
+/// The nodes of every level of a B-tree, then its height
+fn describe_tree(tree: &mut BTree<i64, MemoryStore<i64>>) -> String {
+    let mut text = String::new();
+    let mut level = vec![tree.root()];
+    let mut depth = 0;
+    while !level.is_empty() {
+        let mut nodes = Vec::new();
+        let mut next = Vec::new();
+        for x in level {
+            let node = tree.node(x).expect("memory pages are always readable");
+            let keys: Vec<String> = node.keys.iter().map(|k| k.to_string()).collect();
+            nodes.push(format!("[{}]", keys.join(" ")));
+            next.extend(node.children);
+        }
+        let _ = writeln!(text, "Depth {}: {}", depth, nodes.join(" "));
+        level = next;
+        depth += 1;
+    }
+    let _ = writeln!(text, "Height: {}", depth - 1);
+    text
+}
+
+/// Disk operations of the last B-tree operation
+fn disk_accesses(tree: &BTree<i64, MemoryStore<i64>>) -> String {
+    let stats = tree.store().stats();
+    format!("{} DISK-READ, {} DISK-WRITE", stats.reads, stats.writes)
+}
@@ -0,0 +1,214 @@
+//! # Interval Trees
+//!
+//! A red-black tree of closed intervals keyed by their low endpoints, whose
+//! nodes also store `x.max`, the largest high endpoint in the subtree of
+//! `x` (CLRS Section 17.3). The maxima tell a search which subtree can
+//! hold an interval overlapping the query.
+
+use std::fmt::{self, Write};
+
+use super::augment::Augmentation;
+use super::red_black::RedBlackTree;
+
+/// Closed interval `[low, high]`, ordered by `low` and then `high`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Interval<T> {
+    pub low: T,
+    pub high: T,
+}
+
+impl<T: Ord> Interval<T> {
+    /// # Panics
+    ///
+    /// Panics if `low > high`.
+    pub fn new(low: T, high: T) -> Self {
+        assert!(low <= high, "an interval needs low ≤ high");
+        Self { low, high }
+    }
+
+    /// Whether the intervals share a point, `i.low ≤ j.high` and
+    /// `j.low ≤ i.high` (interval trichotomy).
+    pub fn overlaps(&self, other: &Interval<T>) -> bool {
+        self.low <= other.high && other.low <= self.high
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for Interval<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "[{}, {}]", self.low, self.high)
+    }
+}
+
+/// `x.max`, the largest high endpoint in the subtree of `x`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct MaxHigh<T>(pub T);
+
+impl<T: Ord + Clone, V> Augmentation<Interval<T>, V> for MaxHigh<T> {
+    /// `x.max = max(x.int.high, x.left.max, x.right.max)`
+    fn compute(key: &Interval<T>, _value: &V, left: Option<&Self>, right: Option<&Self>) -> Self {
+        let max = [left, right]
+            .into_iter()
+            .flatten()
+            .map(|m| &m.0)
+            .fold(&key.high, |max, high| max.max(high));
+        MaxHigh(max.clone())
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for MaxHigh<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+/// A red-black tree of intervals augmented with subtree maxima.
+pub type IntervalTree<T, V> = RedBlackTree<Interval<T>, V, MaxHigh<T>>;
+
+impl<T: Ord + Clone, V> RedBlackTree<Interval<T>, V, MaxHigh<T>> {
+    /// # INTERVAL-SEARCH
+    ///
+    /// A node whose interval overlaps `i` (CLRS Section 17.3). If the left
+    /// subtree has a high endpoint of at least `i.low`, then either it holds
+    /// an overlapping interval or no interval in the tree overlaps `i`;
+    /// otherwise only the right subtree can.
+    ///
+    /// # Pseudocode (CLRS)
+    ///
+    /// ```text
+    /// INTERVAL-SEARCH(T, i)
+    /// x = T.root
+    /// while x ≠ T.nil and i does not overlap x.int
+    ///     if x.left ≠ T.nil and x.left.max ≥ i.low
+    ///         x = x.left
+    ///     else x = x.right
+    /// return x
+    /// ```
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use algorithms::trees::interval::{Interval, IntervalTree};
+    ///
+    /// // CLRS Figure 17.4
+    /// let mut tree = IntervalTree::augmented();
+    /// for (low, high) in [(16, 21), (8, 9), (25, 30), (5, 8), (15, 23),
+    ///                     (17, 19), (26, 26), (0, 3), (6, 10), (19, 20)] {
+    ///     tree.insert(Interval::new(low, high), ());
+    /// }
+    /// let x = tree.interval_search(&Interval::new(22, 25)).unwrap();
+    /// assert!(tree.key(x).overlaps(&Interval::new(22, 25)));
+    /// assert_eq!(tree.interval_search(&Interval::new(11, 14)), None);
+    /// ```
+    ///
+    /// # Notes
+    ///
+    /// * Runs in **O(lg n)** time.
+    pub fn interval_search(&self, i: &Interval<T>) -> Option<usize> {
+        let mut x = self.root()?;
+        while !i.overlaps(self.key(x)) {
+            x = match self.left(x) {
+                Some(left) if self.augment(left).0 >= i.low => left,
+                _ => self.right(x)?,
+            };
+        }
+        Some(x)
+    }
+
+    /// # All Overlapping Intervals
+    ///
+    /// Every node whose interval overlaps `i`, in sorted order (CLRS
+    /// Exercise 17.3-4). A subtree is skipped when its maximum lies below
+    /// `i.low`, and the right subtree of a node whose low endpoint lies
+    /// above `i.high`, as every interval there starts even later.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use algorithms::trees::interval::{Interval, IntervalTree};
+    ///
+    /// let mut tree = IntervalTree::augmented();
+    /// for (low, high) in [(16, 21), (8, 9), (25, 30), (5, 8), (15, 23)] {
+    ///     tree.insert(Interval::new(low, high), ());
+    /// }
+    /// let found: Vec<_> = tree
+    ///     .overlapping(&Interval::new(9, 16))
+    ///     .into_iter()
+    ///     .map(|x| *tree.key(x))
+    ///     .collect();
+    /// assert_eq!(found, [Interval::new(8, 9), Interval::new(15, 23), Interval::new(16, 21)]);
+    /// ```
+    ///
+    /// # Notes
+    ///
+    /// * Every node visited either overlaps `i` or lies on one of the
+    ///   **O(lg n)** paths bounding the search, so reporting `k` intervals
+    ///   takes **O(min(n, k lg n))** time.
+    pub fn overlapping(&self, i: &Interval<T>) -> Vec<usize> {
+        let mut found = Vec::new();
+        // Inorder walk of the subtrees that may hold an overlap
+        let mut stack = Vec::new();
+        let mut current = self.root();
+        loop {
+            while let Some(x) = current.filter(|&x| self.augment(x).0 >= i.low) {
+                stack.push(x);
+                current = self.left(x);
+            }
+            let Some(x) = stack.pop() else {
+                break;
+            };
+            if self.key(x).low > i.high {
+                // x and its right subtree start after i ends
+                current = None;
+                continue;
+            }
+            if i.overlaps(self.key(x)) {
+                found.push(x);
+            }
+            current = self.right(x);
+        }
+        found
+    }
+
+    /// `x.max`
+    pub fn max_high(&self, x: usize) -> &T {
+        &self.augment(x).0
+    }
+}
+
+/// The tree of the intervals whose endpoints are consecutive `keys`, with
+/// INTERVAL-SEARCH and all overlaps for each of them
+pub(crate) fn describe(keys: &[i64]) -> String {
+    let intervals: Vec<Interval<i64>> = keys
+        .chunks_exact(2)
+        .map(|pair| Interval::new(pair[0].min(pair[1]), pair[0].max(pair[1])))
+        .collect();
+    let mut tree = IntervalTree::augmented();
+    for &i in &intervals {
+        tree.insert(i, ());
+    }
+    let mut text = String::new();
+    if let [.., odd] = keys.chunks_exact(2).remainder() {
+        let _ = writeln!(text, "Ignored unpaired endpoint {}", odd);
+    }
+    for x in tree.inorder() {
+        let _ = writeln!(text, "{} (max {})", tree.key(x), tree.max_high(x));
+    }
+    for i in &intervals {
+        let found = tree
+            .interval_search(i)
+            .map_or("NIL".to_string(), |x| tree.key(x).to_string());
+        let all: Vec<String> = tree
+            .overlapping(i)
+            .into_iter()
+            .map(|x| tree.key(x).to_string())
+            .collect();
+        let _ = writeln!(
+            text,
+            "Search {}: {}; all overlaps {}",
+            i,
+            found,
+            all.join(" ")
+        );
+    }
+    text
+}
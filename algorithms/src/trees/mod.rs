@@ -0,0 +1,6 @@
+pub mod augment;
+pub mod bst;
+pub mod btree;
+pub mod interval;
+pub mod order_statistic;
+pub mod red_black;
@@ -0,0 +1,152 @@
+//! # Order-Statistic Trees
+//!
+//! A red-black tree whose nodes also store `x.size`, the number of nodes
+//! in the subtree of `x` (CLRS Section 17.1). With the sizes, the `i`th
+//! smallest key and the rank of a node are found in **O(lg n)** time.
+
+use std::fmt::{self, Write};
+
+use super::augment::Augmentation;
+use super::red_black::RedBlackTree;
+
+/// `x.size`, the number of nodes in the subtree of `x`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Size(pub usize);
+
+impl<K, V> Augmentation<K, V> for Size {
+    /// `x.size = x.left.size + x.right.size + 1`
+    fn compute(_key: &K, _value: &V, left: Option<&Self>, right: Option<&Self>) -> Self {
+        Size(left.map_or(0, |l| l.0) + right.map_or(0, |r| r.0) + 1)
+    }
+}
+
+impl fmt::Display for Size {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+/// A red-black tree augmented with subtree sizes.
+pub type OrderStatisticTree<K, V> = RedBlackTree<K, V, Size>;
+
+impl<K, V> RedBlackTree<K, V, Size> {
+    /// # OS-SELECT
+    ///
+    /// The node with the `i`th smallest key, counting from 1 (CLRS Section
+    /// 17.1). The rank of `x` within its own subtree is `x.left.size + 1`,
+    /// which decides whether to stop at `x` or to continue left or right.
+    ///
+    /// # Pseudocode (CLRS)
+    ///
+    /// ```text
+    /// OS-SELECT(x, i)
+    /// r = x.left.size + 1
+    /// if i == r
+    ///     return x
+    /// elseif i < r
+    ///     return OS-SELECT(x.left, i)
+    /// else return OS-SELECT(x.right, i - r)
+    /// ```
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use algorithms::trees::order_statistic::OrderStatisticTree;
+    ///
+    /// let mut tree = OrderStatisticTree::augmented();
+    /// for k in [26, 17, 41, 14, 21, 30, 47, 10, 16, 19] {
+    ///     tree.insert(k, ());
+    /// }
+    /// let x = tree.os_select(4).unwrap();
+    /// assert_eq!(tree.key(x), &17);
+    /// assert_eq!(tree.os_rank(tree.search(&30).unwrap()), 8);
+    /// assert_eq!(tree.os_select(11), None);
+    /// ```
+    ///
+    /// # Notes
+    ///
+    /// * Runs in **O(lg n)** time; `None` if `i` is not in `1..=n`.
+    pub fn os_select(&self, mut i: usize) -> Option<usize> {
+        let mut x = self.root()?;
+        loop {
+            let r = self.left_size(x) + 1;
+            if i == r {
+                return Some(x);
+            } else if i < r {
+                x = self.left(x)?;
+            } else {
+                i -= r;
+                x = self.right(x)?;
+            }
+        }
+    }
+
+    /// # OS-RANK
+    ///
+    /// Position of `x` in the inorder walk, counting from 1 (CLRS Section
+    /// 17.1). Walking up from `x`, every step up from a right child adds
+    /// the parent and its left subtree, which precede `x`.
+    ///
+    /// # Pseudocode (CLRS)
+    ///
+    /// ```text
+    /// OS-RANK(T, x)
+    /// r = x.left.size + 1
+    /// y = x
+    /// while y ≠ T.root
+    ///     if y == y.p.right
+    ///         r = r + y.p.left.size + 1
+    ///     y = y.p
+    /// return r
+    /// ```
+    ///
+    /// # Notes
+    ///
+    /// * Runs in **O(lg n)** time.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `x` is not a node of the tree.
+    pub fn os_rank(&self, x: usize) -> usize {
+        let mut r = self.left_size(x) + 1;
+        let mut y = x;
+        while let Some(p) = self.parent(y) {
+            if self.right(p) == Some(y) {
+                r += self.left_size(p) + 1;
+            }
+            y = p;
+        }
+        r
+    }
+
+    /// `x.size`
+    pub fn size(&self, x: usize) -> usize {
+        self.augment(x).0
+    }
+
+    /// `x.left.size`, 0 for `T.nil`
+    fn left_size(&self, x: usize) -> usize {
+        self.left(x).map_or(0, |l| self.size(l))
+    }
+}
+
+/// OS-SELECT and OS-RANK of every rank in the tree built from `keys`
+pub(crate) fn describe(keys: &[i64]) -> String {
+    let mut tree = OrderStatisticTree::augmented();
+    for &k in keys {
+        tree.insert(k, ());
+    }
+    let mut text = String::new();
+    for i in 1..=tree.len() {
+        let x = tree.os_select(i).expect("i is at most n");
+        let _ = writeln!(
+            text,
+            "OS-SELECT {}: {} (size {}, rank {})",
+            i,
+            tree.key(x),
+            tree.size(x),
+            tree.os_rank(x)
+        );
+    }
+    text
+}
@@ -0,0 +1,898 @@
+//! # Red-Black Trees
+//!
+//! Binary search trees with one extra bit per node, its color, kept so
+//! that no root-to-leaf path is more than twice as long as any other
+//! (CLRS Chapter 13). A tree of `n` nodes has height at most `2 lg(n + 1)`,
+//! so the dynamic-set operations take **O(lg n)** time.
+//!
+//! As in CLRS, one sentinel node `T.nil` stands for every leaf and for the
+//! parent of the root. Its parent pointer is scratch space: `RB-DELETE` sets
+//! it so that `RB-DELETE-FIXUP` can walk up from a leaf.
+
+use std::error::Error;
+use std::fmt::{self, Write};
+
+use super::augment::Augmentation;
+use crate::trace::NoopTracer;
+
+/// Index of the sentinel `T.nil`
+const NIL: usize = 0;
+
+/// Color of a node.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Color {
+    Red,
+    Black,
+}
+
+/// A fixup case about to be applied, reported with the tree before it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FixupCase {
+    /// Case 1, 2 or 3 of `RB-INSERT-FIXUP` at node `z`.
+    Insert { case: u8, z: usize },
+    /// Case 1, 2, 3 or 4 of `RB-DELETE-FIXUP` with `w`, the sibling of
+    /// the doubly black node `x`.
+    Delete { case: u8, w: usize },
+}
+
+/// Observer for the fixup cases of a red-black tree.
+///
+/// Closures of the form `FnMut(&FixupCase, &RedBlackTree<K, V, A>)` are
+/// tracers as well.
+pub trait FixupTracer<K, V, A = ()> {
+    fn record(&mut self, case: FixupCase, tree: &RedBlackTree<K, V, A>);
+}
+
+impl<K, V, A, F> FixupTracer<K, V, A> for F
+where
+    F: FnMut(&FixupCase, &RedBlackTree<K, V, A>),
+{
+    fn record(&mut self, case: FixupCase, tree: &RedBlackTree<K, V, A>) {
+        self(&case, tree)
+    }
+}
+
+impl<K, V, A> FixupTracer<K, V, A> for NoopTracer {
+    #[inline(always)]
+    fn record(&mut self, _case: FixupCase, _tree: &RedBlackTree<K, V, A>) {}
+}
+
+/// A red-black property or a search-tree invariant that does not hold.
+///
+/// Property 1, every node is red or black, holds by construction.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Violation {
+    /// Property 2: the root is red.
+    RedRoot,
+    /// Property 3: the sentinel leaf `T.nil` is red.
+    RedLeaf,
+    /// Property 4: red `node` has a red `child`.
+    RedChild { node: usize, child: usize },
+    /// Property 5: the simple paths from `node` down to leaves contain
+    /// `left` black nodes through its left child but `right` through its
+    /// right child.
+    BlackHeight {
+        node: usize,
+        left: usize,
+        right: usize,
+    },
+    /// `node` has a smaller key than its predecessor in the inorder walk.
+    Order { node: usize },
+    /// `node.p` is not the node that has `node` as a child.
+    Parent { node: usize },
+}
+
+impl Violation {
+    /// Number of the broken red-black property, `None` for search-tree
+    /// invariants.
+    pub fn property(&self) -> Option<u8> {
+        match self {
+            Violation::RedRoot => Some(2),
+            Violation::RedLeaf => Some(3),
+            Violation::RedChild { .. } => Some(4),
+            Violation::BlackHeight { .. } => Some(5),
+            Violation::Order { .. } | Violation::Parent { .. } => None,
+        }
+    }
+}
+
+impl fmt::Display for Violation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Violation::RedRoot => write!(f, "property 2 violated: the root is red"),
+            Violation::RedLeaf => write!(f, "property 3 violated: T.nil is red"),
+            Violation::RedChild { node, child } => write!(
+                f,
+                "property 4 violated: red node {} has a red child {}",
+                node, child
+            ),
+            Violation::BlackHeight { node, left, right } => write!(
+                f,
+                "property 5 violated: paths down from node {} hold {} black nodes on the left but {} on the right",
+                node, left, right
+            ),
+            Violation::Order { node } => write!(f, "node {} is out of search-tree order", node),
+            Violation::Parent { node } => {
+                write!(f, "node {} does not point to its parent", node)
+            }
+        }
+    }
+}
+
+impl Error for Violation {}
+
+/// Left or right child
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Side {
+    Left,
+    Right,
+}
+
+impl Side {
+    fn opposite(self) -> Side {
+        match self {
+            Side::Left => Side::Right,
+            Side::Right => Side::Left,
+        }
+    }
+}
+
+/// Key, value and augmentation of a node
+#[derive(Debug, Clone)]
+struct Entry<K, V, A> {
+    key: K,
+    value: V,
+    augment: A,
+}
+
+/// Node of the tree; the sentinel and deleted nodes have no entry
+#[derive(Debug, Clone)]
+struct Node<K, V, A> {
+    entry: Option<Entry<K, V, A>>,
+    color: Color,
+    parent: usize,
+    left: usize,
+    right: usize,
+}
+
+/// # Red-Black Tree
+///
+/// A binary search tree with the red-black properties (CLRS Section 13.1):
+///
+/// 1. Every node is either red or black.
+/// 2. The root is black.
+/// 3. Every leaf (`T.nil`) is black.
+/// 4. If a node is red, then both its children are black.
+/// 5. For each node, all simple paths from the node to descendant leaves
+///    contain the same number of black nodes.
+///
+/// `RB-INSERT` adds a red node like `TREE-INSERT`; `RB-INSERT-FIXUP` then
+/// moves a red-red conflict up by recoloring (case 1) until one or two
+/// rotations resolve it (cases 2 and 3). `RB-DELETE` splices out a node
+/// like `TREE-DELETE`; if a black node left the tree, `RB-DELETE-FIXUP`
+/// pushes the extra black up (case 2) or absorbs it with at most three
+/// rotations (cases 1, 3 and 4).
+///
+/// # Pseudocode (CLRS)
+///
+/// ```text
+/// RB-INSERT-FIXUP(T, z)
+/// while z.p.color == RED
+///     if z.p == z.p.p.left
+///         y = z.p.p.right
+///         if y.color == RED
+///             z.p.color = BLACK               // case 1
+///             y.color = BLACK
+///             z.p.p.color = RED
+///             z = z.p.p
+///         else
+///             if z == z.p.right
+///                 z = z.p                     // case 2
+///                 LEFT-ROTATE(T, z)
+///             z.p.color = BLACK               // case 3
+///             z.p.p.color = RED
+///             RIGHT-ROTATE(T, z.p.p)
+///     else (same with "right" and "left" exchanged)
+/// T.root.color = BLACK
+///
+/// RB-DELETE-FIXUP(T, x)
+/// while x ≠ T.root and x.color == BLACK
+///     if x == x.p.left
+///         w = x.p.right
+///         if w.color == RED
+///             w.color = BLACK                 // case 1
+///             x.p.color = RED
+///             LEFT-ROTATE(T, x.p)
+///             w = x.p.right
+///         if w.left.color == BLACK and w.right.color == BLACK
+///             w.color = RED                   // case 2
+///             x = x.p
+///         else
+///             if w.right.color == BLACK
+///                 w.left.color = BLACK        // case 3
+///                 w.color = RED
+///                 RIGHT-ROTATE(T, w)
+///                 w = x.p.right
+///             w.color = x.p.color             // case 4
+///             x.p.color = BLACK
+///             w.right.color = BLACK
+///             LEFT-ROTATE(T, x.p)
+///             x = T.root
+///     else (same with "right" and "left" exchanged)
+/// x.color = BLACK
+/// ```
+///
+/// # Example
+///
+/// ```rust
+/// use algorithms::trees::red_black::{Color, RedBlackTree};
+///
+/// // CLRS Figure 13.4
+/// let mut tree = RedBlackTree::new();
+/// for k in [11, 2, 14, 1, 7, 15, 5, 8, 4] {
+///     tree.insert(k, ());
+/// }
+/// let root = tree.root().unwrap();
+/// assert_eq!(tree.key(root), &7);
+/// assert_eq!(tree.color(tree.search(&4).unwrap()), Color::Red);
+/// assert_eq!(tree.check_invariants(), Ok(2));
+///
+/// tree.delete(tree.search(&7).unwrap());
+/// assert_eq!(tree.check_invariants(), Ok(2));
+/// ```
+///
+/// # Notes
+///
+/// * Search, minimum, maximum, successor and predecessor run in
+///   **O(lg n)** time as in any binary search tree of height `O(lg n)`.
+/// * `RB-INSERT` makes at most two rotations and `RB-DELETE` at most
+///   three, each in **O(lg n)** time.
+/// * The [`Augmentation`] `A` is kept up to date in every node, by
+///   default the empty `()`.
+#[derive(Debug, Clone)]
+pub struct RedBlackTree<K, V, A = ()> {
+    /// `nodes[0]` is the sentinel `T.nil`
+    nodes: Vec<Node<K, V, A>>,
+    root: usize,
+    len: usize,
+}
+
+impl<K, V> RedBlackTree<K, V> {
+    pub fn new() -> Self {
+        Self::augmented()
+    }
+}
+
+impl<K, V, A: Augmentation<K, V>> RedBlackTree<K, V, A> {
+    /// An empty tree maintaining the augmentation `A`.
+    pub fn augmented() -> Self {
+        Self {
+            nodes: vec![Node {
+                entry: None,
+                color: Color::Black,
+                parent: NIL,
+                left: NIL,
+                right: NIL,
+            }],
+            root: NIL,
+            len: 0,
+        }
+    }
+
+    /// `T.root`, `None` for an empty tree.
+    pub fn root(&self) -> Option<usize> {
+        real(self.root)
+    }
+
+    /// Number of nodes, not counting `T.nil`.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Whether `x` is a node of the tree.
+    pub fn contains(&self, x: usize) -> bool {
+        self.nodes.get(x).is_some_and(|node| node.entry.is_some())
+    }
+
+    /// `x.key`
+    ///
+    /// # Panics
+    ///
+    /// Panics if `x` is not a node of the tree; so do all methods taking a
+    /// node.
+    pub fn key(&self, x: usize) -> &K {
+        &self.entry(x).key
+    }
+
+    /// Value stored with `x.key`.
+    pub fn value(&self, x: usize) -> &V {
+        &self.entry(x).value
+    }
+
+    /// Store `value` with `x.key` instead, updating the augmentation up to
+    /// the root; returns the old value.
+    pub fn replace_value(&mut self, x: usize, value: V) -> V {
+        self.check_node(x);
+        let entry = self.nodes[x].entry.as_mut().expect("node is in the tree");
+        let old = std::mem::replace(&mut entry.value, value);
+        self.update_path(x);
+        old
+    }
+
+    /// The augmentation of `x`, computed from `x` and its children.
+    pub fn augment(&self, x: usize) -> &A {
+        &self.entry(x).augment
+    }
+
+    /// `x.color`
+    pub fn color(&self, x: usize) -> Color {
+        self.check_node(x);
+        self.nodes[x].color
+    }
+
+    /// Repaint `x`, which may break the red-black properties; meant for
+    /// experiments with [`check_invariants`](Self::check_invariants).
+    pub fn set_color(&mut self, x: usize, color: Color) {
+        self.check_node(x);
+        self.nodes[x].color = color;
+    }
+
+    /// `x.p`, `None` for the root.
+    pub fn parent(&self, x: usize) -> Option<usize> {
+        self.check_node(x);
+        real(self.nodes[x].parent)
+    }
+
+    /// `x.left`, `None` for `T.nil`.
+    pub fn left(&self, x: usize) -> Option<usize> {
+        self.check_node(x);
+        real(self.nodes[x].left)
+    }
+
+    /// `x.right`, `None` for `T.nil`.
+    pub fn right(&self, x: usize) -> Option<usize> {
+        self.check_node(x);
+        real(self.nodes[x].right)
+    }
+
+    /// `TREE-MINIMUM(x)`
+    pub fn minimum(&self, mut x: usize) -> usize {
+        while let Some(left) = self.left(x) {
+            x = left;
+        }
+        x
+    }
+
+    /// `TREE-MAXIMUM(x)`
+    pub fn maximum(&self, mut x: usize) -> usize {
+        while let Some(right) = self.right(x) {
+            x = right;
+        }
+        x
+    }
+
+    /// `TREE-SUCCESSOR(x)`
+    pub fn successor(&self, mut x: usize) -> Option<usize> {
+        if let Some(right) = self.right(x) {
+            return Some(self.minimum(right));
+        }
+        let mut y = self.parent(x);
+        while let Some(p) = y
+            && self.right(p) == Some(x)
+        {
+            x = p;
+            y = self.parent(p);
+        }
+        y
+    }
+
+    /// `TREE-PREDECESSOR(x)`
+    pub fn predecessor(&self, mut x: usize) -> Option<usize> {
+        if let Some(left) = self.left(x) {
+            return Some(self.maximum(left));
+        }
+        let mut y = self.parent(x);
+        while let Some(p) = y
+            && self.left(p) == Some(x)
+        {
+            x = p;
+            y = self.parent(p);
+        }
+        y
+    }
+
+    /// Nodes in sorted order of their keys, from `TREE-MINIMUM` along
+    /// `TREE-SUCCESSOR`.
+    pub fn inorder(&self) -> impl Iterator<Item = usize> + '_ {
+        std::iter::successors(self.root().map(|r| self.minimum(r)), |&x| self.successor(x))
+    }
+
+    /// Number of edges on the longest path from the root down to a node, 0
+    /// for an empty tree.
+    pub fn height(&self) -> usize {
+        let mut height = 0;
+        let mut stack: Vec<(usize, usize)> = self.root().map(|r| (r, 0)).into_iter().collect();
+        while let Some((x, depth)) = stack.pop() {
+            height = height.max(depth);
+            for child in [self.left(x), self.right(x)].into_iter().flatten() {
+                stack.push((child, depth + 1));
+            }
+        }
+        height
+    }
+
+    /// `bh(x)`: black nodes on any simple path from `x` down to a leaf, not
+    /// counting `x` itself. Assumes property 5.
+    pub fn black_height(&self, x: usize) -> usize {
+        self.check_node(x);
+        let mut bh = 0;
+        let mut y = self.nodes[x].left;
+        loop {
+            if self.nodes[y].color == Color::Black {
+                bh += 1;
+            }
+            if y == NIL {
+                return bh;
+            }
+            y = self.nodes[y].left;
+        }
+    }
+
+    /// `RB-DELETE(T, z)`: remove node `z` and return its key and value.
+    pub fn delete(&mut self, z: usize) -> (K, V) {
+        self.delete_traced(z, &mut NoopTracer)
+    }
+
+    /// Same as [`delete`](Self::delete), but reports every case of
+    /// `RB-DELETE-FIXUP` to `tracer` before applying it.
+    pub fn delete_traced<R>(&mut self, z: usize, tracer: &mut R) -> (K, V)
+    where
+        R: FixupTracer<K, V, A> + ?Sized,
+    {
+        self.check_node(z);
+        let (z_left, z_right) = (self.nodes[z].left, self.nodes[z].right);
+        let mut y_original_color = self.nodes[z].color;
+        let x;
+        if z_left == NIL {
+            x = z_right;
+            self.transplant(z, z_right);
+        } else if z_right == NIL {
+            x = z_left;
+            self.transplant(z, z_left);
+        } else {
+            let y = self.minimum(z_right);
+            y_original_color = self.nodes[y].color;
+            x = self.nodes[y].right;
+            if y != z_right {
+                self.transplant(y, x);
+                self.nodes[y].right = z_right;
+                self.nodes[z_right].parent = y;
+            } else {
+                // x may be T.nil, which then remembers y as its parent
+                self.nodes[x].parent = y;
+            }
+            self.transplant(z, y);
+            self.nodes[y].left = z_left;
+            self.nodes[z_left].parent = y;
+            self.nodes[y].color = self.nodes[z].color;
+        }
+        // Every node whose subtree changed lies on the path up from x.p
+        self.update_path(self.nodes[x].parent);
+        if y_original_color == Color::Black {
+            self.delete_fixup(x, tracer);
+        }
+        self.len -= 1;
+        let entry = self.nodes[z].entry.take().expect("node is in the tree");
+        (entry.key, entry.value)
+    }
+
+    /// `RB-TRANSPLANT(T, u, v)`
+    fn transplant(&mut self, u: usize, v: usize) {
+        let parent = self.nodes[u].parent;
+        if parent == NIL {
+            self.root = v;
+        } else if u == self.nodes[parent].left {
+            self.nodes[parent].left = v;
+        } else {
+            self.nodes[parent].right = v;
+        }
+        self.nodes[v].parent = parent;
+    }
+
+    /// `RB-DELETE-FIXUP(T, x)`
+    fn delete_fixup<R>(&mut self, mut x: usize, tracer: &mut R)
+    where
+        R: FixupTracer<K, V, A> + ?Sized,
+    {
+        while x != self.root && self.nodes[x].color == Color::Black {
+            let p = self.nodes[x].parent;
+            let side = if x == self.nodes[p].left {
+                Side::Left
+            } else {
+                Side::Right
+            };
+            let other = side.opposite();
+            let mut w = self.child(p, other);
+            if self.nodes[w].color == Color::Red {
+                tracer.record(FixupCase::Delete { case: 1, w }, self);
+                self.nodes[w].color = Color::Black;
+                self.nodes[p].color = Color::Red;
+                self.rotate(p, side);
+                w = self.child(p, other);
+            }
+            if self.is_black(self.child(w, side)) && self.is_black(self.child(w, other)) {
+                tracer.record(FixupCase::Delete { case: 2, w }, self);
+                self.nodes[w].color = Color::Red;
+                x = p;
+            } else {
+                if self.is_black(self.child(w, other)) {
+                    tracer.record(FixupCase::Delete { case: 3, w }, self);
+                    let near = self.child(w, side);
+                    self.nodes[near].color = Color::Black;
+                    self.nodes[w].color = Color::Red;
+                    self.rotate(w, other);
+                    w = self.child(p, other);
+                }
+                tracer.record(FixupCase::Delete { case: 4, w }, self);
+                self.nodes[w].color = self.nodes[p].color;
+                self.nodes[p].color = Color::Black;
+                let far = self.child(w, other);
+                self.nodes[far].color = Color::Black;
+                self.rotate(p, side);
+                x = self.root;
+            }
+        }
+        self.nodes[x].color = Color::Black;
+    }
+
+    /// `LEFT-ROTATE(T, x)` for `Side::Left`, `RIGHT-ROTATE(T, x)` for
+    /// `Side::Right`
+    fn rotate(&mut self, x: usize, side: Side) {
+        let other = side.opposite();
+        let y = self.child(x, other);
+        let inner = self.child(y, side);
+        self.set_child(x, other, inner);
+        if inner != NIL {
+            self.nodes[inner].parent = x;
+        }
+        let parent = self.nodes[x].parent;
+        self.nodes[y].parent = parent;
+        if parent == NIL {
+            self.root = y;
+        } else if x == self.nodes[parent].left {
+            self.nodes[parent].left = y;
+        } else {
+            self.nodes[parent].right = y;
+        }
+        self.set_child(y, side, x);
+        self.nodes[x].parent = y;
+        // Only x and y have new subtrees, and x is now below y
+        self.update(x);
+        self.update(y);
+    }
+
+    /// Recompute the augmentation of `x` from its children
+    fn update(&mut self, x: usize) {
+        let node = &self.nodes[x];
+        let entry = node.entry.as_ref().expect("node is in the tree");
+        let augment = A::compute(
+            &entry.key,
+            &entry.value,
+            self.nodes[node.left].entry.as_ref().map(|e| &e.augment),
+            self.nodes[node.right].entry.as_ref().map(|e| &e.augment),
+        );
+        self.nodes[x]
+            .entry
+            .as_mut()
+            .expect("node is in the tree")
+            .augment = augment;
+    }
+
+    /// Recompute the augmentation from `x` up to the root
+    fn update_path(&mut self, mut x: usize) {
+        while x != NIL {
+            self.update(x);
+            x = self.nodes[x].parent;
+        }
+    }
+
+    fn child(&self, x: usize, side: Side) -> usize {
+        match side {
+            Side::Left => self.nodes[x].left,
+            Side::Right => self.nodes[x].right,
+        }
+    }
+
+    fn set_child(&mut self, x: usize, side: Side, child: usize) {
+        match side {
+            Side::Left => self.nodes[x].left = child,
+            Side::Right => self.nodes[x].right = child,
+        }
+    }
+
+    fn is_black(&self, x: usize) -> bool {
+        self.nodes[x].color == Color::Black
+    }
+
+    fn entry(&self, x: usize) -> &Entry<K, V, A> {
+        self.nodes
+            .get(x)
+            .and_then(|node| node.entry.as_ref())
+            .expect("node is in the tree")
+    }
+
+    fn check_node(&self, x: usize) {
+        assert!(self.contains(x), "node {} is not in the tree", x);
+    }
+}
+
+impl<K: Ord, V, A: Augmentation<K, V>> RedBlackTree<K, V, A> {
+    /// `RB-INSERT(T, z)` for a new node `z` with `key` and `value`;
+    /// returns `z`.
+    pub fn insert(&mut self, key: K, value: V) -> usize {
+        self.insert_traced(key, value, &mut NoopTracer)
+    }
+
+    /// Same as [`insert`](Self::insert), but reports every case of
+    /// `RB-INSERT-FIXUP` to `tracer` before applying it.
+    pub fn insert_traced<R>(&mut self, key: K, value: V, tracer: &mut R) -> usize
+    where
+        R: FixupTracer<K, V, A> + ?Sized,
+    {
+        let mut y = NIL;
+        let mut x = self.root;
+        while x != NIL {
+            y = x;
+            x = if key < *self.key(x) {
+                self.nodes[x].left
+            } else {
+                self.nodes[x].right
+            };
+        }
+
+        let z = self.nodes.len();
+        if y == NIL {
+            self.root = z;
+        } else if key < *self.key(y) {
+            self.nodes[y].left = z;
+        } else {
+            self.nodes[y].right = z;
+        }
+        let augment = A::compute(&key, &value, None, None);
+        self.nodes.push(Node {
+            entry: Some(Entry {
+                key,
+                value,
+                augment,
+            }),
+            color: Color::Red,
+            parent: y,
+            left: NIL,
+            right: NIL,
+        });
+        self.len += 1;
+        self.update_path(y);
+        self.insert_fixup(z, tracer);
+        z
+    }
+
+    /// `ITERATIVE-TREE-SEARCH(T.root, k)`: a node with key `k`.
+    pub fn search(&self, k: &K) -> Option<usize> {
+        let mut x = self.root;
+        while x != NIL {
+            let key = self.key(x);
+            if k == key {
+                return Some(x);
+            }
+            x = if k < key {
+                self.nodes[x].left
+            } else {
+                self.nodes[x].right
+            };
+        }
+        None
+    }
+
+    /// # Red-Black Invariants
+    ///
+    /// Verifies red-black properties 2 to 5, the search-tree order of the
+    /// keys and the parent pointers, and returns the black-height of the
+    /// root, 0 for an empty tree.
+    ///
+    /// # Errors
+    ///
+    /// The first [`Violation`] found, checking properties 2 and 3 first and
+    /// then the nodes bottom up.
+    pub fn check_invariants(&self) -> Result<usize, Violation> {
+        if let Some(root) = self.root()
+            && self.nodes[root].color == Color::Red
+        {
+            return Err(Violation::RedRoot);
+        }
+        if self.nodes[NIL].color == Color::Red {
+            return Err(Violation::RedLeaf);
+        }
+        if let Some(root) = self.root()
+            && self.nodes[root].parent != NIL
+        {
+            return Err(Violation::Parent { node: root });
+        }
+        let black_nodes = self.check_subtree(self.root)?;
+
+        let mut previous: Option<usize> = None;
+        for x in self.inorder() {
+            if let Some(p) = previous
+                && self.key(x) < self.key(p)
+            {
+                return Err(Violation::Order { node: x });
+            }
+            previous = Some(x);
+        }
+        Ok(black_nodes - usize::from(self.is_black(self.root)))
+    }
+
+    /// Black nodes on every path from `x` down to a leaf, counting `x` and
+    /// the leaf
+    fn check_subtree(&self, x: usize) -> Result<usize, Violation> {
+        if x == NIL {
+            return Ok(1);
+        }
+        let node = &self.nodes[x];
+        for child in [node.left, node.right] {
+            if child == NIL {
+                continue;
+            }
+            if self.nodes[child].parent != x {
+                return Err(Violation::Parent { node: child });
+            }
+            if node.color == Color::Red && self.nodes[child].color == Color::Red {
+                return Err(Violation::RedChild { node: x, child });
+            }
+        }
+        let left = self.check_subtree(node.left)?;
+        let right = self.check_subtree(node.right)?;
+        if left != right {
+            return Err(Violation::BlackHeight {
+                node: x,
+                left,
+                right,
+            });
+        }
+        Ok(left + usize::from(node.color == Color::Black))
+    }
+
+    /// `RB-INSERT-FIXUP(T, z)`
+    fn insert_fixup<R>(&mut self, mut z: usize, tracer: &mut R)
+    where
+        R: FixupTracer<K, V, A> + ?Sized,
+    {
+        while self.nodes[self.nodes[z].parent].color == Color::Red {
+            let p = self.nodes[z].parent;
+            let g = self.nodes[p].parent;
+            let side = if p == self.nodes[g].left {
+                Side::Left
+            } else {
+                Side::Right
+            };
+            let y = self.child(g, side.opposite());
+            if self.nodes[y].color == Color::Red {
+                tracer.record(FixupCase::Insert { case: 1, z }, self);
+                self.nodes[p].color = Color::Black;
+                self.nodes[y].color = Color::Black;
+                self.nodes[g].color = Color::Red;
+                z = g;
+            } else {
+                if z == self.child(p, side.opposite()) {
+                    tracer.record(FixupCase::Insert { case: 2, z }, self);
+                    z = p;
+                    self.rotate(z, side);
+                }
+                tracer.record(FixupCase::Insert { case: 3, z }, self);
+                let p = self.nodes[z].parent;
+                let g = self.nodes[p].parent;
+                self.nodes[p].color = Color::Black;
+                self.nodes[g].color = Color::Red;
+                self.rotate(g, side.opposite());
+            }
+        }
+        let root = self.root;
+        self.nodes[root].color = Color::Black;
+    }
+}
+
+impl<K, V, A: Augmentation<K, V>> Default for RedBlackTree<K, V, A> {
+    fn default() -> Self {
+        Self::augmented()
+    }
+}
+
+/// `x` unless it is `T.nil`
+fn real(x: usize) -> Option<usize> {
+    (x != NIL).then_some(x)
+}
+
+/// The fixup cases of every insertion of `keys`, then the tree
+pub(crate) fn describe_insert(keys: &[i64]) -> String {
+    let mut tree = RedBlackTree::new();
+    let mut text = String::new();
+    for &k in keys {
+        let mut cases = Vec::new();
+        tree.insert_traced(k, (), &mut |case: &FixupCase, _: &RedBlackTree<i64, ()>| {
+            cases.push(*case)
+        });
+        let _ = writeln!(text, "Insert {}: {}", k, fixup_cases(&cases));
+    }
+    text + &describe_tree(&tree)
+}
+
+/// The tree built from `keys`, then the fixup cases of deleting each key
+pub(crate) fn describe_delete(keys: &[i64]) -> String {
+    let mut tree = RedBlackTree::new();
+    for &k in keys {
+        tree.insert(k, ());
+    }
+    let mut text = describe_tree(&tree);
+    for k in keys {
+        let Some(z) = tree.search(k) else {
+            continue;
+        };
+        let mut cases = Vec::new();
+        tree.delete_traced(z, &mut |case: &FixupCase, _: &RedBlackTree<i64, ()>| {
+            cases.push(*case)
+        });
+        let _ = writeln!(text, "Delete {}: {}", k, fixup_cases(&cases));
+    }
+    text
+}
+
+/// Colors and children of every node in preorder, the black-height and
+/// the height
+fn describe_tree(tree: &RedBlackTree<i64, ()>) -> String {
+    let mut text = String::new();
+    let key = |x: Option<usize>| x.map_or("NIL".to_string(), |x| tree.key(x).to_string());
+    let mut stack: Vec<usize> = tree.root().into_iter().collect();
+    while let Some(x) = stack.pop() {
+        let color = match tree.color(x) {
+            Color::Red => "red",
+            Color::Black => "black",
+        };
+        let _ = writeln!(
+            text,
+            "{} ({}): left {}, right {}",
+            tree.key(x),
+            color,
+            key(tree.left(x)),
+            key(tree.right(x))
+        );
+        stack.extend(tree.right(x));
+        stack.extend(tree.left(x));
+    }
+    match tree.check_invariants() {
+        Ok(bh) => {
+            let _ = writeln!(text, "Black-height: {}\nHeight: {}", bh, tree.height());
+        }
+        Err(violation) => {
+            let _ = writeln!(text, "{}", violation);
+        }
+    }
+    text
+}
+
+/// Numbers of the fixup cases applied, in order
+fn fixup_cases(cases: &[FixupCase]) -> String {
+    if cases.is_empty() {
+        return "no fixup".to_string();
+    }
+    let numbers: Vec<String> = cases
+        .iter()
+        .map(|case| match case {
+            FixupCase::Insert { case, .. } | FixupCase::Delete { case, .. } => case.to_string(),
+        })
+        .collect();
+    let label = if numbers.len() == 1 { "case" } else { "cases" };
+    format!("{} {}", label, numbers.join(" "))
+}
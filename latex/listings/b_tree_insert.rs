@@ -0,0 +1,371 @@
+use std::fmt::Write;
+use std::io;
+
+use super::disk::{BTreeNode, DiskStats, DiskStore, MemoryStore};
+
+enum Target<'a, K> {
+    Key(&'a K),
+    Max,
+    Min,
+}
+
+#[derive(Debug)]
+pub struct BTree<K, S> {
+    store: S,
+    t: usize,
+    root: usize,
+    root_node: BTreeNode<K>,
+}
+
+impl<K: Ord + Clone, S: DiskStore<K>> BTree<K, S> {
+    pub fn create(mut store: S, t: usize) -> io::Result<Self> {
+        assert!(t >= 2, "a B-tree needs minimum degree t ≥ 2");
+        let x = store.allocate();
+        let root_node = BTreeNode::empty_leaf();
+        store.write(x, &root_node)?;
+        Ok(Self {
+            store,
+            t,
+            root: x,
+            root_node,
+        })
+    }
+
+    pub fn min_degree(&self) -> usize {
+        self.t
+    }
+
+    pub fn root(&self) -> usize {
+        self.root
+    }
+
+    pub fn store(&self) -> &S {
+        &self.store
+    }
+
+    pub fn store_mut(&mut self) -> &mut S {
+        &mut self.store
+    }
+
+    pub fn stats(&self) -> DiskStats {
+        self.store.stats()
+    }
+
+    pub fn node(&mut self, x: usize) -> io::Result<BTreeNode<K>> {
+        self.disk_read(x)
+    }
+
+    pub fn height(&mut self) -> io::Result<usize> {
+        let mut node = self.root_node.clone();
+        let mut h = 0;
+        while !node.leaf {
+            node = self.store.read(node.children[0])?;
+            h += 1;
+        }
+        Ok(h)
+    }
+
+    pub fn search(&mut self, k: &K) -> io::Result<Option<(usize, usize)>> {
+        let mut x = self.root;
+        let mut node = self.root_node.clone();
+        loop {
+            let i = first_at_least(&node, k);
+            if i < node.n() && node.keys[i] == *k {
+                return Ok(Some((x, i)));
+            } else if node.leaf {
+                return Ok(None);
+            }
+            x = node.children[i];
+            node = self.store.read(x)?;
+        }
+    }
+
+    pub fn insert(&mut self, k: K) -> io::Result<()> {
+        if self.root_node.n() == 2 * self.t - 1 {
+            self.split_root()?;
+        }
+        self.insert_nonfull(self.root, self.root_node.clone(), k)
+    }
+
+    pub fn delete(&mut self, k: &K) -> io::Result<bool> {
+        let root = self.root_node.clone();
+        Ok(self.delete_from(self.root, root, Target::Key(k))?.is_some())
+    }
+
+    fn split_root(&mut self) -> io::Result<()> {
+        let s = self.store.allocate();
+        let mut s_node = BTreeNode {
+            keys: Vec::new(),
+            children: vec![self.root],
+            leaf: false,
+        };
+        let r_node = std::mem::replace(&mut self.root_node, s_node.clone());
+        self.root = s;
+        self.split_child(s, &mut s_node, 0, r_node)?;
+        Ok(())
+    }
+
+    fn split_child(
+        &mut self,
+        x: usize,
+        x_node: &mut BTreeNode<K>,
+        i: usize,
+        mut y: BTreeNode<K>,
+    ) -> io::Result<(BTreeNode<K>, BTreeNode<K>)> {
+        let t = self.t;
+        let z = self.store.allocate();
+        let z_node = BTreeNode {
+            keys: y.keys.split_off(t),
+            children: if y.leaf {
+                Vec::new()
+            } else {
+                y.children.split_off(t)
+            },
+            leaf: y.leaf,
+        };
+        let median = y.keys.pop().expect("a full node has 2t - 1 keys");
+        x_node.children.insert(i + 1, z);
+        x_node.keys.insert(i, median);
+        self.disk_write(x_node.children[i], &y)?;
+        self.disk_write(z, &z_node)?;
+        self.disk_write(x, x_node)?;
+        Ok((y, z_node))
+    }
+
+    fn insert_nonfull(&mut self, mut x: usize, mut node: BTreeNode<K>, k: K) -> io::Result<()> {
+        loop {
+            // Past the last key that is at most k
+            let mut i = node
+                .keys
+                .iter()
+                .rposition(|key| *key <= k)
+                .map_or(0, |j| j + 1);
+            if node.leaf {
+                node.keys.insert(i, k);
+                return self.disk_write(x, &node);
+            }
+            let child = self.store.read(node.children[i])?;
+            (x, node) = if child.n() == 2 * self.t - 1 {
+                let (y, z) = self.split_child(x, &mut node, i, child)?;
+                if k > node.keys[i] {
+                    i += 1;
+                    (node.children[i], z)
+                } else {
+                    (node.children[i], y)
+                }
+            } else {
+                (node.children[i], child)
+            };
+        }
+    }
+
+    fn delete_from(
+        &mut self,
+        mut x: usize,
+        mut node: BTreeNode<K>,
+        target: Target<K>,
+    ) -> io::Result<Option<K>> {
+        let t = self.t;
+        loop {
+            // k is x.key_i if found, else in the subtree of x.c_i
+            let (i, found) = match target {
+                Target::Key(k) => {
+                    let i = first_at_least(&node, k);
+                    (i, i < node.n() && node.keys[i] == *k)
+                }
+                Target::Max if node.leaf => (node.n() - 1, true),
+                Target::Max => (node.n(), false),
+                Target::Min => (0, node.leaf),
+            };
+
+            if found && node.leaf {
+                // Case 1
+                let key = node.keys.remove(i);
+                self.disk_write(x, &node)?;
+                return Ok(Some(key));
+            }
+            if found {
+                let y = node.children[i];
+                let y_node = self.store.read(y)?;
+                if y_node.n() >= t {
+                    // Case 2a
+                    let pred = self.delete_from(y, y_node, Target::Max)?;
+                    let key = std::mem::replace(&mut node.keys[i], pred.expect("y has keys"));
+                    self.disk_write(x, &node)?;
+                    return Ok(Some(key));
+                }
+                let z = node.children[i + 1];
+                let z_node = self.store.read(z)?;
+                if z_node.n() >= t {
+                    // Case 2b
+                    let succ = self.delete_from(z, z_node, Target::Min)?;
+                    let key = std::mem::replace(&mut node.keys[i], succ.expect("z has keys"));
+                    self.disk_write(x, &node)?;
+                    return Ok(Some(key));
+                }
+                // Case 2c
+                node = self.merge(x, &mut node, i, y_node, z_node)?;
+                x = y;
+                continue;
+            }
+            if node.leaf {
+                return Ok(None);
+            }
+
+            let mut c = node.children[i];
+            let mut c_node = self.store.read(c)?;
+            if c_node.n() == t - 1 {
+                let left = match i {
+                    0 => None,
+                    _ => Some(self.store.read(node.children[i - 1])?),
+                };
+                // The right sibling is only read if the left one cannot help
+                let right = match left {
+                    Some(ref l_node) if l_node.n() >= t => None,
+                    _ if i == node.n() => None,
+                    _ => Some(self.store.read(node.children[i + 1])?),
+                };
+                match (left, right) {
+                    (Some(mut l_node), _) if l_node.n() >= t => {
+                        // Case 3a, from the left sibling
+                        let up = l_node.keys.pop().expect("the sibling has t keys");
+                        c_node
+                            .keys
+                            .insert(0, std::mem::replace(&mut node.keys[i - 1], up));
+                        if let Some(child) = l_node.children.pop() {
+                            c_node.children.insert(0, child);
+                        }
+                        self.disk_write(node.children[i - 1], &l_node)?;
+                        self.disk_write(c, &c_node)?;
+                        self.disk_write(x, &node)?;
+                    }
+                    (_, Some(mut r_node)) if r_node.n() >= t => {
+                        // Case 3a, from the right sibling
+                        let up = r_node.keys.remove(0);
+                        c_node.keys.push(std::mem::replace(&mut node.keys[i], up));
+                        if !r_node.leaf {
+                            c_node.children.push(r_node.children.remove(0));
+                        }
+                        self.disk_write(node.children[i + 1], &r_node)?;
+                        self.disk_write(c, &c_node)?;
+                        self.disk_write(x, &node)?;
+                    }
+                    (_, Some(r_node)) => {
+                        // Case 3b, with the right sibling
+                        c_node = self.merge(x, &mut node, i, c_node, r_node)?;
+                    }
+                    (Some(l_node), None) => {
+                        // Case 3b, with the left sibling
+                        c = node.children[i - 1];
+                        c_node = self.merge(x, &mut node, i - 1, l_node, c_node)?;
+                    }
+                    (None, None) => unreachable!("a child has a sibling"),
+                }
+            }
+            (x, node) = (c, c_node);
+        }
+    }
+
+    fn merge(
+        &mut self,
+        x: usize,
+        x_node: &mut BTreeNode<K>,
+        i: usize,
+        mut y_node: BTreeNode<K>,
+        z_node: BTreeNode<K>,
+    ) -> io::Result<BTreeNode<K>> {
+        let z = x_node.children.remove(i + 1);
+        y_node.keys.push(x_node.keys.remove(i));
+        y_node.keys.extend(z_node.keys);
+        y_node.children.extend(z_node.children);
+        self.store.free(z);
+        let y = x_node.children[i];
+        if x_node.n() == 0 {
+            // Only the root can run out of keys; the tree shrinks
+            self.store.free(x);
+            self.root = y;
+        } else {
+            self.disk_write(x, x_node)?;
+        }
+        self.disk_write(y, &y_node)?;
+        Ok(y_node)
+    }
+
+    fn disk_read(&mut self, x: usize) -> io::Result<BTreeNode<K>> {
+        if x == self.root {
+            Ok(self.root_node.clone())
+        } else {
+            self.store.read(x)
+        }
+    }
+
+    fn disk_write(&mut self, x: usize, node: &BTreeNode<K>) -> io::Result<()> {
+        if x == self.root {
+            self.root_node = node.clone();
+        }
+        self.store.write(x, node)
+    }
+}
+
+fn first_at_least<K: Ord>(node: &BTreeNode<K>, k: &K) -> usize {
+    node.keys
+        .iter()
+        .position(|key| key >= k)
+        .unwrap_or(node.n())
+}
+
+pub(crate) fn describe_insert(keys: &[i64]) -> String {
+    let mut tree = from_keys(&[]);
+    let mut text = String::new();
+    for &k in keys {
+        tree.store_mut().reset_stats();
+        tree.insert(k).expect("memory pages are always readable");
+        let _ = writeln!(text, "Insert {}: {}", k, disk_accesses(&tree));
+    }
+    text + &describe_tree(&mut tree)
+}
+
+pub(crate) fn describe_delete(keys: &[i64]) -> String {
+    let mut tree = from_keys(keys);
+    let mut text = describe_tree(&mut tree);
+    for k in keys {
+        tree.store_mut().reset_stats();
+        tree.delete(k).expect("memory pages are always readable");
+        let _ = writeln!(text, "Delete {}: {}", k, disk_accesses(&tree));
+    }
+    text
+}
+
+fn from_keys(keys: &[i64]) -> BTree<i64, MemoryStore<i64>> {
+    let mut tree = BTree::create(MemoryStore::new(), 2).expect("memory pages are always writable");
+    for &k in keys {
+        tree.insert(k).expect("memory pages are always readable");
+    }
+    tree
+}
+
+fn describe_tree(tree: &mut BTree<i64, MemoryStore<i64>>) -> String {
+    let mut text = String::new();
+    let mut level = vec![tree.root()];
+    let mut depth = 0;
+    while !level.is_empty() {
+        let mut nodes = Vec::new();
+        let mut next = Vec::new();
+        for x in level {
+            let node = tree.node(x).expect("memory pages are always readable");
+            let keys: Vec<String> = node.keys.iter().map(|k| k.to_string()).collect();
+            nodes.push(format!("[{}]", keys.join(" ")));
+            next.extend(node.children);
+        }
+        let _ = writeln!(text, "Depth {}: {}", depth, nodes.join(" "));
+        level = next;
+        depth += 1;
+    }
+    let _ = writeln!(text, "Height: {}", depth - 1);
+    text
+}
+
+fn disk_accesses(tree: &BTree<i64, MemoryStore<i64>>) -> String {
+    let stats = tree.store().stats();
+    format!("{} DISK-READ, {} DISK-WRITE", stats.reads, stats.writes)
+}
@@ -0,0 +1,114 @@
+use std::collections::VecDeque;
+use std::fmt::Write;
+
+use super::adjacency_list::AdjacencyList;
+use super::representation::{Graph, labels};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Color {
+    White,
+    Gray,
+    Black,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BfsResult {
+    pub source: usize,
+    pub d: Vec<Option<usize>>,
+    pub pi: Vec<Option<usize>>,
+    pub order: Vec<usize>,
+}
+
+impl BfsResult {
+    pub fn color_after(&self, v: usize, steps: usize) -> Color {
+        match self.order.iter().position(|&x| x == v) {
+            Some(i) if i < steps => Color::Black,
+            Some(i) if i < self.discovered_after(steps) => Color::Gray,
+            _ => Color::White,
+        }
+    }
+
+    pub fn queue_after(&self, steps: usize) -> &[usize] {
+        let end = self.discovered_after(steps);
+        &self.order[steps.min(end)..end]
+    }
+
+    fn discovered_after(&self, steps: usize) -> usize {
+        let dequeued = &self.order[..steps.min(self.order.len())];
+        1 + self
+            .pi
+            .iter()
+            .filter(|p| p.is_some_and(|p| dequeued.contains(&p)))
+            .count()
+    }
+}
+
+pub fn bfs<G: Graph>(g: &G, s: usize) -> BfsResult {
+    let n = g.vertex_count();
+    assert!(s < n, "source {} is not a vertex of G", s);
+
+    let mut color = vec![Color::White; n];
+    let mut d = vec![None; n];
+    let mut pi = vec![None; n];
+    let mut order = vec![s];
+    color[s] = Color::Gray;
+    d[s] = Some(0);
+
+    let mut queue = VecDeque::from([s]);
+    while let Some(u) = queue.pop_front() {
+        for (v, _) in g.neighbors(u) {
+            if color[v] == Color::White {
+                color[v] = Color::Gray;
+                d[v] = d[u].map(|du| du + 1);
+                pi[v] = Some(u);
+                queue.push_back(v);
+                order.push(v);
+            }
+        }
+        color[u] = Color::Black;
+    }
+
+    BfsResult {
+        source: s,
+        d,
+        pi,
+        order,
+    }
+}
+
+pub fn print_path(pi: &[Option<usize>], s: usize, v: usize) -> Option<Vec<usize>> {
+    let mut path = vec![v];
+    let mut current = v;
+    while current != s {
+        current = pi[current]?;
+        // A predecessor cycle never reaches s.
+        if path.len() > pi.len() {
+            return None;
+        }
+        path.push(current);
+    }
+    path.reverse();
+    Some(path)
+}
+
+pub(crate) fn describe(g: &AdjacencyList, s: usize) -> String {
+    let result = bfs(g, s);
+    let mut text = String::new();
+    for v in g.vertices() {
+        let d = result.d[v].map_or("∞".to_string(), |d| d.to_string());
+        let pi = result.pi[v].map_or("NIL", |p| g.label(p));
+        let path = match print_path(&result.pi, s, v) {
+            Some(path) => labels(g, &path),
+            None => format!("no path from {} to {} exists", g.label(s), g.label(v)),
+        };
+        let _ = writeln!(
+            text,
+            "{}: d = {}, π = {}, path: {}",
+            g.label(v),
+            d,
+            pi,
+            path
+        );
+    }
+    text
+}
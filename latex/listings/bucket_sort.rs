@@ -0,0 +1,27 @@
+use super::insertion_sort::insertion_sort_by;
+
+pub fn bucket_sort(arr: &mut [f64]) {
+    let n = arr.len();
+    let mut buckets: Vec<Vec<f64>> = vec![Vec::new(); n];
+
+    for &x in arr.iter() {
+        assert!((0.0..1.0).contains(&x), "value {} is not in [0, 1)", x);
+        buckets[(n as f64 * x) as usize].push(x);
+    }
+
+    for bucket in &mut buckets {
+        insertion_sort_by(bucket, f64::total_cmp);
+    }
+
+    for (slot, x) in arr.iter_mut().zip(buckets.into_iter().flatten()) {
+        *slot = x;
+    }
+}
+
+pub(crate) fn sort_unit_interval(arr: &mut [f64]) -> Result<(), String> {
+    if let Some(x) = arr.iter().find(|x| !(0.0..1.0).contains(*x)) {
+        return Err(format!("value {} is not in [0, 1)", x));
+    }
+    bucket_sort(arr);
+    Ok(())
+}
@@ -0,0 +1,106 @@
+use std::cell::Cell;
+use std::fmt::Write;
+
+use super::hash_function::{Division, HashFunction, natural_keys, table_size};
+use super::stats::{ProbeStats, describe_load};
+
+#[derive(Debug, Clone)]
+pub struct ChainedHashTable<V, H: HashFunction> {
+    h: H,
+    chains: Vec<Vec<(u64, V)>>,
+    len: usize,
+    stats: Cell<ProbeStats>,
+}
+
+impl<V, H: HashFunction> ChainedHashTable<V, H> {
+    pub fn new(h: H) -> Self {
+        let chains = (0..h.slots()).map(|_| Vec::new()).collect();
+        Self {
+            h,
+            chains,
+            len: 0,
+            stats: Cell::new(ProbeStats::default()),
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    pub fn load_factor(&self) -> f64 {
+        self.len as f64 / self.chains.len() as f64
+    }
+
+    pub fn insert(&mut self, k: u64, value: V) -> Option<V> {
+        let j = self.h.hash(k);
+        if let Some(i) = self.position(j, k) {
+            return Some(std::mem::replace(&mut self.chains[j][i].1, value));
+        }
+        self.chains[j].push((k, value));
+        self.len += 1;
+        None
+    }
+
+    pub fn search(&self, k: u64) -> Option<&V> {
+        let j = self.h.hash(k);
+        self.position(j, k).map(|i| &self.chains[j][i].1)
+    }
+
+    pub fn delete(&mut self, k: u64) -> Option<V> {
+        let j = self.h.hash(k);
+        let i = self.position(j, k)?;
+        self.len -= 1;
+        Some(self.chains[j].remove(i).1)
+    }
+
+    pub fn chain(&self, j: usize) -> Vec<u64> {
+        self.chains[j].iter().rev().map(|(k, _)| *k).collect()
+    }
+
+    pub fn chain_lengths(&self) -> Vec<usize> {
+        self.chains.iter().map(Vec::len).collect()
+    }
+
+    pub fn stats(&self) -> ProbeStats {
+        self.stats.get()
+    }
+
+    pub fn reset_stats(&self) {
+        self.stats.set(ProbeStats::default());
+    }
+
+    fn position(&self, j: usize, k: u64) -> Option<usize> {
+        let chain = &self.chains[j];
+        let found = chain.iter().rposition(|(key, _)| *key == k);
+        let probes = found.map_or(chain.len(), |i| chain.len() - i);
+        let mut stats = self.stats.get();
+        stats.record(found.is_some(), probes);
+        self.stats.set(stats);
+        found
+    }
+}
+
+pub(crate) fn describe(keys: &[i64]) -> String {
+    let Some(keys) = natural_keys(keys) else {
+        return "hash tables need nonnegative keys\n".to_string();
+    };
+    let m = table_size(keys.len());
+    let mut table = ChainedHashTable::new(Division::new(m));
+    for &k in &keys {
+        table.insert(k, ());
+    }
+    let mut text = format!("m = {}, h(k) = k mod {}\n", m, m);
+    for j in 0..m {
+        let chain: String = table.chain(j).iter().map(|k| format!(" {}", k)).collect();
+        let _ = writeln!(text, "{}:{}", j, chain);
+    }
+    table.reset_stats();
+    for &k in &keys {
+        table.search(k);
+    }
+    text + &describe_load(table.load_factor(), table.stats().mean_successful())
+}
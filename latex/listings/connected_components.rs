@@ -0,0 +1,39 @@
+use std::fmt::Write;
+
+use super::forest::DisjointSetForest;
+use super::union_find::DisjointSet;
+use crate::graph::adjacency_list::AdjacencyList;
+use crate::graph::representation::{Graph, labels};
+
+pub fn connected_components<G: Graph, D: DisjointSet>(g: &G, sets: &mut D) -> Vec<usize> {
+    assert!(sets.is_empty(), "connected components need empty sets");
+    for _ in g.vertices() {
+        sets.make_set();
+    }
+    for edge in g.edges() {
+        sets.union(edge.from, edge.to);
+    }
+
+    let mut number = vec![None; g.vertex_count()];
+    let mut count = 0;
+    g.vertices()
+        .map(|v| {
+            let root = sets.find_set(v);
+            *number[root].get_or_insert_with(|| {
+                count += 1;
+                count - 1
+            })
+        })
+        .collect()
+}
+
+pub(crate) fn describe(g: &AdjacencyList, _: usize) -> String {
+    let component = connected_components(g, &mut DisjointSetForest::new());
+    let count = component.iter().max().map_or(0, |c| c + 1);
+    let mut text = String::new();
+    for c in 0..count {
+        let members: Vec<usize> = g.vertices().filter(|&v| component[v] == c).collect();
+        let _ = writeln!(text, "Component {}: {}", c + 1, labels(g, &members));
+    }
+    text
+}
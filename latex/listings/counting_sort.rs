@@ -0,0 +1,49 @@
+pub fn counting_sort<T, F>(input: &[T], k: usize, key: F) -> Vec<T>
+where
+    T: Clone,
+    F: Fn(&T) -> usize,
+{
+    let mut count = vec![0usize; k + 1];
+    for x in input {
+        let digit = key(x);
+        assert!(digit <= k, "key {} is larger than k = {}", digit, k);
+        count[digit] += 1;
+    }
+
+    // count[i] = number of elements with key <= i
+    for i in 1..=k {
+        count[i] += count[i - 1];
+    }
+
+    // Walk backwards so equal keys keep their relative order.
+    let mut output: Vec<Option<T>> = vec![None; input.len()];
+    for x in input.iter().rev() {
+        let digit = key(x);
+        count[digit] -= 1;
+        output[count[digit]] = Some(x.clone());
+    }
+
+    output.into_iter().flatten().collect()
+}
+
+const MAX_KEY: i64 = 1 << 20;
+
+pub(crate) fn sort_naturals(arr: &mut [i64]) -> Result<(), String> {
+    let k = natural_keys(arr)?.max().unwrap_or(0);
+    if k > MAX_KEY {
+        return Err(format!(
+            "key {} is larger than the supported k = {}",
+            k, MAX_KEY
+        ));
+    }
+    let sorted = counting_sort(arr, k as usize, |x| *x as usize);
+    arr.copy_from_slice(&sorted);
+    Ok(())
+}
+
+pub(crate) fn natural_keys(arr: &[i64]) -> Result<impl Iterator<Item = i64> + '_, String> {
+    match arr.iter().find(|&&x| x < 0) {
+        Some(x) => Err(format!("key {} is negative", x)),
+        None => Ok(arr.iter().copied()),
+    }
+}
@@ -0,0 +1,184 @@
+use std::fmt::Write;
+
+use super::adjacency_list::AdjacencyList;
+use super::bfs::Color;
+use super::representation::{Edge, Graph, Weight};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EdgeClass {
+    Tree,
+    Back,
+    Forward,
+    Cross,
+}
+
+impl EdgeClass {
+    pub fn letter(self) -> char {
+        match self {
+            EdgeClass::Tree => 'T',
+            EdgeClass::Back => 'B',
+            EdgeClass::Forward => 'F',
+            EdgeClass::Cross => 'C',
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DfsResult {
+    pub d: Vec<usize>,
+    pub f: Vec<usize>,
+    pub pi: Vec<Option<usize>>,
+    pub edges: Vec<(Edge, EdgeClass)>,
+}
+
+impl DfsResult {
+    pub fn color_at(&self, v: usize, time: usize) -> Color {
+        if time < self.d[v] {
+            Color::White
+        } else if time < self.f[v] {
+            Color::Gray
+        } else {
+            Color::Black
+        }
+    }
+
+    pub fn by_decreasing_finish(&self) -> Vec<usize> {
+        let mut order: Vec<usize> = (0..self.f.len()).collect();
+        order.sort_by_key(|&v| std::cmp::Reverse(self.f[v]));
+        order
+    }
+
+    pub fn roots(&self) -> Vec<usize> {
+        let mut roots: Vec<usize> = (0..self.pi.len())
+            .filter(|&v| self.pi[v].is_none())
+            .collect();
+        roots.sort_by_key(|&v| self.d[v]);
+        roots
+    }
+
+    pub fn class_of(&self, u: usize, v: usize) -> Option<EdgeClass> {
+        self.edges
+            .iter()
+            .find(|(e, _)| (e.from, e.to) == (u, v))
+            .map(|(_, class)| *class)
+    }
+}
+
+pub fn dfs<G: Graph>(g: &G) -> DfsResult {
+    let order: Vec<usize> = g.vertices().collect();
+    dfs_in_order(g, &order)
+}
+
+pub fn dfs_in_order<G: Graph>(g: &G, order: &[usize]) -> DfsResult {
+    let n = g.vertex_count();
+    let mut search = Search {
+        directed: g.is_directed(),
+        color: vec![Color::White; n],
+        time: 0,
+        result: DfsResult {
+            d: vec![0; n],
+            f: vec![0; n],
+            pi: vec![None; n],
+            edges: Vec::new(),
+        },
+    };
+    for &u in order {
+        if search.color[u] == Color::White {
+            search.visit(g, u);
+        }
+    }
+    search.result
+}
+
+struct Search {
+    directed: bool,
+    color: Vec<Color>,
+    time: usize,
+    result: DfsResult,
+}
+
+struct Frame {
+    u: usize,
+    neighbors: Vec<(usize, Weight)>,
+    next: usize,
+    parent_skipped: bool,
+}
+
+impl Search {
+    fn visit<G: Graph>(&mut self, g: &G, root: usize) {
+        let mut stack = vec![self.discover(g, root)];
+
+        while let Some(frame) = stack.last_mut() {
+            let u = frame.u;
+            let Some(&(v, w)) = frame.neighbors.get(frame.next) else {
+                self.time += 1;
+                self.result.f[u] = self.time;
+                self.color[u] = Color::Black;
+                stack.pop();
+                continue;
+            };
+            frame.next += 1;
+
+            let class = match self.color[v] {
+                Color::White => EdgeClass::Tree,
+                Color::Gray if self.directed => EdgeClass::Back,
+                Color::Gray => {
+                    // The tree edge {π, u} seen again from u's side
+                    if Some(v) == self.result.pi[u] && !frame.parent_skipped {
+                        frame.parent_skipped = true;
+                        continue;
+                    }
+                    EdgeClass::Back
+                }
+                // Undirected: already classified from v's side
+                Color::Black if !self.directed => continue,
+                Color::Black if self.result.d[u] < self.result.d[v] => EdgeClass::Forward,
+                Color::Black => EdgeClass::Cross,
+            };
+            self.result.edges.push((Edge::new(u, v, w), class));
+
+            if class == EdgeClass::Tree {
+                self.result.pi[v] = Some(u);
+                stack.push(self.discover(g, v));
+            }
+        }
+    }
+
+    fn discover<G: Graph>(&mut self, g: &G, u: usize) -> Frame {
+        self.time += 1;
+        self.result.d[u] = self.time;
+        self.color[u] = Color::Gray;
+        Frame {
+            u,
+            neighbors: g.neighbors(u).collect(),
+            next: 0,
+            parent_skipped: false,
+        }
+    }
+}
+
+pub(crate) fn describe(g: &AdjacencyList, _: usize) -> String {
+    let result = dfs(g);
+    let mut text = String::new();
+    for v in g.vertices() {
+        let pi = result.pi[v].map_or("NIL", |p| g.label(p));
+        let _ = writeln!(
+            text,
+            "{}: d/f = {}/{}, π = {}",
+            g.label(v),
+            result.d[v],
+            result.f[v],
+            pi
+        );
+    }
+    for (edge, class) in &result.edges {
+        let _ = writeln!(
+            text,
+            "({}, {}): {:?}",
+            g.label(edge.from),
+            g.label(edge.to),
+            class
+        );
+    }
+    text
+}
@@ -0,0 +1,364 @@
+//! # Single-Source Shortest Paths
+//!
+//! The Bellman-Ford algorithm, shortest paths in a dag and Dijkstra's
+//! algorithm (CLRS Chapter 22). All three start with
+//! [`initialize_single_source`] and change `v.d` and `v.π` only through
+//! [`relax`]; they differ in the order in which they relax the edges. The
+//! `_traced` variants report every step to a [`PathTracer`], which is how
+//! the report draws figures in the style of CLRS Figure 22.4. Systems of
+//! difference constraints (Section 22.4) are solved on top of Bellman-Ford.
+
+use std::error::Error;
+use std::fmt::{self, Write};
+
+use super::adjacency_list::AdjacencyList;
+use super::bfs::print_path;
+use super::representation::{Graph, Weight, labels};
+use super::topological_sort::{Cycle, topological_sort};
+use crate::heap::min_queue::MinQueue;
+use crate::heap::priority_queue::MinPriorityQueue;
+use crate::trace::NoopTracer;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ShortestPaths {
+    pub source: usize,
+    pub d: Vec<Option<Weight>>,
+    pub pi: Vec<Option<usize>>,
+}
+
+impl ShortestPaths {
+    pub fn path(&self, v: usize) -> Option<Vec<usize>> {
+        print_path(&self.pi, self.source, v)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PathEvent {
+    Initialize,
+    Relax {
+        u: usize,
+        v: usize,
+        weight: Weight,
+        improved: bool,
+    },
+    PassComplete { pass: usize },
+    VertexComplete { u: usize },
+}
+
+pub trait PathTracer {
+    fn record(&mut self, event: PathEvent, paths: &ShortestPaths);
+}
+
+impl<F> PathTracer for F
+where
+    F: FnMut(&PathEvent, &ShortestPaths),
+{
+    fn record(&mut self, event: PathEvent, paths: &ShortestPaths) {
+        self(&event, paths)
+    }
+}
+
+impl PathTracer for NoopTracer {
+    #[inline(always)]
+    fn record(&mut self, _event: PathEvent, _paths: &ShortestPaths) {}
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NegativeCycle {
+    pub vertices: Vec<usize>,
+}
+
+impl fmt::Display for NegativeCycle {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let vertices: Vec<String> = self.vertices.iter().map(|v| v.to_string()).collect();
+        write!(
+            f,
+            "graph has a negative-weight cycle through {}",
+            vertices.join(" -> ")
+        )
+    }
+}
+
+impl Error for NegativeCycle {}
+
+pub fn initialize_single_source(n: usize, s: usize) -> ShortestPaths {
+    assert!(s < n, "source {} is not a vertex of G", s);
+    let mut d = vec![None; n];
+    d[s] = Some(0);
+    ShortestPaths {
+        source: s,
+        d,
+        pi: vec![None; n],
+    }
+}
+
+pub fn relax(paths: &mut ShortestPaths, u: usize, v: usize, w: Weight) -> bool {
+    let Some(through_u) = paths.d[u].and_then(|du| du.checked_add(w)) else {
+        return false;
+    };
+    if paths.d[v].is_some_and(|dv| dv <= through_u) {
+        return false;
+    }
+    paths.d[v] = Some(through_u);
+    paths.pi[v] = Some(u);
+    true
+}
+
+pub fn bellman_ford<G: Graph>(g: &G, s: usize) -> Result<ShortestPaths, NegativeCycle> {
+    bellman_ford_traced(g, s, &mut NoopTracer)
+}
+
+pub fn bellman_ford_traced<G, R>(
+    g: &G,
+    s: usize,
+    tracer: &mut R,
+) -> Result<ShortestPaths, NegativeCycle>
+where
+    G: Graph,
+    R: PathTracer + ?Sized,
+{
+    let n = g.vertex_count();
+    let mut paths = initialize_single_source(n, s);
+    tracer.record(PathEvent::Initialize, &paths);
+
+    for pass in 1..n {
+        for u in g.vertices() {
+            for (v, w) in g.neighbors(u) {
+                traced_relax(&mut paths, u, v, w, tracer);
+            }
+        }
+        tracer.record(PathEvent::PassComplete { pass }, &paths);
+    }
+
+    let violated = g.vertices().any(|u| {
+        g.neighbors(u)
+            .any(|(v, w)| match paths.d[u].and_then(|du| du.checked_add(w)) {
+                Some(through_u) => paths.d[v].is_none_or(|dv| dv > through_u),
+                None => false,
+            })
+    });
+    if violated {
+        return Err(negative_cycle(g, paths));
+    }
+    Ok(paths)
+}
+
+pub fn dag_shortest_paths<G: Graph>(g: &G, s: usize) -> Result<ShortestPaths, Cycle> {
+    dag_shortest_paths_traced(g, s, &mut NoopTracer)
+}
+
+pub fn dag_shortest_paths_traced<G, R>(
+    g: &G,
+    s: usize,
+    tracer: &mut R,
+) -> Result<ShortestPaths, Cycle>
+where
+    G: Graph,
+    R: PathTracer + ?Sized,
+{
+    let order = topological_sort(g)?;
+    let mut paths = initialize_single_source(g.vertex_count(), s);
+    tracer.record(PathEvent::Initialize, &paths);
+
+    for u in order {
+        for (v, w) in g.neighbors(u) {
+            traced_relax(&mut paths, u, v, w, tracer);
+        }
+        tracer.record(PathEvent::VertexComplete { u }, &paths);
+    }
+    Ok(paths)
+}
+
+pub fn dijkstra<G, Q>(g: &G, s: usize, queue: &mut Q) -> ShortestPaths
+where
+    G: Graph,
+    Q: MinQueue<Weight, usize>,
+{
+    dijkstra_traced(g, s, queue, &mut NoopTracer)
+}
+
+pub fn dijkstra_traced<G, Q, R>(g: &G, s: usize, queue: &mut Q, tracer: &mut R) -> ShortestPaths
+where
+    G: Graph,
+    Q: MinQueue<Weight, usize>,
+    R: PathTracer + ?Sized,
+{
+    assert!(
+        g.vertices().all(|u| g.neighbors(u).all(|(_, w)| w >= 0)),
+        "Dijkstra's algorithm needs nonnegative edge weights"
+    );
+    assert!(queue.is_empty(), "queue must start empty");
+
+    let mut paths = initialize_single_source(g.vertex_count(), s);
+    tracer.record(PathEvent::Initialize, &paths);
+    let key = |d: Option<Weight>| d.unwrap_or(Weight::MAX);
+    let handles: Vec<_> = g
+        .vertices()
+        .map(|u| queue.insert(key(paths.d[u]), u))
+        .collect();
+
+    while let Some((_, u)) = queue.extract_min() {
+        for (v, w) in g.neighbors(u) {
+            if traced_relax(&mut paths, u, v, w, tracer) {
+                queue.decrease_key(handles[v], key(paths.d[v]));
+            }
+        }
+        tracer.record(PathEvent::VertexComplete { u }, &paths);
+    }
+    paths
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DifferenceConstraint {
+    pub j: usize,
+    pub i: usize,
+    pub b: Weight,
+}
+
+impl DifferenceConstraint {
+    pub fn new(j: usize, i: usize, b: Weight) -> Self {
+        Self { j, i, b }
+    }
+}
+
+pub fn constraint_graph(n: usize, constraints: &[DifferenceConstraint]) -> AdjacencyList {
+    let mut g = AdjacencyList::directed(n + 1);
+    for k in 0..n {
+        g.set_label(k, &format!("x{}", k));
+    }
+    g.set_label(n, "s");
+    for k in 0..n {
+        g.add_weighted_edge(n, k, 0);
+    }
+    for c in constraints {
+        assert!(
+            c.i < n && c.j < n,
+            "constraint x{} - x{} ≤ {} has no such variable",
+            c.j,
+            c.i,
+            c.b
+        );
+        g.add_weighted_edge(c.i, c.j, c.b);
+    }
+    g
+}
+
+pub fn solve_difference_constraints(
+    n: usize,
+    constraints: &[DifferenceConstraint],
+) -> Result<Vec<Weight>, NegativeCycle> {
+    let g = constraint_graph(n, constraints);
+    let paths = bellman_ford(&g, n)?;
+    Ok(paths.d[..n]
+        .iter()
+        .map(|d| d.expect("every vertex is reachable from s"))
+        .collect())
+}
+
+fn traced_relax<R: PathTracer + ?Sized>(
+    paths: &mut ShortestPaths,
+    u: usize,
+    v: usize,
+    w: Weight,
+    tracer: &mut R,
+) -> bool {
+    let improved = relax(paths, u, v, w);
+    tracer.record(
+        PathEvent::Relax {
+            u,
+            v,
+            weight: w,
+            improved,
+        },
+        paths,
+    );
+    improved
+}
+
+fn negative_cycle<G: Graph>(g: &G, mut paths: ShortestPaths) -> NegativeCycle {
+    let mut last = None;
+    for u in g.vertices() {
+        for (v, w) in g.neighbors(u) {
+            if relax(&mut paths, u, v, w) {
+                last = Some(v);
+            }
+        }
+    }
+
+    // |V| steps back along π from a lowered estimate end on the cycle
+    let predecessor = |v: usize| paths.pi[v].expect("lowered estimates have a predecessor");
+    let mut start = last.expect("an edge can still be relaxed");
+    for _ in g.vertices() {
+        start = predecessor(start);
+    }
+    let mut vertices = vec![start];
+    let mut current = predecessor(start);
+    while current != start {
+        vertices.push(current);
+        current = predecessor(current);
+    }
+    vertices.reverse();
+
+    let first = (0..vertices.len())
+        .min_by_key(|&i| vertices[i])
+        .unwrap_or(0);
+    vertices.rotate_left(first);
+    NegativeCycle { vertices }
+}
+
+pub(crate) fn describe_bellman_ford(g: &AdjacencyList, s: usize) -> String {
+    match bellman_ford(g, s) {
+        Ok(paths) => describe(g, &paths),
+        Err(cycle) => format!(
+            "Negative-weight cycle: {} {}\n",
+            labels(g, &cycle.vertices),
+            g.label(cycle.vertices[0])
+        ),
+    }
+}
+
+pub(crate) fn describe_dag(g: &AdjacencyList, s: usize) -> String {
+    if !g.is_directed() {
+        return "a dag needs a directed graph\n".to_string();
+    }
+    match dag_shortest_paths(g, s) {
+        Ok(paths) => describe(g, &paths),
+        Err(cycle) => format!(
+            "Not acyclic, cycle: {} {}\n",
+            labels(g, &cycle.vertices),
+            g.label(cycle.vertices[0])
+        ),
+    }
+}
+
+pub(crate) fn describe_dijkstra(g: &AdjacencyList, s: usize) -> String {
+    if g.edges().iter().any(|e| e.weight < 0) {
+        return "Dijkstra's algorithm needs nonnegative edge weights\n".to_string();
+    }
+    describe(g, &dijkstra(g, s, &mut MinPriorityQueue::new()))
+}
+
+fn describe(g: &AdjacencyList, paths: &ShortestPaths) -> String {
+    let mut text = String::new();
+    for v in g.vertices() {
+        let d = paths.d[v].map_or("∞".to_string(), |d| d.to_string());
+        let pi = paths.pi[v].map_or("NIL", |p| g.label(p));
+        let path = match paths.path(v) {
+            Some(path) => labels(g, &path),
+            None => format!(
+                "no path from {} to {} exists",
+                g.label(paths.source),
+                g.label(v)
+            ),
+        };
+        let _ = writeln!(
+            text,
+            "{}: d = {}, π = {}, path: {}",
+            g.label(v),
+            d,
+            pi,
+            path
+        );
+    }
+    text
+}
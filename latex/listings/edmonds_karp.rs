@@ -0,0 +1,143 @@
+use std::collections::VecDeque;
+
+use super::network::{FlowNetwork, describe_max_flow};
+use crate::graph::adjacency_list::AdjacencyList;
+use crate::graph::representation::{Graph, Weight};
+use crate::trace::NoopTracer;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Augmentation {
+    pub path: Vec<usize>,
+    pub amount: Weight,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MaxFlow {
+    pub value: Weight,
+    pub augmentations: Vec<Augmentation>,
+}
+
+pub trait AugmentationTracer {
+    fn record(&mut self, augmentation: &Augmentation, net: &FlowNetwork);
+}
+
+impl<F> AugmentationTracer for F
+where
+    F: FnMut(&Augmentation, &FlowNetwork),
+{
+    fn record(&mut self, augmentation: &Augmentation, net: &FlowNetwork) {
+        self(augmentation, net)
+    }
+}
+
+impl AugmentationTracer for NoopTracer {
+    #[inline(always)]
+    fn record(&mut self, _augmentation: &Augmentation, _net: &FlowNetwork) {}
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MinCut {
+    pub s_side: Vec<usize>,
+    pub t_side: Vec<usize>,
+    pub edges: Vec<usize>,
+    pub capacity: Weight,
+}
+
+pub fn edmonds_karp(net: &mut FlowNetwork, s: usize, t: usize) -> MaxFlow {
+    edmonds_karp_traced(net, s, t, &mut NoopTracer)
+}
+
+pub fn edmonds_karp_traced<R>(net: &mut FlowNetwork, s: usize, t: usize, tracer: &mut R) -> MaxFlow
+where
+    R: AugmentationTracer + ?Sized,
+{
+    check_terminals(net, s, t);
+    net.clear_flow();
+
+    let mut augmentations = Vec::new();
+    while let Some(arcs) = shortest_augmenting_path(net, s, t) {
+        let amount = arcs
+            .iter()
+            .map(|&arc| net.residual_capacity(arc))
+            .min()
+            .expect("a path from s ≠ t has an arc");
+        let mut path = vec![s];
+        path.extend(arcs.iter().map(|&arc| net.head(arc)));
+        let augmentation = Augmentation { path, amount };
+        tracer.record(&augmentation, net);
+        augmentations.push(augmentation);
+        for arc in arcs {
+            net.push(arc, amount);
+        }
+    }
+
+    MaxFlow {
+        value: net.value(s),
+        augmentations,
+    }
+}
+
+pub fn min_cut(net: &FlowNetwork, s: usize) -> MinCut {
+    let reachable = residual_search(net, s).0;
+    let (s_side, t_side): (Vec<usize>, Vec<usize>) = net.vertices().partition(|&v| reachable[v]);
+    let edges: Vec<usize> = net
+        .flow_edges()
+        .iter()
+        .enumerate()
+        .filter(|(_, e)| reachable[e.from] && !reachable[e.to])
+        .map(|(i, _)| i)
+        .collect();
+    let capacity = edges.iter().map(|&i| net.flow_edges()[i].capacity).sum();
+    MinCut {
+        s_side,
+        t_side,
+        edges,
+        capacity,
+    }
+}
+
+fn shortest_augmenting_path(net: &FlowNetwork, s: usize, t: usize) -> Option<Vec<usize>> {
+    let (reached, parent_arc) = residual_search(net, s);
+    if !reached[t] {
+        return None;
+    }
+    let mut arcs = Vec::new();
+    let mut v = t;
+    while v != s {
+        let arc = parent_arc[v].expect("reached vertices other than s have a parent");
+        arcs.push(arc);
+        v = net.tail(arc);
+    }
+    arcs.reverse();
+    Some(arcs)
+}
+
+fn residual_search(net: &FlowNetwork, s: usize) -> (Vec<bool>, Vec<Option<usize>>) {
+    let n = net.vertex_count();
+    let mut reached = vec![false; n];
+    let mut parent_arc = vec![None; n];
+    reached[s] = true;
+    let mut queue = VecDeque::from([s]);
+    while let Some(u) = queue.pop_front() {
+        for &arc in net.arcs(u) {
+            let v = net.head(arc);
+            if !reached[v] && net.residual_capacity(arc) > 0 {
+                reached[v] = true;
+                parent_arc[v] = Some(arc);
+                queue.push_back(v);
+            }
+        }
+    }
+    (reached, parent_arc)
+}
+
+pub(super) fn check_terminals(net: &FlowNetwork, s: usize, t: usize) {
+    let n = net.vertex_count();
+    assert!(s < n, "source {} is not a vertex of G", s);
+    assert!(t < n, "sink {} is not a vertex of G", t);
+    assert!(s != t, "source and sink must differ");
+}
+
+pub(crate) fn describe(g: &AdjacencyList, s: usize) -> String {
+    describe_max_flow(g, s, |net, s, t| edmonds_karp(net, s, t).value)
+}
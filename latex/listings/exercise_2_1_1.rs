@@ -0,0 +1,18 @@
+use algorithms::sorting::insertion_sort::insertion_sort_traced;
+use algorithms::trace::TraceEvent;
+
+fn main() {
+    let mut arr = vec![31, 41, 59, 26, 41, 58];
+    println!("Initial array: {:?}", arr);
+    println!("--- Starting Insertion Sort Steps ---");
+
+    // Print the array each time a key has been inserted (one line per iteration).
+    insertion_sort_traced(&mut arr, &mut |event: &TraceEvent<i32>, state: &[i32]| {
+        if let TraceEvent::Insert { index } = event {
+            println!("After inserting key at index {}: {:?}", index, state);
+        }
+    });
+
+    println!("--- End of Sort ---");
+    println!("Sorted array: {:?}", arr);
+}
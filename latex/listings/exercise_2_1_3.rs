@@ -0,0 +1,23 @@
+use algorithms::sorting::insertion_sort::insertion_sort_by_traced;
+use algorithms::trace::TraceEvent;
+
+fn main() {
+    let mut arr = vec![31, 41, 59, 26, 41, 58];
+    println!("Initial array: {:?}", arr);
+    println!("--- Starting Insertion Sort (Decreasing) Steps ---");
+
+    // Reversing the comparison shifts *smaller* elements to the right,
+    // which sorts into monotonically decreasing order.
+    insertion_sort_by_traced(
+        &mut arr,
+        |a: &i32, b: &i32| b.cmp(a),
+        &mut |event: &TraceEvent<i32>, state: &[i32]| {
+            if let TraceEvent::Insert { index } = event {
+                println!("After inserting key at index {}: {:?}", index, state);
+            }
+        },
+    );
+
+    println!("--- End of Sort ---");
+    println!("Sorted array: {:?}", arr);
+}
@@ -0,0 +1,274 @@
+//! # All-Pairs Shortest Paths
+//!
+//! Shortest paths between every pair of vertices (CLRS Chapter 23): by
+//! repeated min-plus "multiplication" of the weight matrix, by the
+//! Floyd-Warshall algorithm and, for sparse graphs, by Johnson's algorithm,
+//! which reweights the edges so that Dijkstra's algorithm can run from
+//! every vertex. The transitive closure is Floyd-Warshall over `(∨, ∧)`.
+//!
+//! Matrices are indexed by vertex, with `None` for ∞ or NIL.
+
+use std::fmt::Write;
+
+use super::adjacency_list::AdjacencyList;
+use super::representation::{Graph, Weight, labels};
+use super::sssp::{NegativeCycle, bellman_ford, dijkstra};
+use crate::heap::priority_queue::MinPriorityQueue;
+
+pub type DistanceMatrix = Vec<Vec<Option<Weight>>>;
+
+pub type PredecessorMatrix = Vec<Vec<Option<usize>>>;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AllPairs {
+    pub d: DistanceMatrix,
+    pub pi: PredecessorMatrix,
+}
+
+impl AllPairs {
+    pub fn path(&self, i: usize, j: usize) -> Option<Vec<usize>> {
+        let mut path = vec![j];
+        let mut current = j;
+        while current != i {
+            current = self.pi[i][current]?;
+            // A predecessor cycle (negative-weight cycle) never reaches i.
+            if path.len() > self.pi.len() {
+                return None;
+            }
+            path.push(current);
+        }
+        path.reverse();
+        Some(path)
+    }
+
+    pub fn has_negative_cycle(&self) -> bool {
+        (0..self.d.len()).any(|i| self.d[i][i].is_some_and(|d| d < 0))
+    }
+}
+
+pub fn weight_matrix<G: Graph>(g: &G) -> DistanceMatrix {
+    let n = g.vertex_count();
+    let mut w = vec![vec![None; n]; n];
+    for i in g.vertices() {
+        w[i][i] = Some(0);
+        for (j, weight) in g.neighbors(i) {
+            if shorter(Some(weight), w[i][j]) {
+                w[i][j] = Some(weight);
+            }
+        }
+    }
+    w
+}
+
+pub fn extend_shortest_paths(l: &DistanceMatrix, w: &DistanceMatrix) -> DistanceMatrix {
+    let n = l.len();
+    assert!(
+        w.len() == n && l.iter().chain(w).all(|row| row.len() == n),
+        "min-plus product needs two n × n matrices"
+    );
+    let mut product = vec![vec![None; n]; n];
+    for i in 0..n {
+        for j in 0..n {
+            for k in 0..n {
+                let through_k = add(l[i][k], w[k][j]);
+                if shorter(through_k, product[i][j]) {
+                    product[i][j] = through_k;
+                }
+            }
+        }
+    }
+    product
+}
+
+pub fn slow_all_pairs<G: Graph>(g: &G) -> DistanceMatrix {
+    let w = weight_matrix(g);
+    // L^(1) = W
+    let mut l = w.clone();
+    for _ in 2..g.vertex_count() {
+        l = extend_shortest_paths(&l, &w);
+    }
+    l
+}
+
+pub fn faster_all_pairs<G: Graph>(g: &G) -> DistanceMatrix {
+    let n = g.vertex_count();
+    let mut l = weight_matrix(g);
+    let mut r = 1;
+    while r + 1 < n {
+        l = extend_shortest_paths(&l, &l);
+        r *= 2;
+    }
+    l
+}
+
+pub fn floyd_warshall<G: Graph>(g: &G) -> AllPairs {
+    let mut current = initial_predecessors(g);
+    for k in g.vertices() {
+        floyd_warshall_step(&mut current, k);
+    }
+    current
+}
+
+pub fn floyd_warshall_steps<G: Graph>(g: &G) -> Vec<AllPairs> {
+    let mut steps = vec![initial_predecessors(g)];
+    for k in g.vertices() {
+        let mut next = steps[k].clone();
+        floyd_warshall_step(&mut next, k);
+        steps.push(next);
+    }
+    steps
+}
+
+pub fn transitive_closure<G: Graph>(g: &G) -> Vec<Vec<bool>> {
+    let n = g.vertex_count();
+    let mut t = vec![vec![false; n]; n];
+    for i in g.vertices() {
+        t[i][i] = true;
+        for (j, _) in g.neighbors(i) {
+            t[i][j] = true;
+        }
+    }
+    for k in 0..n {
+        let through = t[k].clone();
+        for row in t.iter_mut().filter(|row| row[k]) {
+            for (reachable, &via_k) in row.iter_mut().zip(&through) {
+                *reachable |= via_k;
+            }
+        }
+    }
+    t
+}
+
+pub fn johnson<G: Graph>(g: &G) -> Result<AllPairs, NegativeCycle> {
+    let n = g.vertex_count();
+    let mut augmented = AdjacencyList::directed(n + 1);
+    for u in g.vertices() {
+        for (v, w) in g.neighbors(u) {
+            augmented.add_weighted_edge(u, v, w);
+        }
+        augmented.add_weighted_edge(n, u, 0);
+    }
+    let h: Vec<Weight> = bellman_ford(&augmented, n)?
+        .d
+        .into_iter()
+        .map(|d| d.expect("s reaches every vertex"))
+        .collect();
+
+    let mut reweighted = AdjacencyList::directed(n);
+    for u in g.vertices() {
+        for (v, w) in g.neighbors(u) {
+            if let Some(w) = w.checked_add(h[u]).and_then(|w| w.checked_sub(h[v])) {
+                reweighted.add_weighted_edge(u, v, w);
+            }
+        }
+    }
+
+    let mut d = Vec::with_capacity(n);
+    let mut pi = Vec::with_capacity(n);
+    for u in g.vertices() {
+        let paths = dijkstra(&reweighted, u, &mut MinPriorityQueue::new());
+        d.push(
+            g.vertices()
+                .map(|v| paths.d[v].and_then(|dv| dv.checked_add(h[v])?.checked_sub(h[u])))
+                .collect(),
+        );
+        pi.push(paths.pi);
+    }
+    Ok(AllPairs { d, pi })
+}
+
+fn initial_predecessors<G: Graph>(g: &G) -> AllPairs {
+    let d = weight_matrix(g);
+    let pi = d
+        .iter()
+        .enumerate()
+        .map(|(i, row)| {
+            row.iter()
+                .enumerate()
+                .map(|(j, w)| (i != j && w.is_some()).then_some(i))
+                .collect()
+        })
+        .collect();
+    AllPairs { d, pi }
+}
+
+fn floyd_warshall_step(current: &mut AllPairs, k: usize) {
+    let n = current.d.len();
+    for i in 0..n {
+        for j in 0..n {
+            let through_k = add(current.d[i][k], current.d[k][j]);
+            if shorter(through_k, current.d[i][j]) {
+                current.d[i][j] = through_k;
+                current.pi[i][j] = current.pi[k][j];
+            }
+        }
+    }
+}
+
+fn add(a: Option<Weight>, b: Option<Weight>) -> Option<Weight> {
+    a?.checked_add(b?)
+}
+
+fn shorter(candidate: Option<Weight>, current: Option<Weight>) -> bool {
+    candidate.is_some_and(|c| current.is_none_or(|d| c < d))
+}
+
+pub(crate) fn describe_slow(g: &AdjacencyList, _: usize) -> String {
+    describe(g, &slow_all_pairs(g))
+}
+
+pub(crate) fn describe_faster(g: &AdjacencyList, _: usize) -> String {
+    describe(g, &faster_all_pairs(g))
+}
+
+pub(crate) fn describe_floyd_warshall(g: &AdjacencyList, _: usize) -> String {
+    let result = floyd_warshall(g);
+    let mut text = describe(g, &result.d);
+    if result.has_negative_cycle() {
+        text.push_str("Negative-weight cycle: some d_ii < 0\n");
+    }
+    text
+}
+
+pub(crate) fn describe_transitive_closure(g: &AdjacencyList, _: usize) -> String {
+    let mut text = String::new();
+    for (i, row) in transitive_closure(g).iter().enumerate() {
+        let reachable: Vec<usize> = g.vertices().filter(|&j| row[j]).collect();
+        let _ = writeln!(text, "{}: {}", g.label(i), labels(g, &reachable));
+    }
+    text
+}
+
+pub(crate) fn describe_johnson(g: &AdjacencyList, _: usize) -> String {
+    match johnson(g) {
+        Ok(result) => describe(g, &result.d),
+        Err(cycle) => format!(
+            "Negative-weight cycle: {} {}\n",
+            labels(g, &cycle.vertices),
+            g.label(cycle.vertices[0])
+        ),
+    }
+}
+
+fn describe(g: &AdjacencyList, d: &DistanceMatrix) -> String {
+    let cell = |w: &Option<i64>| w.map_or("∞".to_string(), |w| w.to_string());
+    let width = g
+        .vertices()
+        .map(|v| g.label(v).chars().count())
+        .chain(d.iter().flatten().map(|w| cell(w).chars().count()))
+        .max()
+        .unwrap_or(1);
+    let mut text = format!("{:width$}", "");
+    for v in g.vertices() {
+        let _ = write!(text, " {:>width$}", g.label(v));
+    }
+    text.push('\n');
+    for (u, row) in d.iter().enumerate() {
+        let _ = write!(text, "{:width$}", g.label(u));
+        for w in row {
+            let _ = write!(text, " {:>width$}", cell(w));
+        }
+        text.push('\n');
+    }
+    text
+}
@@ -0,0 +1,102 @@
+use crate::trace::{NoopTracer, TraceEvent, Tracer};
+
+pub fn parent(i: usize) -> usize {
+    i / 2
+}
+
+pub fn left(i: usize) -> usize {
+    2 * i
+}
+
+pub fn right(i: usize) -> usize {
+    2 * i + 1
+}
+
+pub fn max_heapify<T: Ord>(arr: &mut [T], i: usize) {
+    max_heapify_traced(arr, i, &mut NoopTracer);
+}
+
+pub fn build_max_heap<T: Ord>(arr: &mut [T]) {
+    build_max_heap_traced(arr, &mut NoopTracer);
+}
+
+pub fn heapsort<T: Ord>(arr: &mut [T]) {
+    heapsort_traced(arr, &mut NoopTracer);
+}
+
+pub fn max_heapify_traced<T, R>(arr: &mut [T], i: usize, tracer: &mut R)
+where
+    T: Ord,
+    R: Tracer<T> + ?Sized,
+{
+    let heap_size = arr.len();
+    heapify(arr, heap_size, i, tracer);
+}
+
+pub fn build_max_heap_traced<T, R>(arr: &mut [T], tracer: &mut R)
+where
+    T: Ord,
+    R: Tracer<T> + ?Sized,
+{
+    for i in (1..=parent(arr.len())).rev() {
+        max_heapify_traced(arr, i, tracer);
+    }
+}
+
+pub fn heapsort_traced<T, R>(arr: &mut [T], tracer: &mut R)
+where
+    T: Ord,
+    R: Tracer<T> + ?Sized,
+{
+    build_max_heap_traced(arr, tracer);
+    for i in (2..=arr.len()).rev() {
+        arr.swap(0, i - 1);
+        tracer.record(TraceEvent::Swap { i: 0, j: i - 1 }, arr);
+        heapify(arr, i - 1, 1, tracer);
+    }
+}
+
+fn heapify<T, R>(arr: &mut [T], heap_size: usize, mut i: usize, tracer: &mut R)
+where
+    T: Ord,
+    R: Tracer<T> + ?Sized,
+{
+    // Iterative form of the tail-recursive CLRS procedure.
+    loop {
+        let l = left(i);
+        let r = right(i);
+        let mut largest = i;
+
+        if l <= heap_size {
+            tracer.record(TraceEvent::Compare { i: l - 1, j: i - 1 }, arr);
+            if arr[l - 1] > arr[i - 1] {
+                largest = l;
+            }
+        }
+        if r <= heap_size {
+            tracer.record(
+                TraceEvent::Compare {
+                    i: r - 1,
+                    j: largest - 1,
+                },
+                arr,
+            );
+            if arr[r - 1] > arr[largest - 1] {
+                largest = r;
+            }
+        }
+        if largest == i {
+            return;
+        }
+
+        arr.swap(i - 1, largest - 1);
+        tracer.record(
+            TraceEvent::Swap {
+                i: i - 1,
+                j: largest - 1,
+            },
+            arr,
+        );
+        i = largest;
+    }
+}
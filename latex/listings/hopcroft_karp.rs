@@ -0,0 +1,220 @@
+//! # Bipartite Matching
+//!
+//! Maximum matchings in a bipartite graph `G = (V, E)` with `V = L ∪ R`:
+//! once through a unit-capacity flow network (CLRS Section 24.3) and once
+//! with the Hopcroft-Karp algorithm (CLRS Section 25.1). A graph is taken
+//! as undirected; directed edges simply count in both directions.
+
+use std::collections::VecDeque;
+use std::fmt::Write;
+
+use super::ford_fulkerson::edmonds_karp;
+use super::network::FlowNetwork;
+use crate::graph::adjacency_list::AdjacencyList;
+use crate::graph::representation::Graph;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Matching {
+    pub mate: Vec<Option<usize>>,
+}
+
+impl Matching {
+    pub fn size(&self) -> usize {
+        self.edges().len()
+    }
+
+    pub fn edges(&self) -> Vec<(usize, usize)> {
+        self.mate
+            .iter()
+            .enumerate()
+            .filter_map(|(u, &v)| v.filter(|&v| u < v).map(|v| (u, v)))
+            .collect()
+    }
+}
+
+pub fn bipartition<G: Graph>(g: &G) -> Option<Vec<bool>> {
+    let adj = undirected_neighbors(g);
+    let mut side: Vec<Option<bool>> = vec![None; g.vertex_count()];
+    for root in g.vertices() {
+        if side[root].is_some() {
+            continue;
+        }
+        side[root] = Some(false);
+        let mut queue = VecDeque::from([root]);
+        while let Some(u) = queue.pop_front() {
+            for &v in &adj[u] {
+                match side[v] {
+                    None => {
+                        side[v] = side[u].map(|right| !right);
+                        queue.push_back(v);
+                    }
+                    Some(right) if Some(right) == side[u] => return None,
+                    Some(_) => {}
+                }
+            }
+        }
+    }
+    side.into_iter().collect()
+}
+
+pub fn bipartite_matching<G: Graph>(g: &G) -> Matching {
+    let right = bipartition(g).expect("graph is not bipartite");
+    let n = g.vertex_count();
+    let (s, t) = (n, n + 1);
+
+    let adj = undirected_neighbors(g);
+    let mut net = FlowNetwork::new(n + 2);
+    net.set_label(s, "s");
+    net.set_label(t, "t");
+    for u in g.vertices() {
+        if right[u] {
+            net.add_edge(u, t, 1);
+        } else {
+            net.add_edge(s, u, 1);
+            for &v in &adj[u] {
+                net.add_edge(u, v, 1);
+            }
+        }
+    }
+    edmonds_karp(&mut net, s, t);
+
+    let mut mate = vec![None; n];
+    for e in net.flow_edges() {
+        if e.flow > 0 && e.from < n && e.to < n {
+            mate[e.from] = Some(e.to);
+            mate[e.to] = Some(e.from);
+        }
+    }
+    Matching { mate }
+}
+
+pub fn hopcroft_karp<G: Graph>(g: &G) -> Matching {
+    let right = bipartition(g).expect("graph is not bipartite");
+    let adj = undirected_neighbors(g);
+    let left: Vec<usize> = g.vertices().filter(|&u| !right[u]).collect();
+    let mut mate = vec![None; g.vertex_count()];
+
+    while let Some(layers) = alternating_layers(&adj, &left, &mate) {
+        // a position in adj[u], so each edge is tried once per phase
+        let mut current = vec![0; g.vertex_count()];
+        for &u in &left {
+            if mate[u].is_none() {
+                augment(&adj, &layers, &mut current, &mut mate, u);
+            }
+        }
+    }
+    Matching { mate }
+}
+
+struct Layers {
+    layer: Vec<Option<usize>>,
+    last: usize,
+}
+
+fn alternating_layers(
+    adj: &[Vec<usize>],
+    left: &[usize],
+    mate: &[Option<usize>],
+) -> Option<Layers> {
+    let mut layer: Vec<Option<usize>> = vec![None; adj.len()];
+    let mut queue = VecDeque::new();
+    for &u in left {
+        if mate[u].is_none() {
+            layer[u] = Some(0);
+            queue.push_back((u, 0));
+        }
+    }
+    let mut last = None;
+    while let Some((u, d)) = queue.pop_front() {
+        if last.is_some_and(|last| d > last) {
+            break;
+        }
+        for &v in &adj[u] {
+            match mate[v] {
+                None => last = Some(d),
+                Some(w) if layer[w].is_none() => {
+                    layer[w] = Some(d + 1);
+                    queue.push_back((w, d + 1));
+                }
+                Some(_) => {}
+            }
+        }
+    }
+    last.map(|last| Layers { layer, last })
+}
+
+fn augment(
+    adj: &[Vec<usize>],
+    layers: &Layers,
+    current: &mut [usize],
+    mate: &mut [Option<usize>],
+    root: usize,
+) -> bool {
+    if layers.layer[root].is_none() {
+        return false;
+    }
+    // The vertices of L on the path so far; each one below the top left
+    // through its edge adj[u][current[u] - 1]
+    let mut stack = vec![root];
+
+    while let Some(&u) = stack.last() {
+        let d = layers.layer[u].expect("only layered vertices are pushed");
+        let Some(&v) = adj[u].get(current[u]) else {
+            // No augmenting path leaves u in this phase
+            stack.pop();
+            continue;
+        };
+        current[u] += 1;
+
+        match mate[v] {
+            None if d == layers.last => {
+                // Match every vertex on the path to the one it left through
+                for &x in &stack {
+                    let y = adj[x][current[x] - 1];
+                    mate[x] = Some(y);
+                    mate[y] = Some(x);
+                }
+                return true;
+            }
+            Some(w) if d < layers.last && layers.layer[w] == Some(d + 1) => stack.push(w),
+            _ => {}
+        }
+    }
+    false
+}
+
+fn undirected_neighbors<G: Graph>(g: &G) -> Vec<Vec<usize>> {
+    let mut adj = vec![Vec::new(); g.vertex_count()];
+    for u in g.vertices() {
+        for (v, _) in g.neighbors(u) {
+            if !adj[u].contains(&v) {
+                adj[u].push(v);
+            }
+            if !adj[v].contains(&u) {
+                adj[v].push(u);
+            }
+        }
+    }
+    adj
+}
+
+pub(crate) fn describe_bipartite_matching(g: &AdjacencyList, _: usize) -> String {
+    describe(g, bipartite_matching)
+}
+
+pub(crate) fn describe_hopcroft_karp(g: &AdjacencyList, _: usize) -> String {
+    describe(g, hopcroft_karp)
+}
+
+fn describe(g: &AdjacencyList, matching: impl Fn(&AdjacencyList) -> Matching) -> String {
+    if bipartition(g).is_none() {
+        return "a bipartite matching needs a bipartite graph\n".to_string();
+    }
+    let m = matching(g);
+    let mut text = String::new();
+    for (u, v) in m.edges() {
+        let _ = writeln!(text, "({}, {})", g.label(u), g.label(v));
+    }
+    let _ = writeln!(text, "Matching size: {}", m.size());
+    text
+}
@@ -1,17 +1,59 @@
+use std::cmp::Ordering;
+
+use crate::trace::{NoopTracer, TraceEvent, Tracer};
+
 pub fn insertion_sort<T>(arr: &mut [T])
 where
     T: Ord + Clone,
+{
+    insertion_sort_by_traced(arr, T::cmp, &mut NoopTracer);
+}
+
+pub fn insertion_sort_by<T, F>(arr: &mut [T], compare: F)
+where
+    T: Clone,
+    F: FnMut(&T, &T) -> Ordering,
+{
+    insertion_sort_by_traced(arr, compare, &mut NoopTracer);
+}
+
+pub fn insertion_sort_traced<T, R>(arr: &mut [T], tracer: &mut R)
+where
+    T: Ord + Clone,
+    R: Tracer<T> + ?Sized,
+{
+    insertion_sort_by_traced(arr, T::cmp, tracer);
+}
+
+pub fn insertion_sort_by_traced<T, F, R>(arr: &mut [T], mut compare: F, tracer: &mut R)
+where
+    T: Clone,
+    F: FnMut(&T, &T) -> Ordering,
+    R: Tracer<T> + ?Sized,
 {
     for i in 1..arr.len() {
         let key = arr[i].clone();
+        tracer.record(
+            TraceEvent::SelectKey {
+                index: i,
+                key: key.clone(),
+            },
+            arr,
+        );
         let mut j = i;
 
         // Move elements greater than key one position ahead.
-        while j > 0 && arr[j - 1] > key {
+        while j > 0 {
+            tracer.record(TraceEvent::CompareKey { index: j - 1 }, arr);
+            if compare(&arr[j - 1], &key) != Ordering::Greater {
+                break;
+            }
             arr[j] = arr[j - 1].clone();
+            tracer.record(TraceEvent::Shift { from: j - 1, to: j }, arr);
             j -= 1;
         }
 
         arr[j] = key;
+        tracer.record(TraceEvent::Insert { index: j }, arr);
     }
 }
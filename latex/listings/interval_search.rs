@@ -0,0 +1,135 @@
+//! # Interval Trees
+//!
+//! A red-black tree of closed intervals keyed by their low endpoints, whose
+//! nodes also store `x.max`, the largest high endpoint in the subtree of
+//! `x` (CLRS Section 17.3). The maxima tell a search which subtree can
+//! hold an interval overlapping the query.
+
+use std::fmt::{self, Write};
+
+use super::augment::Augmentation;
+use super::red_black::RedBlackTree;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Interval<T> {
+    pub low: T,
+    pub high: T,
+}
+
+impl<T: Ord> Interval<T> {
+    pub fn new(low: T, high: T) -> Self {
+        assert!(low <= high, "an interval needs low ≤ high");
+        Self { low, high }
+    }
+
+    pub fn overlaps(&self, other: &Interval<T>) -> bool {
+        self.low <= other.high && other.low <= self.high
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for Interval<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "[{}, {}]", self.low, self.high)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct MaxHigh<T>(pub T);
+
+impl<T: Ord + Clone, V> Augmentation<Interval<T>, V> for MaxHigh<T> {
+    fn compute(key: &Interval<T>, _value: &V, left: Option<&Self>, right: Option<&Self>) -> Self {
+        let max = [left, right]
+            .into_iter()
+            .flatten()
+            .map(|m| &m.0)
+            .fold(&key.high, |max, high| max.max(high));
+        MaxHigh(max.clone())
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for MaxHigh<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+pub type IntervalTree<T, V> = RedBlackTree<Interval<T>, V, MaxHigh<T>>;
+
+impl<T: Ord + Clone, V> RedBlackTree<Interval<T>, V, MaxHigh<T>> {
+    pub fn interval_search(&self, i: &Interval<T>) -> Option<usize> {
+        let mut x = self.root()?;
+        while !i.overlaps(self.key(x)) {
+            x = match self.left(x) {
+                Some(left) if self.augment(left).0 >= i.low => left,
+                _ => self.right(x)?,
+            };
+        }
+        Some(x)
+    }
+
+    pub fn overlapping(&self, i: &Interval<T>) -> Vec<usize> {
+        let mut found = Vec::new();
+        // Inorder walk of the subtrees that may hold an overlap
+        let mut stack = Vec::new();
+        let mut current = self.root();
+        loop {
+            while let Some(x) = current.filter(|&x| self.augment(x).0 >= i.low) {
+                stack.push(x);
+                current = self.left(x);
+            }
+            let Some(x) = stack.pop() else {
+                break;
+            };
+            if self.key(x).low > i.high {
+                // x and its right subtree start after i ends
+                current = None;
+                continue;
+            }
+            if i.overlaps(self.key(x)) {
+                found.push(x);
+            }
+            current = self.right(x);
+        }
+        found
+    }
+
+    pub fn max_high(&self, x: usize) -> &T {
+        &self.augment(x).0
+    }
+}
+
+pub(crate) fn describe(keys: &[i64]) -> String {
+    let intervals: Vec<Interval<i64>> = keys
+        .chunks_exact(2)
+        .map(|pair| Interval::new(pair[0].min(pair[1]), pair[0].max(pair[1])))
+        .collect();
+    let mut tree = IntervalTree::augmented();
+    for &i in &intervals {
+        tree.insert(i, ());
+    }
+    let mut text = String::new();
+    if let [.., odd] = keys.chunks_exact(2).remainder() {
+        let _ = writeln!(text, "Ignored unpaired endpoint {}", odd);
+    }
+    for x in tree.inorder() {
+        let _ = writeln!(text, "{} (max {})", tree.key(x), tree.max_high(x));
+    }
+    for i in &intervals {
+        let found = tree
+            .interval_search(i)
+            .map_or("NIL".to_string(), |x| tree.key(x).to_string());
+        let all: Vec<String> = tree
+            .overlapping(i)
+            .into_iter()
+            .map(|x| tree.key(x).to_string())
+            .collect();
+        let _ = writeln!(
+            text,
+            "Search {}: {}; all overlaps {}",
+            i,
+            found,
+            all.join(" ")
+        );
+    }
+    text
+}
@@ -0,0 +1,123 @@
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Arrow {
+    UpLeft,
+    Up,
+    Left,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LcsTables {
+    pub c: Vec<Vec<usize>>,
+    pub b: Vec<Vec<Option<Arrow>>>,
+}
+
+impl LcsTables {
+    pub fn length(&self) -> usize {
+        self.c
+            .last()
+            .and_then(|row| row.last())
+            .copied()
+            .unwrap_or(0)
+    }
+
+    pub fn path(&self) -> Vec<(usize, usize)> {
+        let mut i = self.b.len() - 1;
+        let mut j = self.b[0].len() - 1;
+        let mut path = Vec::new();
+        while let Some(arrow) = self.b[i][j] {
+            path.push((i, j));
+            match arrow {
+                Arrow::UpLeft => {
+                    i -= 1;
+                    j -= 1;
+                }
+                Arrow::Up => i -= 1,
+                Arrow::Left => j -= 1,
+            }
+        }
+        path
+    }
+}
+
+pub fn lcs_length<T: Eq>(x: &[T], y: &[T]) -> LcsTables {
+    let (m, n) = (x.len(), y.len());
+    let mut c = vec![vec![0; n + 1]; m + 1];
+    let mut b = vec![vec![None; n + 1]; m + 1];
+
+    for i in 1..=m {
+        for j in 1..=n {
+            if x[i - 1] == y[j - 1] {
+                c[i][j] = c[i - 1][j - 1] + 1;
+                b[i][j] = Some(Arrow::UpLeft);
+            } else if c[i - 1][j] >= c[i][j - 1] {
+                c[i][j] = c[i - 1][j];
+                b[i][j] = Some(Arrow::Up);
+            } else {
+                c[i][j] = c[i][j - 1];
+                b[i][j] = Some(Arrow::Left);
+            }
+        }
+    }
+
+    LcsTables { c, b }
+}
+
+pub fn print_lcs<'a, T>(tables: &LcsTables, x: &'a [T], i: usize, j: usize) -> Vec<&'a T> {
+    let mut out = Vec::new();
+    collect_lcs(&tables.b, x, i, j, &mut out);
+    out
+}
+
+pub fn lcs<T: Eq + Clone>(x: &[T], y: &[T]) -> Vec<T> {
+    let tables = lcs_length(x, y);
+    print_lcs(&tables, x, x.len(), y.len())
+        .into_iter()
+        .cloned()
+        .collect()
+}
+
+pub fn lcs_length_two_rows<T: Eq>(x: &[T], y: &[T]) -> usize {
+    // Let the rows run over the shorter sequence.
+    let (long, short) = if x.len() >= y.len() { (x, y) } else { (y, x) };
+    let mut previous = vec![0; short.len() + 1];
+    let mut current = vec![0; short.len() + 1];
+
+    for a in long {
+        for (j, b) in short.iter().enumerate() {
+            current[j + 1] = if a == b {
+                previous[j] + 1
+            } else {
+                previous[j + 1].max(current[j])
+            };
+        }
+        std::mem::swap(&mut previous, &mut current);
+    }
+
+    previous[short.len()]
+}
+
+fn collect_lcs<'a, T>(
+    b: &[Vec<Option<Arrow>>],
+    x: &'a [T],
+    i: usize,
+    j: usize,
+    out: &mut Vec<&'a T>,
+) {
+    if i == 0 || j == 0 {
+        return;
+    }
+    match b[i][j] {
+        Some(Arrow::UpLeft) => {
+            collect_lcs(b, x, i - 1, j - 1, out);
+            out.push(&x[i - 1]);
+        }
+        Some(Arrow::Up) => collect_lcs(b, x, i - 1, j, out),
+        _ => collect_lcs(b, x, i, j - 1, out),
+    }
+}
+
+pub(crate) fn describe(x: &str, y: &str) -> String {
+    let x: Vec<char> = x.chars().collect();
+    let y: Vec<char> = y.chars().collect();
+    lcs(&x, &y).into_iter().collect()
+}
@@ -0,0 +1,305 @@
+//! # Open Addressing
+//!
+//! All elements live in the table itself; a key `k` is looked for along
+//! its probe sequence `⟨h(k, 0), h(k, 1), ..., h(k, m - 1)⟩` until it or an
+//! empty slot turns up (CLRS Section 11.4). The probe sequence is a
+//! [`ProbeSequence`], so one table implements linear probing, quadratic
+//! probing and double hashing.
+
+use std::cell::Cell;
+use std::error::Error;
+use std::fmt::{self, Write};
+
+use super::hash_function::{Division, HashFunction, natural_keys, table_size};
+use super::stats::{ProbeStats, describe_load};
+
+pub trait ProbeSequence {
+    fn probe(&self, k: u64, i: usize) -> usize;
+
+    fn slots(&self) -> usize;
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LinearProbing<H: HashFunction> {
+    h: H,
+}
+
+impl<H: HashFunction> LinearProbing<H> {
+    pub fn new(h: H) -> Self {
+        Self { h }
+    }
+}
+
+impl<H: HashFunction> ProbeSequence for LinearProbing<H> {
+    fn probe(&self, k: u64, i: usize) -> usize {
+        (self.h.hash(k) + i) % self.h.slots()
+    }
+
+    fn slots(&self) -> usize {
+        self.h.slots()
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct QuadraticProbing<H: HashFunction> {
+    h: H,
+}
+
+impl<H: HashFunction> QuadraticProbing<H> {
+    pub fn new(h: H) -> Self {
+        Self { h }
+    }
+}
+
+impl<H: HashFunction> ProbeSequence for QuadraticProbing<H> {
+    fn probe(&self, k: u64, i: usize) -> usize {
+        let m = self.h.slots() as u128;
+        let offset = (i as u128 * (i as u128 + 1) / 2) % m;
+        ((self.h.hash(k) as u128 + offset) % m) as usize
+    }
+
+    fn slots(&self) -> usize {
+        self.h.slots()
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DoubleHashing<H1: HashFunction, H2: HashFunction> {
+    h1: H1,
+    h2: H2,
+}
+
+impl<H1: HashFunction, H2: HashFunction> DoubleHashing<H1, H2> {
+    pub fn new(h1: H1, h2: H2) -> Self {
+        assert!(
+            h2.slots() < h1.slots(),
+            "h'₂ needs fewer than {} slots",
+            h1.slots()
+        );
+        Self { h1, h2 }
+    }
+}
+
+impl<H1: HashFunction, H2: HashFunction> ProbeSequence for DoubleHashing<H1, H2> {
+    fn probe(&self, k: u64, i: usize) -> usize {
+        let m = self.h1.slots() as u128;
+        let step = 1 + self.h2.hash(k) as u128;
+        ((self.h1.hash(k) as u128 + i as u128 * step) % m) as usize
+    }
+
+    fn slots(&self) -> usize {
+        self.h1.slots()
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Overflow {
+    pub key: u64,
+}
+
+impl fmt::Display for Overflow {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "hash table overflow inserting {}", self.key)
+    }
+}
+
+impl Error for Overflow {}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Slot<V> {
+    Empty,
+    Deleted,
+    Occupied(u64, V),
+}
+
+#[derive(Debug, Clone)]
+pub struct OpenAddressTable<V, P: ProbeSequence> {
+    probing: P,
+    slots: Vec<Slot<V>>,
+    len: usize,
+    stats: Cell<ProbeStats>,
+}
+
+impl<V, P: ProbeSequence> OpenAddressTable<V, P> {
+    pub fn new(probing: P) -> Self {
+        let slots = (0..probing.slots()).map(|_| Slot::Empty).collect();
+        Self {
+            probing,
+            slots,
+            len: 0,
+            stats: Cell::new(ProbeStats::default()),
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    pub fn load_factor(&self) -> f64 {
+        self.len as f64 / self.slots.len() as f64
+    }
+
+    pub fn insert(&mut self, k: u64, value: V) -> Result<Option<V>, Overflow> {
+        let (found, free) = self.find(k);
+        if let Some(q) = found {
+            let Slot::Occupied(_, old) = std::mem::replace(&mut self.slots[q], Slot::Empty) else {
+                unreachable!("find returns an occupied slot");
+            };
+            self.slots[q] = Slot::Occupied(k, value);
+            return Ok(Some(old));
+        }
+        let q = free.ok_or(Overflow { key: k })?;
+        self.slots[q] = Slot::Occupied(k, value);
+        self.len += 1;
+        Ok(None)
+    }
+
+    pub fn search(&self, k: u64) -> Option<&V> {
+        match &self.slots[self.find(k).0?] {
+            Slot::Occupied(_, value) => Some(value),
+            _ => None,
+        }
+    }
+
+    pub fn delete(&mut self, k: u64) -> Option<V> {
+        let q = self.find(k).0?;
+        self.len -= 1;
+        match std::mem::replace(&mut self.slots[q], Slot::Deleted) {
+            Slot::Occupied(_, value) => Some(value),
+            _ => unreachable!("find returns an occupied slot"),
+        }
+    }
+
+    pub fn keys(&self) -> Vec<Option<u64>> {
+        self.slots
+            .iter()
+            .map(|slot| match slot {
+                Slot::Occupied(k, _) => Some(*k),
+                _ => None,
+            })
+            .collect()
+    }
+
+    pub fn slots(&self) -> &[Slot<V>] {
+        &self.slots
+    }
+
+    pub fn tombstones(&self) -> usize {
+        self.slots
+            .iter()
+            .filter(|slot| matches!(slot, Slot::Deleted))
+            .count()
+    }
+
+    pub fn stats(&self) -> ProbeStats {
+        self.stats.get()
+    }
+
+    pub fn reset_stats(&self) {
+        self.stats.set(ProbeStats::default());
+    }
+
+    fn find(&self, k: u64) -> (Option<usize>, Option<usize>) {
+        let mut free = None;
+        let mut probes = 0;
+        let mut found = None;
+        for i in 0..self.slots.len() {
+            let q = self.probing.probe(k, i);
+            probes += 1;
+            match &self.slots[q] {
+                Slot::Occupied(key, _) if *key == k => {
+                    found = Some(q);
+                    break;
+                }
+                Slot::Occupied(..) => {}
+                Slot::Deleted => {
+                    free.get_or_insert(q);
+                }
+                Slot::Empty => {
+                    free.get_or_insert(q);
+                    break;
+                }
+            }
+        }
+        let mut stats = self.stats.get();
+        stats.record(found.is_some(), probes);
+        self.stats.set(stats);
+        (found, free)
+    }
+}
+
+impl<V, H: HashFunction> OpenAddressTable<V, LinearProbing<H>> {
+    pub fn linear_probing_delete(&mut self, k: u64) -> Option<V> {
+        let mut q = self.find(k).0?;
+        self.len -= 1;
+        let m = self.slots.len();
+        let h = &self.probing.h;
+        let g = |k: u64, q: usize| (q + m - h.hash(k)) % m;
+
+        let Slot::Occupied(_, value) = std::mem::replace(&mut self.slots[q], Slot::Empty) else {
+            unreachable!("find returns an occupied slot");
+        };
+        let mut next = q;
+        loop {
+            next = (next + 1) % m;
+            match self.slots[next] {
+                Slot::Empty => return Some(value),
+                Slot::Occupied(k2, _) if g(k2, q) < g(k2, next) => {
+                    self.slots.swap(q, next);
+                    q = next;
+                }
+                _ => {}
+            }
+        }
+    }
+}
+
+pub(crate) fn describe_linear(keys: &[i64]) -> String {
+    let m = table_size(keys.len());
+    describe(keys, LinearProbing::new(Division::new(m)), "h(k) = k mod m")
+}
+
+pub(crate) fn describe_quadratic(keys: &[i64]) -> String {
+    let m = table_size(keys.len());
+    describe(
+        keys,
+        QuadraticProbing::new(Division::new(m)),
+        "h(k) = k mod m, offsets i(i + 1)/2",
+    )
+}
+
+pub(crate) fn describe_double(keys: &[i64]) -> String {
+    let m = table_size(keys.len());
+    describe(
+        keys,
+        DoubleHashing::new(Division::new(m), Division::new(m - 1)),
+        "h1(k) = k mod m, h2(k) = 1 + (k mod (m - 1))",
+    )
+}
+
+fn describe<P: ProbeSequence>(keys: &[i64], probing: P, hash: &str) -> String {
+    let Some(keys) = natural_keys(keys) else {
+        return "hash tables need nonnegative keys\n".to_string();
+    };
+    let m = probing.slots();
+    let mut table = OpenAddressTable::new(probing);
+    let mut text = format!("m = {}, {}\n", m, hash);
+    for &k in &keys {
+        if let Err(overflow) = table.insert(k, ()) {
+            let _ = writeln!(text, "{}", overflow);
+        }
+    }
+    for (q, key) in table.keys().iter().enumerate() {
+        let key = key.map_or(String::new(), |k| format!(" {}", k));
+        let _ = writeln!(text, "{}:{}", q, key);
+    }
+    table.reset_stats();
+    for &k in &keys {
+        table.search(k);
+    }
+    text + &describe_load(table.load_factor(), table.stats().mean_successful())
+}
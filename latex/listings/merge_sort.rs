@@ -0,0 +1,174 @@
+use std::cmp::Ordering;
+
+use crate::trace::{NoopTracer, TraceEvent, Tracer};
+
+pub trait Sentinel {
+    const INFINITY: Self;
+}
+
+macro_rules! impl_sentinel {
+    ($($t:ty),*) => {
+        $(impl Sentinel for $t {
+            const INFINITY: Self = <$t>::MAX;
+        })*
+    };
+}
+
+impl_sentinel!(
+    i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize, char
+);
+
+pub fn merge_sort<T>(arr: &mut [T])
+where
+    T: Ord + Clone,
+{
+    merge_sort_by_traced(arr, T::cmp, &mut NoopTracer);
+}
+
+pub fn merge_sort_by<T, F>(arr: &mut [T], compare: F)
+where
+    T: Clone,
+    F: FnMut(&T, &T) -> Ordering,
+{
+    merge_sort_by_traced(arr, compare, &mut NoopTracer);
+}
+
+pub fn merge_sort_traced<T, R>(arr: &mut [T], tracer: &mut R)
+where
+    T: Ord + Clone,
+    R: Tracer<T> + ?Sized,
+{
+    merge_sort_by_traced(arr, T::cmp, tracer);
+}
+
+pub fn merge_sort_by_traced<T, F, R>(arr: &mut [T], mut compare: F, tracer: &mut R)
+where
+    T: Clone,
+    F: FnMut(&T, &T) -> Ordering,
+    R: Tracer<T> + ?Sized,
+{
+    if arr.len() > 1 {
+        sort_range(arr, 0, arr.len() - 1, &mut compare, tracer);
+    }
+}
+
+pub fn merge_sort_with_sentinels<T>(arr: &mut [T])
+where
+    T: Ord + Clone + Sentinel,
+{
+    fn sort<T: Ord + Clone + Sentinel>(arr: &mut [T], p: usize, r: usize) {
+        if p >= r {
+            return;
+        }
+        let q = p + (r - p) / 2;
+        sort(arr, p, q);
+        sort(arr, q + 1, r);
+        merge_with_sentinels(arr, p, q, r);
+    }
+
+    if arr.len() > 1 {
+        sort(arr, 0, arr.len() - 1);
+    }
+}
+
+pub fn merge<T>(arr: &mut [T], p: usize, q: usize, r: usize)
+where
+    T: Ord + Clone,
+{
+    merge_by_traced(arr, p, q, r, &mut T::cmp, &mut NoopTracer);
+}
+
+pub fn merge_with_sentinels<T>(arr: &mut [T], p: usize, q: usize, r: usize)
+where
+    T: Ord + Clone + Sentinel,
+{
+    let mut left = arr[p..=q].to_vec();
+    let mut right = arr[q + 1..=r].to_vec();
+    // An element equal to ∞ would let the loop run past the sentinel
+    assert!(
+        left.iter().chain(right.iter()).all(|x| *x < T::INFINITY),
+        "elements must be strictly less than the sentinel"
+    );
+    left.push(T::INFINITY);
+    right.push(T::INFINITY);
+
+    let (mut i, mut j) = (0, 0);
+    for slot in &mut arr[p..=r] {
+        if left[i] <= right[j] {
+            *slot = left[i].clone();
+            i += 1;
+        } else {
+            *slot = right[j].clone();
+            j += 1;
+        }
+    }
+}
+
+fn sort_range<T, F, R>(arr: &mut [T], p: usize, r: usize, compare: &mut F, tracer: &mut R)
+where
+    T: Clone,
+    F: FnMut(&T, &T) -> Ordering,
+    R: Tracer<T> + ?Sized,
+{
+    if p >= r {
+        return;
+    }
+    let q = p + (r - p) / 2;
+    sort_range(arr, p, q, compare, tracer);
+    sort_range(arr, q + 1, r, compare, tracer);
+    merge_by_traced(arr, p, q, r, compare, tracer);
+}
+
+fn merge_by_traced<T, F, R>(
+    arr: &mut [T],
+    p: usize,
+    q: usize,
+    r: usize,
+    compare: &mut F,
+    tracer: &mut R,
+) where
+    T: Clone,
+    F: FnMut(&T, &T) -> Ordering,
+    R: Tracer<T> + ?Sized,
+{
+    let left = arr[p..=q].to_vec();
+    let right = arr[q + 1..=r].to_vec();
+    let (n_left, n_right) = (left.len(), right.len());
+    let (mut i, mut j, mut k) = (0, 0, p);
+
+    // As long as both L and R contain an uncopied element,
+    // copy the smallest uncopied element back into A[p..=r].
+    while i < n_left && j < n_right {
+        tracer.record(
+            TraceEvent::Compare {
+                i: p + i,
+                j: q + 1 + j,
+            },
+            arr,
+        );
+        if compare(&left[i], &right[j]) != Ordering::Greater {
+            arr[k] = left[i].clone();
+            i += 1;
+        } else {
+            arr[k] = right[j].clone();
+            j += 1;
+        }
+        tracer.record(TraceEvent::Write { index: k }, arr);
+        k += 1;
+    }
+
+    // Having gone through one of L and R entirely,
+    // copy the remainder of the other to the end of A[p..=r].
+    while i < n_left {
+        arr[k] = left[i].clone();
+        tracer.record(TraceEvent::Write { index: k }, arr);
+        i += 1;
+        k += 1;
+    }
+    while j < n_right {
+        arr[k] = right[j].clone();
+        tracer.record(TraceEvent::Write { index: k }, arr);
+        j += 1;
+        k += 1;
+    }
+}
@@ -0,0 +1,193 @@
+//! # Minimum Spanning Trees
+//!
+//! Kruskal's and Prim's algorithms (CLRS Chapter 21) and the second-best
+//! minimum spanning tree of Problem 21-1. All of them take a connected,
+//! undirected, weighted graph; on a disconnected graph they return a
+//! minimum spanning forest.
+
+use std::fmt::Write;
+
+use super::adjacency_list::AdjacencyList;
+use super::representation::{Edge, Graph, Weight};
+use crate::disjoint_set::forest::DisjointSetForest;
+use crate::heap::min_queue::MinQueue;
+use crate::heap::priority_queue::MinPriorityQueue;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SpanningTree {
+    pub edges: Vec<Edge>,
+    pub weight: Weight,
+}
+
+impl SpanningTree {
+    fn from_edges(edges: Vec<Edge>) -> Self {
+        let weight = edges.iter().map(|e| e.weight).sum();
+        Self { edges, weight }
+    }
+
+    pub fn contains(&self, u: usize, v: usize) -> bool {
+        self.edges
+            .iter()
+            .any(|e| (e.from, e.to) == (u, v) || (e.from, e.to) == (v, u))
+    }
+}
+
+pub fn mst_kruskal<G: Graph>(g: &G) -> SpanningTree {
+    assert!(
+        !g.is_directed(),
+        "a spanning tree needs an undirected graph"
+    );
+    let edges = sorted_edges(g);
+    let in_tree = kruskal(g.vertex_count(), &edges);
+    SpanningTree::from_edges(
+        edges
+            .into_iter()
+            .zip(in_tree)
+            .filter_map(|(edge, taken)| taken.then_some(edge))
+            .collect(),
+    )
+}
+
+pub fn mst_prim<G, Q>(g: &G, r: usize, queue: &mut Q) -> SpanningTree
+where
+    G: Graph,
+    Q: MinQueue<Weight, usize>,
+{
+    assert!(
+        !g.is_directed(),
+        "a spanning tree needs an undirected graph"
+    );
+    assert!(r < g.vertex_count(), "root {} is not a vertex of G", r);
+    assert!(queue.is_empty(), "queue must start empty");
+
+    let mut key = vec![Weight::MAX; g.vertex_count()];
+    let mut pi = vec![None; g.vertex_count()];
+    key[r] = 0;
+    let handles: Vec<_> = g.vertices().map(|u| queue.insert(key[u], u)).collect();
+
+    while let Some((_, u)) = queue.extract_min() {
+        for (v, w) in g.neighbors(u) {
+            if queue.contains(handles[v]) && w < key[v] {
+                pi[v] = Some(u);
+                key[v] = w;
+                queue.decrease_key(handles[v], w);
+            }
+        }
+    }
+
+    SpanningTree::from_edges(
+        g.vertices()
+            .filter_map(|v| pi[v].map(|u| Edge::new(u, v, key[v])))
+            .collect(),
+    )
+}
+
+pub fn second_best_mst<G: Graph>(g: &G) -> Option<SpanningTree> {
+    assert!(
+        !g.is_directed(),
+        "a spanning tree needs an undirected graph"
+    );
+    let n = g.vertex_count();
+    let edges = sorted_edges(g);
+    let in_tree = kruskal(n, &edges);
+
+    let mut tree = AdjacencyList::undirected(n);
+    for (edge, _) in edges.iter().zip(&in_tree).filter(|(_, taken)| **taken) {
+        tree.add_weighted_edge(edge.from, edge.to, edge.weight);
+    }
+    let max = heaviest_tree_edges(&tree);
+
+    // (weight gained, nontree edge to add, tree edge to drop)
+    let (_, add, drop) = edges
+        .iter()
+        .zip(&in_tree)
+        .filter(|(edge, taken)| !**taken && edge.from != edge.to)
+        .filter_map(|(edge, _)| {
+            let heaviest = max[edge.from][edge.to]?;
+            Some((edge.weight - heaviest.weight, *edge, heaviest))
+        })
+        .min_by_key(|(gain, _, _)| *gain)?;
+
+    let mut result: Vec<Edge> = edges
+        .into_iter()
+        .zip(in_tree)
+        .filter(|(edge, taken)| *taken && !same_edge(edge, &drop))
+        .map(|(edge, _)| edge)
+        .collect();
+    result.push(add);
+    Some(SpanningTree::from_edges(result))
+}
+
+fn heaviest_tree_edges(tree: &AdjacencyList) -> Vec<Vec<Option<Edge>>> {
+    let n = tree.vertex_count();
+    let mut max: Vec<Vec<Option<Edge>>> = vec![vec![None; n]; n];
+    for u in tree.vertices() {
+        let mut visited = vec![false; n];
+        visited[u] = true;
+        let mut stack = vec![u];
+        while let Some(x) = stack.pop() {
+            for (y, w) in tree.neighbors(x) {
+                if visited[y] {
+                    continue;
+                }
+                visited[y] = true;
+                let edge = Edge::new(x, y, w);
+                max[u][y] = match max[u][x] {
+                    Some(heaviest) if heaviest.weight >= w => Some(heaviest),
+                    _ => Some(edge),
+                };
+                stack.push(y);
+            }
+        }
+    }
+    max
+}
+
+fn kruskal(n: usize, edges: &[Edge]) -> Vec<bool> {
+    let mut sets = DisjointSetForest::new();
+    for _ in 0..n {
+        sets.make_set();
+    }
+    edges
+        .iter()
+        .map(|edge| sets.union(edge.from, edge.to))
+        .collect()
+}
+
+fn sorted_edges<G: Graph>(g: &G) -> Vec<Edge> {
+    let mut edges = g.edges();
+    edges.sort_by_key(|e| e.weight);
+    edges
+}
+
+fn same_edge(a: &Edge, b: &Edge) -> bool {
+    a.weight == b.weight
+        && (a.from.min(a.to), a.from.max(a.to)) == (b.from.min(b.to), b.from.max(b.to))
+}
+
+pub(crate) fn describe_kruskal(g: &AdjacencyList, _: usize) -> String {
+    describe(g, mst_kruskal)
+}
+
+pub(crate) fn describe_prim(g: &AdjacencyList, r: usize) -> String {
+    describe(g, |g| mst_prim(g, r, &mut MinPriorityQueue::new()))
+}
+
+fn describe(g: &AdjacencyList, mst: impl Fn(&AdjacencyList) -> SpanningTree) -> String {
+    if g.is_directed() {
+        return "a spanning tree needs an undirected graph\n".to_string();
+    }
+    let tree = mst(g);
+    let mut text = String::new();
+    for edge in &tree.edges {
+        let _ = writeln!(
+            text,
+            "({}, {}): {}",
+            g.label(edge.from),
+            g.label(edge.to),
+            edge.weight
+        );
+    }
+    let _ = writeln!(text, "Total weight: {}", tree.weight);
+    text
+}
@@ -0,0 +1,84 @@
+use std::fmt::Write;
+
+use super::forest::DisjointSetForest;
+use super::union_find::DisjointSet;
+use crate::graph::adjacency_list::AdjacencyList;
+use crate::graph::representation::Graph;
+
+pub fn offline_lca<G: Graph, D: DisjointSet>(
+    tree: &G,
+    root: usize,
+    pairs: &[(usize, usize)],
+    sets: &mut D,
+) -> Vec<Option<usize>> {
+    assert!(sets.is_empty(), "offline LCA needs empty sets");
+    let n = tree.vertex_count();
+    assert!(root < n, "root {} is not a vertex of T", root);
+
+    let mut queries: Vec<Vec<(usize, usize)>> = vec![Vec::new(); n];
+    for (i, &(u, v)) in pairs.iter().enumerate() {
+        queries[u].push((v, i));
+        if u != v {
+            queries[v].push((u, i));
+        }
+    }
+
+    // Elements are handed out in visiting order, so they index 0..n too
+    let mut element = vec![None; n];
+    let mut ancestor = vec![root; n];
+    let mut black = vec![false; n];
+    let mut answers = vec![None; pairs.len()];
+    // (u, children of u, next child to visit)
+    let mut stack: Vec<(usize, Vec<usize>, usize)> = Vec::new();
+    let mut entering = Some(root);
+
+    loop {
+        if let Some(u) = entering.take() {
+            let x = sets.make_set();
+            element[u] = Some(x);
+            ancestor[sets.find_set(x)] = u;
+            stack.push((u, tree.neighbors(u).map(|(v, _)| v).collect(), 0));
+        }
+        let Some((u, children, next)) = stack.last_mut() else {
+            break;
+        };
+        let u = *u;
+        if let Some(&v) = children.get(*next) {
+            *next += 1;
+            if element[v].is_none() {
+                entering = Some(v);
+            }
+            continue;
+        }
+        stack.pop();
+
+        black[u] = true;
+        for &(v, i) in &queries[u] {
+            if let Some(y) = element[v].filter(|_| black[v]) {
+                answers[i] = Some(ancestor[sets.find_set(y)]);
+            }
+        }
+        if let Some(&(parent, _, _)) = stack.last() {
+            let x = element[parent].expect("vertices on the stack are visited");
+            let y = element[u].expect("vertices on the stack are visited");
+            sets.union(x, y);
+            ancestor[sets.find_set(x)] = parent;
+        }
+    }
+    answers
+}
+
+pub(crate) fn describe(g: &AdjacencyList, root: usize) -> String {
+    let pairs: Vec<(usize, usize)> = g
+        .vertices()
+        .flat_map(|u| g.vertices().filter(move |&v| u < v).map(move |v| (u, v)))
+        .collect();
+    let lca = offline_lca(g, root, &pairs, &mut DisjointSetForest::new());
+    let mut text = String::new();
+    for (&(u, v), w) in pairs.iter().zip(lca) {
+        if let Some(w) = w {
+            let _ = writeln!(text, "LCA({}, {}) = {}", g.label(u), g.label(v), g.label(w));
+        }
+    }
+    text
+}
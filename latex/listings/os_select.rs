@@ -0,0 +1,84 @@
+//! # Order-Statistic Trees
+//!
+//! A red-black tree whose nodes also store `x.size`, the number of nodes
+//! in the subtree of `x` (CLRS Section 17.1). With the sizes, the `i`th
+//! smallest key and the rank of a node are found in **O(lg n)** time.
+
+use std::fmt::{self, Write};
+
+use super::augment::Augmentation;
+use super::red_black::RedBlackTree;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Size(pub usize);
+
+impl<K, V> Augmentation<K, V> for Size {
+    fn compute(_key: &K, _value: &V, left: Option<&Self>, right: Option<&Self>) -> Self {
+        Size(left.map_or(0, |l| l.0) + right.map_or(0, |r| r.0) + 1)
+    }
+}
+
+impl fmt::Display for Size {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+pub type OrderStatisticTree<K, V> = RedBlackTree<K, V, Size>;
+
+impl<K, V> RedBlackTree<K, V, Size> {
+    pub fn os_select(&self, mut i: usize) -> Option<usize> {
+        let mut x = self.root()?;
+        loop {
+            let r = self.left_size(x) + 1;
+            if i == r {
+                return Some(x);
+            } else if i < r {
+                x = self.left(x)?;
+            } else {
+                i -= r;
+                x = self.right(x)?;
+            }
+        }
+    }
+
+    pub fn os_rank(&self, x: usize) -> usize {
+        let mut r = self.left_size(x) + 1;
+        let mut y = x;
+        while let Some(p) = self.parent(y) {
+            if self.right(p) == Some(y) {
+                r += self.left_size(p) + 1;
+            }
+            y = p;
+        }
+        r
+    }
+
+    pub fn size(&self, x: usize) -> usize {
+        self.augment(x).0
+    }
+
+    fn left_size(&self, x: usize) -> usize {
+        self.left(x).map_or(0, |l| self.size(l))
+    }
+}
+
+pub(crate) fn describe(keys: &[i64]) -> String {
+    let mut tree = OrderStatisticTree::augmented();
+    for &k in keys {
+        tree.insert(k, ());
+    }
+    let mut text = String::new();
+    for i in 1..=tree.len() {
+        let x = tree.os_select(i).expect("i is at most n");
+        let _ = writeln!(
+            text,
+            "OS-SELECT {}: {} (size {}, rank {})",
+            i,
+            tree.key(x),
+            tree.size(x),
+            tree.os_rank(x)
+        );
+    }
+    text
+}
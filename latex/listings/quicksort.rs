@@ -0,0 +1,267 @@
+use std::cmp::Ordering;
+
+use crate::random::RandomSource;
+use crate::trace::{NoopTracer, TraceEvent, Tracer};
+
+pub fn quicksort<T>(arr: &mut [T])
+where
+    T: Ord + Clone,
+{
+    quicksort_traced(arr, &mut NoopTracer);
+}
+
+pub fn quicksort_traced<T, R>(arr: &mut [T], tracer: &mut R)
+where
+    T: Ord + Clone,
+    R: Tracer<T> + ?Sized,
+{
+    if arr.len() > 1 {
+        sort_range(arr, 0, arr.len() - 1, &mut |_: &mut [T], _, _| {}, tracer);
+    }
+}
+
+pub fn partition<T>(arr: &mut [T], p: usize, r: usize) -> usize
+where
+    T: Ord + Clone,
+{
+    partition_traced(arr, p, r, &mut NoopTracer)
+}
+
+pub fn partition_traced<T, R>(arr: &mut [T], p: usize, r: usize, tracer: &mut R) -> usize
+where
+    T: Ord + Clone,
+    R: Tracer<T> + ?Sized,
+{
+    let x = arr[r].clone();
+    // `i` is one past the highest index of the low side (CLRS i + 1).
+    let mut i = p;
+    for j in p..r {
+        tracer.record(TraceEvent::Compare { i: j, j: r }, arr);
+        if arr[j] <= x {
+            arr.swap(i, j);
+            tracer.record(TraceEvent::Swap { i, j }, arr);
+            i += 1;
+        }
+    }
+    arr.swap(i, r);
+    tracer.record(TraceEvent::Swap { i, j: r }, arr);
+    i
+}
+
+pub fn hoare_partition<T>(arr: &mut [T], p: usize, r: usize) -> usize
+where
+    T: Ord + Clone,
+{
+    let x = arr[p].clone();
+    // `i` and `j` point at the next candidates, which avoids p - 1 underflowing.
+    let (mut i, mut j) = (p, r);
+    loop {
+        while arr[j] > x {
+            j -= 1;
+        }
+        while arr[i] < x {
+            i += 1;
+        }
+        if i < j {
+            arr.swap(i, j);
+            i += 1;
+            j -= 1;
+        } else {
+            return j;
+        }
+    }
+}
+
+pub fn quicksort_hoare<T>(arr: &mut [T])
+where
+    T: Ord + Clone,
+{
+    fn sort<T: Ord + Clone>(arr: &mut [T], mut p: usize, mut r: usize) {
+        while p < r {
+            let j = hoare_partition(arr, p, r);
+            if j - p < r - j {
+                sort(arr, p, j);
+                p = j + 1;
+            } else {
+                sort(arr, j + 1, r);
+                r = j;
+            }
+        }
+    }
+
+    if arr.len() > 1 {
+        sort(arr, 0, arr.len() - 1);
+    }
+}
+
+pub fn randomized_quicksort<T, G>(arr: &mut [T], rng: &mut G)
+where
+    T: Ord + Clone,
+    G: RandomSource,
+{
+    randomized_quicksort_traced(arr, rng, &mut NoopTracer);
+}
+
+pub fn randomized_quicksort_traced<T, G, R>(arr: &mut [T], rng: &mut G, tracer: &mut R)
+where
+    T: Ord + Clone,
+    G: RandomSource,
+    R: Tracer<T> + ?Sized,
+{
+    if arr.len() > 1 {
+        let mut choose = |a: &mut [T], p: usize, r: usize| {
+            let i = rng.gen_range(p, r + 1);
+            a.swap(r, i);
+        };
+        sort_range(arr, 0, arr.len() - 1, &mut choose, tracer);
+    }
+}
+
+pub fn median_of_three_partition<T>(arr: &mut [T], p: usize, r: usize) -> usize
+where
+    T: Ord + Clone,
+{
+    move_median_to_end(arr, p, r);
+    partition(arr, p, r)
+}
+
+pub fn quicksort_median_of_three<T>(arr: &mut [T])
+where
+    T: Ord + Clone,
+{
+    if arr.len() > 1 {
+        let mut choose = |a: &mut [T], p: usize, r: usize| move_median_to_end(a, p, r);
+        sort_range(arr, 0, arr.len() - 1, &mut choose, &mut NoopTracer);
+    }
+}
+
+pub fn partition_three_way<T>(arr: &mut [T], p: usize, r: usize) -> (usize, usize)
+where
+    T: Ord + Clone,
+{
+    partition_three_way_traced(arr, p, r, &mut NoopTracer)
+}
+
+pub fn partition_three_way_traced<T, R>(
+    arr: &mut [T],
+    p: usize,
+    r: usize,
+    tracer: &mut R,
+) -> (usize, usize)
+where
+    T: Ord + Clone,
+    R: Tracer<T> + ?Sized,
+{
+    let x = arr[r].clone();
+    tracer.record(
+        TraceEvent::SelectKey {
+            index: r,
+            key: x.clone(),
+        },
+        arr,
+    );
+    let (mut lt, mut i, mut gt) = (p, p, r);
+    // `gt` cannot underflow: until `i` passes a copy of the pivot,
+    // that copy still lies in A[i..=gt].
+    while i <= gt {
+        tracer.record(TraceEvent::CompareKey { index: i }, arr);
+        match arr[i].cmp(&x) {
+            Ordering::Less => {
+                arr.swap(lt, i);
+                tracer.record(TraceEvent::Swap { i: lt, j: i }, arr);
+                lt += 1;
+                i += 1;
+            }
+            Ordering::Greater => {
+                arr.swap(i, gt);
+                tracer.record(TraceEvent::Swap { i, j: gt }, arr);
+                gt -= 1;
+            }
+            Ordering::Equal => i += 1,
+        }
+    }
+    (lt, gt)
+}
+
+pub fn quicksort_three_way<T>(arr: &mut [T])
+where
+    T: Ord + Clone,
+{
+    fn sort<T: Ord + Clone>(arr: &mut [T], mut p: usize, mut r: usize) {
+        while p < r {
+            let (q, t) = partition_three_way(arr, p, r);
+            if q - p < r - t {
+                if q > p {
+                    sort(arr, p, q - 1);
+                }
+                p = t + 1;
+            } else {
+                sort(arr, t + 1, r);
+                if q == p {
+                    // Both sides are empty
+                    break;
+                }
+                r = q - 1;
+            }
+        }
+    }
+
+    if arr.len() > 1 {
+        sort(arr, 0, arr.len() - 1);
+    }
+}
+
+pub fn tail_recursive_quicksort<T>(arr: &mut [T])
+where
+    T: Ord + Clone,
+{
+    fn sort<T: Ord + Clone>(arr: &mut [T], mut p: usize, mut r: usize) {
+        while p < r {
+            let q = partition(arr, p, r);
+            if q - p < r - q {
+                if q > p {
+                    sort(arr, p, q - 1);
+                }
+                p = q + 1;
+            } else {
+                // Here q > p, so q - 1 cannot underflow.
+                sort(arr, q + 1, r);
+                r = q - 1;
+            }
+        }
+    }
+
+    if arr.len() > 1 {
+        sort(arr, 0, arr.len() - 1);
+    }
+}
+
+fn sort_range<T, C, R>(arr: &mut [T], p: usize, r: usize, choose_pivot: &mut C, tracer: &mut R)
+where
+    T: Ord + Clone,
+    C: FnMut(&mut [T], usize, usize),
+    R: Tracer<T> + ?Sized,
+{
+    if p < r {
+        choose_pivot(arr, p, r);
+        let q = partition_traced(arr, p, r, tracer);
+        if q > p {
+            sort_range(arr, p, q - 1, choose_pivot, tracer);
+        }
+        sort_range(arr, q + 1, r, choose_pivot, tracer);
+    }
+}
+
+fn move_median_to_end<T: Ord>(arr: &mut [T], p: usize, r: usize) {
+    let mid = p + (r - p) / 2;
+    if arr[mid] < arr[p] {
+        arr.swap(mid, p);
+    }
+    if arr[r] < arr[p] {
+        arr.swap(r, p);
+    }
+    // Now A[p] is the smallest of the three; the median is min(A[mid], A[r]).
+    if arr[mid] < arr[r] {
+        arr.swap(mid, r);
+    }
+}
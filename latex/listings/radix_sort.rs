@@ -0,0 +1,62 @@
+use super::counting_sort::{counting_sort, natural_keys};
+
+pub fn radix_sort<T>(arr: &mut [T], digit_bits: u32)
+where
+    T: Copy + Into<u64>,
+{
+    assert!(
+        (1..=16).contains(&digit_bits),
+        "digit size must be between 1 and 16 bits"
+    );
+    let key_bits = (size_of::<T>() * 8) as u32;
+    let mask = (1u64 << digit_bits) - 1;
+
+    let mut shift = 0;
+    while shift < key_bits {
+        let digit = |x: &T| (((*x).into() >> shift) & mask) as usize;
+        let sorted = counting_sort(arr, mask as usize, digit);
+        arr.copy_from_slice(&sorted);
+        shift += digit_bits;
+    }
+}
+
+pub fn radix_sort_bytes<S>(arr: &mut [S], digit_bytes: usize)
+where
+    S: AsRef<[u8]> + Clone,
+{
+    assert!(
+        digit_bytes == 1 || digit_bytes == 2,
+        "digit size must be 1 or 2 bytes"
+    );
+    let Some(width) = arr.first().map(|s| s.as_ref().len()) else {
+        return;
+    };
+    assert!(
+        arr.iter().all(|s| s.as_ref().len() == width),
+        "all strings must have the same width"
+    );
+    let k = (1usize << (8 * digit_bytes)) - 1;
+
+    // Digits are byte ranges [start, end), least significant (rightmost) first.
+    let mut end = width;
+    while end > 0 {
+        let start = end.saturating_sub(digit_bytes);
+        let digit = |s: &S| {
+            s.as_ref()[start..end]
+                .iter()
+                .fold(0usize, |acc, &b| (acc << 8) | b as usize)
+        };
+        let sorted = counting_sort(arr, k, digit);
+        arr.clone_from_slice(&sorted);
+        end = start;
+    }
+}
+
+pub(crate) fn sort_naturals(arr: &mut [i64]) -> Result<(), String> {
+    let mut keys: Vec<u64> = natural_keys(arr)?.map(|k| k as u64).collect();
+    radix_sort(&mut keys, 8);
+    for (slot, key) in arr.iter_mut().zip(keys) {
+        *slot = key as i64;
+    }
+    Ok(())
+}
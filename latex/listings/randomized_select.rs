@@ -0,0 +1,49 @@
+use crate::random::RandomSource;
+use crate::sorting::quicksort::partition_three_way_traced;
+use crate::trace::{NoopTracer, TraceEvent, Tracer};
+
+pub fn randomized_select<'a, T, G>(arr: &'a mut [T], i: usize, rng: &mut G) -> &'a T
+where
+    T: Ord + Clone,
+    G: RandomSource,
+{
+    randomized_select_traced(arr, i, rng, &mut NoopTracer)
+}
+
+pub fn randomized_select_traced<'a, T, G, R>(
+    arr: &'a mut [T],
+    i: usize,
+    rng: &mut G,
+    tracer: &mut R,
+) -> &'a T
+where
+    T: Ord + Clone,
+    G: RandomSource,
+    R: Tracer<T> + ?Sized,
+{
+    assert!(
+        (1..=arr.len()).contains(&i),
+        "rank {} is outside 1..={}",
+        i,
+        arr.len()
+    );
+    let (mut p, mut r, mut i) = (0, arr.len() - 1, i);
+
+    // Iterative form: each recursive call only narrows [p, r].
+    while p < r {
+        let pivot = rng.gen_range(p, r + 1);
+        arr.swap(r, pivot);
+        tracer.record(TraceEvent::Swap { i: r, j: pivot }, arr);
+        // A[p : lt - 1] < x, A[lt : gt] = x and A[gt + 1 : r] > x
+        let (lt, gt) = partition_three_way_traced(arr, p, r, tracer);
+        if i <= lt - p {
+            r = lt - 1;
+        } else if i <= gt - p + 1 {
+            return &arr[p + i - 1];
+        } else {
+            i -= gt - p + 1;
+            p = gt + 1;
+        }
+    }
+    &arr[p]
+}
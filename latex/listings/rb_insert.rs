@@ -0,0 +1,714 @@
+//! # Red-Black Trees
+//!
+//! Binary search trees with one extra bit per node, its color, kept so
+//! that no root-to-leaf path is more than twice as long as any other
+//! (CLRS Chapter 13). A tree of `n` nodes has height at most `2 lg(n + 1)`,
+//! so the dynamic-set operations take **O(lg n)** time.
+//!
+//! As in CLRS, one sentinel node `T.nil` stands for every leaf and for the
+//! parent of the root. Its parent pointer is scratch space: `RB-DELETE` sets
+//! it so that `RB-DELETE-FIXUP` can walk up from a leaf.
+
+use std::error::Error;
+use std::fmt::{self, Write};
+
+use super::augment::Augmentation;
+use crate::trace::NoopTracer;
+
+const NIL: usize = 0;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Color {
+    Red,
+    Black,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FixupCase {
+    Insert { case: u8, z: usize },
+    Delete { case: u8, w: usize },
+}
+
+pub trait FixupTracer<K, V, A = ()> {
+    fn record(&mut self, case: FixupCase, tree: &RedBlackTree<K, V, A>);
+}
+
+impl<K, V, A, F> FixupTracer<K, V, A> for F
+where
+    F: FnMut(&FixupCase, &RedBlackTree<K, V, A>),
+{
+    fn record(&mut self, case: FixupCase, tree: &RedBlackTree<K, V, A>) {
+        self(&case, tree)
+    }
+}
+
+impl<K, V, A> FixupTracer<K, V, A> for NoopTracer {
+    #[inline(always)]
+    fn record(&mut self, _case: FixupCase, _tree: &RedBlackTree<K, V, A>) {}
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Violation {
+    RedRoot,
+    RedLeaf,
+    RedChild { node: usize, child: usize },
+    BlackHeight {
+        node: usize,
+        left: usize,
+        right: usize,
+    },
+    Order { node: usize },
+    Parent { node: usize },
+}
+
+impl Violation {
+    pub fn property(&self) -> Option<u8> {
+        match self {
+            Violation::RedRoot => Some(2),
+            Violation::RedLeaf => Some(3),
+            Violation::RedChild { .. } => Some(4),
+            Violation::BlackHeight { .. } => Some(5),
+            Violation::Order { .. } | Violation::Parent { .. } => None,
+        }
+    }
+}
+
+impl fmt::Display for Violation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Violation::RedRoot => write!(f, "property 2 violated: the root is red"),
+            Violation::RedLeaf => write!(f, "property 3 violated: T.nil is red"),
+            Violation::RedChild { node, child } => write!(
+                f,
+                "property 4 violated: red node {} has a red child {}",
+                node, child
+            ),
+            Violation::BlackHeight { node, left, right } => write!(
+                f,
+                "property 5 violated: paths down from node {} hold {} black nodes on the left but {} on the right",
+                node, left, right
+            ),
+            Violation::Order { node } => write!(f, "node {} is out of search-tree order", node),
+            Violation::Parent { node } => {
+                write!(f, "node {} does not point to its parent", node)
+            }
+        }
+    }
+}
+
+impl Error for Violation {}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Side {
+    Left,
+    Right,
+}
+
+impl Side {
+    fn opposite(self) -> Side {
+        match self {
+            Side::Left => Side::Right,
+            Side::Right => Side::Left,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+struct Entry<K, V, A> {
+    key: K,
+    value: V,
+    augment: A,
+}
+
+#[derive(Debug, Clone)]
+struct Node<K, V, A> {
+    entry: Option<Entry<K, V, A>>,
+    color: Color,
+    parent: usize,
+    left: usize,
+    right: usize,
+}
+
+#[derive(Debug, Clone)]
+pub struct RedBlackTree<K, V, A = ()> {
+    nodes: Vec<Node<K, V, A>>,
+    root: usize,
+    len: usize,
+}
+
+impl<K, V> RedBlackTree<K, V> {
+    pub fn new() -> Self {
+        Self::augmented()
+    }
+}
+
+impl<K, V, A: Augmentation<K, V>> RedBlackTree<K, V, A> {
+    pub fn augmented() -> Self {
+        Self {
+            nodes: vec![Node {
+                entry: None,
+                color: Color::Black,
+                parent: NIL,
+                left: NIL,
+                right: NIL,
+            }],
+            root: NIL,
+            len: 0,
+        }
+    }
+
+    pub fn root(&self) -> Option<usize> {
+        real(self.root)
+    }
+
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    pub fn contains(&self, x: usize) -> bool {
+        self.nodes.get(x).is_some_and(|node| node.entry.is_some())
+    }
+
+    pub fn key(&self, x: usize) -> &K {
+        &self.entry(x).key
+    }
+
+    pub fn value(&self, x: usize) -> &V {
+        &self.entry(x).value
+    }
+
+    pub fn replace_value(&mut self, x: usize, value: V) -> V {
+        self.check_node(x);
+        let entry = self.nodes[x].entry.as_mut().expect("node is in the tree");
+        let old = std::mem::replace(&mut entry.value, value);
+        self.update_path(x);
+        old
+    }
+
+    pub fn augment(&self, x: usize) -> &A {
+        &self.entry(x).augment
+    }
+
+    pub fn color(&self, x: usize) -> Color {
+        self.check_node(x);
+        self.nodes[x].color
+    }
+
+    pub fn set_color(&mut self, x: usize, color: Color) {
+        self.check_node(x);
+        self.nodes[x].color = color;
+    }
+
+    pub fn parent(&self, x: usize) -> Option<usize> {
+        self.check_node(x);
+        real(self.nodes[x].parent)
+    }
+
+    pub fn left(&self, x: usize) -> Option<usize> {
+        self.check_node(x);
+        real(self.nodes[x].left)
+    }
+
+    pub fn right(&self, x: usize) -> Option<usize> {
+        self.check_node(x);
+        real(self.nodes[x].right)
+    }
+
+    pub fn minimum(&self, mut x: usize) -> usize {
+        while let Some(left) = self.left(x) {
+            x = left;
+        }
+        x
+    }
+
+    pub fn maximum(&self, mut x: usize) -> usize {
+        while let Some(right) = self.right(x) {
+            x = right;
+        }
+        x
+    }
+
+    pub fn successor(&self, mut x: usize) -> Option<usize> {
+        if let Some(right) = self.right(x) {
+            return Some(self.minimum(right));
+        }
+        let mut y = self.parent(x);
+        while let Some(p) = y
+            && self.right(p) == Some(x)
+        {
+            x = p;
+            y = self.parent(p);
+        }
+        y
+    }
+
+    pub fn predecessor(&self, mut x: usize) -> Option<usize> {
+        if let Some(left) = self.left(x) {
+            return Some(self.maximum(left));
+        }
+        let mut y = self.parent(x);
+        while let Some(p) = y
+            && self.left(p) == Some(x)
+        {
+            x = p;
+            y = self.parent(p);
+        }
+        y
+    }
+
+    pub fn inorder(&self) -> impl Iterator<Item = usize> + '_ {
+        std::iter::successors(self.root().map(|r| self.minimum(r)), |&x| self.successor(x))
+    }
+
+    pub fn height(&self) -> usize {
+        let mut height = 0;
+        let mut stack: Vec<(usize, usize)> = self.root().map(|r| (r, 0)).into_iter().collect();
+        while let Some((x, depth)) = stack.pop() {
+            height = height.max(depth);
+            for child in [self.left(x), self.right(x)].into_iter().flatten() {
+                stack.push((child, depth + 1));
+            }
+        }
+        height
+    }
+
+    pub fn black_height(&self, x: usize) -> usize {
+        self.check_node(x);
+        let mut bh = 0;
+        let mut y = self.nodes[x].left;
+        loop {
+            if self.nodes[y].color == Color::Black {
+                bh += 1;
+            }
+            if y == NIL {
+                return bh;
+            }
+            y = self.nodes[y].left;
+        }
+    }
+
+    pub fn delete(&mut self, z: usize) -> (K, V) {
+        self.delete_traced(z, &mut NoopTracer)
+    }
+
+    pub fn delete_traced<R>(&mut self, z: usize, tracer: &mut R) -> (K, V)
+    where
+        R: FixupTracer<K, V, A> + ?Sized,
+    {
+        self.check_node(z);
+        let (z_left, z_right) = (self.nodes[z].left, self.nodes[z].right);
+        let mut y_original_color = self.nodes[z].color;
+        let x;
+        if z_left == NIL {
+            x = z_right;
+            self.transplant(z, z_right);
+        } else if z_right == NIL {
+            x = z_left;
+            self.transplant(z, z_left);
+        } else {
+            let y = self.minimum(z_right);
+            y_original_color = self.nodes[y].color;
+            x = self.nodes[y].right;
+            if y != z_right {
+                self.transplant(y, x);
+                self.nodes[y].right = z_right;
+                self.nodes[z_right].parent = y;
+            } else {
+                // x may be T.nil, which then remembers y as its parent
+                self.nodes[x].parent = y;
+            }
+            self.transplant(z, y);
+            self.nodes[y].left = z_left;
+            self.nodes[z_left].parent = y;
+            self.nodes[y].color = self.nodes[z].color;
+        }
+        // Every node whose subtree changed lies on the path up from x.p
+        self.update_path(self.nodes[x].parent);
+        if y_original_color == Color::Black {
+            self.delete_fixup(x, tracer);
+        }
+        self.len -= 1;
+        let entry = self.nodes[z].entry.take().expect("node is in the tree");
+        (entry.key, entry.value)
+    }
+
+    fn transplant(&mut self, u: usize, v: usize) {
+        let parent = self.nodes[u].parent;
+        if parent == NIL {
+            self.root = v;
+        } else if u == self.nodes[parent].left {
+            self.nodes[parent].left = v;
+        } else {
+            self.nodes[parent].right = v;
+        }
+        self.nodes[v].parent = parent;
+    }
+
+    fn delete_fixup<R>(&mut self, mut x: usize, tracer: &mut R)
+    where
+        R: FixupTracer<K, V, A> + ?Sized,
+    {
+        while x != self.root && self.nodes[x].color == Color::Black {
+            let p = self.nodes[x].parent;
+            let side = if x == self.nodes[p].left {
+                Side::Left
+            } else {
+                Side::Right
+            };
+            let other = side.opposite();
+            let mut w = self.child(p, other);
+            if self.nodes[w].color == Color::Red {
+                tracer.record(FixupCase::Delete { case: 1, w }, self);
+                self.nodes[w].color = Color::Black;
+                self.nodes[p].color = Color::Red;
+                self.rotate(p, side);
+                w = self.child(p, other);
+            }
+            if self.is_black(self.child(w, side)) && self.is_black(self.child(w, other)) {
+                tracer.record(FixupCase::Delete { case: 2, w }, self);
+                self.nodes[w].color = Color::Red;
+                x = p;
+            } else {
+                if self.is_black(self.child(w, other)) {
+                    tracer.record(FixupCase::Delete { case: 3, w }, self);
+                    let near = self.child(w, side);
+                    self.nodes[near].color = Color::Black;
+                    self.nodes[w].color = Color::Red;
+                    self.rotate(w, other);
+                    w = self.child(p, other);
+                }
+                tracer.record(FixupCase::Delete { case: 4, w }, self);
+                self.nodes[w].color = self.nodes[p].color;
+                self.nodes[p].color = Color::Black;
+                let far = self.child(w, other);
+                self.nodes[far].color = Color::Black;
+                self.rotate(p, side);
+                x = self.root;
+            }
+        }
+        self.nodes[x].color = Color::Black;
+    }
+
+    fn rotate(&mut self, x: usize, side: Side) {
+        let other = side.opposite();
+        let y = self.child(x, other);
+        let inner = self.child(y, side);
+        self.set_child(x, other, inner);
+        if inner != NIL {
+            self.nodes[inner].parent = x;
+        }
+        let parent = self.nodes[x].parent;
+        self.nodes[y].parent = parent;
+        if parent == NIL {
+            self.root = y;
+        } else if x == self.nodes[parent].left {
+            self.nodes[parent].left = y;
+        } else {
+            self.nodes[parent].right = y;
+        }
+        self.set_child(y, side, x);
+        self.nodes[x].parent = y;
+        // Only x and y have new subtrees, and x is now below y
+        self.update(x);
+        self.update(y);
+    }
+
+    fn update(&mut self, x: usize) {
+        let node = &self.nodes[x];
+        let entry = node.entry.as_ref().expect("node is in the tree");
+        let augment = A::compute(
+            &entry.key,
+            &entry.value,
+            self.nodes[node.left].entry.as_ref().map(|e| &e.augment),
+            self.nodes[node.right].entry.as_ref().map(|e| &e.augment),
+        );
+        self.nodes[x]
+            .entry
+            .as_mut()
+            .expect("node is in the tree")
+            .augment = augment;
+    }
+
+    fn update_path(&mut self, mut x: usize) {
+        while x != NIL {
+            self.update(x);
+            x = self.nodes[x].parent;
+        }
+    }
+
+    fn child(&self, x: usize, side: Side) -> usize {
+        match side {
+            Side::Left => self.nodes[x].left,
+            Side::Right => self.nodes[x].right,
+        }
+    }
+
+    fn set_child(&mut self, x: usize, side: Side, child: usize) {
+        match side {
+            Side::Left => self.nodes[x].left = child,
+            Side::Right => self.nodes[x].right = child,
+        }
+    }
+
+    fn is_black(&self, x: usize) -> bool {
+        self.nodes[x].color == Color::Black
+    }
+
+    fn entry(&self, x: usize) -> &Entry<K, V, A> {
+        self.nodes
+            .get(x)
+            .and_then(|node| node.entry.as_ref())
+            .expect("node is in the tree")
+    }
+
+    fn check_node(&self, x: usize) {
+        assert!(self.contains(x), "node {} is not in the tree", x);
+    }
+}
+
+impl<K: Ord, V, A: Augmentation<K, V>> RedBlackTree<K, V, A> {
+    pub fn insert(&mut self, key: K, value: V) -> usize {
+        self.insert_traced(key, value, &mut NoopTracer)
+    }
+
+    pub fn insert_traced<R>(&mut self, key: K, value: V, tracer: &mut R) -> usize
+    where
+        R: FixupTracer<K, V, A> + ?Sized,
+    {
+        let mut y = NIL;
+        let mut x = self.root;
+        while x != NIL {
+            y = x;
+            x = if key < *self.key(x) {
+                self.nodes[x].left
+            } else {
+                self.nodes[x].right
+            };
+        }
+
+        let z = self.nodes.len();
+        if y == NIL {
+            self.root = z;
+        } else if key < *self.key(y) {
+            self.nodes[y].left = z;
+        } else {
+            self.nodes[y].right = z;
+        }
+        let augment = A::compute(&key, &value, None, None);
+        self.nodes.push(Node {
+            entry: Some(Entry {
+                key,
+                value,
+                augment,
+            }),
+            color: Color::Red,
+            parent: y,
+            left: NIL,
+            right: NIL,
+        });
+        self.len += 1;
+        self.update_path(y);
+        self.insert_fixup(z, tracer);
+        z
+    }
+
+    pub fn search(&self, k: &K) -> Option<usize> {
+        let mut x = self.root;
+        while x != NIL {
+            let key = self.key(x);
+            if k == key {
+                return Some(x);
+            }
+            x = if k < key {
+                self.nodes[x].left
+            } else {
+                self.nodes[x].right
+            };
+        }
+        None
+    }
+
+    pub fn check_invariants(&self) -> Result<usize, Violation> {
+        if let Some(root) = self.root()
+            && self.nodes[root].color == Color::Red
+        {
+            return Err(Violation::RedRoot);
+        }
+        if self.nodes[NIL].color == Color::Red {
+            return Err(Violation::RedLeaf);
+        }
+        if let Some(root) = self.root()
+            && self.nodes[root].parent != NIL
+        {
+            return Err(Violation::Parent { node: root });
+        }
+        let black_nodes = self.check_subtree(self.root)?;
+
+        let mut previous: Option<usize> = None;
+        for x in self.inorder() {
+            if let Some(p) = previous
+                && self.key(x) < self.key(p)
+            {
+                return Err(Violation::Order { node: x });
+            }
+            previous = Some(x);
+        }
+        Ok(black_nodes - usize::from(self.is_black(self.root)))
+    }
+
+    fn check_subtree(&self, x: usize) -> Result<usize, Violation> {
+        if x == NIL {
+            return Ok(1);
+        }
+        let node = &self.nodes[x];
+        for child in [node.left, node.right] {
+            if child == NIL {
+                continue;
+            }
+            if self.nodes[child].parent != x {
+                return Err(Violation::Parent { node: child });
+            }
+            if node.color == Color::Red && self.nodes[child].color == Color::Red {
+                return Err(Violation::RedChild { node: x, child });
+            }
+        }
+        let left = self.check_subtree(node.left)?;
+        let right = self.check_subtree(node.right)?;
+        if left != right {
+            return Err(Violation::BlackHeight {
+                node: x,
+                left,
+                right,
+            });
+        }
+        Ok(left + usize::from(node.color == Color::Black))
+    }
+
+    fn insert_fixup<R>(&mut self, mut z: usize, tracer: &mut R)
+    where
+        R: FixupTracer<K, V, A> + ?Sized,
+    {
+        while self.nodes[self.nodes[z].parent].color == Color::Red {
+            let p = self.nodes[z].parent;
+            let g = self.nodes[p].parent;
+            let side = if p == self.nodes[g].left {
+                Side::Left
+            } else {
+                Side::Right
+            };
+            let y = self.child(g, side.opposite());
+            if self.nodes[y].color == Color::Red {
+                tracer.record(FixupCase::Insert { case: 1, z }, self);
+                self.nodes[p].color = Color::Black;
+                self.nodes[y].color = Color::Black;
+                self.nodes[g].color = Color::Red;
+                z = g;
+            } else {
+                if z == self.child(p, side.opposite()) {
+                    tracer.record(FixupCase::Insert { case: 2, z }, self);
+                    z = p;
+                    self.rotate(z, side);
+                }
+                tracer.record(FixupCase::Insert { case: 3, z }, self);
+                let p = self.nodes[z].parent;
+                let g = self.nodes[p].parent;
+                self.nodes[p].color = Color::Black;
+                self.nodes[g].color = Color::Red;
+                self.rotate(g, side.opposite());
+            }
+        }
+        let root = self.root;
+        self.nodes[root].color = Color::Black;
+    }
+}
+
+impl<K, V, A: Augmentation<K, V>> Default for RedBlackTree<K, V, A> {
+    fn default() -> Self {
+        Self::augmented()
+    }
+}
+
+fn real(x: usize) -> Option<usize> {
+    (x != NIL).then_some(x)
+}
+
+pub(crate) fn describe_insert(keys: &[i64]) -> String {
+    let mut tree = RedBlackTree::new();
+    let mut text = String::new();
+    for &k in keys {
+        let mut cases = Vec::new();
+        tree.insert_traced(k, (), &mut |case: &FixupCase, _: &RedBlackTree<i64, ()>| {
+            cases.push(*case)
+        });
+        let _ = writeln!(text, "Insert {}: {}", k, fixup_cases(&cases));
+    }
+    text + &describe_tree(&tree)
+}
+
+pub(crate) fn describe_delete(keys: &[i64]) -> String {
+    let mut tree = RedBlackTree::new();
+    for &k in keys {
+        tree.insert(k, ());
+    }
+    let mut text = describe_tree(&tree);
+    for k in keys {
+        let Some(z) = tree.search(k) else {
+            continue;
+        };
+        let mut cases = Vec::new();
+        tree.delete_traced(z, &mut |case: &FixupCase, _: &RedBlackTree<i64, ()>| {
+            cases.push(*case)
+        });
+        let _ = writeln!(text, "Delete {}: {}", k, fixup_cases(&cases));
+    }
+    text
+}
+
+fn describe_tree(tree: &RedBlackTree<i64, ()>) -> String {
+    let mut text = String::new();
+    let key = |x: Option<usize>| x.map_or("NIL".to_string(), |x| tree.key(x).to_string());
+    let mut stack: Vec<usize> = tree.root().into_iter().collect();
+    while let Some(x) = stack.pop() {
+        let color = match tree.color(x) {
+            Color::Red => "red",
+            Color::Black => "black",
+        };
+        let _ = writeln!(
+            text,
+            "{} ({}): left {}, right {}",
+            tree.key(x),
+            color,
+            key(tree.left(x)),
+            key(tree.right(x))
+        );
+        stack.extend(tree.right(x));
+        stack.extend(tree.left(x));
+    }
+    match tree.check_invariants() {
+        Ok(bh) => {
+            let _ = writeln!(text, "Black-height: {}\nHeight: {}", bh, tree.height());
+        }
+        Err(violation) => {
+            let _ = writeln!(text, "{}", violation);
+        }
+    }
+    text
+}
+
+fn fixup_cases(cases: &[FixupCase]) -> String {
+    if cases.is_empty() {
+        return "no fixup".to_string();
+    }
+    let numbers: Vec<String> = cases
+        .iter()
+        .map(|case| match case {
+            FixupCase::Insert { case, .. } | FixupCase::Delete { case, .. } => case.to_string(),
+        })
+        .collect();
+    let label = if numbers.len() == 1 { "case" } else { "cases" };
+    format!("{} {}", label, numbers.join(" "))
+}
@@ -0,0 +1,162 @@
+//! # Push-Relabel
+//!
+//! Maximum flow by pushing excess along admissible arcs and relabeling
+//! vertices that have none (CLRS 3rd edition, Sections 26.4-26.5). Instead
+//! of a flow, these algorithms maintain a preflow, which may leave excess
+//! `u.e > 0` at a vertex; heights `u.h` decide where it goes. When no
+//! vertex but `s` and `t` has excess, the preflow is a maximum flow.
+
+use std::collections::VecDeque;
+
+use super::ford_fulkerson::check_terminals;
+use super::network::{FlowNetwork, describe_max_flow};
+use crate::graph::adjacency_list::AdjacencyList;
+use crate::graph::representation::{Graph, Weight};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PushRelabel {
+    pub value: Weight,
+    pub pushes: usize,
+    pub relabels: usize,
+}
+
+pub fn generic_push_relabel(net: &mut FlowNetwork, s: usize, t: usize) -> PushRelabel {
+    let mut state = Preflow::new(net, s, t);
+    let mut overflowing: VecDeque<usize> = net
+        .vertices()
+        .filter(|&v| state.is_overflowing(v))
+        .collect();
+
+    while let Some(&u) = overflowing.front() {
+        let admissible = net
+            .arcs(u)
+            .iter()
+            .copied()
+            .find(|&arc| state.is_admissible(net, arc));
+        match admissible {
+            Some(arc) => {
+                let v = net.head(arc);
+                let was_overflowing = state.is_overflowing(v);
+                state.push(net, arc);
+                if !was_overflowing && state.is_overflowing(v) {
+                    overflowing.push_back(v);
+                }
+            }
+            None => state.relabel(net, u),
+        }
+        if !state.is_overflowing(u) {
+            overflowing.pop_front();
+        }
+    }
+    state.finish(net)
+}
+
+pub fn relabel_to_front(net: &mut FlowNetwork, s: usize, t: usize) -> PushRelabel {
+    let mut state = Preflow::new(net, s, t);
+    let mut list: Vec<usize> = net.vertices().filter(|&v| v != s && v != t).collect();
+    // u.current as a position in net.arcs(u)
+    let mut current = vec![0; net.vertex_count()];
+
+    let mut i = 0;
+    while i < list.len() {
+        let u = list[i];
+        let old_height = state.height[u];
+        state.discharge(net, u, &mut current[u]);
+        if state.height[u] > old_height {
+            list[..=i].rotate_right(1);
+            i = 0;
+        }
+        i += 1;
+    }
+    state.finish(net)
+}
+
+struct Preflow {
+    s: usize,
+    t: usize,
+    height: Vec<usize>,
+    excess: Vec<Weight>,
+    pushes: usize,
+    relabels: usize,
+}
+
+impl Preflow {
+    fn new(net: &mut FlowNetwork, s: usize, t: usize) -> Self {
+        check_terminals(net, s, t);
+        net.clear_flow();
+        let n = net.vertex_count();
+        let mut state = Self {
+            s,
+            t,
+            height: vec![0; n],
+            excess: vec![0; n],
+            pushes: 0,
+            relabels: 0,
+        };
+        state.height[s] = n;
+        for arc in net.arcs(s).to_vec() {
+            let c = net.residual_capacity(arc);
+            if arc.is_multiple_of(2) && c > 0 {
+                net.push(arc, c);
+                state.excess[net.head(arc)] += c;
+                state.excess[s] -= c;
+            }
+        }
+        state
+    }
+
+    fn is_overflowing(&self, u: usize) -> bool {
+        u != self.s && u != self.t && self.excess[u] > 0
+    }
+
+    fn is_admissible(&self, net: &FlowNetwork, arc: usize) -> bool {
+        net.residual_capacity(arc) > 0
+            && self.height[net.tail(arc)] == self.height[net.head(arc)] + 1
+    }
+
+    fn push(&mut self, net: &mut FlowNetwork, arc: usize) {
+        let (u, v) = (net.tail(arc), net.head(arc));
+        let delta = self.excess[u].min(net.residual_capacity(arc));
+        net.push(arc, delta);
+        self.excess[u] -= delta;
+        self.excess[v] += delta;
+        self.pushes += 1;
+    }
+
+    fn relabel(&mut self, net: &FlowNetwork, u: usize) {
+        let lowest = net
+            .arcs(u)
+            .iter()
+            .filter(|&&arc| net.residual_capacity(arc) > 0)
+            .map(|&arc| self.height[net.head(arc)])
+            .min()
+            .expect("an overflowing vertex has a residual arc");
+        self.height[u] = lowest + 1;
+        self.relabels += 1;
+    }
+
+    fn discharge(&mut self, net: &mut FlowNetwork, u: usize, current: &mut usize) {
+        while self.excess[u] > 0 {
+            match net.arcs(u).get(*current).copied() {
+                None => {
+                    self.relabel(net, u);
+                    *current = 0;
+                }
+                Some(arc) if self.is_admissible(net, arc) => self.push(net, arc),
+                Some(_) => *current += 1,
+            }
+        }
+    }
+
+    fn finish(self, net: &FlowNetwork) -> PushRelabel {
+        PushRelabel {
+            value: net.value(self.s),
+            pushes: self.pushes,
+            relabels: self.relabels,
+        }
+    }
+}
+
+pub(crate) fn describe(g: &AdjacencyList, s: usize) -> String {
+    describe_max_flow(g, s, |net, s, t| relabel_to_front(net, s, t).value)
+}
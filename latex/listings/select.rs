@@ -0,0 +1,81 @@
+use crate::sorting::quicksort::partition_three_way_traced;
+use crate::trace::{NoopTracer, TraceEvent, Tracer};
+
+pub fn select<T>(arr: &mut [T], i: usize) -> &T
+where
+    T: Ord + Clone,
+{
+    select_traced(arr, i, &mut NoopTracer)
+}
+
+pub fn select_traced<'a, T, R>(arr: &'a mut [T], i: usize, tracer: &mut R) -> &'a T
+where
+    T: Ord + Clone,
+    R: Tracer<T> + ?Sized,
+{
+    assert!(
+        (1..=arr.len()).contains(&i),
+        "rank {} is outside 1..={}",
+        i,
+        arr.len()
+    );
+    let q = select_index(arr, 0, arr.len() - 1, i, tracer);
+    &arr[q]
+}
+
+fn select_index<T, R>(arr: &mut [T], mut p: usize, r: usize, mut i: usize, tracer: &mut R) -> usize
+where
+    T: Ord + Clone,
+    R: Tracer<T> + ?Sized,
+{
+    while !(r - p + 1).is_multiple_of(5) {
+        // Put the minimum into A[p].
+        for j in p + 1..=r {
+            tracer.record(TraceEvent::Compare { i: p, j }, arr);
+            if arr[p] > arr[j] {
+                arr.swap(p, j);
+                tracer.record(TraceEvent::Swap { i: p, j }, arr);
+            }
+        }
+        if i == 1 {
+            return p;
+        }
+        p += 1;
+        i -= 1;
+    }
+
+    // Sort each group <A[j], A[j + g], ..., A[j + 4g]> with insertion sort.
+    let g = (r - p + 1) / 5;
+    for j in p..p + g {
+        for a in 1..5 {
+            let mut b = a;
+            while b > 0 {
+                let (lo, hi) = (j + (b - 1) * g, j + b * g);
+                tracer.record(TraceEvent::Compare { i: lo, j: hi }, arr);
+                if arr[lo] <= arr[hi] {
+                    break;
+                }
+                arr.swap(lo, hi);
+                tracer.record(TraceEvent::Swap { i: lo, j: hi }, arr);
+                b -= 1;
+            }
+        }
+    }
+
+    // Median of the group medians, which lie in A[p + 2g : p + 3g - 1].
+    let x = select_index(arr, p + 2 * g, p + 3 * g - 1, g.div_ceil(2), tracer);
+
+    // PARTITION-AROUND(A, p, r, x): move the pivot to A[r], then split
+    // A[p : r] into the keys < x, = x and > x.
+    arr.swap(x, r);
+    tracer.record(TraceEvent::Swap { i: x, j: r }, arr);
+    let (lt, gt) = partition_three_way_traced(arr, p, r, tracer);
+
+    if i <= lt - p {
+        select_index(arr, p, lt - 1, i, tracer)
+    } else if i <= gt - p + 1 {
+        p + i - 1
+    } else {
+        select_index(arr, gt + 1, r, i - (gt - p + 1), tracer)
+    }
+}
@@ -0,0 +1,187 @@
+use std::fmt::Write;
+
+use super::adjacency_list::AdjacencyList;
+use super::dfs::{dfs, dfs_in_order};
+use super::representation::{Graph, labels};
+
+pub fn strongly_connected_components<G: Graph>(g: &G) -> Vec<Vec<usize>> {
+    let first = dfs(g);
+    let transpose = AdjacencyList::from_graph(g).transpose();
+    let second = dfs_in_order(&transpose, &first.by_decreasing_finish());
+
+    // Parents are discovered before their children; times are distinct
+    let mut by_discovery = vec![None; 2 * g.vertex_count() + 1];
+    for v in g.vertices() {
+        by_discovery[second.d[v]] = Some(v);
+    }
+    let mut component_of = vec![0; g.vertex_count()];
+    let mut count = 0;
+    for v in by_discovery.into_iter().flatten() {
+        component_of[v] = match second.pi[v] {
+            Some(p) => component_of[p],
+            None => {
+                count += 1;
+                count - 1
+            }
+        };
+    }
+
+    let mut components = vec![Vec::new(); count];
+    for v in g.vertices() {
+        components[component_of[v]].push(v);
+    }
+    components
+}
+
+pub fn tarjan_scc<G: Graph>(g: &G) -> Vec<Vec<usize>> {
+    let n = g.vertex_count();
+    let mut search = Tarjan {
+        d: vec![0; n],
+        low: vec![0; n],
+        on_stack: vec![false; n],
+        stack: Vec::new(),
+        time: 0,
+        components: Vec::new(),
+    };
+    for root in g.vertices() {
+        if search.d[root] == 0 {
+            search.visit(g, root);
+        }
+    }
+    search.components
+}
+
+struct Tarjan {
+    d: Vec<usize>,
+    low: Vec<usize>,
+    on_stack: Vec<bool>,
+    stack: Vec<usize>,
+    time: usize,
+    components: Vec<Vec<usize>>,
+}
+
+struct Frame {
+    u: usize,
+    neighbors: Vec<usize>,
+    next: usize,
+}
+
+impl Tarjan {
+    fn visit<G: Graph>(&mut self, g: &G, root: usize) {
+        let mut frames = vec![self.discover(g, root)];
+
+        while let Some(frame) = frames.last_mut() {
+            let u = frame.u;
+            if let Some(&v) = frame.neighbors.get(frame.next) {
+                frame.next += 1;
+                if self.d[v] == 0 {
+                    frames.push(self.discover(g, v));
+                } else if self.on_stack[v] {
+                    self.low[u] = self.low[u].min(self.d[v]);
+                }
+                continue;
+            }
+
+            frames.pop();
+            if let Some(parent) = frames.last() {
+                self.low[parent.u] = self.low[parent.u].min(self.low[u]);
+            }
+            if self.low[u] == self.d[u] {
+                let mut component = Vec::new();
+                while let Some(v) = self.stack.pop() {
+                    self.on_stack[v] = false;
+                    component.push(v);
+                    if v == u {
+                        break;
+                    }
+                }
+                component.sort_unstable();
+                self.components.push(component);
+            }
+        }
+    }
+
+    fn discover<G: Graph>(&mut self, g: &G, u: usize) -> Frame {
+        self.time += 1;
+        self.d[u] = self.time;
+        self.low[u] = self.time;
+        self.stack.push(u);
+        self.on_stack[u] = true;
+        Frame {
+            u,
+            neighbors: g.neighbors(u).map(|(v, _)| v).collect(),
+            next: 0,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ComponentGraph {
+    pub components: Vec<Vec<usize>>,
+    pub component_of: Vec<usize>,
+    pub graph: AdjacencyList,
+}
+
+pub fn component_graph<G: Graph>(g: &G) -> ComponentGraph {
+    let components = strongly_connected_components(g);
+    let mut component_of = vec![0; g.vertex_count()];
+    for (i, component) in components.iter().enumerate() {
+        for &v in component {
+            component_of[v] = i;
+        }
+    }
+
+    let mut graph = AdjacencyList::directed(components.len());
+    for (i, component) in components.iter().enumerate() {
+        let labels: Vec<&str> = component.iter().map(|&v| g.label(v)).collect();
+        let separator = if labels.iter().all(|l| l.chars().count() == 1) {
+            ""
+        } else {
+            ","
+        };
+        graph.set_label(i, &labels.join(separator));
+    }
+
+    let mut seen = vec![usize::MAX; components.len()];
+    for (i, component) in components.iter().enumerate() {
+        for &u in component {
+            for (v, _) in g.neighbors(u) {
+                let j = component_of[v];
+                if j != i && seen[j] != i {
+                    seen[j] = i;
+                    graph.add_edge(i, j);
+                }
+            }
+        }
+    }
+
+    ComponentGraph {
+        components,
+        component_of,
+        graph,
+    }
+}
+
+pub(crate) fn describe_component_graph(g: &AdjacencyList, _: usize) -> String {
+    let scc = component_graph(g);
+    let mut text = String::new();
+    for component in &scc.components {
+        let _ = writeln!(text, "{{{}}}", labels(g, component));
+    }
+    let edges: Vec<String> = scc
+        .graph
+        .edges()
+        .iter()
+        .map(|e| format!("({}, {})", scc.graph.label(e.from), scc.graph.label(e.to)))
+        .collect();
+    let _ = writeln!(text, "Component graph: {}", edges.join(" "));
+    text
+}
+
+pub(crate) fn describe_tarjan(g: &AdjacencyList, _: usize) -> String {
+    let mut text = String::new();
+    for component in tarjan_scc(g) {
+        let _ = writeln!(text, "{{{}}}", labels(g, &component));
+    }
+    text
+}
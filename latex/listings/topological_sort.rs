@@ -0,0 +1,57 @@
+use std::error::Error;
+use std::fmt;
+
+use super::adjacency_list::AdjacencyList;
+use super::dfs::{EdgeClass, dfs};
+use super::representation::{Graph, labels};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Cycle {
+    pub vertices: Vec<usize>,
+}
+
+impl fmt::Display for Cycle {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let vertices: Vec<String> = self.vertices.iter().map(|v| v.to_string()).collect();
+        write!(f, "graph has a cycle through {}", vertices.join(" -> "))
+    }
+}
+
+impl Error for Cycle {}
+
+pub fn topological_sort<G: Graph>(g: &G) -> Result<Vec<usize>, Cycle> {
+    assert!(g.is_directed(), "topological sort needs a directed graph");
+
+    let result = dfs(g);
+    let back_edge = result
+        .edges
+        .iter()
+        .find(|(_, class)| *class == EdgeClass::Back);
+    if let Some((edge, _)) = back_edge {
+        // v is an ancestor of u: walk up the tree from u to v
+        let mut vertices = vec![edge.from];
+        let mut current = edge.from;
+        while current != edge.to {
+            current = result.pi[current].expect("back edge leads to an ancestor");
+            vertices.push(current);
+        }
+        vertices.reverse();
+        return Err(Cycle { vertices });
+    }
+
+    Ok(result.by_decreasing_finish())
+}
+
+pub(crate) fn describe(g: &AdjacencyList, _: usize) -> String {
+    if !g.is_directed() {
+        return "topological sort needs a directed graph\n".to_string();
+    }
+    match topological_sort(g) {
+        Ok(order) => format!("Order: {}\n", labels(g, &order)),
+        Err(cycle) => format!(
+            "Not acyclic, cycle: {} {}\n",
+            labels(g, &cycle.vertices),
+            g.label(cycle.vertices[0])
+        ),
+    }
+}
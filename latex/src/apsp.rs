@@ -0,0 +1,71 @@
+use std::fmt::Write;
+
+use algorithms::graph::apsp::{AllPairs, DistanceMatrix, PredecessorMatrix};
+use algorithms::graph::representation::Graph;
+
+use crate::generate::escape_latex_text;
+
+/// # Distance Matrix
+///
+/// `matrix` as a parenthesized LaTeX array for math mode, `\infty` for
+/// missing paths. Entries that differ from `previous` are set in bold.
+pub fn distance_matrix(matrix: &DistanceMatrix, previous: Option<&DistanceMatrix>) -> String {
+    matrix_array(matrix.len(), |i, j| {
+        let entry = matrix[i][j].map_or("\\infty".to_string(), |w| w.to_string());
+        if previous.is_some_and(|p| p[i][j] != matrix[i][j]) {
+            format!("\\mathbf{{{}}}", entry)
+        } else {
+            entry
+        }
+    })
+}
+
+/// # Predecessor Matrix
+///
+/// `Π` as a parenthesized LaTeX array for math mode, with vertex labels
+/// and NIL for missing predecessors.
+pub fn predecessor_matrix<G: Graph>(g: &G, pi: &PredecessorMatrix) -> String {
+    matrix_array(pi.len(), |i, j| match pi[i][j] {
+        Some(p) => format!("\\text{{{}}}", escape_latex_text(g.label(p))),
+        None => "\\textsc{nil}".to_string(),
+    })
+}
+
+/// # Floyd-Warshall Tables
+///
+/// The sequence `D^(0), ..., D^(n)` next to `Π^(0), ..., Π^(n)` in the
+/// style of CLRS Figure 23.4, one pair per line, with the entries that
+/// step `k` improved in bold.
+pub fn floyd_warshall_tables<G: Graph>(g: &G, steps: &[AllPairs]) -> String {
+    let mut tex = String::new();
+    for (k, step) in steps.iter().enumerate() {
+        let previous = k.checked_sub(1).map(|p| &steps[p].d);
+        let _ = writeln!(
+            tex,
+            "$D^{{({k})}} = {}$ \\qquad $\\Pi^{{({k})}} = {}$",
+            distance_matrix(&step.d, previous),
+            predecessor_matrix(g, &step.pi)
+        );
+        tex.push_str(if k + 1 < steps.len() {
+            "\\par\\medskip\n"
+        } else {
+            "\n"
+        });
+    }
+    tex
+}
+
+/// `n × n` array in parentheses with the given entries
+fn matrix_array(n: usize, entry: impl Fn(usize, usize) -> String) -> String {
+    let mut tex = String::new();
+    let _ = write!(tex, "\\left(\\begin{{array}}{{{}}}", "r".repeat(n));
+    for i in 0..n {
+        let row: Vec<String> = (0..n).map(|j| entry(i, j)).collect();
+        let _ = write!(tex, " {}", row.join(" & "));
+        if i + 1 < n {
+            tex.push_str(" \\\\");
+        }
+    }
+    tex.push_str(" \\end{array}\\right)");
+    tex
+}
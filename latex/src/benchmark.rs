@@ -0,0 +1,105 @@
+use std::fmt::Write;
+
+/// Plot colors per compared implementation
+const COLORS: [&str; 4] = ["blue", "red", "teal", "orange"];
+
+/// Cost of every compared implementation as a parameter of the input grows
+#[derive(Debug, Clone)]
+pub struct Benchmark {
+    /// What the rows vary, e.g. "sparse, $|E| = 4|V|$"
+    pub title: String,
+    /// The parameter of the rows in math mode, e.g. "$n$"
+    pub parameter: String,
+    /// Names of the implementations, one column each
+    pub names: Vec<String>,
+    /// `(parameter, cost per implementation)`
+    pub rows: Vec<(f64, Vec<f64>)>,
+}
+
+/// # Benchmark Table
+///
+/// One row per parameter value and one column per implementation, for
+/// every benchmark side by side. The benchmarks share the parameter of the
+/// first one.
+pub fn benchmark_table(benchmarks: &[Benchmark]) -> String {
+    let mut tex = String::new();
+    let columns: String = benchmarks
+        .iter()
+        .map(|b| format!("|{}", "r".repeat(b.names.len())))
+        .collect();
+    let _ = writeln!(tex, "\\begin{{tabular}}{{r{}}}", columns);
+    let parameter = benchmarks.first().map_or("$n$", |b| b.parameter.as_str());
+    let _ = write!(tex, "\\hline\n{}", parameter);
+    for b in benchmarks {
+        let _ = write!(
+            tex,
+            " & \\multicolumn{{{}}}{{c}}{{{}}}",
+            b.names.len(),
+            b.title
+        );
+    }
+    tex.push_str(" \\\\\n");
+    for b in benchmarks {
+        for name in &b.names {
+            let _ = write!(tex, " & {}", name);
+        }
+    }
+    tex.push_str(" \\\\\n\\hline\n");
+
+    let rows = benchmarks.iter().map(|b| b.rows.len()).max().unwrap_or(0);
+    for row in 0..rows {
+        let n = benchmarks
+            .iter()
+            .find_map(|b| b.rows.get(row).map(|(n, _)| *n))
+            .unwrap_or_default();
+        let _ = write!(tex, "{}", n);
+        for b in benchmarks {
+            match b.rows.get(row) {
+                Some((_, costs)) => {
+                    for cost in costs {
+                        let _ = write!(tex, " & {}", cost);
+                    }
+                }
+                None => tex.push_str(&" & ".repeat(b.names.len())),
+            }
+        }
+        tex.push_str(" \\\\\n");
+    }
+    tex.push_str("\\hline\n\\end{tabular}\n");
+    tex
+}
+
+/// # Benchmark Plot
+///
+/// pgfplots chart of the cost of every implementation versus the parameter.
+pub fn benchmark_plot(benchmark: &Benchmark, ylabel: &str) -> String {
+    let mut tex = String::new();
+    tex.push_str("\\begin{tikzpicture}\n");
+    tex.push_str("\\begin{axis}[\n");
+    tex.push_str("  width=0.48\\textwidth, height=6cm,\n");
+    let _ = writeln!(
+        tex,
+        "  title={{{}}}, xlabel={{{}}}, ylabel={{{}}},",
+        benchmark.title, benchmark.parameter, ylabel
+    );
+    tex.push_str("  legend pos=north west, legend cell align=left,\n");
+    tex.push_str("  legend style={font=\\scriptsize}, scaled ticks=false, grid=major\n");
+    tex.push_str("]\n");
+
+    for (i, (name, color)) in benchmark
+        .names
+        .iter()
+        .zip(COLORS.iter().cycle())
+        .enumerate()
+    {
+        let _ = write!(tex, "\\addplot[mark=*, color={}] coordinates {{", color);
+        for (n, costs) in &benchmark.rows {
+            let _ = write!(tex, " ({},{})", n, costs[i]);
+        }
+        tex.push_str(" };\n");
+        let _ = writeln!(tex, "\\addlegendentry{{{}}}", name);
+    }
+
+    tex.push_str("\\end{axis}\n\\end{tikzpicture}\n");
+    tex
+}
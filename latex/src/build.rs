@@ -1,6 +1,6 @@
 use std::error::Error;
-use std::process::Command;
 use std::path::Path;
+use std::process::Command;
 
 /// Simple standalone builder (optional)
 pub fn build_report() -> Result<(), Box<dyn Error>> {
@@ -0,0 +1,172 @@
+use std::fmt::Write;
+
+use algorithms::analysis::{Counts, InputCase};
+
+/// Plot colors per input case (best, average, worst)
+const COLORS: [&str; 3] = ["teal", "blue", "red"];
+
+/// Operation counts of one input case over growing input sizes
+#[derive(Debug, Clone)]
+pub struct Series {
+    pub case: InputCase,
+    pub points: Vec<(usize, Counts)>,
+}
+
+/// Fitted curve `count ≈ coefficient · n^exponent`
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PowerFit {
+    pub coefficient: f64,
+    pub exponent: f64,
+}
+
+impl Series {
+    /// Fit a power law to the comparison counts of this series
+    pub fn comparison_fit(&self) -> Option<PowerFit> {
+        let points: Vec<(f64, f64)> = self
+            .points
+            .iter()
+            .map(|(n, c)| (*n as f64, c.comparisons as f64))
+            .collect();
+        fit_power_law(&points)
+    }
+}
+
+/// # Power-Law Fit
+///
+/// Least-squares line through `(ln n, ln y)`, i.e. the best `y ≈ a · n^k`.
+/// Points with a non-positive coordinate are ignored; at least two
+/// distinct sizes are required.
+pub fn fit_power_law(points: &[(f64, f64)]) -> Option<PowerFit> {
+    let logs: Vec<(f64, f64)> = points
+        .iter()
+        .filter(|(x, y)| *x > 0.0 && *y > 0.0)
+        .map(|(x, y)| (x.ln(), y.ln()))
+        .collect();
+    let m = logs.len() as f64;
+    if logs.len() < 2 {
+        return None;
+    }
+
+    let mean_x = logs.iter().map(|(x, _)| x).sum::<f64>() / m;
+    let mean_y = logs.iter().map(|(_, y)| y).sum::<f64>() / m;
+    let sxx: f64 = logs.iter().map(|(x, _)| (x - mean_x).powi(2)).sum();
+    let sxy: f64 = logs.iter().map(|(x, y)| (x - mean_x) * (y - mean_y)).sum();
+    if sxx == 0.0 {
+        return None;
+    }
+
+    let exponent = sxy / sxx;
+    let coefficient = (mean_y - exponent * mean_x).exp();
+    Some(PowerFit {
+        coefficient,
+        exponent,
+    })
+}
+
+/// # Complexity Table
+///
+/// One row per input size with comparisons, comparisons per element and
+/// writes for every case, followed by the fitted exponent of the comparison
+/// counts. A linear algorithm keeps a steady number of comparisons per
+/// element.
+pub fn complexity_table(series: &[Series]) -> String {
+    let mut tex = String::new();
+    let _ = writeln!(
+        tex,
+        "\\begin{{tabular}}{{r{}}}",
+        "|rrr".repeat(series.len())
+    );
+    tex.push_str("\\hline\n");
+
+    let _ = write!(tex, "$n$");
+    for s in series {
+        let _ = write!(tex, " & \\multicolumn{{3}}{{c}}{{{}}}", s.case.name());
+    }
+    tex.push_str(" \\\\\n");
+    for _ in series {
+        tex.push_str(" & cmp & cmp$/n$ & writes");
+    }
+    tex.push_str(" \\\\\n\\hline\n");
+
+    let rows = series.iter().map(|s| s.points.len()).max().unwrap_or(0);
+    for row in 0..rows {
+        let n = series
+            .iter()
+            .find_map(|s| s.points.get(row).map(|(n, _)| *n))
+            .unwrap_or_default();
+        let _ = write!(tex, "{}", n);
+        for s in series {
+            match s.points.get(row) {
+                Some((n, c)) => {
+                    let per_element = c.comparisons as f64 / (*n).max(1) as f64;
+                    let _ = write!(
+                        tex,
+                        " & {} & {:.2} & {}",
+                        c.comparisons, per_element, c.writes
+                    );
+                }
+                None => tex.push_str(" & & & "),
+            }
+        }
+        tex.push_str(" \\\\\n");
+    }
+
+    tex.push_str("\\hline\nfit");
+    for s in series {
+        match s.comparison_fit() {
+            Some(fit) => {
+                let _ = write!(tex, " & $n^{{{:.2}}}$ & & ", fit.exponent);
+            }
+            None => tex.push_str(" & -- & & "),
+        }
+    }
+    tex.push_str(" \\\\\n\\hline\n\\end{tabular}\n");
+    tex
+}
+
+/// # Complexity Plot
+///
+/// pgfplots chart of comparison counts versus `n`: measured points as marks,
+/// the fitted power law of every case as a dashed curve.
+pub fn complexity_plot(series: &[Series]) -> String {
+    let mut tex = String::new();
+    tex.push_str("\\begin{tikzpicture}\n");
+    tex.push_str("\\begin{axis}[\n");
+    tex.push_str("  width=0.9\\textwidth, height=7cm,\n");
+    tex.push_str("  xlabel={$n$}, ylabel={comparisons},\n");
+    tex.push_str("  legend pos=north west, legend cell align=left,\n");
+    tex.push_str("  scaled ticks=false, grid=major\n");
+    tex.push_str("]\n");
+
+    for s in series {
+        let color = COLORS[s.case as usize];
+        let _ = write!(
+            tex,
+            "\\addplot[only marks, mark=*, color={}] coordinates {{",
+            color
+        );
+        for (n, c) in &s.points {
+            let _ = write!(tex, " ({},{})", n, c.comparisons);
+        }
+        tex.push_str(" };\n");
+        let _ = writeln!(tex, "\\addlegendentry{{{}}}", s.case.name());
+
+        let min = s.points.iter().map(|(n, _)| *n).min();
+        let max = s.points.iter().map(|(n, _)| *n).max();
+        if let (Some(fit), Some(min), Some(max)) = (s.comparison_fit(), min, max) {
+            let _ = writeln!(
+                tex,
+                "\\addplot[domain={}:{}, samples=50, dashed, color={}] {{{:.4}*x^{:.4}}};",
+                min, max, color, fit.coefficient, fit.exponent
+            );
+            let _ = writeln!(
+                tex,
+                "\\addlegendentry{{${:.2}\\,n^{{{:.2}}}$}}",
+                fit.coefficient, fit.exponent
+            );
+        }
+    }
+
+    tex.push_str("\\end{axis}\n\\end{tikzpicture}\n");
+    tex
+}
@@ -5,14 +5,83 @@ use std::io::{BufRead, BufReader, Write};
 use std::path::{Path, PathBuf};
 use std::process::Command;
 
+use algorithms::analysis::{Counts, InputCase, random_connected_graph};
+use algorithms::disjoint_set::forest::DisjointSetForest;
+use algorithms::disjoint_set::linked_list::DisjointSetList;
+use algorithms::disjoint_set::union_find::DisjointSet;
+use algorithms::dp::lcs::lcs_length;
+use algorithms::graph::adjacency_list::AdjacencyList;
+use algorithms::graph::apsp::floyd_warshall_steps;
+use algorithms::graph::bfs::bfs;
+use algorithms::graph::dfs::dfs;
+use algorithms::graph::flow::ford_fulkerson::{Augmentation, edmonds_karp_traced};
+use algorithms::graph::flow::network::FlowNetwork;
+use algorithms::graph::mst::mst_prim;
+use algorithms::graph::parse::parse_graph;
+use algorithms::graph::representation::{Graph, Weight};
+use algorithms::graph::sssp::{
+    PathEvent, ShortestPaths, bellman_ford_traced, dag_shortest_paths_traced, dijkstra_traced,
+};
+use algorithms::hashing::chained::ChainedHashTable;
+use algorithms::hashing::hash_function::{HashFunction, Universal};
+use algorithms::hashing::open_addressing::{
+    DoubleHashing, LinearProbing, OpenAddressTable, ProbeSequence, QuadraticProbing,
+};
+use algorithms::hashing::stats::ProbeStats;
+use algorithms::heap::fibonacci_heap::FibonacciHeap;
+use algorithms::heap::min_queue::{ArrayQueue, MinQueue};
+use algorithms::heap::priority_queue::MinPriorityQueue;
+use algorithms::random::{RandomSource, XorShift64};
+use algorithms::registry::{self, Algorithm};
+use algorithms::trace::{Snapshot, SnapshotCollector};
+use algorithms::trees::bst::BinarySearchTree;
+use algorithms::trees::order_statistic::OrderStatisticTree;
+use algorithms::trees::red_black::{FixupCase, RedBlackTree};
 use serde::Deserialize;
 use tempfile::TempDir;
-use walkdir::WalkDir;
+
+use crate::apsp::floyd_warshall_tables;
+use crate::benchmark::{Benchmark, benchmark_plot, benchmark_table};
+use crate::complexity::{Series, complexity_plot, complexity_table};
+use crate::graph::{
+    bfs_figure, dfs_figure, max_flow_figure, parenthesis_figure, shortest_paths_figure,
+};
+use crate::lcs::lcs_table_figure;
+use crate::tikz::array_trace_figure;
+use crate::tree::{augmented_figure, red_black_figure, search_tree_figure};
 
 /// Struct representing the YAML report file
 #[derive(Debug, Deserialize)]
 struct Report {
     chapters: Vec<Chapter>,
+    #[serde(default)]
+    exercises: Vec<Exercise>,
+    #[serde(default)]
+    analysis: Analysis,
+}
+
+/// Settings for the empirical complexity experiments
+#[derive(Debug, Deserialize)]
+struct Analysis {
+    sizes: Vec<usize>,
+    seed: u64,
+    /// Inputs per size whose operation counts are averaged
+    #[serde(default = "one_trial")]
+    trials: u64,
+}
+
+impl Default for Analysis {
+    fn default() -> Self {
+        Self {
+            sizes: vec![10, 20, 40, 80, 160, 320],
+            seed: 42,
+            trials: one_trial(),
+        }
+    }
+}
+
+fn one_trial() -> u64 {
+    1
 }
 
 /// Single algorithm entry in the YAML
@@ -21,6 +90,37 @@ struct Chapter {
     id: String,
     title: String,
     pseudocode: String,
+    /// Optional input to illustrate step by step (CLRS Figure 2.2 style)
+    #[serde(default)]
+    illustrate: Option<Vec<i64>>,
+    /// Optional pair of strings to illustrate an LCS table (CLRS Figure 14.8 style)
+    #[serde(default)]
+    sequences: Option<Vec<String>>,
+    /// Optional graph (edge list or DOT) to illustrate a graph search (CLRS Figures 20.3, 20.4)
+    #[serde(default)]
+    graph: Option<String>,
+    /// Label of the source vertex in `graph`, its first vertex by default
+    #[serde(default)]
+    source: Option<String>,
+    /// Whether to benchmark alternative implementations (e.g. Prim's queues)
+    #[serde(default)]
+    benchmark: bool,
+    /// Optional keys to insert into a search tree (CLRS Figure 12.2 style)
+    #[serde(default)]
+    keys: Option<Vec<i64>>,
+    /// Input sizes of the complexity experiment, instead of `analysis.sizes`
+    #[serde(default)]
+    sizes: Option<Vec<usize>>,
+}
+
+/// Single exercise entry in the YAML
+#[derive(Debug, Deserialize)]
+struct Exercise {
+    id: String,
+    title: String,
+    /// Optional written answer or proof (raw LaTeX)
+    #[serde(default)]
+    answer: Option<String>,
 }
 
 /// Public entry point for generating LaTeX chapters and building the PDF.
@@ -31,6 +131,7 @@ pub fn generate_clrs_doc() -> Result<(), Box<dyn Error>> {
     let listings_dir = base_dir.join("listings");
     let generated_dir = base_dir.join("generated");
     let algorithms_dir = Path::new("algorithms/src");
+    let exercises_dir = Path::new("exercises");
 
     // Ensure directories exist
     fs::create_dir_all(&listings_dir)?;
@@ -55,61 +156,160 @@ pub fn generate_clrs_doc() -> Result<(), Box<dyn Error>> {
     // Process all chapters
     for chapter in &report.chapters {
         let id = &chapter.id;
-        let title = &chapter.title;
+        // The title is typeset in captions and as the chapter heading
+        let title = &escape_latex_text(&chapter.title);
         let pseudocode = chapter.pseudocode.trim_end();
 
-        // Locate Rust source file
-        let rust_file_path = find_rust_file(&algorithms_dir, id)?;
+        // Look up the algorithm and its Rust source file
+        let algorithm =
+            registry::find(id).ok_or_else(|| format!("algorithm '{}' is not registered", id))?;
+        let rust_file_path = algorithms_dir.join(algorithm.source);
         let target_file = listings_dir.join(format!("{}.rs", id));
 
         // Copy + sanitize Rust file (filter out doc comments)
-        let file = File::open(&rust_file_path)?;
-        let reader = BufReader::new(file);
-        let mut clean_content = String::new();
-
-        for line in reader.lines() {
-            let line = line?;
-            if !line.trim_start().starts_with("///") {
-                clean_content.push_str(&line);
-                clean_content.push('\n');
-            }
-        }
-
-        let replacements = [
-            ("²", "^2"),
-            ("•", "\\\\textbullet{}"),
-            ("–", "-"),
-            ("—", "--"),
-            ("…", "..."),
-            ("“", "\""),
-            ("”", "\""),
-            ("’", "'"),
-            ("©", "(c)"),
-            ("\r\n", "\n"),
-        ];
-        for (from, to) in &replacements {
-            clean_content = clean_content.replace(from, to);
-        }
-        fs::write(&target_file, clean_content.as_bytes())?;
+        copy_sanitized_listing(&rust_file_path, &target_file)?;
 
         // Write pseudocode file (with escaping)
         let pseudo_file = generated_dir.join(format!("{}_pseudo.tex", id));
         println!("--- writing pseudocode to {:?}", pseudo_file);
-        let sanitized = pseudocode
-            .replace('%', "\\%")
-            .replace('$', "\\$")
-            .replace('#', "\\#")
-            .replace('&', "\\&")
-            .replace('_', "\\_")
-            .replace('{', "\\{")
-            .replace('}', "\\}");
-        fs::write(&pseudo_file, sanitized)?;
+        fs::write(&pseudo_file, escape_pseudocode(pseudocode))?;
         println!("✅ written: {}", pseudo_file.exists());
 
+        // Write illustration figure (if requested)
+        if let Some(input) = &chapter.illustrate {
+            let snapshots = trace_algorithm(algorithm, input, report.analysis.seed)?;
+            let figure_file = generated_dir.join(format!("{}_figure.tex", id));
+            let mut figure = array_trace_figure(input, &snapshots);
+            let values: Vec<String> = input.iter().map(|v| v.to_string()).collect();
+            figure.push_str(&format!(
+                "\\caption{{The operation of {} on the array $A = \\langle {} \\rangle$.}}\n",
+                title,
+                values.join(", ")
+            ));
+            fs::write(&figure_file, figure)?;
+        }
+
+        // Write LCS table figure (if requested)
+        if let Some(sequences) = &chapter.sequences {
+            let [x, y] = sequences.as_slice() else {
+                return Err(format!("'{}' needs exactly two sequences", id).into());
+            };
+            let xs: Vec<char> = x.chars().collect();
+            let ys: Vec<char> = y.chars().collect();
+            let figure_file = generated_dir.join(format!("{}_figure.tex", id));
+            let mut figure = lcs_table_figure(&xs, &ys, &lcs_length(&xs, &ys));
+            figure.push_str(&format!(
+                "\\caption{{The $c$ and $b$ tables computed by LCS-LENGTH on $X = \\langle {} \\rangle$ and $Y = \\langle {} \\rangle$.}}\n",
+                escape_latex_text(&comma_separated(&xs)),
+                escape_latex_text(&comma_separated(&ys))
+            ));
+            fs::write(&figure_file, figure)?;
+        }
+
+        // Write graph search figure (if requested)
+        if let Some(text) = &chapter.graph {
+            let g = parse_graph(text)?;
+            let source = match &chapter.source {
+                Some(label) => g
+                    .vertex_named(label)
+                    .ok_or_else(|| format!("'{}' has no vertex named '{}'", id, label))?,
+                None => 0,
+            };
+            let figure_file = generated_dir.join(format!("{}_figure.tex", id));
+            fs::write(&figure_file, graph_figure(id, title, &g, source)?)?;
+        }
+
+        // Write search tree figure (if requested)
+        if let Some(keys) = &chapter.keys {
+            let figure_file = generated_dir.join(format!("{}_figure.tex", id));
+            fs::write(&figure_file, tree_figure(id, title, keys)?)?;
+        }
+
+        // Write benchmark of alternative implementations (if requested)
+        if chapter.benchmark {
+            let (benchmarks, description, ylabel) = match id.as_str() {
+                "mst_prim" => (
+                    prim_queue_benchmarks(&report.analysis),
+                    "Key comparisons made by the priority queue.",
+                    "comparisons",
+                ),
+                "linear_probing" => (
+                    hashing_benchmarks(&report.analysis),
+                    "Mean probes per search after inserting $n = \\alpha m$ random keys \
+                     with $m = 1021$, or $m = 1024$ for quadratic probing.",
+                    "probes per search",
+                ),
+                "connected_components" => (
+                    disjoint_set_benchmarks(&report.analysis),
+                    "Pointers followed or changed by $n$ MAKE-SETs, $n - 1$ UNIONs and $2n$ FIND-SETs.",
+                    "pointer operations",
+                ),
+                _ => return Err(format!("no benchmark for '{}'", id).into()),
+            };
+            let benchmark_file = generated_dir.join(format!("{}_benchmark.tex", id));
+            let mut content = String::new();
+            content.push_str(description);
+            content.push_str("\n\n\\begin{center}\n");
+            content.push_str(&benchmark_table(&benchmarks));
+            content.push_str("\\end{center}\n\n\\begin{center}\n");
+            for benchmark in &benchmarks {
+                content.push_str(&benchmark_plot(benchmark, ylabel));
+                content.push_str("\\hfill\n");
+            }
+            content.push_str("\\end{center}\n");
+            fs::write(&benchmark_file, content)?;
+        }
+
+        // Write empirical complexity table and chart (if the algorithm is instrumented)
+        let sizes = chapter.sizes.as_ref().unwrap_or(&report.analysis.sizes);
+        if let Some(series) = measure_algorithm(algorithm, sizes, &report.analysis) {
+            let complexity_file = generated_dir.join(format!("{}_complexity.tex", id));
+            let mut content = String::new();
+            content.push_str("\\begin{center}\n");
+            content.push_str(&complexity_table(&series));
+            content.push_str("\\end{center}\n\n\\begin{center}\n");
+            content.push_str(&complexity_plot(&series));
+            content.push_str("\\end{center}\n");
+            fs::write(&complexity_file, content)?;
+        }
+
         // Write chapter entry
         writeln!(tex_out, "\\AlgorithmSection{{{}}}{{{}}}", id, title)?;
     }
 
+    // Create exercises.tex
+    let exercises_file = generated_dir.join("exercises.tex");
+    let mut ex_out = File::create(&exercises_file)?;
+
+    // Process all exercises
+    for exercise in &report.exercises {
+        let id = &exercise.id;
+        let (number, statement) = split_exercise_title(&exercise.title);
+
+        // Locate the exercise crate and copy its solution
+        let main_file = find_exercise_file(exercises_dir, id)?;
+        let target_file = listings_dir.join(format!("{}.rs", id));
+        copy_sanitized_listing(&main_file, &target_file)?;
+
+        // Problem statement (plain text, escaped)
+        let statement_file = generated_dir.join(format!("{}_statement.tex", id));
+        fs::write(&statement_file, escape_latex_text(statement))?;
+
+        // Optional answer / proof (raw LaTeX)
+        if let Some(answer) = &exercise.answer {
+            let answer_file = generated_dir.join(format!("{}_answer.tex", id));
+            fs::write(&answer_file, answer.trim_end())?;
+        }
+
+        // Write exercise entry
+        writeln!(
+            ex_out,
+            "\\ExerciseSection{{{}}}{{{}}}",
+            id,
+            escape_latex_text(number)
+        )?;
+    }
+
     // Run LaTeX (2 passes) inside a temporary build directory
     let build_dir = TempDir::new()?;
     let main_tex = base_dir.join("main.tex");
@@ -124,14 +324,555 @@ pub fn generate_clrs_doc() -> Result<(), Box<dyn Error>> {
     Ok(())
 }
 
-/// Search recursively for .rs file matching the algorithm ID
-fn find_rust_file(base_dir: &Path, id: &str) -> Result<PathBuf, Box<dyn Error>> {
-    for entry in WalkDir::new(base_dir).into_iter().filter_map(|e| e.ok()) {
-        if entry.file_name().to_str() == Some(&format!("{}.rs", id)) {
-            return Ok(entry.path().to_path_buf());
+/// Run a registered algorithm on `input` and record its trace
+fn trace_algorithm(
+    algorithm: &Algorithm,
+    input: &[i64],
+    seed: u64,
+) -> Result<Vec<Snapshot<i64>>, Box<dyn Error>> {
+    let mut data = input.to_vec();
+    let mut collector = SnapshotCollector::new();
+    if !algorithm.run_on_array_traced(&mut data, &mut XorShift64::new(seed), &mut collector) {
+        return Err(format!("'{}' does not run on an integer array", algorithm.id).into());
+    }
+    Ok(collector.into_snapshots())
+}
+
+/// Draw the search `id` on `g` from `source`, with caption
+fn graph_figure(
+    id: &str,
+    title: &str,
+    g: &AdjacencyList,
+    source: usize,
+) -> Result<String, Box<dyn Error>> {
+    let mut figure = match id {
+        "bfs" => bfs_figure(g, &bfs(g, source)),
+        "dfs" => {
+            let result = dfs(g);
+            let mut figure = dfs_figure(g, &result);
+            figure.push_str("\\par\\medskip\n");
+            figure.push_str(&parenthesis_figure(g, &result));
+            figure
+        }
+        "bellman_ford" | "dag_shortest_paths" | "dijkstra" => {
+            let mut steps: Vec<(PathEvent, ShortestPaths)> = Vec::new();
+            let mut record = |event: &PathEvent, paths: &ShortestPaths| {
+                steps.push((*event, paths.clone()));
+            };
+            match id {
+                "bellman_ford" => {
+                    // A negative-weight cycle is drawn like any other result
+                    let _ = bellman_ford_traced(g, source, &mut record);
+                }
+                "dag_shortest_paths" => {
+                    dag_shortest_paths_traced(g, source, &mut record)?;
+                }
+                _ => {
+                    dijkstra_traced(g, source, &mut MinPriorityQueue::new(), &mut record);
+                }
+            }
+            shortest_paths_figure(g, &steps)
+        }
+        "floyd_warshall" => floyd_warshall_tables(g, &floyd_warshall_steps(g)),
+        "edmonds_karp" => {
+            if !g.is_directed() || g.edges().iter().any(|e| e.weight < 0) {
+                return Err("a flow network needs a directed graph with capacities".into());
+            }
+            let sink = flow_sink(g);
+            let mut net = FlowNetwork::from_graph(g);
+            let mut residuals = Vec::new();
+            let result = edmonds_karp_traced(
+                &mut net,
+                source,
+                sink,
+                &mut |_: &Augmentation, net: &FlowNetwork| residuals.push(net.residual_network()),
+            );
+            max_flow_figure(&net, &result, &residuals)
+        }
+        _ => return Err(format!("no graph figure for '{}'", id).into()),
+    };
+    let from = if matches!(
+        id,
+        "bfs" | "bellman_ford" | "dag_shortest_paths" | "dijkstra"
+    ) {
+        format!(" from ${}$", escape_latex_text(g.label(source)))
+    } else if id == "edmonds_karp" {
+        format!(
+            " from ${}$ to ${}$",
+            escape_latex_text(g.label(source)),
+            escape_latex_text(g.label(flow_sink(g)))
+        )
+    } else {
+        String::new()
+    };
+    figure.push_str(&format!(
+        "\\caption{{The operation of {}{} on a graph with {} vertices and {} edges.}}\n",
+        title,
+        from,
+        g.vertex_count(),
+        g.edge_count()
+    ));
+    Ok(figure)
+}
+
+/// Draw the tree `id` builds from `keys`, with caption
+fn tree_figure(id: &str, title: &str, keys: &[i64]) -> Result<String, Box<dyn Error>> {
+    let values: Vec<String> = keys.iter().map(|k| k.to_string()).collect();
+    let built = format!(
+        "the tree built by inserting the keys $\\langle {} \\rangle$",
+        values.join(", ")
+    );
+    let (mut figure, caption) = match id {
+        "inorder_tree_walk" | "tree_insert" | "tree_delete" => {
+            let mut tree = BinarySearchTree::new();
+            for &k in keys {
+                tree.insert(k, ());
+            }
+            let mut figure = search_tree_figure(&tree, 0, "");
+            if id == "tree_delete" {
+                // Delete the root up to three times
+                for panel in 1..=3 {
+                    let Some(root) = tree.root() else {
+                        break;
+                    };
+                    let (k, ()) = tree.delete(root);
+                    figure.push_str("\\hfill\n");
+                    figure.push_str(&search_tree_figure(&tree, panel, &format!("delete {}", k)));
+                }
+            }
+            (figure, format!("{} on {}", title, built))
+        }
+        "rb_insert" => {
+            let Some((&last, rest)) = keys.split_last() else {
+                return Err(format!("'{}' needs at least one key", id).into());
+            };
+            let mut tree = RedBlackTree::new();
+            for &k in rest {
+                tree.insert(k, ());
+            }
+            let mut panels = Vec::new();
+            tree.insert_traced(
+                last,
+                (),
+                &mut |case: &FixupCase, tree: &RedBlackTree<i64, ()>| {
+                    if let FixupCase::Insert { case, z } = *case {
+                        panels.push(red_black_figure(
+                            tree,
+                            Some(z),
+                            panels.len(),
+                            &format!("case {}", case),
+                        ));
+                    }
+                },
+            );
+            panels.push(red_black_figure(&tree, None, panels.len(), ""));
+            let values: Vec<String> = rest.iter().map(|k| k.to_string()).collect();
+            let caption = format!(
+                "{} of {} into the tree built by inserting the keys $\\langle {} \\rangle$, \
+                 before every case of RB-INSERT-FIXUP at the outlined node $z$",
+                title,
+                last,
+                values.join(", ")
+            );
+            (panels.join("\\hfill\n"), caption)
+        }
+        "rb_delete" => {
+            let Some(&first) = keys.first() else {
+                return Err(format!("'{}' needs at least one key", id).into());
+            };
+            let mut tree = RedBlackTree::new();
+            for &k in keys {
+                tree.insert(k, ());
+            }
+            let mut panels = Vec::new();
+            let z = tree.search(&first).expect("the key was inserted");
+            tree.delete_traced(z, &mut |case: &FixupCase, tree: &RedBlackTree<i64, ()>| {
+                if let FixupCase::Delete { case, w } = *case {
+                    panels.push(red_black_figure(
+                        tree,
+                        Some(w),
+                        panels.len(),
+                        &format!("case {}", case),
+                    ));
+                }
+            });
+            panels.push(red_black_figure(&tree, None, panels.len(), ""));
+            let caption = format!(
+                "{} of {} from {}, before every case of RB-DELETE-FIXUP with the outlined sibling $w$",
+                title, first, built
+            );
+            (panels.join("\\hfill\n"), caption)
+        }
+        "os_select" => {
+            let mut tree = OrderStatisticTree::augmented();
+            for &k in keys {
+                tree.insert(k, ());
+            }
+            // The lower median
+            let i = tree.len().div_ceil(2);
+            let x = tree.os_select(i);
+            let figure = augmented_figure(&tree, x, 0, "");
+            let caption = format!(
+                "{} on {}, with $x.size$ below every node and the outlined node found by OS-SELECT(T.root, {})",
+                title, built, i
+            );
+            (figure, caption)
+        }
+        _ => return Err(format!("no tree figure for '{}'", id).into()),
+    };
+    figure.push_str(&format!("\\caption{{{}.}}\n", caption));
+    Ok(figure)
+}
+
+/// The vertex labeled `t`, or the last vertex if there is none
+fn flow_sink(g: &AdjacencyList) -> usize {
+    g.vertex_named("t")
+        .unwrap_or(g.vertex_count().saturating_sub(1))
+}
+
+/// Run best/average/worst experiments over `sizes`, leaving out the cases
+/// the algorithm has no inputs for
+fn measure_algorithm(
+    algorithm: &Algorithm,
+    sizes: &[usize],
+    analysis: &Analysis,
+) -> Option<Vec<Series>> {
+    let mut rng = XorShift64::new(analysis.seed);
+    let mut all = Vec::new();
+    for case in InputCase::ALL {
+        let points: Option<Vec<_>> = sizes
+            .iter()
+            .map(|&n| {
+                Some((
+                    n,
+                    mean_counts(algorithm, case, n, analysis.trials, &mut rng)?,
+                ))
+            })
+            .collect();
+        if let Some(points) = points {
+            all.push(Series { case, points });
+        }
+    }
+    (!all.is_empty()).then_some(all)
+}
+
+/// Operation counts of `case` on inputs of size `n`, averaged over `trials`
+/// inputs drawn one after another from `rng`
+fn mean_counts(
+    algorithm: &Algorithm,
+    case: InputCase,
+    n: usize,
+    trials: u64,
+    rng: &mut XorShift64,
+) -> Option<Counts> {
+    let trials = trials.max(1);
+    let mut total = Counts::default();
+    for _ in 0..trials {
+        let counts = algorithm.count_operations(case, n, rng)?;
+        total.comparisons += counts.comparisons;
+        total.writes += counts.writes;
+    }
+    Some(Counts {
+        comparisons: total.comparisons / trials,
+        writes: total.writes / trials,
+    })
+}
+
+/// Key comparisons of MST-PRIM with each queue on sparse and dense graphs
+fn prim_queue_benchmarks(analysis: &Analysis) -> Vec<Benchmark> {
+    let names = ["binary heap", "Fibonacci heap", "array"].map(String::from);
+    // (title, dense); a dense graph asks for more edges than fit, i.e. all
+    let densities = [
+        ("sparse, $|E| = 4|V|$", false),
+        ("dense, $|E| = |V|(|V| - 1)/2$", true),
+    ];
+    densities
+        .iter()
+        .map(|&(title, dense)| {
+            let mut rng = XorShift64::new(analysis.seed);
+            let rows = analysis
+                .sizes
+                .iter()
+                .map(|&n| {
+                    let g =
+                        random_connected_graph(n, if dense { n * n } else { 4 * n }, 100, &mut rng);
+                    let costs = vec![
+                        prim_comparisons(&g, MinPriorityQueue::new()),
+                        prim_comparisons(&g, FibonacciHeap::new()),
+                        prim_comparisons(&g, ArrayQueue::new()),
+                    ];
+                    (n as f64, costs.into_iter().map(|c| c as f64).collect())
+                })
+                .collect();
+            Benchmark {
+                title: title.to_string(),
+                parameter: "$n$".to_string(),
+                names: names.to_vec(),
+                rows,
+            }
+        })
+        .collect()
+}
+
+/// Key comparisons made by `queue` during MST-PRIM on `g`
+fn prim_comparisons<Q: MinQueue<Weight, usize>>(g: &AdjacencyList, mut queue: Q) -> u64 {
+    mst_prim(g, 0, &mut queue);
+    queue.comparisons()
+}
+
+/// Pointer operations of both disjoint-set representations when the sets
+/// are joined in random order and when sets of equal size are joined
+fn disjoint_set_benchmarks(analysis: &Analysis) -> Vec<Benchmark> {
+    let names = ["linked lists", "forest"].map(String::from);
+    [("random unions", true), ("balanced unions", false)]
+        .iter()
+        .map(|&(title, random)| {
+            let mut rng = XorShift64::new(analysis.seed);
+            let rows = analysis
+                .sizes
+                .iter()
+                .map(|&n| {
+                    let unions = if random {
+                        (1..n).map(|i| (rng.gen_range(0, i), i)).collect()
+                    } else {
+                        balanced_unions(n)
+                    };
+                    let costs = vec![
+                        disjoint_set_pointer_ops(n, &unions, DisjointSetList::new()),
+                        disjoint_set_pointer_ops(n, &unions, DisjointSetForest::new()),
+                    ];
+                    (n as f64, costs.into_iter().map(|c| c as f64).collect())
+                })
+                .collect();
+            Benchmark {
+                title: title.to_string(),
+                parameter: "$n$".to_string(),
+                names: names.to_vec(),
+                rows,
+            }
+        })
+        .collect()
+}
+
+/// Pairs joining `0..n` like the rounds of a knockout tournament: sets of
+/// size 1, then 2, 4, ...
+fn balanced_unions(n: usize) -> Vec<(usize, usize)> {
+    let mut unions = Vec::new();
+    let mut step = 1;
+    while step < n {
+        for i in (0..n - step).step_by(2 * step) {
+            unions.push((i, i + step));
+        }
+        step *= 2;
+    }
+    unions
+}
+
+/// Pointer operations of `n` MAKE-SETs, a UNION per pair in `unions` and a
+/// FIND-SET of every element before and after
+fn disjoint_set_pointer_ops<D: DisjointSet>(
+    n: usize,
+    unions: &[(usize, usize)],
+    mut sets: D,
+) -> u64 {
+    for _ in 0..n {
+        sets.make_set();
+    }
+    for x in 0..n {
+        sets.find_set(x);
+    }
+    for &(x, y) in unions {
+        sets.union(x, y);
+    }
+    for x in 0..n {
+        sets.find_set(x);
+    }
+    sets.pointer_ops()
+}
+
+/// Mean probes of unsuccessful and successful searches in every kind of
+/// hash table as the load factor grows
+fn hashing_benchmarks(analysis: &Analysis) -> Vec<Benchmark> {
+    const LOAD_FACTORS: [f64; 6] = [0.1, 0.25, 0.5, 0.75, 0.9, 0.95];
+    const SEARCHES: usize = 1000;
+    let names = [
+        "chaining",
+        "linear probing",
+        "quadratic probing",
+        "double hashing",
+    ]
+    .map(String::from);
+    let mut rows = [Vec::new(), Vec::new()];
+    for alpha in LOAD_FACTORS {
+        let mut rng = XorShift64::new(analysis.seed);
+        let mut stats = Vec::new();
+        for method in 0..names.len() {
+            let m = if method == 2 { 1024 } else { 1021 };
+            let keys: Vec<u64> = (0..(alpha * m as f64).round() as usize)
+                .map(|_| rng.next_u64())
+                .collect();
+            let misses: Vec<u64> = (0..SEARCHES).map(|_| rng.next_u64()).collect();
+            let h = Universal::random(m, &mut rng);
+            stats.push(match method {
+                0 => chained_probes(ChainedHashTable::new(h), &keys, &misses),
+                1 => open_address_probes(LinearProbing::new(h), &keys, &misses),
+                2 => open_address_probes(QuadraticProbing::new(h), &keys, &misses),
+                _ => {
+                    let h2 = Universal::random(m - 1, &mut rng);
+                    open_address_probes(DoubleHashing::new(h, h2), &keys, &misses)
+                }
+            });
+        }
+        let round = |mean: f64| (mean * 100.0).round() / 100.0;
+        rows[0].push((
+            alpha,
+            stats.iter().map(|s| round(s.mean_unsuccessful())).collect(),
+        ));
+        rows[1].push((
+            alpha,
+            stats.iter().map(|s| round(s.mean_successful())).collect(),
+        ));
+    }
+    let [unsuccessful, successful] = rows;
+    [
+        ("unsuccessful search", unsuccessful),
+        ("successful search", successful),
+    ]
+    .into_iter()
+    .map(|(title, rows)| Benchmark {
+        title: title.to_string(),
+        parameter: "$\\alpha$".to_string(),
+        names: names.to_vec(),
+        rows,
+    })
+    .collect()
+}
+
+/// Probes of searching `table` for every key in `keys` once they are all
+/// inserted, and for every key in `misses`
+fn chained_probes<H: HashFunction>(
+    mut table: ChainedHashTable<(), H>,
+    keys: &[u64],
+    misses: &[u64],
+) -> ProbeStats {
+    for &k in keys {
+        table.insert(k, ());
+    }
+    table.reset_stats();
+    for &k in keys.iter().chain(misses) {
+        table.search(k);
+    }
+    table.stats()
+}
+
+/// Like [`chained_probes`] for an open-address table probing along `probing`
+fn open_address_probes<P: ProbeSequence>(probing: P, keys: &[u64], misses: &[u64]) -> ProbeStats {
+    let mut table = OpenAddressTable::new(probing);
+    for &k in keys {
+        table.insert(k, ()).expect("the load factor stays below 1");
+    }
+    table.reset_stats();
+    for &k in keys.iter().chain(misses) {
+        table.search(k);
+    }
+    table.stats()
+}
+
+/// Join the elements of a sequence with ", "
+fn comma_separated(items: &[char]) -> String {
+    items
+        .iter()
+        .map(|c| c.to_string())
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+/// Locate `exercises/<id>/src/main.rs` for the given exercise ID
+fn find_exercise_file(base_dir: &Path, id: &str) -> Result<PathBuf, Box<dyn Error>> {
+    let main_file = base_dir.join(id).join("src").join("main.rs");
+    if main_file.is_file() {
+        return Ok(main_file);
+    }
+    Err(format!("exercise crate for '{}' not found at {:?}", id, main_file).into())
+}
+
+/// Split an exercise title like "2.1-1 Illustrate ..." into number and statement
+fn split_exercise_title(title: &str) -> (&str, &str) {
+    let title = title.trim();
+    match title.split_once(char::is_whitespace) {
+        Some((number, rest)) if number.starts_with(|c: char| c.is_ascii_digit()) => {
+            (number, rest.trim_start())
+        }
+        _ => ("", title),
+    }
+}
+
+/// Copy a Rust source file into the listings directory, dropping doc comments
+/// and replacing characters that `listings` cannot typeset.
+fn copy_sanitized_listing(source: &Path, target: &Path) -> Result<(), Box<dyn Error>> {
+    let file = File::open(source)?;
+    let reader = BufReader::new(file);
+    let mut clean_content = String::new();
+
+    for line in reader.lines() {
+        let line = line?;
+        if !line.trim_start().starts_with("///") {
+            clean_content.push_str(&line);
+            clean_content.push('\n');
+        }
+    }
+
+    let replacements = [
+        ("²", "^2"),
+        ("•", "\\\\textbullet{}"),
+        ("–", "-"),
+        ("—", "--"),
+        ("…", "..."),
+        ("“", "\""),
+        ("”", "\""),
+        ("’", "'"),
+        ("©", "(c)"),
+        ("ä", "ae"),
+        ("ö", "oe"),
+        ("ü", "ue"),
+        ("ß", "ss"),
+        ("\r\n", "\n"),
+    ];
+    for (from, to) in &replacements {
+        clean_content = clean_content.replace(from, to);
+    }
+    fs::write(target, clean_content.as_bytes())?;
+    Ok(())
+}
+
+/// Escape pseudocode for the plain-text listing environment
+fn escape_pseudocode(pseudocode: &str) -> String {
+    pseudocode
+        .replace('%', "\\%")
+        .replace('$', "\\$")
+        .replace('#', "\\#")
+        .replace('&', "\\&")
+        .replace('_', "\\_")
+        .replace('{', "\\{")
+        .replace('}', "\\}")
+}
+
+/// Escape free text so it can be typeset in LaTeX text mode
+pub(crate) fn escape_latex_text(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '\\' => out.push_str("\\textbackslash{}"),
+            '%' | '$' | '#' | '&' | '_' | '{' | '}' => {
+                out.push('\\');
+                out.push(c);
+            }
+            '~' => out.push_str("\\textasciitilde{}"),
+            '^' => out.push_str("\\textasciicircum{}"),
+            _ => out.push(c),
         }
     }
-    Err(format!("Rust source file for '{}' not found in {:?}", id, base_dir).into())
+    out
 }
 
 /// Run pdflatex with full output into the given build directory
@@ -0,0 +1,411 @@
+use std::fmt::Write;
+
+use algorithms::graph::adjacency_list::AdjacencyList;
+use algorithms::graph::bfs::{BfsResult, Color};
+use algorithms::graph::dfs::{DfsResult, EdgeClass};
+use algorithms::graph::flow::ford_fulkerson::MaxFlow;
+use algorithms::graph::flow::network::FlowNetwork;
+use algorithms::graph::representation::Graph;
+use algorithms::graph::sssp::{PathEvent, ShortestPaths};
+
+use crate::generate::escape_latex_text;
+use crate::tikz::panel_label;
+
+/// Distance between two BFS layers and between vertices of a layer (cm)
+const LAYER_GAP: f32 = 1.5;
+/// Width of one time unit in the parenthesis figure (cm)
+const TIME_WIDTH: f32 = 0.5;
+
+/// Styles shared by all graph figures
+const STYLES: &str = "\
+  vertex/.style={draw, circle, minimum size=7mm, inner sep=0pt},\n\
+  white/.style={vertex, fill=white},\n\
+  gray/.style={vertex, fill=gray!45},\n\
+  black/.style={vertex, fill=black, text=white},\n\
+  name/.style={font=\\scriptsize, above=1pt},\n\
+  note/.style={font=\\scriptsize, below=1pt},\n\
+  edge/.style={thin},\n\
+  tree/.style={line width=2pt, draw=gray!60},\n\
+  class/.style={font=\\scriptsize, fill=white, inner sep=1pt}\n";
+
+/// # BFS Figure
+///
+/// Renders the progress of breadth-first search in the style of CLRS
+/// Figure 20.3: one panel after every iteration of the while loop with
+/// white, gray and black vertices, `v.d` inside every discovered vertex,
+/// the tree edges found so far shaded and the queue `Q` below. Vertices
+/// are laid out in layers by their final distance from the source.
+pub fn bfs_figure<G: Graph>(g: &G, result: &BfsResult) -> String {
+    let positions = layered_layout(g, result);
+    let mut tex = String::new();
+
+    for steps in 0..=result.order.len() {
+        tex.push_str("\\begin{tikzpicture}[scale=0.8, transform shape,\n");
+        tex.push_str(STYLES);
+        tex.push_str("]\n");
+
+        for v in g.vertices() {
+            let color = result.color_after(v, steps);
+            let d = match (color, result.d[v]) {
+                (Color::White, _) | (_, None) => String::new(),
+                (_, Some(d)) => d.to_string(),
+            };
+            let (x, y) = positions[v];
+            let _ = writeln!(
+                tex,
+                "\\node[{}] (v{v}) at ({x:.2},{y:.2}) {{{d}}};",
+                style_name(color)
+            );
+            let _ = writeln!(
+                tex,
+                "\\node[name] at (v{v}.north) {{{}}};",
+                escape_latex_text(g.label(v))
+            );
+        }
+        for edge in g.edges() {
+            let (u, v) = (edge.from, edge.to);
+            let discovered = |x: usize| result.color_after(x, steps) != Color::White;
+            let is_tree = (result.pi[v] == Some(u) && discovered(v))
+                || (!g.is_directed() && result.pi[u] == Some(v) && discovered(u));
+            write_edge(
+                &mut tex,
+                g,
+                u,
+                v,
+                if is_tree { "tree" } else { "edge" },
+                None,
+            );
+        }
+
+        let queue: Vec<String> = result
+            .queue_after(steps)
+            .iter()
+            .map(|&v| escape_latex_text(g.label(v)))
+            .collect();
+        let bottom = positions.iter().map(|p| p.1).fold(0.0, f32::min) - 1.0;
+        let _ = writeln!(
+            tex,
+            "\\node[anchor=west] at (0,{bottom:.2}) {{({}) $Q = \\langle$ {} $\\rangle$}};",
+            panel_label(steps),
+            queue.join(", ")
+        );
+        tex.push_str("\\end{tikzpicture}\n");
+        tex.push_str(if steps % 3 == 2 { "\n\n" } else { "\\hfill\n" });
+    }
+    tex
+}
+
+/// # DFS Figure
+///
+/// Renders a finished depth-first search in the style of CLRS Figure
+/// 20.5(a): vertices on a circle annotated with `v.d/v.f`, tree edges
+/// shaded and every edge labeled `T`, `B`, `F` or `C` by its class.
+pub fn dfs_figure<G: Graph>(g: &G, result: &DfsResult) -> String {
+    let positions = circle_layout(g.vertex_count());
+    let mut tex = String::new();
+    tex.push_str("\\begin{tikzpicture}[\n");
+    tex.push_str(STYLES);
+    tex.push_str("]\n");
+
+    for v in g.vertices() {
+        let (x, y) = positions[v];
+        let _ = writeln!(
+            tex,
+            "\\node[white] (v{v}) at ({x:.2},{y:.2}) {{{}}};",
+            escape_latex_text(g.label(v))
+        );
+        let _ = writeln!(
+            tex,
+            "\\node[note] at (v{v}.south) {{{}/{}}};",
+            result.d[v], result.f[v]
+        );
+    }
+    for (edge, class) in &result.edges {
+        let style = if *class == EdgeClass::Tree {
+            "tree"
+        } else {
+            "edge"
+        };
+        write_edge(
+            &mut tex,
+            g,
+            edge.from,
+            edge.to,
+            style,
+            Some(&class.letter().to_string()),
+        );
+    }
+
+    tex.push_str("\\end{tikzpicture}\n");
+    tex
+}
+
+/// # Parenthesis Figure
+///
+/// Renders the parenthesis structure of a depth-first search as in CLRS
+/// Figure 20.5(b): every vertex `v` is an interval `[v.d, v.f]` on the
+/// time axis, nested intervals drawn below their ancestors, followed by
+/// the matching parenthesization `(u (v ... v) u)`.
+pub fn parenthesis_figure<G: Graph>(g: &G, result: &DfsResult) -> String {
+    let n = g.vertex_count();
+    let depth: Vec<usize> = (0..n)
+        .map(|v| {
+            let mut depth = 0;
+            let mut current = v;
+            while let Some(p) = result.pi[current] {
+                depth += 1;
+                current = p;
+            }
+            depth
+        })
+        .collect();
+
+    let mut tex = String::new();
+    tex.push_str("\\begin{tikzpicture}[\n");
+    tex.push_str("  interval/.style={draw, fill=gray!20, minimum height=5mm, inner sep=0pt, font=\\small},\n");
+    tex.push_str("  tick/.style={font=\\scriptsize, above=1pt}\n");
+    tex.push_str("]\n");
+
+    for t in 1..=2 * n {
+        let x = t as f32 * TIME_WIDTH;
+        let _ = writeln!(tex, "\\node[tick] at ({x:.2},0.00) {{{t}}};");
+        let _ = writeln!(tex, "\\draw[gray!50] ({x:.2},0.00) -- ({x:.2},0.10);");
+    }
+    for v in g.vertices() {
+        let start = result.d[v] as f32 * TIME_WIDTH;
+        let end = result.f[v] as f32 * TIME_WIDTH;
+        let y = -0.6 * (depth[v] + 1) as f32;
+        let _ = writeln!(
+            tex,
+            "\\node[interval, minimum width={:.2}cm] at ({:.2},{y:.2}) {{{}}};",
+            end - start,
+            (start + end) / 2.0,
+            escape_latex_text(g.label(v))
+        );
+    }
+
+    // (u (v (y (x x) y) v) u) ...
+    let mut events: Vec<(usize, String)> = Vec::new();
+    for v in g.vertices() {
+        let label = escape_latex_text(g.label(v));
+        events.push((result.d[v], format!("({}", label)));
+        events.push((result.f[v], format!("{})", label)));
+    }
+    events.sort();
+    let text: Vec<String> = events.into_iter().map(|(_, s)| s).collect();
+    let bottom = -0.6 * (depth.iter().max().copied().unwrap_or(0) + 2) as f32;
+    let _ = writeln!(
+        tex,
+        "\\node[anchor=west] at ({TIME_WIDTH:.2},{bottom:.2}) {{{}}};",
+        text.join(" ")
+    );
+
+    tex.push_str("\\end{tikzpicture}\n");
+    tex
+}
+
+/// # Shortest-Paths Figure
+///
+/// Renders the trace of a shortest-paths algorithm in the style of CLRS
+/// Figures 22.4-22.6: one panel after initialization and one after every
+/// pass of Bellman-Ford or every finished vertex of DAG-SHORTEST-PATHS
+/// and DIJKSTRA. Every vertex shows its estimate `v.d`, the predecessor
+/// edges `(v.π, v)` are shaded, the vertex just finished is gray and those
+/// finished before it are black. Vertices lie on a circle and every edge is
+/// labeled with its weight.
+pub fn shortest_paths_figure<G: Graph>(g: &G, steps: &[(PathEvent, ShortestPaths)]) -> String {
+    let positions = circle_layout(g.vertex_count());
+    let mut finished = vec![false; g.vertex_count()];
+    let mut tex = String::new();
+
+    let panels = steps
+        .iter()
+        .filter(|(event, _)| !matches!(event, PathEvent::Relax { .. }));
+    for (panel, (event, paths)) in panels.enumerate() {
+        let current = match event {
+            PathEvent::VertexComplete { u } => Some(*u),
+            _ => None,
+        };
+        tex.push_str("\\begin{tikzpicture}[scale=0.8, transform shape,\n");
+        tex.push_str(STYLES);
+        tex.push_str("]\n");
+
+        for v in g.vertices() {
+            let style = if current == Some(v) {
+                "gray"
+            } else if finished[v] {
+                "black"
+            } else {
+                "white"
+            };
+            let d = paths.d[v].map_or("$\\infty$".to_string(), |d| format!("${d}$"));
+            let (x, y) = positions[v];
+            let _ = writeln!(tex, "\\node[{style}] (v{v}) at ({x:.2},{y:.2}) {{{d}}};");
+            let _ = writeln!(
+                tex,
+                "\\node[name] at (v{v}.north) {{{}}};",
+                escape_latex_text(g.label(v))
+            );
+        }
+        for edge in g.edges() {
+            let (u, v) = (edge.from, edge.to);
+            let is_tree = paths.pi[v] == Some(u) || (!g.is_directed() && paths.pi[u] == Some(v));
+            write_edge(
+                &mut tex,
+                g,
+                u,
+                v,
+                if is_tree { "tree" } else { "edge" },
+                Some(&format!("${}$", edge.weight)),
+            );
+        }
+
+        let bottom = positions.iter().map(|p| p.1).fold(0.0, f32::min) - 1.0;
+        let _ = writeln!(
+            tex,
+            "\\node at (0,{bottom:.2}) {{({})}};",
+            panel_label(panel)
+        );
+        tex.push_str("\\end{tikzpicture}\n");
+        tex.push_str(if panel % 3 == 2 { "\n\n" } else { "\\hfill\n" });
+
+        if let Some(u) = current {
+            finished[u] = true;
+        }
+    }
+    tex
+}
+
+/// # Maximum Flow Figure
+///
+/// Renders the Ford-Fulkerson method in the style of CLRS Figure 24.6: one
+/// panel per augmentation with the residual network `G_f`, its residual
+/// capacities and the augmenting path `p` shaded, followed by a last panel
+/// of the flow network with `f/c` on every edge. `net` must hold the flow
+/// that `result` ends with, and `residuals[i]` is the `G_f` of the `i`-th
+/// augmentation.
+pub fn max_flow_figure(net: &FlowNetwork, result: &MaxFlow, residuals: &[AdjacencyList]) -> String {
+    let positions = circle_layout(net.vertex_count());
+    let bottom = positions.iter().map(|p| p.1).fold(0.0, f32::min) - 1.0;
+    let mut tex = String::new();
+    let begin = |tex: &mut String| {
+        tex.push_str("\\begin{tikzpicture}[scale=0.8, transform shape,\n");
+        tex.push_str(STYLES);
+        tex.push_str("]\n");
+        for v in net.vertices() {
+            let (x, y) = positions[v];
+            let _ = writeln!(
+                tex,
+                "\\node[white] (v{v}) at ({x:.2},{y:.2}) {{{}}};",
+                escape_latex_text(net.label(v))
+            );
+        }
+    };
+
+    for (panel, (augmentation, residual)) in result.augmentations.iter().zip(residuals).enumerate()
+    {
+        begin(&mut tex);
+        for edge in residual.edges() {
+            let on_path = augmentation
+                .path
+                .windows(2)
+                .any(|pair| pair == [edge.from, edge.to]);
+            write_edge(
+                &mut tex,
+                residual,
+                edge.from,
+                edge.to,
+                if on_path { "tree" } else { "edge" },
+                Some(&format!("${}$", edge.weight)),
+            );
+        }
+        let _ = writeln!(
+            tex,
+            "\\node at (0,{bottom:.2}) {{({}) $c_f(p) = {}$}};",
+            panel_label(panel),
+            augmentation.amount
+        );
+        tex.push_str("\\end{tikzpicture}\n");
+        tex.push_str(if panel % 3 == 2 { "\n\n" } else { "\\hfill\n" });
+    }
+
+    begin(&mut tex);
+    for edge in net.flow_edges() {
+        write_edge(
+            &mut tex,
+            net,
+            edge.from,
+            edge.to,
+            if edge.flow > 0 { "tree" } else { "edge" },
+            Some(&format!("${}/{}$", edge.flow, edge.capacity)),
+        );
+    }
+    let _ = writeln!(
+        tex,
+        "\\node at (0,{bottom:.2}) {{({}) $|f| = {}$}};",
+        panel_label(result.augmentations.len()),
+        result.value
+    );
+    tex.push_str("\\end{tikzpicture}\n");
+    tex
+}
+
+/// Vertices in rows by BFS distance, unreachable vertices in a last row
+fn layered_layout<G: Graph>(g: &G, result: &BfsResult) -> Vec<(f32, f32)> {
+    let last = result.d.iter().flatten().max().map_or(0, |d| d + 1);
+    let mut used = vec![0; last + 1];
+    g.vertices()
+        .map(|v| {
+            let layer = result.d[v].unwrap_or(last);
+            let x = used[layer] as f32 * LAYER_GAP;
+            used[layer] += 1;
+            (x, 0.0 - layer as f32 * LAYER_GAP)
+        })
+        .collect()
+}
+
+/// `n` points on a circle, the first at the top, clockwise
+fn circle_layout(n: usize) -> Vec<(f32, f32)> {
+    let radius = (n as f32 * 0.4).max(1.5);
+    (0..n)
+        .map(|i| {
+            let angle = std::f32::consts::FRAC_PI_2 - std::f32::consts::TAU * i as f32 / n as f32;
+            (radius * angle.cos(), radius * angle.sin())
+        })
+        .collect()
+}
+
+/// Draw the edge `(u, v)`, as a loop if `u == v` and bent if `(v, u)` is an
+/// edge as well, optionally labeled
+fn write_edge<G: Graph>(
+    tex: &mut String,
+    g: &G,
+    u: usize,
+    v: usize,
+    style: &str,
+    label: Option<&str>,
+) {
+    let directed = g.is_directed();
+    let arrow = if directed {
+        "-{Stealth[length=2mm]}, "
+    } else {
+        ""
+    };
+    let path = if u == v {
+        "to[loop above]"
+    } else if directed && g.has_edge(v, u) {
+        "to[bend left=15]"
+    } else {
+        "--"
+    };
+    let label = label.map_or(String::new(), |text| format!(" node[class] {{{text}}}"));
+    let _ = writeln!(tex, "\\draw[{arrow}{style}] (v{u}) {path}{label} (v{v});");
+}
+
+fn style_name(color: Color) -> &'static str {
+    match color {
+        Color::White => "white",
+        Color::Gray => "gray",
+        Color::Black => "black",
+    }
+}
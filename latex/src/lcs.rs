@@ -0,0 +1,112 @@
+use std::fmt::{Display, Write};
+
+use algorithms::dp::lcs::{Arrow, LcsTables};
+
+use crate::generate::escape_latex_text;
+
+/// Width and height of one table cell (cm)
+const CELL_SIZE: f32 = 0.9;
+
+/// # LCS Table Figure
+///
+/// Renders the `c` and `b` tables computed by
+/// [`lcs_length`](algorithms::dp::lcs::lcs_length) as a TikZ picture in
+/// the style of CLRS Figure 14.8: `j` and `y_j` across the top, `i` and
+/// `x_i` down the left, every entry `c[i, j]` with the arrow of `b[i, j]`,
+/// and the entries on the path traced by `PRINT-LCS` shaded.
+pub fn lcs_table_figure<T: Display>(x: &[T], y: &[T], tables: &LcsTables) -> String {
+    let path = tables.path();
+
+    let mut tex = String::new();
+    tex.push_str("\\begin{tikzpicture}[\n");
+    tex.push_str("  entry/.style={draw, minimum size=9mm, inner sep=0pt},\n");
+    tex.push_str("  path/.style={entry, fill=gray!30},\n");
+    tex.push_str("  head/.style={font=\\small},\n");
+    tex.push_str("  arrow/.style={font=\\scriptsize, anchor=north west, inner sep=1pt}\n");
+    tex.push_str("]\n");
+
+    // Column headers: j and y_j
+    let _ = writeln!(
+        tex,
+        "\\node[head] at ({:.2},{:.2}) {{$j$}};",
+        -CELL_SIZE,
+        2.0 * CELL_SIZE
+    );
+    for j in 0..=y.len() {
+        let cx = j as f32 * CELL_SIZE;
+        let _ = writeln!(
+            tex,
+            "\\node[head] at ({cx:.2},{:.2}) {{{j}}};",
+            2.0 * CELL_SIZE
+        );
+        if j > 0 {
+            let _ = writeln!(
+                tex,
+                "\\node[head] at ({cx:.2},{CELL_SIZE:.2}) {{{}}};",
+                escape_latex_text(&y[j - 1].to_string())
+            );
+        }
+    }
+    let _ = writeln!(
+        tex,
+        "\\node[head] at ({:.2},{CELL_SIZE:.2}) {{$y_j$}};",
+        -CELL_SIZE
+    );
+
+    for i in 0..=x.len() {
+        let cy = 0.0 - i as f32 * CELL_SIZE;
+        // Row headers: i and x_i
+        let _ = writeln!(
+            tex,
+            "\\node[head] at ({:.2},{cy:.2}) {{{i}}};",
+            -2.0 * CELL_SIZE
+        );
+        if i > 0 {
+            let _ = writeln!(
+                tex,
+                "\\node[head] at ({:.2},{cy:.2}) {{{}}};",
+                -CELL_SIZE,
+                escape_latex_text(&x[i - 1].to_string())
+            );
+        }
+
+        for j in 0..=y.len() {
+            let cx = j as f32 * CELL_SIZE;
+            let style = if path.contains(&(i, j)) {
+                "path"
+            } else {
+                "entry"
+            };
+            let _ = writeln!(
+                tex,
+                "\\node[{style}] (c{i}x{j}) at ({cx:.2},{cy:.2}) {{{}}};",
+                tables.c[i][j]
+            );
+            if let Some(arrow) = tables.b[i][j] {
+                let _ = writeln!(
+                    tex,
+                    "\\node[arrow] at (c{i}x{j}.north west) {{{}}};",
+                    arrow_symbol(arrow)
+                );
+            }
+        }
+    }
+    let _ = writeln!(
+        tex,
+        "\\node[head] at ({:.2},{CELL_SIZE:.2}) {{$i$}};",
+        -2.0 * CELL_SIZE
+    );
+    let _ = writeln!(tex, "\\node[head] at ({:.2},0.00) {{$x_i$}};", -CELL_SIZE);
+
+    tex.push_str("\\end{tikzpicture}\n");
+    tex
+}
+
+/// Math-mode arrow for an entry of the `b` table
+fn arrow_symbol(arrow: Arrow) -> &'static str {
+    match arrow {
+        Arrow::UpLeft => "$\\nwarrow$",
+        Arrow::Up => "$\\uparrow$",
+        Arrow::Left => "$\\leftarrow$",
+    }
+}
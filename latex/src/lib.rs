@@ -1,2 +1,9 @@
-pub mod generate;
+pub mod apsp;
+pub mod benchmark;
 pub mod build;
+pub mod complexity;
+pub mod generate;
+pub mod graph;
+pub mod lcs;
+pub mod tikz;
+pub mod tree;
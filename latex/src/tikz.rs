@@ -0,0 +1,145 @@
+use std::fmt::{Display, Write};
+
+use algorithms::trace::{Snapshot, TraceEvent};
+
+use crate::generate::escape_latex_text;
+
+/// Horizontal distance between array cells (cm)
+const CELL_WIDTH: f32 = 0.8;
+/// Vertical distance between two panels (cm)
+const PANEL_HEIGHT: f32 = 1.8;
+
+/// One outer-loop iteration reconstructed from a trace
+struct Pass<T> {
+    before: Vec<T>,
+    key: usize,
+    shifts: Vec<(usize, usize)>,
+    insert: usize,
+}
+
+/// # Array Trace Figure
+///
+/// Renders a recorded execution trace as a TikZ picture in the style of
+/// CLRS Figure 2.2: one panel per iteration showing the array before the
+/// iteration, the sorted prefix lightly shaded, the key cell dark, arrows
+/// above the array for every shift and an arrow below the array from the
+/// key to the position where it is inserted. A last panel shows the result.
+///
+/// `input` is the array before the run; `snapshots` is what a
+/// [`SnapshotCollector`](algorithms::trace::SnapshotCollector) recorded.
+pub fn array_trace_figure<T>(input: &[T], snapshots: &[Snapshot<T>]) -> String
+where
+    T: Clone + Display,
+{
+    let passes = collect_passes(snapshots);
+    let result = snapshots
+        .last()
+        .map(|s| s.array.clone())
+        .unwrap_or_else(|| input.to_vec());
+
+    let mut tex = String::new();
+    tex.push_str("\\begin{tikzpicture}[\n");
+    tex.push_str("  cell/.style={draw, minimum size=6mm, inner sep=0pt},\n");
+    tex.push_str("  sorted/.style={cell, fill=gray!20},\n");
+    tex.push_str("  key/.style={cell, fill=black!75, text=white},\n");
+    tex.push_str("  index/.style={font=\\scriptsize, above=1pt},\n");
+    tex.push_str("  move/.style={-{Stealth[length=1.5mm]}, thick}\n");
+    tex.push_str("]\n");
+
+    for (p, pass) in passes.iter().enumerate() {
+        write_cells(&mut tex, p, &pass.before, |i| {
+            if i == pass.key {
+                "key"
+            } else if i < pass.key {
+                "sorted"
+            } else {
+                "cell"
+            }
+        });
+        for (from, to) in &pass.shifts {
+            let _ = writeln!(
+                tex,
+                "\\draw[move] (p{p}c{from}.north east) to[bend left=50] (p{p}c{to}.north west);"
+            );
+        }
+        if pass.insert != pass.key {
+            let _ = writeln!(
+                tex,
+                "\\draw[move] (p{p}c{}.south) to[bend left=25] (p{p}c{}.south);",
+                pass.key, pass.insert
+            );
+        }
+    }
+
+    write_cells(&mut tex, passes.len(), &result, |_| "cell");
+
+    tex.push_str("\\end{tikzpicture}\n");
+    tex
+}
+
+/// Split a trace into passes delimited by key selection and insertion
+fn collect_passes<T: Clone>(snapshots: &[Snapshot<T>]) -> Vec<Pass<T>> {
+    let mut passes = Vec::new();
+    let mut current: Option<Pass<T>> = None;
+
+    for snapshot in snapshots {
+        match &snapshot.event {
+            TraceEvent::SelectKey { index, .. } => {
+                current = Some(Pass {
+                    before: snapshot.array.clone(),
+                    key: *index,
+                    shifts: Vec::new(),
+                    insert: *index,
+                });
+            }
+            TraceEvent::Shift { from, to } => {
+                if let Some(pass) = current.as_mut() {
+                    pass.shifts.push((*from, *to));
+                }
+            }
+            TraceEvent::Insert { index } => {
+                if let Some(mut pass) = current.take() {
+                    pass.insert = *index;
+                    passes.push(pass);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    passes
+}
+
+/// Draw one row of cells with 1-based index labels and a panel label
+fn write_cells<T, F>(tex: &mut String, panel: usize, values: &[T], style: F)
+where
+    T: Display,
+    F: Fn(usize) -> &'static str,
+{
+    let y = 0.0 - panel as f32 * PANEL_HEIGHT;
+    for (i, value) in values.iter().enumerate() {
+        let x = i as f32 * CELL_WIDTH;
+        let _ = writeln!(
+            tex,
+            "\\node[{}] (p{panel}c{i}) at ({x:.2},{y:.2}) {{{}}};",
+            style(i),
+            escape_latex_text(&value.to_string())
+        );
+        let _ = writeln!(tex, "\\node[index] at (p{panel}c{i}.north) {{{}}};", i + 1);
+    }
+    let _ = writeln!(
+        tex,
+        "\\node[left=4mm] at ({:.2},{y:.2}) {{({})}};",
+        -CELL_WIDTH / 2.0,
+        panel_label(panel)
+    );
+}
+
+/// CLRS-style panel labels: (a), (b), ... falling back to numbers
+pub(crate) fn panel_label(panel: usize) -> String {
+    if panel < 26 {
+        ((b'a' + panel as u8) as char).to_string()
+    } else {
+        (panel + 1).to_string()
+    }
+}
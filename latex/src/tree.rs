@@ -0,0 +1,161 @@
+use std::fmt::{Display, Write};
+
+use algorithms::trees::augment::Augmentation;
+use algorithms::trees::bst::BinarySearchTree;
+use algorithms::trees::red_black::{Color, RedBlackTree};
+
+use crate::generate::escape_latex_text;
+use crate::tikz::panel_label;
+
+/// Horizontal distance between nodes adjacent in the inorder walk (cm)
+const NODE_GAP: f32 = 0.7;
+/// Vertical distance between two levels of the tree (cm)
+const LEVEL_GAP: f32 = 1.0;
+
+/// Styles shared by all tree figures
+const STYLES: &str = "\
+  node/.style={draw, circle, minimum size=6mm, inner sep=0pt, font=\\small},\n\
+  rednode/.style={node, fill=red!70!black, text=white},\n\
+  blacknode/.style={node, fill=black, text=white},\n\
+  focus/.style={draw=orange, line width=1.5pt},\n\
+  augment/.style={font=\\scriptsize, inner sep=1pt},\n\
+  edge/.style={thin}\n";
+
+/// # Search Tree Figure
+///
+/// Renders a binary search tree in the style of CLRS Figure 12.1: every
+/// node one level below its parent and to the right of all nodes before it
+/// in the inorder walk, so the keys read in sorted order from left to
+/// right. Below the tree is the panel label `(a)`, `(b)`, ... followed by
+/// `caption`.
+pub fn search_tree_figure<K: Display, V>(
+    tree: &BinarySearchTree<K, V>,
+    panel: usize,
+    caption: &str,
+) -> String {
+    tree_picture(
+        tree.root(),
+        |x| [tree.left(x), tree.right(x)],
+        |x| ("node".to_string(), tree.key(x).to_string()),
+        format!("({}) {}", panel_label(panel), caption).trim_end(),
+    )
+}
+
+/// # Red-Black Tree Figure
+///
+/// Renders a red-black tree in the style of CLRS Figure 13.4, laid out
+/// like [`search_tree_figure`], with red and black nodes and the node
+/// `focus` of a fixup case, `z` or `w`, outlined. The leaves `T.nil` are
+/// omitted.
+pub fn red_black_figure<K: Display, V, A: Augmentation<K, V>>(
+    tree: &RedBlackTree<K, V, A>,
+    focus: Option<usize>,
+    panel: usize,
+    caption: &str,
+) -> String {
+    tree_picture(
+        tree.root(),
+        |x| [tree.left(x), tree.right(x)],
+        |x| {
+            (
+                color_style(tree, x, focus).to_string(),
+                tree.key(x).to_string(),
+            )
+        },
+        format!("({}) {}", panel_label(panel), caption).trim_end(),
+    )
+}
+
+/// # Augmented Tree Figure
+///
+/// Renders an augmented red-black tree in the style of CLRS Figure 17.1:
+/// a [`red_black_figure`] with the augmentation of every node, such as
+/// `x.size` or `x.max`, written below it.
+pub fn augmented_figure<K: Display, V, A: Augmentation<K, V> + Display>(
+    tree: &RedBlackTree<K, V, A>,
+    focus: Option<usize>,
+    panel: usize,
+    caption: &str,
+) -> String {
+    tree_picture(
+        tree.root(),
+        |x| [tree.left(x), tree.right(x)],
+        |x| {
+            let style = format!(
+                "{}, label={{[augment]below:{}}}",
+                color_style(tree, x, focus),
+                escape_latex_text(&tree.augment(x).to_string())
+            );
+            (style, tree.key(x).to_string())
+        },
+        format!("({}) {}", panel_label(panel), caption).trim_end(),
+    )
+}
+
+/// Style of a red or black node, outlined if it is `focus`
+fn color_style<K, V, A: Augmentation<K, V>>(
+    tree: &RedBlackTree<K, V, A>,
+    x: usize,
+    focus: Option<usize>,
+) -> &'static str {
+    match (tree.color(x), focus == Some(x)) {
+        (Color::Red, false) => "rednode",
+        (Color::Red, true) => "rednode, focus",
+        (Color::Black, false) => "blacknode",
+        (Color::Black, true) => "blacknode, focus",
+    }
+}
+
+/// One TikZ picture of the tree below `root`, laid out by an inorder walk;
+/// `node` gives the style and the label of every node
+fn tree_picture<C, N>(root: Option<usize>, children: C, node: N, label: &str) -> String
+where
+    C: Fn(usize) -> [Option<usize>; 2],
+    N: Fn(usize) -> (String, String),
+{
+    let mut tex = String::new();
+    tex.push_str("\\begin{tikzpicture}[\n");
+    tex.push_str(STYLES);
+    tex.push_str("]\n");
+
+    let mut stack: Vec<(usize, usize)> = Vec::new();
+    let mut current = root.map(|r| (r, 0));
+    let mut edges = Vec::new();
+    let (mut column, mut height) = (0usize, 0);
+    loop {
+        while let Some((x, depth)) = current {
+            stack.push((x, depth));
+            current = children(x)[0].map(|left| (left, depth + 1));
+        }
+        let Some((x, depth)) = stack.pop() else {
+            break;
+        };
+        let (style, key) = node(x);
+        let _ = writeln!(
+            tex,
+            "\\node[{}] (n{}) at ({:.2},{:.2}) {{{}}};",
+            style,
+            x,
+            column as f32 * NODE_GAP,
+            0.0 - depth as f32 * LEVEL_GAP,
+            escape_latex_text(&key)
+        );
+        edges.extend(children(x).into_iter().flatten().map(|child| (x, child)));
+        column += 1;
+        height = height.max(depth);
+        current = children(x)[1].map(|right| (right, depth + 1));
+    }
+
+    // Edges go last, once both of their nodes are placed
+    for (x, child) in edges {
+        let _ = writeln!(tex, "\\draw[edge] (n{x}) -- (n{child});");
+    }
+    let middle = column.saturating_sub(1) as f32 * NODE_GAP / 2.0;
+    let bottom = 0.0 - height as f32 * LEVEL_GAP - 0.7;
+    let _ = writeln!(
+        tex,
+        "\\node[anchor=north] at ({middle:.2},{bottom:.2}) {{{label}}};"
+    );
+    tex.push_str("\\end{tikzpicture}\n");
+    tex
+}
@@ -0,0 +1,241 @@
+//! # Command Line
+//!
+//! The `list` and `run` subcommands, driven entirely by the
+//! [`registry`](algorithms::registry): every registered algorithm can be
+//! run without touching this module.
+
+use std::error::Error;
+use std::fmt::Write;
+use std::fs;
+use std::io::{self, Read};
+
+use algorithms::graph::parse::parse_graph;
+use algorithms::graph::representation::Graph;
+use algorithms::random::XorShift64;
+use algorithms::registry::{self, Algorithm, Entry};
+use algorithms::trace::NoopTracer;
+
+/// Seed for randomized algorithms unless `--seed` is given
+const DEFAULT_SEED: u64 = 42;
+
+/// Where the input of `run` comes from
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InputSource {
+    /// Values given after `--input`
+    Args(Vec<String>),
+    /// File given with `--file`
+    File(String),
+    /// Standard input (default)
+    Stdin,
+}
+
+/// Parsed arguments of `run <id> ...`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RunOptions {
+    pub id: String,
+    pub input: InputSource,
+    /// 1-based rank for selection algorithms, lower median by default
+    pub rank: Option<usize>,
+    /// Label of the source vertex for graph algorithms, first vertex by default
+    pub source: Option<String>,
+    pub seed: u64,
+}
+
+/// Usage text printed for `help` and on missing arguments.
+pub fn usage() -> String {
+    let mut text = String::new();
+    text.push_str("Usage: cargo run -- <command>\n");
+    text.push_str("Commands:\n");
+    text.push_str("  doc                      Generate full LaTeX documentation (PDF)\n");
+    text.push_str("  list                     List all algorithms by CLRS chapter\n");
+    text.push_str("  run <id> [options]       Run one algorithm\n");
+    text.push_str("Options of run:\n");
+    text.push_str("  --input <values>...      Input on the command line\n");
+    text.push_str("  --file <path>            Read the input from a file\n");
+    text.push_str("  --rank <i>               Rank to select (selection algorithms)\n");
+    text.push_str("  --source <vertex>        Source vertex (graph algorithms)\n");
+    text.push_str("  --seed <n>               Seed for randomized algorithms\n");
+    text.push_str("Without --input or --file the input is read from stdin.\n");
+    text.push_str("Graphs are given as an edge list (one 'u v [weight]' per line) or in DOT.\n");
+    text
+}
+
+/// # List
+///
+/// All registered algorithms grouped by CLRS chapter.
+pub fn list() -> String {
+    // Pad IDs to a common column, leaving at least one space after the longest
+    let width = registry::all()
+        .iter()
+        .map(|a| a.id.len())
+        .max()
+        .unwrap_or(0)
+        + 1;
+    let mut text = String::new();
+    for (chapter, algorithms) in registry::by_chapter() {
+        let _ = writeln!(
+            text,
+            "Chapter {}: {}",
+            chapter,
+            registry::chapter_title(chapter)
+        );
+        for algorithm in algorithms {
+            let _ = writeln!(
+                text,
+                "  {:<width$}{} ({}, {})",
+                algorithm.id, algorithm.name, algorithm.section, algorithm.pseudocode
+            );
+        }
+    }
+    text
+}
+
+/// Parse the arguments following `run`.
+pub fn parse_run_args(args: &[String]) -> Result<RunOptions, Box<dyn Error>> {
+    let (id, rest) = args.split_first().ok_or("missing algorithm id")?;
+    let mut options = RunOptions {
+        id: id.clone(),
+        input: InputSource::Stdin,
+        rank: None,
+        source: None,
+        seed: DEFAULT_SEED,
+    };
+
+    let mut i = 0;
+    while i < rest.len() {
+        let value = || {
+            rest.get(i + 1)
+                .ok_or_else(|| format!("{} needs a value", rest[i]))
+        };
+        match rest[i].as_str() {
+            "--input" => {
+                let values: Vec<String> = rest[i + 1..]
+                    .iter()
+                    .take_while(|a| !a.starts_with("--"))
+                    .cloned()
+                    .collect();
+                i += values.len() + 1;
+                options.input = InputSource::Args(values);
+                continue;
+            }
+            "--file" => options.input = InputSource::File(value()?.clone()),
+            "--rank" => options.rank = Some(value()?.parse()?),
+            "--source" => options.source = Some(value()?.clone()),
+            "--seed" => options.seed = value()?.parse()?,
+            other => return Err(format!("unknown option '{}'", other).into()),
+        }
+        i += 2;
+    }
+    Ok(options)
+}
+
+/// Read the raw input text from the chosen source; `--input` values become
+/// one line each.
+pub fn read_input(source: &InputSource) -> Result<String, Box<dyn Error>> {
+    match source {
+        InputSource::Args(values) => Ok(values.join("\n")),
+        InputSource::File(path) => Ok(fs::read_to_string(path)?),
+        InputSource::Stdin => {
+            let mut text = String::new();
+            io::stdin().read_to_string(&mut text)?;
+            Ok(text)
+        }
+    }
+}
+
+/// Split input text on whitespace and commas and parse every value.
+pub fn parse_values<T>(text: &str) -> Result<Vec<T>, Box<dyn Error>>
+where
+    T: std::str::FromStr,
+    T::Err: Error + 'static,
+{
+    text.split(|c: char| c.is_whitespace() || c == ',')
+        .filter(|s| !s.is_empty())
+        .map(|s| {
+            s.parse::<T>()
+                .map_err(|e| format!("invalid value '{}': {}", s, e).into())
+        })
+        .collect()
+}
+
+/// # Run
+///
+/// Runs `algorithm` on the input text and returns the report to print.
+pub fn run(
+    algorithm: &Algorithm,
+    options: &RunOptions,
+    input: &str,
+) -> Result<String, Box<dyn Error>> {
+    let mut text = String::new();
+    let _ = writeln!(
+        text,
+        "--- Running {} (CLRS {}, {}) ---",
+        algorithm.name, algorithm.section, algorithm.pseudocode
+    );
+    let mut rng = XorShift64::new(options.seed);
+
+    match algorithm.entry {
+        Entry::Sort(sort) => {
+            let mut data: Vec<i64> = parse_values(input)?;
+            let _ = writeln!(text, "Input: {:?}", data);
+            sort(&mut data, &mut rng, &mut NoopTracer);
+            let _ = writeln!(text, "Output: {:?}", data);
+        }
+        Entry::SortNaturals(sort) => {
+            let mut data: Vec<i64> = parse_values(input)?;
+            let _ = writeln!(text, "Input: {:?}", data);
+            sort(&mut data)?;
+            let _ = writeln!(text, "Output: {:?}", data);
+        }
+        Entry::SortReals(sort) => {
+            let mut data: Vec<f64> = parse_values(input)?;
+            let _ = writeln!(text, "Input: {:?}", data);
+            sort(&mut data)?;
+            let _ = writeln!(text, "Output: {:?}", data);
+        }
+        Entry::Select(select) => {
+            let mut data: Vec<i64> = parse_values(input)?;
+            if data.is_empty() {
+                return Err("selection needs at least one value".into());
+            }
+            let rank = options.rank.unwrap_or(data.len().div_ceil(2));
+            if !(1..=data.len()).contains(&rank) {
+                return Err(format!("rank {} is outside 1..={}", rank, data.len()).into());
+            }
+            let _ = writeln!(text, "Input: {:?}", data);
+            let value = select(&mut data, rank, &mut rng, &mut NoopTracer);
+            let _ = writeln!(text, "Rank {}: {}", rank, value);
+        }
+        Entry::Strings(combine) => {
+            let words: Vec<&str> = input.split_whitespace().collect();
+            let [x, y] = words.as_slice() else {
+                return Err(format!("'{}' needs exactly two strings", algorithm.id).into());
+            };
+            let _ = writeln!(text, "Input: {} {}", x, y);
+            let _ = writeln!(text, "Output: {}", combine(x, y));
+        }
+        Entry::Graph(describe) => {
+            let graph = parse_graph(input)?;
+            let source = match &options.source {
+                Some(label) => graph
+                    .vertex_named(label)
+                    .ok_or_else(|| format!("no vertex named '{}'", label))?,
+                None if graph.vertex_count() > 0 => 0,
+                None => return Err("the graph has no vertices".into()),
+            };
+            let _ = writeln!(
+                text,
+                "Input: {} vertices, {} edges",
+                graph.vertex_count(),
+                graph.edge_count()
+            );
+            text.push_str(&describe(&graph, source));
+        }
+        Entry::Keys(describe) => {
+            let keys: Vec<i64> = parse_values(input)?;
+            let _ = writeln!(text, "Input: {:?}", keys);
+            text.push_str(&describe(&keys));
+        }
+    }
+    Ok(text)
+}
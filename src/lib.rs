@@ -0,0 +1,21 @@
+//! # CLRS with Rust
+//!
+//! Facade over the workspace crates. `clrs::algorithms` is the
+//! [`algorithms`](::algorithms) crate with the algorithm modules of every
+//! topic also reachable directly, e.g. `clrs::algorithms::insertion_sort`.
+
+pub mod cli;
+
+pub mod algorithms {
+    pub use ::algorithms::disjoint_set::*;
+    pub use ::algorithms::dp::*;
+    pub use ::algorithms::graph::*;
+    pub use ::algorithms::hashing::*;
+    pub use ::algorithms::heap::*;
+    pub use ::algorithms::selection::*;
+    pub use ::algorithms::sorting::*;
+    pub use ::algorithms::trees::*;
+    pub use ::algorithms::*;
+}
+
+pub use latex;
@@ -1,8 +1,10 @@
 use std::env;
 use std::error::Error;
 
-// Import algorithm from algorithms crate
-use algorithms::sorting::insertion_sort::insertion_sort as InsertionSort;
+use algorithms::registry;
+
+// Registry-driven subcommands
+use clrs::cli;
 
 // Import LaTeX generation pipeline
 use latex::generate::generate_clrs_doc;
@@ -11,10 +13,7 @@ fn main() -> Result<(), Box<dyn Error>> {
     let args: Vec<String> = env::args().collect();
 
     if args.len() < 2 {
-        println!("Usage: cargo run -- <command>");
-        println!("Commands:");
-        println!("  doc              Generate full LaTeX documentation (PDF)");
-        println!("  insertion_sort   Run the insertion sort demo");
+        print!("{}", cli::usage());
         return Ok(());
     }
 
@@ -25,14 +24,22 @@ fn main() -> Result<(), Box<dyn Error>> {
             println!("✅ Report generated successfully!");
         }
 
-        "insertion_sort" => {
-            println!("--- Running Insertion Sort ---");
-            let mut data = [5, 2, 4, 6, 1, 3];
-            println!("Input: {:?}", data);
-            InsertionSort(&mut data);
-            println!("Output: {:?}", data);
+        "list" => print!("{}", cli::list()),
+
+        "run" => {
+            let options = cli::parse_run_args(&args[2..])?;
+            let algorithm = registry::find(&options.id).ok_or_else(|| {
+                format!(
+                    "unknown algorithm '{}' (see `cargo run -- list`)",
+                    options.id
+                )
+            })?;
+            let input = cli::read_input(&options.input)?;
+            print!("{}", cli::run(algorithm, &options, &input)?);
         }
 
+        "help" | "--help" | "-h" => print!("{}", cli::usage()),
+
         cmd => println!("Unknown command: {}", cmd),
     }
 
@@ -0,0 +1,41 @@
+use algorithms::analysis::{OpCounter, random_input, reversed_input, sorted_input};
+use algorithms::random::XorShift64;
+use algorithms::sorting::insertion_sort::insertion_sort_traced;
+
+/// Tests operation counting on insertion sort.
+///
+/// Sorted input is the best case (n - 1 comparisons), reversed input the
+/// worst case (n(n - 1)/2 comparisons and shifts).
+
+#[test]
+fn counts_best_case() {
+    let mut data = sorted_input(50);
+    let mut counter = OpCounter::new();
+    insertion_sort_traced(&mut data, &mut counter);
+
+    assert_eq!(counter.counts().comparisons, 49);
+    assert_eq!(counter.counts().writes, 49);
+}
+
+#[test]
+fn counts_worst_case() {
+    let mut data = reversed_input(50);
+    let mut counter = OpCounter::new();
+    insertion_sort_traced(&mut data, &mut counter);
+
+    // Every key is compared with and shifted past all elements before it
+    assert_eq!(counter.counts().comparisons, 50 * 49 / 2);
+    assert_eq!(counter.counts().writes, 50 * 49 / 2 + 49);
+    assert_eq!(data, sorted_input(50).as_slice());
+}
+
+#[test]
+fn random_input_is_reproducible_permutation() {
+    let a = random_input(100, &mut XorShift64::new(7));
+    let b = random_input(100, &mut XorShift64::new(7));
+    assert_eq!(a, b);
+
+    let mut sorted = a.clone();
+    sorted.sort();
+    assert_eq!(sorted, sorted_input(100));
+}
@@ -0,0 +1,119 @@
+use algorithms::graph::adjacency_list::AdjacencyList;
+use algorithms::graph::apsp::{
+    faster_all_pairs, floyd_warshall, floyd_warshall_steps, johnson, slow_all_pairs,
+    transitive_closure,
+};
+use algorithms::graph::parse::parse_edge_list;
+use algorithms::graph::sssp::bellman_ford;
+use algorithms::random::{RandomSource, XorShift64};
+
+/// Tests the all-pairs shortest-paths algorithms of CLRS Chapter 23.
+///
+/// Every method must reproduce Figure 23.4 and agree with Bellman-Ford run
+/// from every vertex on random graphs with negative edges but without
+/// negative-weight cycles.
+
+#[test]
+fn all_methods_on_figure_23_4() {
+    let g = figure_23_4();
+    let expected = vec![
+        vec![Some(0), Some(1), Some(-3), Some(2), Some(-4)],
+        vec![Some(3), Some(0), Some(-4), Some(1), Some(-1)],
+        vec![Some(7), Some(4), Some(0), Some(5), Some(3)],
+        vec![Some(2), Some(-1), Some(-5), Some(0), Some(-2)],
+        vec![Some(8), Some(5), Some(1), Some(6), Some(0)],
+    ];
+
+    assert_eq!(slow_all_pairs(&g), expected);
+    assert_eq!(faster_all_pairs(&g), expected);
+    let result = floyd_warshall(&g);
+    assert_eq!(result.d, expected);
+    assert_eq!(johnson(&g).unwrap().d, expected);
+
+    // Π^(5), row 1: NIL 3 4 5 1
+    assert_eq!(result.pi[0], [None, Some(2), Some(3), Some(4), Some(0)]);
+    assert_eq!(result.path(0, 1), Some(vec![0, 4, 3, 2, 1]));
+    assert_eq!(johnson(&g).unwrap().path(0, 1), Some(vec![0, 4, 3, 2, 1]));
+}
+
+#[test]
+fn floyd_warshall_steps_match_figure_23_4() {
+    let steps = floyd_warshall_steps(&figure_23_4());
+
+    assert_eq!(steps.len(), 6);
+    assert_eq!(steps[5], floyd_warshall(&figure_23_4()));
+    // D^(1): the path 4 → 1 → 2 appears
+    assert_eq!(steps[1].d[3][1], Some(5));
+    assert_eq!(steps[1].pi[3][1], Some(0));
+    // D^(2): 1 → 2 → 4
+    assert_eq!(steps[2].d[0][3], Some(4));
+    assert_eq!(steps[1].d[0][3], None);
+}
+
+#[test]
+fn methods_agree_on_random_graphs() {
+    let mut rng = XorShift64::new(23);
+    for n in [1, 2, 5, 12] {
+        for _ in 0..5 {
+            let g = random_potential_graph(n, 3 * n, &mut rng);
+            let expected: Vec<_> = (0..n).map(|s| bellman_ford(&g, s).unwrap().d).collect();
+
+            assert_eq!(slow_all_pairs(&g), expected);
+            assert_eq!(faster_all_pairs(&g), expected);
+            assert_eq!(floyd_warshall(&g).d, expected);
+            let result = johnson(&g).unwrap();
+            assert_eq!(result.d, expected);
+
+            let closure = transitive_closure(&g);
+            for i in 0..n {
+                for j in 0..n {
+                    assert_eq!(closure[i][j], expected[i][j].is_some());
+                    let path = result.path(i, j);
+                    assert_eq!(path.is_some(), closure[i][j]);
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn negative_cycles_are_detected() {
+    let g = parse_edge_list("a b 1\nb c -3\nc a 1\nc d 2\n").unwrap();
+
+    assert!(floyd_warshall(&g).has_negative_cycle());
+    assert!(!floyd_warshall(&figure_23_4()).has_negative_cycle());
+    assert_eq!(johnson(&g).unwrap_err().vertices, [0, 1, 2]);
+    assert!(slow_all_pairs(&g)[0][0].is_some_and(|d| d < 0));
+}
+
+#[test]
+fn weights_that_overflow_count_as_infinity() {
+    // ŵ(a, b) = w(a, b) + h(a) - h(b) = MAX + 1, but a reaches b through c
+    let g = parse_edge_list(&format!("a b {}\nb c 1\na c 5\nc b -1\n", i64::MAX)).unwrap();
+    let expected = floyd_warshall(&g).d;
+
+    assert_eq!(expected[0], [Some(0), Some(4), Some(5)]);
+    assert_eq!(johnson(&g).unwrap().d, expected);
+    assert_eq!(slow_all_pairs(&g), expected);
+}
+
+/// The directed graph of CLRS Figure 23.4 on the vertices 1, ..., 5
+fn figure_23_4() -> AdjacencyList {
+    parse_edge_list(
+        "1\n2\n3\n4\n5\n1 2 3\n1 3 8\n1 5 -4\n2 4 1\n2 5 7\n3 2 4\n4 1 2\n4 3 -5\n5 4 6\n",
+    )
+    .unwrap()
+}
+
+/// Random directed graph whose weights `w(u, v) + p(u) - p(v)` may be
+/// negative, while every cycle keeps its nonnegative weight
+fn random_potential_graph<R: RandomSource>(n: usize, m: usize, rng: &mut R) -> AdjacencyList {
+    let potential: Vec<i64> = (0..n).map(|_| rng.gen_range(0, 20) as i64).collect();
+    let mut g = AdjacencyList::directed(n);
+    for _ in 0..m {
+        let (u, v) = (rng.gen_range(0, n), rng.gen_range(0, n));
+        let w = rng.gen_range(0, 10) as i64;
+        g.add_weighted_edge(u, v, w + potential[u] - potential[v]);
+    }
+    g
+}
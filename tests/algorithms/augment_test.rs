@@ -0,0 +1,165 @@
+use algorithms::random::{RandomSource, XorShift64};
+use algorithms::trees::augment::Augmentation;
+use algorithms::trees::interval::{Interval, IntervalTree};
+use algorithms::trees::order_statistic::OrderStatisticTree;
+use algorithms::trees::red_black::RedBlackTree;
+
+/// Tests the augmented red-black trees of CLRS Chapter 17.
+///
+/// Sizes, interval maxima and user-defined attributes must survive every
+/// rotation of insertion and deletion, so each query is checked against a
+/// brute-force answer after a long run of random updates.
+
+#[test]
+fn os_select_and_os_rank_match_sorted_order() {
+    let mut rng = XorShift64::new(17);
+    let mut tree = OrderStatisticTree::augmented();
+    let mut expected: Vec<usize> = Vec::new();
+    for _ in 0..2000 {
+        let k = rng.gen_range(0, 200);
+        if rng.gen_range(0, 3) > 0 {
+            tree.insert(k, ());
+            let at = expected.partition_point(|&e| e <= k);
+            expected.insert(at, k);
+        } else if let Some(z) = tree.search(&k) {
+            tree.delete(z);
+            let at = expected.binary_search(&k).unwrap();
+            expected.remove(at);
+        }
+        assert!(tree.check_invariants().is_ok());
+    }
+    for (i, k) in expected.iter().enumerate() {
+        let x = tree.os_select(i + 1).unwrap();
+        assert_eq!(tree.key(x), k);
+        assert_eq!(tree.os_rank(x), i + 1);
+    }
+    assert_eq!(tree.os_select(0), None);
+    assert_eq!(tree.os_select(expected.len() + 1), None);
+    assert_sizes(&tree);
+}
+
+#[test]
+fn sizes_survive_every_rotation() {
+    // Sorted insertions rotate at almost every step
+    let mut tree = OrderStatisticTree::augmented();
+    for k in 0..100 {
+        tree.insert(k, ());
+        assert_sizes(&tree);
+    }
+    for k in (0..100).step_by(3) {
+        tree.delete(tree.search(&k).unwrap());
+        assert_sizes(&tree);
+    }
+    assert_eq!(tree.size(tree.root().unwrap()), tree.len());
+}
+
+#[test]
+fn interval_queries_match_brute_force() {
+    let mut rng = XorShift64::new(173);
+    let mut tree = IntervalTree::augmented();
+    let mut stored = Vec::new();
+    for step in 0..300 {
+        let low = rng.gen_range(0, 1000);
+        let i = Interval::new(low, low + rng.gen_range(0, 60));
+        tree.insert(i, step);
+        stored.push(i);
+        if step % 4 == 3 {
+            let gone = stored.swap_remove(rng.gen_range(0, stored.len()));
+            tree.delete(tree.search(&gone).unwrap());
+        }
+    }
+    for low in (0..1100).step_by(7) {
+        let i = Interval::new(low, low + 5);
+        let mut expected: Vec<Interval<usize>> =
+            stored.iter().copied().filter(|j| i.overlaps(j)).collect();
+        expected.sort();
+        let found: Vec<Interval<usize>> = tree
+            .overlapping(&i)
+            .into_iter()
+            .map(|x| *tree.key(x))
+            .collect();
+        assert_eq!(found, expected, "query {}", i);
+        match tree.interval_search(&i) {
+            Some(x) => assert!(i.overlaps(tree.key(x))),
+            None => assert!(expected.is_empty()),
+        }
+    }
+    for x in tree.inorder() {
+        let max = subtree(&tree, x).map(|y| tree.key(y).high).max();
+        assert_eq!(Some(*tree.max_high(x)), max);
+    }
+}
+
+#[test]
+fn interval_search_follows_figure_17_4() {
+    let mut tree = IntervalTree::augmented();
+    for (low, high) in [
+        (16, 21),
+        (8, 9),
+        (25, 30),
+        (5, 8),
+        (15, 23),
+        (17, 19),
+        (26, 26),
+        (0, 3),
+        (6, 10),
+        (19, 20),
+    ] {
+        tree.insert(Interval::new(low, high), ());
+    }
+    assert_eq!(tree.max_high(tree.root().unwrap()), &30);
+    let x = tree.interval_search(&Interval::new(22, 25)).unwrap();
+    assert_eq!(tree.key(x), &Interval::new(15, 23));
+    assert_eq!(tree.interval_search(&Interval::new(11, 14)), None);
+}
+
+#[test]
+fn custom_augmentation_is_maintained() {
+    let mut rng = XorShift64::new(29);
+    let mut tree: RedBlackTree<usize, i64, Total> = RedBlackTree::augmented();
+    let mut total = 0;
+    for k in 0..500 {
+        let value = rng.gen_range(0, 100) as i64;
+        tree.insert(k, value);
+        total += value;
+    }
+    for k in (0..500).step_by(2) {
+        let (_, value) = tree.delete(tree.search(&k).unwrap());
+        total -= value;
+    }
+    let root = tree.root().unwrap();
+    assert_eq!(tree.augment(root).0, total);
+    let x = tree.search(&251).unwrap();
+    let old = tree.replace_value(x, 1000);
+    assert_eq!(tree.augment(root).0, total - old + 1000);
+}
+
+/// Sum of the values in a subtree
+struct Total(i64);
+
+impl<K> Augmentation<K, i64> for Total {
+    fn compute(_key: &K, value: &i64, left: Option<&Self>, right: Option<&Self>) -> Self {
+        Total(value + left.map_or(0, |l| l.0) + right.map_or(0, |r| r.0))
+    }
+}
+
+/// Every `x.size` equals the number of nodes below `x`
+fn assert_sizes<K>(tree: &OrderStatisticTree<K, ()>) {
+    for x in tree.inorder() {
+        assert_eq!(tree.size(x), subtree(tree, x).count());
+    }
+}
+
+/// The nodes of the subtree rooted at `x`
+fn subtree<'a, K, V, A: Augmentation<K, V>>(
+    tree: &'a RedBlackTree<K, V, A>,
+    x: usize,
+) -> impl Iterator<Item = usize> + 'a {
+    let mut stack = vec![x];
+    std::iter::from_fn(move || {
+        let y = stack.pop()?;
+        stack.extend(tree.left(y));
+        stack.extend(tree.right(y));
+        Some(y)
+    })
+}
@@ -0,0 +1,140 @@
+use std::collections::BTreeMap;
+
+use algorithms::random::{RandomSource, XorShift64};
+use algorithms::trees::bst::BinarySearchTree;
+
+/// Tests the binary search trees of CLRS Chapter 12.
+///
+/// Under random insertions and deletions the tree must agree with
+/// `BTreeMap`, keep the binary-search-tree property and consistent parent
+/// pointers, and all walks must visit every node in their order.
+
+#[test]
+fn agrees_with_btree_map() {
+    let mut rng = XorShift64::new(12);
+    let mut tree = BinarySearchTree::new();
+    let mut expected = BTreeMap::new();
+    for step in 0..3000 {
+        let k = rng.gen_range(0, 200);
+        if rng.gen_range(0, 2) == 0 {
+            if tree.search(&k).is_none() {
+                tree.insert(k, step);
+                assert_eq!(expected.insert(k, step), None);
+            }
+        } else if let Some(z) = tree.search(&k) {
+            assert_eq!(tree.delete(z), (k, expected.remove(&k).unwrap()));
+        } else {
+            assert!(!expected.contains_key(&k));
+        }
+    }
+    check_structure(&tree);
+    let pairs: Vec<(usize, usize)> = tree
+        .inorder()
+        .map(|x| (*tree.key(x), *tree.value(x)))
+        .collect();
+    assert_eq!(pairs, expected.into_iter().collect::<Vec<_>>());
+}
+
+#[test]
+fn walks_visit_every_node_in_order() {
+    // CLRS Figure 12.2
+    let tree = build(&[15, 6, 18, 3, 7, 17, 20, 2, 4, 13, 9]);
+    let keys = |walk: &mut dyn Iterator<Item = usize>| -> Vec<i32> {
+        walk.map(|x| *tree.key(x)).collect()
+    };
+    let sorted = [2, 3, 4, 6, 7, 9, 13, 15, 17, 18, 20];
+    assert_eq!(keys(&mut tree.inorder()), sorted);
+    assert_eq!(keys(&mut tree.inorder_without_stack()), sorted);
+    assert_eq!(
+        keys(&mut tree.preorder()),
+        [15, 6, 3, 2, 4, 7, 13, 9, 18, 17, 20]
+    );
+    assert_eq!(
+        keys(&mut tree.postorder()),
+        [2, 4, 3, 9, 13, 7, 6, 17, 20, 18, 15]
+    );
+
+    let empty: BinarySearchTree<i32, ()> = BinarySearchTree::new();
+    assert_eq!(empty.inorder_without_stack().count(), 0);
+    assert_eq!(empty.postorder().count(), 0);
+}
+
+#[test]
+fn stackless_walk_matches_on_random_shapes() {
+    let mut rng = XorShift64::new(13);
+    for n in [1, 2, 5, 50] {
+        let keys: Vec<usize> = (0..n).map(|_| rng.gen_range(0, 20)).collect();
+        let tree = build(&keys);
+        let stack: Vec<usize> = tree.inorder().collect();
+        let stackless: Vec<usize> = tree.inorder_without_stack().collect();
+        assert_eq!(stack, stackless);
+        assert_eq!(stack.len(), n);
+    }
+}
+
+#[test]
+fn successor_and_predecessor_follow_the_inorder_walk() {
+    let tree = build(&[15, 6, 18, 3, 7, 17, 20, 2, 4, 13, 9]);
+    let order: Vec<usize> = tree.inorder().collect();
+    for pair in order.windows(2) {
+        assert_eq!(tree.successor(pair[0]), Some(pair[1]));
+        assert_eq!(tree.predecessor(pair[1]), Some(pair[0]));
+    }
+    let root = tree.root().unwrap();
+    assert_eq!(tree.successor(tree.maximum(root)), None);
+    assert_eq!(tree.predecessor(tree.minimum(root)), None);
+    assert_eq!(tree.height(), 4);
+    assert_eq!(tree.depth(tree.search(&9).unwrap()), 4);
+}
+
+#[test]
+fn delete_moves_nodes_without_copying_keys() {
+    let mut tree = build(&[15, 6, 18, 3, 7, 17, 20, 2, 4, 13, 9]);
+    let node_13 = tree.search(&13).unwrap();
+
+    // 6 has two children; its successor 7 is its right child
+    tree.delete(tree.search(&6).unwrap());
+    let node_7 = tree.search(&7).unwrap();
+    assert_eq!(tree.key(tree.left(node_7).unwrap()), &3);
+    // 15's successor 17 is deeper in its right subtree
+    tree.delete(tree.root().unwrap());
+    assert_eq!(tree.key(tree.root().unwrap()), &17);
+    assert_eq!(tree.key(node_13), &13);
+    assert_eq!(tree.len(), 9);
+    check_structure(&tree);
+}
+
+#[test]
+fn duplicates_and_sorted_input() {
+    let tree = build(&[5, 5, 5]);
+    assert_eq!(tree.len(), 3);
+    assert_eq!(tree.height(), 2);
+
+    let sorted: Vec<usize> = (0..100).collect();
+    assert_eq!(build(&sorted).height(), 99);
+}
+
+/// Tree with `keys` inserted in order
+fn build<K: Ord + Copy>(keys: &[K]) -> BinarySearchTree<K, ()> {
+    let mut tree = BinarySearchTree::new();
+    for &k in keys {
+        tree.insert(k, ());
+    }
+    tree
+}
+
+/// Assert the binary-search-tree property and that parents match children
+fn check_structure<K: Ord, V>(tree: &BinarySearchTree<K, V>) {
+    assert_eq!(tree.root().and_then(|r| tree.parent(r)), None);
+    for x in tree.preorder() {
+        if let Some(left) = tree.left(x) {
+            assert_eq!(tree.parent(left), Some(x));
+            assert!(tree.key(tree.maximum(left)) <= tree.key(x));
+        }
+        if let Some(right) = tree.right(x) {
+            assert_eq!(tree.parent(right), Some(x));
+            assert!(tree.key(tree.minimum(right)) >= tree.key(x));
+        }
+    }
+    assert_eq!(tree.preorder().count(), tree.len());
+}
@@ -0,0 +1,195 @@
+use algorithms::random::{RandomSource, XorShift64};
+use algorithms::trees::btree::disk::{DiskStore, FileStore, MemoryStore};
+use algorithms::trees::btree::tree::BTree;
+
+/// Tests the B-trees of CLRS Chapter 18.
+///
+/// Every insertion and deletion must keep the B-tree properties for the
+/// minimum degree `t`, the deletion cases must reshape the tree as in CLRS
+/// Figure 18.8, and the disk operations counted by the store must stay
+/// within the `O(h)` bounds of the analysis.
+
+#[test]
+fn deletion_follows_figure_18_8() {
+    let mut tree = BTree::create(MemoryStore::new(), 3).unwrap();
+    // An insertion order that builds Figure 18.8(a)
+    for k in "GXYABOPLFDCQJVZEUNTRMSK".chars() {
+        tree.insert(k).unwrap();
+    }
+    assert_eq!(levels(&mut tree), "P | CGM TX | AB DEF JKL NO QRS UV YZ");
+    let steps = [
+        // Case 1
+        ('F', "P | CGM TX | AB DE JKL NO QRS UV YZ"),
+        // Case 2a
+        ('M', "P | CGL TX | AB DE JK NO QRS UV YZ"),
+        // Case 2c
+        ('G', "P | CL TX | AB DEJK NO QRS UV YZ"),
+        // Case 3b, and the tree shrinks
+        ('D', "CLPTX | AB EJK NO QRS UV YZ"),
+        // Case 3a
+        ('B', "ELPTX | AC JK NO QRS UV YZ"),
+    ];
+    for (k, expected) in steps {
+        assert!(tree.delete(&k).unwrap());
+        assert_eq!(levels(&mut tree), expected, "delete {}", k);
+    }
+    assert!(!tree.delete(&'B').unwrap());
+    assert_eq!(tree.height().unwrap(), 1);
+}
+
+#[test]
+fn random_operations_keep_the_properties() {
+    let mut rng = XorShift64::new(18);
+    for t in [2, 3, 5] {
+        let mut tree = BTree::create(MemoryStore::new(), t).unwrap();
+        let mut expected: Vec<usize> = Vec::new();
+        for step in 0..3000 {
+            let k = rng.gen_range(0, 400);
+            if rng.gen_range(0, 3) > 0 {
+                tree.insert(k).unwrap();
+                let at = expected.partition_point(|&e| e <= k);
+                expected.insert(at, k);
+            } else {
+                let at = expected.binary_search(&k).ok();
+                assert_eq!(tree.delete(&k).unwrap(), at.is_some());
+                if let Some(at) = at {
+                    expected.remove(at);
+                }
+            }
+            if step % 100 == 0 {
+                assert_eq!(check(&mut tree), expected, "t = {}", t);
+            }
+        }
+        assert_eq!(check(&mut tree), expected, "t = {}", t);
+        // Drain the tree; pages of merged nodes are given back
+        for k in expected.clone() {
+            assert!(tree.delete(&k).unwrap());
+        }
+        assert_eq!(check(&mut tree), Vec::<usize>::new());
+        assert_eq!(tree.store().pages_in_use(), 1);
+    }
+}
+
+#[test]
+fn disk_accesses_are_bounded_by_height() {
+    let t = 4;
+    let mut tree = BTree::create(MemoryStore::new(), t).unwrap();
+    let mut rng = XorShift64::new(180);
+    let mut keys: Vec<u32> = (0..2000).collect();
+    algorithms::random::shuffle(&mut keys, &mut rng);
+    for &k in &keys {
+        tree.store_mut().reset_stats();
+        tree.insert(k).unwrap();
+        let stats = tree.stats();
+        let h = tree.height().unwrap() as u64;
+        // One read per level below the root, at most one split per level
+        assert!(stats.reads <= h, "insert {}: {:?}", k, stats);
+        assert!(stats.writes <= 3 * h + 1, "insert {}: {:?}", k, stats);
+    }
+    let h = tree.height().unwrap() as u64;
+    // Theorem 18.1
+    assert!(h as f64 <= ((2000.0 + 1.0) / 2.0_f64).log(t as f64));
+
+    for k in [0, 999, 1999, 2000] {
+        tree.store_mut().reset_stats();
+        let found = tree.search(&k).unwrap();
+        assert_eq!(found.is_some(), k < 2000);
+        assert!(tree.stats().reads <= h);
+        assert_eq!(tree.stats().writes, 0);
+    }
+    for &k in &keys[..1000] {
+        let h = tree.height().unwrap() as u64;
+        tree.store_mut().reset_stats();
+        assert!(tree.delete(&k).unwrap());
+        // Three nodes per level, and twice down a path for cases 2a and 2b
+        assert!(tree.stats().total() <= 2 * 6 * (h + 1), "delete {}", k);
+    }
+}
+
+#[test]
+fn file_store_agrees_with_memory_store() {
+    let path = std::env::temp_dir().join(format!("btree-test-{}.pages", std::process::id()));
+    let mut on_file = BTree::create(FileStore::<i64>::create(&path, 3).unwrap(), 3).unwrap();
+    let mut in_memory = BTree::create(MemoryStore::new(), 3).unwrap();
+    let mut rng = XorShift64::new(1818);
+    for _ in 0..1500 {
+        let k = rng.gen_range(0, 500) as i64 - 250;
+        if rng.gen_range(0, 4) > 0 {
+            on_file.insert(k).unwrap();
+            in_memory.insert(k).unwrap();
+        } else {
+            assert_eq!(on_file.delete(&k).unwrap(), in_memory.delete(&k).unwrap());
+        }
+    }
+    assert_eq!(on_file.stats(), in_memory.stats());
+    assert_eq!(check(&mut on_file), check(&mut in_memory));
+    assert_eq!(
+        std::fs::metadata(&path).unwrap().len() % on_file.store().page_size() as u64,
+        0
+    );
+
+    // A page that was allocated but never written holds no node
+    let x = on_file.store_mut().allocate();
+    assert!(on_file.store_mut().read(x).is_err());
+    assert!(in_memory.store_mut().read(usize::MAX).is_err());
+    std::fs::remove_file(path).unwrap();
+}
+
+/// The keys in sorted order, after asserting the B-tree properties
+fn check<K: Ord + Clone, S: DiskStore<K>>(tree: &mut BTree<K, S>) -> Vec<K> {
+    let mut keys = Vec::new();
+    let mut leaf_depth = None;
+    walk(tree, tree.root(), 0, &mut leaf_depth, &mut keys);
+    assert!(keys.windows(2).all(|w| w[0] <= w[1]), "keys out of order");
+    keys
+}
+
+/// Inorder walk of the subtree on page `x` at `depth`, checking its size
+fn walk<K: Ord + Clone, S: DiskStore<K>>(
+    tree: &mut BTree<K, S>,
+    x: usize,
+    depth: usize,
+    leaf_depth: &mut Option<usize>,
+    keys: &mut Vec<K>,
+) {
+    let t = tree.min_degree();
+    let node = tree.node(x).unwrap();
+    assert!(node.n() < 2 * t, "page {} is overfull", x);
+    assert!(
+        x == tree.root() || node.n() >= t - 1,
+        "page {} underflows",
+        x
+    );
+    if node.leaf {
+        assert_eq!(
+            *leaf_depth.get_or_insert(depth),
+            depth,
+            "leaves differ in depth"
+        );
+        keys.extend(node.keys);
+        return;
+    }
+    assert_eq!(node.children.len(), node.n() + 1);
+    for (i, &c) in node.children.iter().enumerate() {
+        walk(tree, c, depth + 1, leaf_depth, keys);
+        keys.extend(node.keys.get(i).cloned());
+    }
+}
+
+/// The keys of every level, nodes separated by spaces and levels by `|`
+fn levels(tree: &mut BTree<char, MemoryStore<char>>) -> String {
+    let mut text = Vec::new();
+    let mut level = vec![tree.root()];
+    while !level.is_empty() {
+        let mut nodes = Vec::new();
+        let mut next = Vec::new();
+        for x in level {
+            let node = tree.node(x).unwrap();
+            nodes.push(node.keys.iter().collect::<String>());
+            next.extend(node.children);
+        }
+        text.push(nodes.join(" "));
+        level = next;
+    }
+    text.join(" | ")
+}
@@ -0,0 +1,182 @@
+use algorithms::graph::adjacency_list::AdjacencyList;
+use algorithms::graph::bfs::bfs;
+use algorithms::graph::biconnected::{articulation_points, biconnected_components, bridges};
+use algorithms::graph::parse::parse_edge_list;
+use algorithms::graph::representation::Graph;
+use algorithms::graph::scc::{component_graph, strongly_connected_components, tarjan_scc};
+use algorithms::graph::topological_sort::topological_sort;
+use algorithms::random::{RandomSource, XorShift64};
+
+/// Tests topological sort, strongly connected components (CLRS Sections
+/// 20.4, 20.5) and biconnectivity (Problem 20-2).
+///
+/// Results on the book's figures are checked directly; articulation points
+/// and bridges are also compared with brute force on random graphs.
+
+#[test]
+fn topological_sort_orders_figure_20_7() {
+    let g = parse_edge_list(
+        "undershorts pants\nundershorts shoes\npants shoes\npants belt\nbelt jacket\n\
+         shirt belt\nshirt tie\ntie jacket\nsocks shoes\nwatch\n",
+    )
+    .unwrap();
+    let order = topological_sort(&g).unwrap();
+
+    assert_eq!(order.len(), g.vertex_count());
+    let mut position = vec![0; order.len()];
+    for (i, &v) in order.iter().enumerate() {
+        position[v] = i;
+    }
+    for edge in g.edges() {
+        assert!(position[edge.from] < position[edge.to]);
+    }
+}
+
+#[test]
+fn topological_sort_returns_a_cycle() {
+    let g = parse_edge_list("a b\nb c\nc d\nd b\nd e\n").unwrap();
+    let cycle = topological_sort(&g).unwrap_err().vertices;
+
+    assert_eq!(cycle, [1, 2, 3]);
+    for (i, &u) in cycle.iter().enumerate() {
+        assert!(g.has_edge(u, cycle[(i + 1) % cycle.len()]));
+    }
+    assert_eq!(
+        topological_sort(&parse_edge_list("a a\n").unwrap())
+            .unwrap_err()
+            .vertices,
+        [0]
+    );
+}
+
+#[test]
+fn components_of_figure_20_9() {
+    let g = figure_20_9();
+    let expected = [vec![0, 1, 4], vec![2, 3], vec![5, 6], vec![7]];
+
+    assert_eq!(strongly_connected_components(&g), expected);
+    let mut tarjan = tarjan_scc(&g);
+    tarjan.reverse();
+    assert_eq!(tarjan, expected);
+
+    let scc = component_graph(&g);
+    let labels: Vec<&str> = scc.graph.vertices().map(|v| scc.graph.label(v)).collect();
+    assert_eq!(labels, ["abe", "cd", "fg", "h"]);
+    assert_eq!(scc.graph.edge_count(), 5);
+    assert!(scc.graph.has_edge(1, 3));
+    assert!(!scc.graph.has_edge(3, 3));
+    // G^SCC is a dag numbered in topological order
+    assert_eq!(topological_sort(&scc.graph), Ok(vec![0, 1, 2, 3]));
+}
+
+#[test]
+fn kosaraju_and_tarjan_agree_on_random_graphs() {
+    let mut rng = XorShift64::new(20);
+    for _ in 0..50 {
+        let g = random_graph(&mut rng, 12, 20, true);
+        let mut kosaraju = strongly_connected_components(&g);
+        let mut tarjan = tarjan_scc(&g);
+        kosaraju.sort();
+        tarjan.sort();
+        assert_eq!(kosaraju, tarjan);
+    }
+}
+
+#[test]
+fn biconnectivity_of_a_bowtie_with_a_tail() {
+    // Triangles a-b-c and c-d-e share c; the tail e-f-g ends in bridges
+    let g = parse_edge_list("undirected\na b\nb c\nc a\nc d\nd e\ne c\ne f\nf g\n").unwrap();
+
+    assert_eq!(articulation_points(&g), [2, 4, 5]);
+    let bridges: Vec<(usize, usize)> = bridges(&g).iter().map(|e| (e.from, e.to)).collect();
+    assert_eq!(bridges, [(5, 6), (4, 5)]);
+
+    let mut sizes: Vec<usize> = biconnected_components(&g).iter().map(Vec::len).collect();
+    sizes.sort_unstable();
+    assert_eq!(sizes, [1, 1, 3, 3]);
+}
+
+#[test]
+fn biconnectivity_matches_brute_force() {
+    let mut rng = XorShift64::new(22);
+    for _ in 0..50 {
+        let g = random_graph(&mut rng, 10, 14, false);
+
+        let expected: Vec<usize> = g
+            .vertices()
+            .filter(|&v| components_without(&g, Some(v), None) > components_without(&g, None, None))
+            .collect();
+        assert_eq!(articulation_points(&g), expected);
+
+        for edge in bridges(&g) {
+            let key = (edge.from.min(edge.to), edge.from.max(edge.to));
+            assert!(components_without(&g, None, Some(key)) > components_without(&g, None, None));
+        }
+        let bridge_count = g
+            .edges()
+            .iter()
+            .filter(|e| {
+                components_without(&g, None, Some((e.from, e.to)))
+                    > components_without(&g, None, None)
+            })
+            .count();
+        assert_eq!(bridges(&g).len(), bridge_count);
+
+        // Every nonloop edge lies in exactly one component
+        let total: usize = biconnected_components(&g).iter().map(Vec::len).sum();
+        assert_eq!(total, g.edges().iter().filter(|e| e.from != e.to).count());
+    }
+}
+
+/// Figure 20.9: eight vertices `a..h` in four components
+fn figure_20_9() -> AdjacencyList {
+    parse_edge_list(
+        "a\nb\nc\nd\ne\nf\ng\nh\n\
+         a b\nb c\nb e\nb f\nc d\nc g\nd c\nd h\ne a\ne f\nf g\ng f\ng h\nh h\n",
+    )
+    .unwrap()
+}
+
+/// Random graph on `n` vertices with `m` distinct nonloop edges
+fn random_graph(rng: &mut XorShift64, n: usize, m: usize, directed: bool) -> AdjacencyList {
+    let mut g = if directed {
+        AdjacencyList::directed(n)
+    } else {
+        AdjacencyList::undirected(n)
+    };
+    while g.edge_count() < m {
+        let (u, v) = (rng.gen_range(0, n), rng.gen_range(0, n));
+        if u != v && !g.has_edge(u, v) {
+            g.add_edge(u, v);
+        }
+    }
+    g
+}
+
+/// Connected components after deleting vertex `removed` or edge `cut`
+fn components_without(
+    g: &AdjacencyList,
+    removed: Option<usize>,
+    cut: Option<(usize, usize)>,
+) -> usize {
+    let mut h = AdjacencyList::undirected(g.vertex_count());
+    for e in g.edges() {
+        let touches = removed.is_some_and(|v| e.from == v || e.to == v);
+        if !touches && cut != Some((e.from, e.to)) {
+            h.add_edge(e.from, e.to);
+        }
+    }
+    let mut seen = vec![false; g.vertex_count()];
+    let mut count = 0;
+    for v in g.vertices() {
+        if seen[v] || Some(v) == removed {
+            continue;
+        }
+        count += 1;
+        let result = bfs(&h, v);
+        for u in h.vertices() {
+            seen[u] |= result.d[u].is_some();
+        }
+    }
+    count
+}
@@ -0,0 +1,227 @@
+use algorithms::disjoint_set::components::connected_components;
+use algorithms::disjoint_set::forest::DisjointSetForest;
+use algorithms::disjoint_set::lca::offline_lca;
+use algorithms::disjoint_set::linked_list::DisjointSetList;
+use algorithms::disjoint_set::union_find::DisjointSet;
+use algorithms::graph::adjacency_list::AdjacencyList;
+use algorithms::graph::parse::parse_edge_list;
+use algorithms::random::{RandomSource, XorShift64};
+
+/// Tests the disjoint-set representations of CLRS Chapter 19 and their
+/// clients.
+///
+/// Both representations must agree with a naive labeling under random
+/// operations and stay within their running-time bounds, counted in
+/// pointer operations; offline LCA must agree with walking up the tree.
+
+#[test]
+fn representations_agree_with_naive_labels() {
+    let mut rng = XorShift64::new(19);
+    for n in [1, 2, 10, 100] {
+        let operations: Vec<(usize, usize)> = (0..2 * n)
+            .map(|_| (rng.gen_range(0, n), rng.gen_range(0, n)))
+            .collect();
+        check_against_labels(DisjointSetList::new(), n, &operations);
+        check_against_labels(DisjointSetForest::new(), n, &operations);
+    }
+}
+
+#[test]
+fn linked_lists_follow_one_pointer_per_find() {
+    let mut sets = DisjointSetList::new();
+    let elements: Vec<usize> = (0..6).map(|_| sets.make_set()).collect();
+    assert!(sets.union(elements[0], elements[1]));
+    assert!(sets.union(elements[2], elements[3]));
+    assert!(sets.union(elements[4], elements[0]));
+    assert!(!sets.union(elements[1], elements[4]));
+
+    // {4} was appended to the longer list {0, 1}
+    assert_eq!(sets.members(4), [0, 1, 4]);
+    assert_eq!(sets.members(3), [2, 3]);
+    let before = sets.pointer_ops();
+    assert_eq!(sets.find_set(4), 0);
+    assert_eq!(sets.pointer_ops(), before + 1);
+}
+
+#[test]
+fn forest_unions_by_rank() {
+    let mut sets = DisjointSetForest::new();
+    let elements: Vec<usize> = (0..8).map(|_| sets.make_set()).collect();
+    for pair in elements.chunks(2) {
+        assert!(sets.union(pair[0], pair[1]));
+    }
+    assert!(sets.union(0, 2));
+    assert!(sets.union(4, 6));
+    assert!(sets.union(0, 4));
+    assert!(!sets.union(1, 7));
+
+    let root = sets.find_set(7);
+    assert!(elements.iter().all(|&x| sets.find_set(x) == root));
+    assert_eq!(sets.len(), 8);
+}
+
+#[test]
+fn weighted_union_and_rank_bounds() {
+    for n in [16, 100, 1024] {
+        let unions = balanced_unions(n);
+        let lg_n = n.ilog2() as u64;
+
+        // Every element changes its set pointer at most lg n times
+        let mut list = DisjointSetList::new();
+        run(&mut list, n, &unions);
+        let m = (n + 2 * unions.len()) as u64;
+        assert!(list.pointer_ops() <= m + n as u64 * lg_n);
+
+        // A root of rank r has at least 2^r elements
+        let mut forest = DisjointSetForest::new();
+        run(&mut forest, n, &unions);
+        assert!((0..n).all(|x| forest.rank(x) as u64 <= lg_n));
+        let root = forest.find_set(0);
+        assert_eq!(forest.rank(root) as u64, lg_n);
+    }
+}
+
+#[test]
+fn forest_stays_nearly_linear() {
+    let mut rng = XorShift64::new(42);
+    let n = 1 << 14;
+    let mut forest = DisjointSetForest::new();
+    for _ in 0..n {
+        forest.make_set();
+    }
+    let mut operations = n as u64;
+    for _ in 0..4 * n {
+        let (x, y) = (rng.gen_range(0, n), rng.gen_range(0, n));
+        forest.union(x, y);
+        forest.find_set(rng.gen_range(0, n));
+        operations += 2;
+    }
+    // α(n) ≤ 4 for any practical n; every operation costs a few pointers
+    assert!(forest.pointer_ops() <= 8 * operations);
+}
+
+#[test]
+fn connected_components_with_both_representations() {
+    let g = parse_edge_list("undirected\na b\nb c\nd e\nf\ng h\nh d\n").unwrap();
+    let expected = [0, 0, 0, 1, 1, 2, 1, 1];
+
+    assert_eq!(
+        connected_components(&g, &mut DisjointSetList::new()),
+        expected
+    );
+    assert_eq!(
+        connected_components(&g, &mut DisjointSetForest::new()),
+        expected
+    );
+}
+
+#[test]
+fn offline_lca_agrees_with_walking_up() {
+    let mut rng = XorShift64::new(3);
+    for n in [1, 2, 7, 50] {
+        // Vertex v > 0 hangs from a random earlier vertex
+        let parent: Vec<usize> = (0..n)
+            .map(|v| if v == 0 { 0 } else { rng.gen_range(0, v) })
+            .collect();
+        let mut directed = AdjacencyList::directed(n);
+        let mut undirected = AdjacencyList::undirected(n);
+        for (v, &p) in parent.iter().enumerate().skip(1) {
+            directed.add_edge(p, v);
+            undirected.add_edge(v, p);
+        }
+        let pairs: Vec<(usize, usize)> = (0..3 * n)
+            .map(|_| (rng.gen_range(0, n), rng.gen_range(0, n)))
+            .collect();
+        let expected: Vec<Option<usize>> = pairs
+            .iter()
+            .map(|&(u, v)| Some(naive_lca(&parent, u, v)))
+            .collect();
+
+        assert_eq!(
+            offline_lca(&directed, 0, &pairs, &mut DisjointSetForest::new()),
+            expected
+        );
+        assert_eq!(
+            offline_lca(&undirected, 0, &pairs, &mut DisjointSetList::new()),
+            expected
+        );
+    }
+}
+
+#[test]
+fn offline_lca_skips_vertices_outside_the_tree() {
+    let g = parse_edge_list("r a\nr b\nx y\n").unwrap();
+    let lca = offline_lca(
+        &g,
+        0,
+        &[(1, 2), (1, 3), (3, 4)],
+        &mut DisjointSetForest::new(),
+    );
+
+    assert_eq!(lca, [Some(0), None, None]);
+}
+
+/// Apply `operations` as unions to `sets` and a list of labels side by side
+fn check_against_labels<D: DisjointSet>(mut sets: D, n: usize, operations: &[(usize, usize)]) {
+    for x in 0..n {
+        assert_eq!(sets.make_set(), x);
+    }
+    let mut label: Vec<usize> = (0..n).collect();
+    for &(x, y) in operations {
+        let joined = label[x] != label[y];
+        assert_eq!(sets.union(x, y), joined);
+        if joined {
+            let (old, new) = (label[y], label[x]);
+            for l in label.iter_mut().filter(|l| **l == old) {
+                *l = new;
+            }
+        }
+    }
+    for x in 0..n {
+        for y in 0..n {
+            assert_eq!(sets.find_set(x) == sets.find_set(y), label[x] == label[y]);
+        }
+    }
+    assert_eq!(sets.len(), n);
+}
+
+/// Unions joining sets of equal size: 1, then 2, 4, ...
+fn balanced_unions(n: usize) -> Vec<(usize, usize)> {
+    let mut unions = Vec::new();
+    let mut step = 1;
+    while step < n {
+        for i in (0..n - step).step_by(2 * step) {
+            unions.push((i, i + step));
+        }
+        step *= 2;
+    }
+    unions
+}
+
+/// `n` MAKE-SETs, then a UNION per pair
+fn run<D: DisjointSet>(sets: &mut D, n: usize, unions: &[(usize, usize)]) {
+    for _ in 0..n {
+        sets.make_set();
+    }
+    for &(x, y) in unions {
+        sets.union(x, y);
+    }
+}
+
+/// Least common ancestor by marking the ancestors of `u`
+fn naive_lca(parent: &[usize], u: usize, v: usize) -> usize {
+    let mut ancestors = vec![false; parent.len()];
+    let mut x = u;
+    loop {
+        ancestors[x] = true;
+        if x == 0 {
+            break;
+        }
+        x = parent[x];
+    }
+    let mut y = v;
+    while !ancestors[y] {
+        y = parent[y];
+    }
+    y
+}
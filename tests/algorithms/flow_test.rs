@@ -0,0 +1,222 @@
+use algorithms::graph::adjacency_list::AdjacencyList;
+use algorithms::graph::flow::ford_fulkerson::{
+    Augmentation, edmonds_karp, edmonds_karp_traced, min_cut,
+};
+use algorithms::graph::flow::matching::{Matching, bipartite_matching, bipartition, hopcroft_karp};
+use algorithms::graph::flow::network::FlowNetwork;
+use algorithms::graph::flow::push_relabel::{generic_push_relabel, relabel_to_front};
+use algorithms::graph::parse::parse_edge_list;
+use algorithms::graph::representation::Graph;
+use algorithms::random::{RandomSource, XorShift64};
+
+/// Tests the maximum-flow algorithms of CLRS Chapter 24 and the bipartite
+/// matching algorithms of Chapter 25.
+///
+/// Every method must find the flow of value 23 in Figure 24.6, and all of
+/// them must agree with each other and with the minimum cut on random
+/// networks, leaving a feasible flow behind.
+
+#[test]
+fn edmonds_karp_on_figure_24_6() {
+    let g = figure_24_6();
+    let (s, t) = (vertex(&g, "s"), vertex(&g, "t"));
+    let mut net = FlowNetwork::from_graph(&g);
+    let mut residuals = Vec::new();
+    let result = edmonds_karp_traced(
+        &mut net,
+        s,
+        t,
+        &mut |_: &Augmentation, net: &FlowNetwork| residuals.push(net.residual_network()),
+    );
+
+    assert_eq!(result.value, 23);
+    assert_flow_is_feasible(&net, s, t);
+    assert_eq!(residuals.len(), result.augmentations.len());
+    // The first residual network is G itself; BFS finds s → v1 → v3 → t
+    let first = &result.augmentations[0];
+    assert_eq!(residuals[0], g);
+    assert_eq!(first.path, [s, vertex(&g, "v1"), vertex(&g, "v3"), t]);
+    assert_eq!(first.amount, 12);
+    // Every augmenting path is at least as long as the one before
+    let lengths: Vec<usize> = result.augmentations.iter().map(|a| a.path.len()).collect();
+    assert!(lengths.windows(2).all(|pair| pair[0] <= pair[1]));
+    let total: i64 = result.augmentations.iter().map(|a| a.amount).sum();
+    assert_eq!(total, 23);
+
+    let cut = min_cut(&net, s);
+    assert_eq!(cut.capacity, 23);
+    let mut s_side: Vec<&str> = cut.s_side.iter().map(|&v| g.label(v)).collect();
+    s_side.sort();
+    assert_eq!(s_side, ["s", "v1", "v2", "v4"]);
+}
+
+#[test]
+fn push_relabel_on_figure_24_6() {
+    let g = figure_24_6();
+    let (s, t) = (vertex(&g, "s"), vertex(&g, "t"));
+    let mut net = FlowNetwork::from_graph(&g);
+
+    let generic = generic_push_relabel(&mut net, s, t);
+    assert_eq!(generic.value, 23);
+    assert_flow_is_feasible(&net, s, t);
+
+    let to_front = relabel_to_front(&mut net, s, t);
+    assert_eq!(to_front.value, 23);
+    assert!(to_front.pushes > 0 && to_front.relabels > 0);
+    assert_flow_is_feasible(&net, s, t);
+}
+
+#[test]
+fn methods_agree_on_random_networks() {
+    let mut rng = XorShift64::new(24);
+    for n in [2, 3, 6, 12] {
+        for _ in 0..10 {
+            let mut net = random_network(n, 3 * n, &mut rng);
+            let (s, t) = (0, n - 1);
+
+            let value = edmonds_karp(&mut net, s, t).value;
+            assert_flow_is_feasible(&net, s, t);
+            assert_eq!(min_cut(&net, s).capacity, value);
+            assert!(!min_cut(&net, s).s_side.contains(&t));
+
+            assert_eq!(generic_push_relabel(&mut net, s, t).value, value);
+            assert_flow_is_feasible(&net, s, t);
+            assert_eq!(relabel_to_front(&mut net, s, t).value, value);
+            assert_flow_is_feasible(&net, s, t);
+        }
+    }
+}
+
+#[test]
+fn residual_network_reverses_flow() {
+    let mut net = FlowNetwork::new(3);
+    net.add_edge(0, 1, 5);
+    net.add_edge(1, 0, 2);
+    net.add_edge(1, 2, 3);
+    edmonds_karp(&mut net, 0, 2);
+
+    let residual = net.residual_network();
+    assert_eq!(residual.weight(0, 1), Some(2));
+    // c_f(1, 0) = c(1, 0) + f(0, 1)
+    assert_eq!(residual.weight(1, 0), Some(5));
+    assert_eq!(residual.weight(2, 1), Some(3));
+    assert!(!residual.has_edge(1, 2));
+}
+
+#[test]
+fn matchings_agree_on_random_bipartite_graphs() {
+    let mut rng = XorShift64::new(25);
+    for (left, right) in [(1, 1), (3, 5), (8, 8), (20, 12)] {
+        for _ in 0..10 {
+            let g = random_bipartite_graph(left, right, 2 * (left + right), &mut rng);
+            let by_flow = bipartite_matching(&g);
+            let by_phases = hopcroft_karp(&g);
+
+            assert_matching_is_valid(&g, &by_flow);
+            assert_matching_is_valid(&g, &by_phases);
+            assert_eq!(by_flow.size(), by_phases.size());
+        }
+    }
+}
+
+#[test]
+fn matching_of_a_path_and_an_odd_cycle() {
+    // A perfect matching exists on a path with an even number of vertices
+    let path = parse_edge_list("undirected\na b\nb c\nc d\nd e\ne f\n").unwrap();
+    assert_eq!(hopcroft_karp(&path).edges(), [(0, 1), (2, 3), (4, 5)]);
+    assert_eq!(bipartite_matching(&path).size(), 3);
+
+    let pentagon = parse_edge_list("undirected\na b\nb c\nc d\nd e\ne a\n").unwrap();
+    assert_eq!(bipartition(&pentagon), None);
+}
+
+#[test]
+fn hopcroft_karp_follows_a_long_augmenting_path() {
+    // The first phase matches l_i to r_(i-1) and leaves l0 and r_n free, so
+    // the second phase augments along the whole path l0 r0 l1 r1 ... l_n r_n.
+    let n = 100_000;
+    let mut text = String::from("undirected\n");
+    for i in 1..=n {
+        text.push_str(&format!("l{} r{}\nl{} r{}\n", i, i - 1, i, i));
+    }
+    text.push_str("l0 r0\n");
+    let g = parse_edge_list(&text).unwrap();
+
+    let matching = hopcroft_karp(&g);
+    assert_eq!(matching.size(), n + 1);
+    assert_eq!(matching.mate[vertex(&g, "l0")], Some(vertex(&g, "r0")));
+    assert_matching_is_valid(&g, &matching);
+}
+
+#[test]
+#[should_panic(expected = "not bipartite")]
+fn matching_rejects_odd_cycles() {
+    hopcroft_karp(&parse_edge_list("a b\nb c\nc a\n").unwrap());
+}
+
+/// The flow network of CLRS Figure 24.6, with maximum flow 23
+fn figure_24_6() -> AdjacencyList {
+    parse_edge_list(
+        "s v1 16\ns v2 13\nv1 v3 12\nv2 v1 4\nv2 v4 14\nv3 v2 9\nv3 t 20\nv4 v3 7\nv4 t 4\n",
+    )
+    .unwrap()
+}
+
+/// Index of the vertex with the given label
+fn vertex(g: &AdjacencyList, label: &str) -> usize {
+    g.vertex_named(label).unwrap()
+}
+
+/// Random network on `0..n` with capacities in `0..10`
+fn random_network<R: RandomSource>(n: usize, m: usize, rng: &mut R) -> FlowNetwork {
+    let mut net = FlowNetwork::new(n);
+    for _ in 0..m {
+        let (u, v) = (rng.gen_range(0, n), rng.gen_range(0, n));
+        net.add_edge(u, v, rng.gen_range(0, 10) as i64);
+    }
+    net
+}
+
+/// Random undirected graph with edges between `0..left` and
+/// `left..left + right`
+fn random_bipartite_graph<R: RandomSource>(
+    left: usize,
+    right: usize,
+    m: usize,
+    rng: &mut R,
+) -> AdjacencyList {
+    let mut g = AdjacencyList::undirected(left + right);
+    for _ in 0..m {
+        let (u, v) = (rng.gen_range(0, left), left + rng.gen_range(0, right));
+        if !g.has_edge(u, v) {
+            g.add_edge(u, v);
+        }
+    }
+    g
+}
+
+/// Capacity constraint and flow conservation at every vertex but `s`, `t`
+fn assert_flow_is_feasible(net: &FlowNetwork, s: usize, t: usize) {
+    let mut net_out = vec![0; net.vertex_count()];
+    for e in net.flow_edges() {
+        assert!(0 <= e.flow && e.flow <= e.capacity, "{:?}", e);
+        net_out[e.from] += e.flow;
+        net_out[e.to] -= e.flow;
+    }
+    for v in net.vertices() {
+        if v != s && v != t {
+            assert_eq!(net_out[v], 0, "flow is not conserved at {}", v);
+        }
+    }
+    assert_eq!(net_out[s], net.value(s));
+}
+
+/// Mates are symmetric and every matched pair is an edge of `g`
+fn assert_matching_is_valid(g: &AdjacencyList, m: &Matching) {
+    for (u, mate) in m.mate.iter().enumerate() {
+        if let Some(v) = *mate {
+            assert_eq!(m.mate[v], Some(u));
+            assert!(g.has_edge(u, v));
+        }
+    }
+}
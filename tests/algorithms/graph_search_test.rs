@@ -0,0 +1,154 @@
+use algorithms::graph::adjacency_list::AdjacencyList;
+use algorithms::graph::adjacency_matrix::AdjacencyMatrix;
+use algorithms::graph::bfs::{Color, bfs, print_path};
+use algorithms::graph::dfs::{EdgeClass, dfs, dfs_in_order};
+use algorithms::graph::parse::parse_edge_list;
+use algorithms::graph::representation::Graph;
+
+/// Tests breadth-first and depth-first search (CLRS Sections 20.2, 20.3).
+///
+/// BFS must reproduce the distances and queue of Figure 20.3, DFS the
+/// timestamps and edge classes of Figure 20.4, on both representations.
+
+#[test]
+fn bfs_matches_figure_20_3() {
+    let g = figure_20_3();
+    let s = g.vertex_named("s").unwrap();
+    let result = bfs(&g, s);
+
+    let distances: Vec<(&str, usize)> = g
+        .vertices()
+        .map(|v| (g.label(v), result.d[v].unwrap()))
+        .collect();
+    assert_eq!(
+        distances,
+        [
+            ("r", 1),
+            ("s", 0),
+            ("t", 2),
+            ("u", 3),
+            ("v", 2),
+            ("w", 1),
+            ("x", 2),
+            ("y", 3)
+        ]
+    );
+
+    let u = g.vertex_named("u").unwrap();
+    let path = print_path(&result.pi, s, u).unwrap();
+    assert_eq!(path.len(), 4);
+    assert_eq!(path[0], s);
+    for pair in path.windows(2) {
+        assert!(g.has_edge(pair[0], pair[1]));
+    }
+
+    // Every vertex is enqueued exactly once
+    assert_eq!(result.order.len(), 8);
+    assert_eq!(result.queue_after(0), [s]);
+    assert!(result.queue_after(8).is_empty());
+    assert_eq!(result.color_after(s, 0), Color::Gray);
+    assert_eq!(result.color_after(s, 1), Color::Black);
+    assert_eq!(result.color_after(u, 1), Color::White);
+}
+
+#[test]
+fn bfs_leaves_unreachable_vertices_at_infinity() {
+    let g = parse_edge_list("a b\nc a\n").unwrap();
+    let result = bfs(&g, 0);
+
+    assert_eq!(result.d, [Some(0), Some(1), None]);
+    assert_eq!(print_path(&result.pi, 0, 2), None);
+    assert_eq!(result.order, [0, 1]);
+}
+
+#[test]
+fn dfs_matches_figure_20_4() {
+    let g = figure_20_4();
+    let result = dfs(&g);
+
+    let times: Vec<(&str, usize, usize)> = g
+        .vertices()
+        .map(|v| (g.label(v), result.d[v], result.f[v]))
+        .collect();
+    assert_eq!(
+        times,
+        [
+            ("u", 1, 8),
+            ("v", 2, 7),
+            ("w", 9, 12),
+            ("x", 4, 5),
+            ("y", 3, 6),
+            ("z", 10, 11)
+        ]
+    );
+
+    let class =
+        |u: &str, v: &str| result.class_of(g.vertex_named(u).unwrap(), g.vertex_named(v).unwrap());
+    assert_eq!(class("u", "v"), Some(EdgeClass::Tree));
+    assert_eq!(class("x", "v"), Some(EdgeClass::Back));
+    assert_eq!(class("u", "x"), Some(EdgeClass::Forward));
+    assert_eq!(class("w", "y"), Some(EdgeClass::Cross));
+    assert_eq!(class("z", "z"), Some(EdgeClass::Back));
+    assert_eq!(result.edges.len(), g.edge_count());
+    assert_eq!(result.roots(), [0, 2]);
+
+    let matrix = AdjacencyMatrix::from_graph(&g);
+    assert_eq!(dfs(&matrix), result);
+}
+
+#[test]
+fn dfs_in_order_starts_from_the_given_roots() {
+    let g = figure_20_4();
+    let result = dfs_in_order(&g, &[5, 4, 3, 2, 1, 0]);
+
+    assert_eq!(result.d[5], 1);
+    assert_eq!(result.roots(), [5, 4, 2, 0]);
+    assert_eq!(result.by_decreasing_finish()[0], 0);
+}
+
+#[test]
+fn undirected_dfs_has_only_tree_and_back_edges() {
+    let g = figure_20_3();
+    let result = dfs(&g);
+
+    assert_eq!(result.edges.len(), g.edge_count());
+    assert!(
+        result
+            .edges
+            .iter()
+            .all(|(_, class)| matches!(class, EdgeClass::Tree | EdgeClass::Back))
+    );
+    let tree_edges = result
+        .edges
+        .iter()
+        .filter(|(_, class)| *class == EdgeClass::Tree)
+        .count();
+    assert_eq!(tree_edges, g.vertex_count() - 1);
+}
+
+#[test]
+fn deep_graphs_do_not_overflow_the_stack() {
+    let n = 200_000;
+    let mut g = AdjacencyList::directed(n);
+    for v in 1..n {
+        g.add_edge(v - 1, v);
+    }
+    let result = dfs(&g);
+
+    assert_eq!(result.d[n - 1], n);
+    assert_eq!(result.f[0], 2 * n);
+}
+
+/// Figure 20.3: undirected graph searched from `s`
+fn figure_20_3() -> AdjacencyList {
+    parse_edge_list(
+        "undirected\nr\ns\nt\nu\nv\nw\nx\ny\n\
+         r s\nr v\ns w\nw t\nw x\nt x\nt u\nx u\nx y\nu y\n",
+    )
+    .unwrap()
+}
+
+/// Figure 20.4: directed graph with all four edge classes
+fn figure_20_4() -> AdjacencyList {
+    parse_edge_list("u\nv\nw\nx\ny\nz\nu v\nu x\nv y\ny x\nx v\nw y\nw z\nz z\n").unwrap()
+}
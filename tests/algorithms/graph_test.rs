@@ -0,0 +1,140 @@
+use algorithms::graph::adjacency_list::AdjacencyList;
+use algorithms::graph::adjacency_matrix::AdjacencyMatrix;
+use algorithms::graph::parse::{parse_dot, parse_edge_list};
+use algorithms::graph::representation::{Edge, Graph};
+
+/// Tests the graph representations of CLRS Section 20.1 and the loaders.
+///
+/// Both representations must describe the same graph, and transpose and
+/// square must match Exercises 20.1-3 and 20.1-5.
+
+#[test]
+fn list_and_matrix_agree_on_figure_20_1() {
+    // Figure 20.1: undirected graph on 5 vertices with 7 edges
+    let mut list = AdjacencyList::undirected(5);
+    for (u, v) in [(0, 1), (0, 4), (1, 2), (1, 3), (1, 4), (2, 3), (3, 4)] {
+        list.add_edge(u, v);
+    }
+    let matrix = AdjacencyMatrix::from_graph(&list);
+
+    assert_eq!(list.edge_count(), 7);
+    assert_eq!(matrix.edge_count(), 7);
+    assert_eq!(list.edges(), matrix.edges());
+    assert_eq!(list.degree(1), 4);
+    assert_eq!(matrix.degree(1), 4);
+    for u in list.vertices() {
+        for v in list.vertices() {
+            assert_eq!(list.has_edge(u, v), matrix.has_edge(u, v));
+            assert_eq!(matrix.has_edge(u, v), matrix.has_edge(v, u));
+        }
+    }
+    assert_eq!(AdjacencyList::from_graph(&matrix).edges(), list.edges());
+}
+
+#[test]
+fn transpose_reverses_every_edge() {
+    let mut list = AdjacencyList::directed(4);
+    list.add_weighted_edge(0, 1, 3);
+    list.add_weighted_edge(1, 2, -2);
+    list.add_weighted_edge(3, 3, 5);
+    let matrix = AdjacencyMatrix::from_graph(&list);
+
+    for transposed in [list.transpose().edges(), matrix.transpose().edges()] {
+        assert_eq!(
+            transposed,
+            [Edge::new(1, 0, 3), Edge::new(2, 1, -2), Edge::new(3, 3, 5)]
+        );
+    }
+    assert!(list.transpose().is_weighted());
+
+    let mut undirected = AdjacencyList::undirected(2);
+    undirected.add_edge(0, 1);
+    assert_eq!(undirected.transpose(), undirected);
+}
+
+#[test]
+fn square_adds_two_edge_paths() {
+    // 0 → 1 → 2 → 3 and 1 → 0
+    let mut list = AdjacencyList::directed(4);
+    for (u, v) in [(0, 1), (1, 2), (2, 3), (1, 0)] {
+        list.add_edge(u, v);
+    }
+    let matrix = AdjacencyMatrix::from_graph(&list);
+    let expected = [(0, 1), (0, 2), (1, 0), (1, 2), (1, 3), (2, 3)];
+
+    for square in [list.square().edges(), matrix.square().edges()] {
+        let pairs: Vec<(usize, usize)> = square.iter().map(|e| (e.from, e.to)).collect();
+        let mut sorted = pairs.clone();
+        sorted.sort();
+        assert_eq!(sorted, expected);
+    }
+}
+
+#[test]
+fn parses_edge_lists() {
+    let g = parse_edge_list(
+        "# Figure 20.2\n\
+         directed\n\
+         u v\n\
+         u x 4   # weighted\n\
+         z\n",
+    )
+    .unwrap();
+
+    assert!(g.is_directed());
+    assert!(g.is_weighted());
+    assert_eq!(g.vertex_count(), 4);
+    assert_eq!(g.vertex_named("x"), Some(2));
+    assert_eq!(g.weight(0, 1), Some(1));
+    assert_eq!(g.weight(0, 2), Some(4));
+    assert_eq!(g.degree(3), 0);
+
+    let g = parse_edge_list("undirected\na b\n").unwrap();
+    assert!(!g.is_directed() && !g.is_weighted());
+    assert!(g.has_edge(1, 0));
+
+    let error = parse_edge_list("a b\na b c\n").unwrap_err();
+    assert_eq!(error.line, 2);
+    assert!(parse_edge_list("a b c d").is_err());
+}
+
+#[test]
+fn parses_dot() {
+    let g = parse_dot(
+        "// Figure 22.4\n\
+         digraph G {\n\
+           rankdir = LR;\n\
+           node [shape=circle]\n\
+           s -> t [weight=6, color=red]; s -> y [label=7]\n\
+           \"t\" -> x -> z /* chain */\n\
+           w\n\
+         }",
+    )
+    .unwrap();
+
+    assert!(g.is_directed());
+    assert_eq!(g.vertex_count(), 6);
+    let (s, t, y) = (0, 1, 2);
+    assert_eq!(g.weight(s, t), Some(6));
+    assert_eq!(g.weight(s, y), Some(7));
+    assert!(g.has_edge(g.vertex_named("x").unwrap(), g.vertex_named("z").unwrap()));
+    assert_eq!(g.label(5), "w");
+
+    let g = parse_dot("strict graph { a -- b -- c [weight=-2] }").unwrap();
+    assert!(!g.is_directed());
+    assert_eq!(g.weight(2, 1), Some(-2));
+}
+
+#[test]
+fn reports_dot_errors_with_line_numbers() {
+    assert_eq!(parse_dot("graph {\n a -> b\n}").unwrap_err().line, 2);
+    assert_eq!(
+        parse_dot("digraph {\n\n a -> b [weight=x]\n}")
+            .unwrap_err()
+            .line,
+        3
+    );
+    assert!(parse_dot("digraph { subgraph { a } }").is_err());
+    assert!(parse_dot("digraph { a -> b").is_err());
+    assert!(parse_dot("tree { }").is_err());
+}
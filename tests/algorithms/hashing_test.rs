@@ -0,0 +1,169 @@
+use std::collections::HashMap;
+
+use algorithms::hashing::chained::ChainedHashTable;
+use algorithms::hashing::hash_function::{Division, HashFunction, Multiplication, Universal};
+use algorithms::hashing::open_addressing::{
+    DoubleHashing, LinearProbing, OpenAddressTable, Overflow, ProbeSequence, QuadraticProbing,
+};
+use algorithms::random::{RandomSource, XorShift64};
+
+/// Tests the hash tables and hash functions of CLRS Chapter 11.
+///
+/// Every table must agree with `HashMap` under random inserts, deletes and
+/// searches, and the mean probes of a search must stay within the bounds
+/// the analysis gives in terms of the load factor.
+
+#[test]
+fn hash_functions_stay_in_range() {
+    let mut rng = XorShift64::new(11);
+    let universal = Universal::random(97, &mut rng);
+    for k in [0, 1, 96, 97, 12345, u64::MAX] {
+        assert!(Division::new(97).hash(k) < 97);
+        assert!(Multiplication::new(97).hash(k) < 97);
+        assert!(universal.hash(k) < 97);
+    }
+    assert_eq!(Division::new(701).hash(1000), 299);
+    // 2^14 slots take the top 14 bits of kA mod 1
+    assert_eq!(Multiplication::new(1 << 14).hash(0), 0);
+}
+
+#[test]
+fn universal_functions_are_reproducible_from_a_seed() {
+    let draw = |seed| Universal::random(1000, &mut XorShift64::new(seed));
+    assert_eq!(draw(3), draw(3));
+    assert_ne!(draw(3), draw(4));
+}
+
+#[test]
+fn chained_table_agrees_with_hash_map() {
+    let mut rng = XorShift64::new(21);
+    let mut table = ChainedHashTable::new(Universal::random(13, &mut rng));
+    let mut expected = HashMap::new();
+    for step in 0..2000 {
+        let k = rng.gen_range(0, 60) as u64;
+        match rng.gen_range(0, 3) {
+            0 => assert_eq!(table.insert(k, step), expected.insert(k, step)),
+            1 => assert_eq!(table.delete(k), expected.remove(&k)),
+            _ => assert_eq!(table.search(k), expected.get(&k)),
+        }
+        assert_eq!(table.len(), expected.len());
+    }
+    assert_eq!(table.chain_lengths().iter().sum::<usize>(), table.len());
+}
+
+#[test]
+fn open_address_tables_agree_with_hash_map() {
+    let mut rng = XorShift64::new(22);
+    let h = Universal::random(61, &mut rng);
+    check_against_hash_map(LinearProbing::new(h), &mut rng);
+    check_against_hash_map(QuadraticProbing::new(Division::new(64)), &mut rng);
+    check_against_hash_map(
+        DoubleHashing::new(h, Universal::random(59, &mut rng)),
+        &mut rng,
+    );
+}
+
+#[test]
+fn linear_probing_delete_leaves_no_tombstones() {
+    let mut rng = XorShift64::new(23);
+    let mut table = OpenAddressTable::new(LinearProbing::new(Division::new(31)));
+    let mut expected = HashMap::new();
+    for step in 0..2000 {
+        let k = rng.gen_range(0, 100) as u64;
+        if rng.gen_range(0, 2) == 0 && expected.len() < 25 {
+            assert_eq!(table.insert(k, step).unwrap(), expected.insert(k, step));
+        } else {
+            assert_eq!(table.linear_probing_delete(k), expected.remove(&k));
+        }
+        assert_eq!(table.tombstones(), 0);
+        for (k, v) in &expected {
+            assert_eq!(table.search(*k), Some(v));
+        }
+    }
+}
+
+#[test]
+fn tombstones_are_reused_by_insert() {
+    let mut table = OpenAddressTable::new(LinearProbing::new(Division::new(7)));
+    for k in [0, 7, 14] {
+        table.insert(k, ()).unwrap();
+    }
+    assert_eq!(table.delete(7), Some(()));
+    assert_eq!(table.tombstones(), 1);
+    // 14 must still be found past the tombstone
+    assert_eq!(table.search(14), Some(&()));
+
+    table.insert(21, ()).unwrap();
+    assert_eq!(table.tombstones(), 0);
+    assert_eq!(table.keys()[..4], [Some(0), Some(21), Some(14), None]);
+}
+
+#[test]
+fn full_table_overflows() {
+    let mut table = OpenAddressTable::new(LinearProbing::new(Division::new(3)));
+    for k in 0..3 {
+        table.insert(k, k).unwrap();
+    }
+    assert_eq!(table.insert(3, 3), Err(Overflow { key: 3 }));
+    // Updating a present key needs no free slot
+    assert_eq!(table.insert(1, 10), Ok(Some(1)));
+    assert_eq!(table.search(3), None);
+}
+
+#[test]
+fn probes_stay_within_uniform_hashing_bounds() {
+    let mut rng = XorShift64::new(24);
+    let m = 1021;
+    for alpha in [0.25, 0.5, 0.75] {
+        let n = (alpha * m as f64) as usize;
+        let keys: Vec<u64> = (0..n).map(|_| rng.next_u64() >> 1).collect();
+
+        let mut chained = ChainedHashTable::new(Universal::random(m, &mut rng));
+        let mut double = OpenAddressTable::new(DoubleHashing::new(
+            Universal::random(m, &mut rng),
+            Universal::random(m - 2, &mut rng),
+        ));
+        for &k in &keys {
+            chained.insert(k, ());
+            double.insert(k, ()).unwrap();
+        }
+        chained.reset_stats();
+        double.reset_stats();
+        for &k in &keys {
+            assert!(chained.search(k).is_some());
+            assert!(double.search(k).is_some());
+        }
+        for _ in 0..1000 {
+            let k = rng.next_u64() | 1 << 63;
+            assert!(chained.search(k).is_none());
+            assert!(double.search(k).is_none());
+        }
+
+        // Theorems 11.1, 11.2, 11.6 and 11.8, with slack for the sample
+        let alpha = n as f64 / m as f64;
+        let (c, d) = (chained.stats(), double.stats());
+        assert!(c.mean_unsuccessful() <= 1.2 * alpha);
+        assert!(c.mean_successful() <= 1.2 * (1.0 + alpha / 2.0));
+        assert!(d.mean_unsuccessful() <= 1.2 / (1.0 - alpha));
+        assert!(d.mean_successful() <= 1.2 * (1.0 / alpha) * (1.0 / (1.0 - alpha)).ln());
+    }
+}
+
+/// Random inserts, deletes and searches on an open-address table and a
+/// `HashMap`, keeping the table below full
+fn check_against_hash_map<P: ProbeSequence>(probing: P, rng: &mut XorShift64) {
+    let m = probing.slots();
+    let mut table = OpenAddressTable::new(probing);
+    let mut expected = HashMap::new();
+    for step in 0..3000 {
+        let k = rng.gen_range(0, 3 * m) as u64;
+        match rng.gen_range(0, 3) {
+            0 if expected.len() < m / 2 => {
+                assert_eq!(table.insert(k, step).unwrap(), expected.insert(k, step));
+            }
+            1 => assert_eq!(table.delete(k), expected.remove(&k)),
+            _ => assert_eq!(table.search(k), expected.get(&k)),
+        }
+        assert_eq!(table.len(), expected.len());
+    }
+}
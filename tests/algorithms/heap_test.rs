@@ -0,0 +1,160 @@
+use algorithms::analysis::random_input;
+use algorithms::heap::fibonacci_heap::FibonacciHeap;
+use algorithms::heap::heapsort::{build_max_heap, heapsort, left, max_heapify, parent, right};
+use algorithms::heap::min_queue::{ArrayQueue, MinQueue};
+use algorithms::heap::priority_queue::{Handle, MaxPriorityQueue, MinPriorityQueue};
+use algorithms::random::{RandomSource, XorShift64};
+
+/// Tests heap index helpers, heapsort and the handle-based priority queues.
+///
+/// The heap examples follow CLRS Figures 6.2-6.4; all three min-queue
+/// implementations must behave like a plain sorted model under random
+/// inserts, decrease-keys and extractions.
+
+#[test]
+fn index_helpers_are_one_based() {
+    assert_eq!(parent(1), 0);
+    assert_eq!(parent(7), 3);
+    assert_eq!(left(3), 6);
+    assert_eq!(right(3), 7);
+}
+
+#[test]
+fn max_heapify_floats_value_down() {
+    // CLRS Figure 6.2: MAX-HEAPIFY(A, 2)
+    let mut a = [16, 4, 10, 14, 7, 9, 3, 2, 8, 1];
+    max_heapify(&mut a, 2);
+    assert_eq!(a, [16, 14, 10, 8, 7, 9, 3, 2, 4, 1]);
+}
+
+#[test]
+fn builds_max_heap() {
+    // CLRS Figure 6.3
+    let mut a = [4, 1, 3, 2, 16, 9, 10, 14, 8, 7];
+    build_max_heap(&mut a);
+    assert_eq!(a, [16, 14, 10, 8, 7, 9, 3, 2, 4, 1]);
+    assert!(is_max_heap(&a));
+}
+
+#[test]
+fn heapsort_sorts_random_input() {
+    let mut rng = XorShift64::new(6);
+    for n in [0, 1, 2, 10, 99] {
+        let mut data = random_input(n, &mut rng);
+        let mut expected = data.clone();
+        heapsort(&mut data);
+        expected.sort();
+        assert_eq!(data, expected);
+    }
+}
+
+#[test]
+fn max_queue_extracts_in_decreasing_order() {
+    let mut queue = MaxPriorityQueue::new();
+    for (i, key) in [15, 13, 9, 5, 12, 8, 7, 4, 0, 6, 2, 1]
+        .into_iter()
+        .enumerate()
+    {
+        queue.insert(key, i);
+    }
+    let handle = queue.insert(3, 99);
+    queue.increase_key(handle, 14);
+
+    assert_eq!(queue.maximum(), Some((&15, &0)));
+    let keys: Vec<i32> = std::iter::from_fn(|| queue.extract_max().map(|(k, _)| k)).collect();
+    assert_eq!(keys, [15, 14, 13, 12, 9, 8, 7, 6, 5, 4, 2, 1, 0]);
+    assert!(queue.is_empty());
+}
+
+#[test]
+fn min_queue_tracks_handles() {
+    let mut queue = MinPriorityQueue::new();
+    let a = queue.insert(10, "a");
+    let b = queue.insert(20, "b");
+    let c = queue.insert(30, "c");
+
+    queue.decrease_key(c, 5);
+    queue.update_key(a, 25);
+    assert_eq!(queue.key(a), Some(&25));
+
+    assert_eq!(queue.pop_with_handle(), Some((c, 5, "c")));
+    assert!(!queue.contains(c));
+    assert_eq!(queue.extract_min(), Some((20, "b")));
+    assert!(queue.contains(a) && !queue.contains(b));
+    assert_eq!(queue.extract_min(), Some((25, "a")));
+}
+
+#[test]
+#[should_panic(expected = "new key is smaller than current key")]
+fn increase_key_rejects_smaller_key() {
+    let mut queue = MaxPriorityQueue::new();
+    let handle = queue.insert(5, ());
+    queue.increase_key(handle, 4);
+}
+
+#[test]
+fn min_queues_agree_with_a_model() {
+    for seed in 1..=20 {
+        check_against_model(MinPriorityQueue::new(), seed);
+        check_against_model(FibonacciHeap::new(), seed);
+        check_against_model(ArrayQueue::new(), seed);
+    }
+}
+
+#[test]
+fn fibonacci_heap_consolidates_on_extract_min() {
+    let mut heap = FibonacciHeap::new();
+    for key in [23, 7, 21, 3, 18, 52, 38, 39, 41, 17, 30, 24, 26, 46, 35] {
+        heap.insert(key, ());
+    }
+    assert_eq!(heap.root_count(), 15);
+
+    assert_eq!(heap.extract_min(), Some((3, ())));
+    // 14 nodes consolidate into trees of distinct degrees: 8 + 4 + 2
+    assert_eq!(heap.root_count(), 3);
+    assert_eq!(heap.minimum(), Some((&7, &())));
+    assert_eq!(heap.len(), 14);
+}
+
+#[test]
+#[should_panic(expected = "new key is larger than current key")]
+fn fibonacci_heap_rejects_larger_key() {
+    let mut heap = FibonacciHeap::new();
+    let handle = heap.insert(5, ());
+    heap.decrease_key(handle, 6);
+}
+
+fn is_max_heap(a: &[i32]) -> bool {
+    (2..=a.len()).all(|i| a[parent(i) - 1] >= a[i - 1])
+}
+
+/// Random operations on `queue`, checked against a list of live entries
+fn check_against_model<Q: MinQueue<u64, usize>>(mut queue: Q, seed: u64) {
+    let mut rng = XorShift64::new(seed);
+    let mut live: Vec<(Handle, u64, usize)> = Vec::new();
+    for step in 0..300 {
+        match rng.gen_range(0, 4) {
+            0 | 1 => {
+                let key = rng.gen_range(0, 1000) as u64;
+                live.push((queue.insert(key, step), key, step));
+            }
+            2 if !live.is_empty() => {
+                let i = rng.gen_range(0, live.len());
+                let key = live[i].1 - rng.gen_range(0, live[i].1 as usize + 1) as u64;
+                queue.decrease_key(live[i].0, key);
+                live[i].1 = key;
+            }
+            _ => {
+                let min = live.iter().map(|e| e.1).min();
+                let extracted = queue.extract_min();
+                assert_eq!(extracted.map(|(key, _)| key), min);
+                if let Some((key, value)) = extracted {
+                    let i = live.iter().position(|e| e.2 == value).unwrap();
+                    assert_eq!(live[i].1, key);
+                    assert!(!queue.contains(live.swap_remove(i).0));
+                }
+            }
+        }
+        assert_eq!(queue.is_empty(), live.is_empty());
+    }
+}
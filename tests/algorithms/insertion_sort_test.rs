@@ -1,4 +1,4 @@
-use clrs::algorithms::insertion_sort::insertion_sort;
+use clrs::algorithms::insertion_sort::{insertion_sort, insertion_sort_by};
 
 /// Tests the insertion sort algorithm with different types of input data.
 ///
@@ -28,8 +28,9 @@ fn handles_empty_and_single_element() {
 
 #[test]
 fn sorts_floats() {
-    // Floating-point test: verifies that generic types also work
+    // Floating-point test: floats are only partially ordered, so sort them
+    // with the IEEE total order
     let mut data = [3.2, 1.1, 4.5, 2.0];
-    insertion_sort(&mut data);
+    insertion_sort_by(&mut data, f64::total_cmp);
     assert_eq!(data, [1.1, 2.0, 3.2, 4.5]);
 }
@@ -0,0 +1,72 @@
+use algorithms::dp::lcs::{Arrow, lcs, lcs_length, lcs_length_two_rows, print_lcs};
+use algorithms::random::{RandomSource, XorShift64};
+
+/// Tests the longest-common-subsequence algorithms of CLRS Section 14.4.
+///
+/// The tables are checked against Figure 14.8; every variant must agree on
+/// the length, and a reconstructed LCS must be a subsequence of both inputs.
+
+#[test]
+fn tables_match_figure_14_8() {
+    let x = chars("ABCBDAB");
+    let y = chars("BDCABA");
+    let tables = lcs_length(&x, &y);
+
+    assert_eq!(tables.c[7], [0, 1, 2, 2, 3, 4, 4]);
+    assert_eq!(tables.c[4], [0, 1, 1, 2, 2, 3, 3]);
+    assert_eq!(tables.b[1][1], Some(Arrow::Up));
+    assert_eq!(tables.b[2][1], Some(Arrow::UpLeft));
+    assert_eq!(tables.b[1][5], Some(Arrow::Left));
+    assert_eq!(tables.b[0][3], None);
+    assert_eq!(tables.length(), 4);
+
+    let lcs: String = print_lcs(&tables, &x, 7, 6).into_iter().collect();
+    assert_eq!(lcs, "BCBA");
+    assert_eq!(tables.path().first(), Some(&(7, 6)));
+}
+
+#[test]
+fn works_on_bytes_and_tokens() {
+    assert_eq!(lcs(b"AGGTAB", b"GXTXAYB"), b"GTAB");
+
+    let a: Vec<&str> = "the quick brown fox jumps".split(' ').collect();
+    let b: Vec<&str> = "the lazy brown dog jumps".split(' ').collect();
+    assert_eq!(lcs(&a, &b), ["the", "brown", "jumps"]);
+}
+
+#[test]
+fn handles_empty_sequences() {
+    let empty: [u8; 0] = [];
+    assert_eq!(lcs_length(&empty, b"abc").length(), 0);
+    assert!(lcs(b"abc", &empty).is_empty());
+    assert_eq!(lcs_length_two_rows(&empty, &empty), 0);
+}
+
+#[test]
+fn two_row_variant_agrees_with_full_table() {
+    let mut rng = XorShift64::new(14);
+    for _ in 0..50 {
+        let m = rng.gen_range(0, 20);
+        let n = rng.gen_range(0, 20);
+        let x: Vec<usize> = (0..m).map(|_| rng.gen_range(0, 4)).collect();
+        let y: Vec<usize> = (0..n).map(|_| rng.gen_range(0, 4)).collect();
+
+        let tables = lcs_length(&x, &y);
+        assert_eq!(lcs_length_two_rows(&x, &y), tables.length());
+        assert_eq!(lcs_length_two_rows(&y, &x), tables.length());
+
+        let common = lcs(&x, &y);
+        assert_eq!(common.len(), tables.length());
+        assert!(is_subsequence(&common, &x));
+        assert!(is_subsequence(&common, &y));
+    }
+}
+
+fn chars(s: &str) -> Vec<char> {
+    s.chars().collect()
+}
+
+fn is_subsequence<T: Eq>(sub: &[T], seq: &[T]) -> bool {
+    let mut rest = seq.iter();
+    sub.iter().all(|a| rest.any(|b| a == b))
+}
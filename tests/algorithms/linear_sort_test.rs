@@ -0,0 +1,83 @@
+use algorithms::random::{RandomSource, XorShift64};
+use algorithms::sorting::bucket_sort::bucket_sort;
+use algorithms::sorting::counting_sort::counting_sort;
+use algorithms::sorting::radix_sort::{radix_sort, radix_sort_bytes};
+
+/// Tests the linear-time sorts of CLRS Chapter 8.
+///
+/// Counting sort must be stable, radix sort must agree with a comparison
+/// sort for every digit size, and bucket sort handles uniform `[0, 1)` input.
+
+#[test]
+fn counting_sort_matches_figure_8_2() {
+    let a = [2, 5, 3, 0, 2, 3, 0, 3];
+    assert_eq!(counting_sort(&a, 5, |&x| x), [0, 0, 2, 2, 3, 3, 3, 5]);
+}
+
+#[test]
+fn counting_sort_is_stable() {
+    let records = [(3, 'a'), (1, 'b'), (3, 'c'), (0, 'd'), (1, 'e')];
+    let sorted = counting_sort(&records, 3, |r| r.0);
+    assert_eq!(sorted, [(0, 'd'), (1, 'b'), (1, 'e'), (3, 'a'), (3, 'c')]);
+}
+
+#[test]
+#[should_panic(expected = "larger than k")]
+fn counting_sort_rejects_large_keys() {
+    counting_sort(&[1, 7], 5, |&x| x);
+}
+
+#[test]
+fn radix_sort_matches_figure_8_3() {
+    let mut a: [u16; 7] = [329, 457, 657, 839, 436, 720, 355];
+    radix_sort(&mut a, 8);
+    assert_eq!(a, [329, 355, 436, 457, 657, 720, 839]);
+}
+
+#[test]
+fn radix_sort_agrees_for_all_digit_sizes() {
+    let mut rng = XorShift64::new(8);
+    let input: Vec<u64> = (0..300).map(|_| rng.next_u64()).collect();
+    let mut expected = input.clone();
+    expected.sort();
+
+    for bits in [1, 3, 8, 11, 16] {
+        let mut data = input.clone();
+        radix_sort(&mut data, bits);
+        assert_eq!(data, expected, "digit size {} bits", bits);
+    }
+}
+
+#[test]
+fn radix_sort_sorts_byte_strings() {
+    let mut words: Vec<&[u8]> = vec![
+        b"COW", b"DOG", b"SEA", b"RUG", b"ROW", b"MOB", b"BOX", b"TAB",
+    ];
+    let mut expected = words.clone();
+    expected.sort();
+
+    radix_sort_bytes(&mut words, 1);
+    assert_eq!(words, expected);
+
+    // Odd width with two-byte digits leaves a one-byte leading digit
+    let mut pairs = [*b"ZZA", *b"ABC", *b"ZYA", *b"ABB"];
+    radix_sort_bytes(&mut pairs, 2);
+    assert_eq!(pairs, [*b"ABB", *b"ABC", *b"ZYA", *b"ZZA"]);
+}
+
+#[test]
+fn bucket_sort_sorts_uniform_input() {
+    let mut rng = XorShift64::new(4);
+    let mut data: Vec<f64> = (0..500).map(|_| rng.next_f64()).collect();
+    let mut expected = data.clone();
+    expected.sort_by(f64::total_cmp);
+
+    bucket_sort(&mut data);
+    assert_eq!(data, expected);
+}
+
+#[test]
+#[should_panic(expected = "is not in [0, 1)")]
+fn bucket_sort_rejects_out_of_range() {
+    bucket_sort(&mut [0.5, 1.0]);
+}
@@ -0,0 +1,23 @@
+mod analysis_test;
+mod apsp_test;
+mod augment_test;
+mod bst_test;
+mod btree_test;
+mod connectivity_test;
+mod disjoint_set_test;
+mod flow_test;
+mod graph_search_test;
+mod graph_test;
+mod hashing_test;
+mod heap_test;
+mod insertion_sort_test;
+mod lcs_test;
+mod linear_sort_test;
+mod merge_sort_test;
+mod mst_test;
+mod quicksort_test;
+mod red_black_test;
+mod registry_test;
+mod selection_test;
+mod sssp_test;
+mod trace_test;
@@ -0,0 +1,94 @@
+use algorithms::analysis::{OpCounter, random_input};
+use algorithms::random::XorShift64;
+use algorithms::sorting::merge_sort::{
+    merge, merge_sort, merge_sort_by, merge_sort_traced, merge_sort_with_sentinels,
+    merge_with_sentinels,
+};
+
+/// Tests both MERGE variants and the merge sort entry points.
+///
+/// The sentinel and sentinel-free versions must agree on every input,
+/// and the comparator form must be stable.
+
+#[test]
+fn merges_sorted_halves() {
+    // CLRS Figure 2.3: MERGE(A, 9, 12, 16) on A[9:16] = ⟨2, 4, 6, 7, 1, 2, 3, 5⟩
+    let mut a = [2, 4, 6, 7, 1, 2, 3, 5];
+    merge(&mut a, 0, 3, 7);
+    assert_eq!(a, [1, 2, 2, 3, 4, 5, 6, 7]);
+
+    let mut b = [2, 4, 6, 7, 1, 2, 3, 5];
+    merge_with_sentinels(&mut b, 0, 3, 7);
+    assert_eq!(a, b);
+}
+
+#[test]
+fn merges_only_the_given_range() {
+    let mut a = [9, 3, 5, 1, 4, 0];
+    merge(&mut a, 1, 2, 4);
+    assert_eq!(a, [9, 1, 3, 4, 5, 0]);
+}
+
+#[test]
+fn sorts_integers() {
+    let mut data = [12, 3, 7, 9, 14, 6, 11, 2];
+    merge_sort(&mut data);
+    assert_eq!(data, [2, 3, 6, 7, 9, 11, 12, 14]);
+}
+
+#[test]
+fn handles_empty_and_single_element() {
+    let mut empty: [i32; 0] = [];
+    merge_sort(&mut empty);
+    merge_sort_with_sentinels(&mut empty);
+    assert_eq!(empty, []);
+
+    let mut single = [42];
+    merge_sort(&mut single);
+    merge_sort_with_sentinels(&mut single);
+    assert_eq!(single, [42]);
+}
+
+#[test]
+fn sentinel_variant_agrees_on_random_input() {
+    let mut rng = XorShift64::new(2024);
+    for n in [2, 3, 17, 64, 101] {
+        let input = random_input(n, &mut rng);
+        let mut a = input.clone();
+        let mut b = input.clone();
+        let mut expected = input;
+        merge_sort(&mut a);
+        merge_sort_with_sentinels(&mut b);
+        expected.sort();
+        assert_eq!(a, expected);
+        assert_eq!(b, expected);
+    }
+}
+
+#[test]
+#[should_panic(expected = "strictly less than the sentinel")]
+fn sentinel_variant_rejects_the_sentinel_value() {
+    let mut data = [3, u8::MAX, 1];
+    merge_sort_with_sentinels(&mut data);
+}
+
+#[test]
+fn comparator_form_is_stable() {
+    let mut data = [(2, 'a'), (1, 'b'), (2, 'c'), (1, 'd')];
+    merge_sort_by(&mut data, |x, y| x.0.cmp(&y.0));
+    assert_eq!(data, [(1, 'b'), (1, 'd'), (2, 'a'), (2, 'c')]);
+
+    let mut words = ["pear", "fig", "banana", "kiwi"];
+    merge_sort_by(&mut words, |a, b| b.len().cmp(&a.len()));
+    assert_eq!(words, ["banana", "pear", "kiwi", "fig"]);
+}
+
+#[test]
+fn counts_n_log_n_writes() {
+    // Every level of the recursion writes each element exactly once
+    let mut data: Vec<i64> = (0..64).rev().collect();
+    let mut counter = OpCounter::new();
+    merge_sort_traced(&mut data, &mut counter);
+    assert_eq!(counter.counts().writes, 64 * 6);
+    assert!(counter.counts().comparisons <= 64 * 6);
+}
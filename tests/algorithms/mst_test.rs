@@ -0,0 +1,152 @@
+use algorithms::analysis::random_connected_graph;
+use algorithms::graph::adjacency_list::AdjacencyList;
+use algorithms::graph::adjacency_matrix::AdjacencyMatrix;
+use algorithms::graph::bfs::bfs;
+use algorithms::graph::mst::{SpanningTree, mst_kruskal, mst_prim, second_best_mst};
+use algorithms::graph::parse::parse_edge_list;
+use algorithms::graph::representation::{Edge, Graph};
+use algorithms::heap::fibonacci_heap::FibonacciHeap;
+use algorithms::heap::min_queue::ArrayQueue;
+use algorithms::heap::priority_queue::MinPriorityQueue;
+use algorithms::random::XorShift64;
+
+/// Tests Kruskal's and Prim's algorithms (CLRS Chapter 21) and the
+/// second-best minimum spanning tree of Problem 21-1.
+///
+/// Prim must find a tree of the same weight with every queue; the
+/// second-best tree is compared with all spanning trees of small graphs.
+
+#[test]
+fn both_algorithms_on_figure_21_1() {
+    let g = figure_21_1();
+
+    let kruskal = mst_kruskal(&g);
+    assert_eq!(kruskal.weight, 37);
+    assert_eq!(kruskal.edges.len(), 8);
+    assert!(is_spanning_tree(&g, &kruskal));
+
+    for tree in [
+        mst_prim(&g, 0, &mut MinPriorityQueue::new()),
+        mst_prim(&g, 0, &mut FibonacciHeap::new()),
+        mst_prim(&g, 0, &mut ArrayQueue::new()),
+        mst_prim(&AdjacencyMatrix::from_graph(&g), 0, &mut ArrayQueue::new()),
+    ] {
+        assert_eq!(tree.weight, 37);
+        assert!(is_spanning_tree(&g, &tree));
+    }
+    // The light edges (g, h), (c, i) and (f, g) are in every MST
+    for (u, v) in [("g", "h"), ("c", "i"), ("f", "g")] {
+        let (u, v) = (g.vertex_named(u).unwrap(), g.vertex_named(v).unwrap());
+        assert!(kruskal.contains(u, v));
+    }
+}
+
+#[test]
+fn queues_agree_on_random_graphs() {
+    let mut rng = XorShift64::new(21);
+    for n in [1, 2, 10, 50] {
+        for m in [n, 3 * n, n * n] {
+            let g = random_connected_graph(n, m, 20, &mut rng);
+            let expected = mst_kruskal(&g).weight;
+            assert_eq!(
+                mst_prim(&g, 0, &mut MinPriorityQueue::new()).weight,
+                expected
+            );
+            assert_eq!(
+                mst_prim(&g, n - 1, &mut FibonacciHeap::new()).weight,
+                expected
+            );
+            assert_eq!(mst_prim(&g, 0, &mut ArrayQueue::new()).weight, expected);
+        }
+    }
+}
+
+#[test]
+fn disconnected_graphs_give_a_spanning_forest() {
+    let g = parse_edge_list("undirected\na b 1\nb c 2\na c 3\nd e 4\n").unwrap();
+
+    assert_eq!(mst_kruskal(&g).weight, 7);
+    let prim = mst_prim(&g, 0, &mut FibonacciHeap::new());
+    assert_eq!(prim.weight, 7);
+    assert_eq!(prim.edges.len(), 3);
+}
+
+#[test]
+fn second_best_matches_brute_force() {
+    let g = figure_21_1();
+    let best = mst_kruskal(&g);
+    let second = second_best_mst(&g).unwrap();
+    assert!(is_spanning_tree(&g, &second));
+    assert_ne!(sorted(&second.edges), sorted(&best.edges));
+    assert_eq!(second.weight, brute_force_second_best(&g, &best));
+
+    let mut rng = XorShift64::new(211);
+    for _ in 0..30 {
+        let g = random_connected_graph(6, 9, 5, &mut rng);
+        let best = mst_kruskal(&g);
+        let second = second_best_mst(&g).unwrap();
+        assert!(is_spanning_tree(&g, &second));
+        assert_eq!(second.weight, brute_force_second_best(&g, &best));
+    }
+
+    let path = parse_edge_list("undirected\na b 1\nb c 2\n").unwrap();
+    assert_eq!(second_best_mst(&path), None);
+}
+
+/// Figure 21.1: vertices `a..i`, minimum spanning tree of weight 37
+fn figure_21_1() -> AdjacencyList {
+    parse_edge_list(
+        "undirected\na b 4\na h 8\nb c 8\nb h 11\nc d 7\nc f 4\nc i 2\n\
+         d e 9\nd f 14\ne f 10\nf g 2\ng h 1\ng i 6\nh i 7\n",
+    )
+    .unwrap()
+}
+
+/// `|V| - 1` edges of `g` that connect all vertices
+fn is_spanning_tree<G: Graph>(g: &G, tree: &SpanningTree) -> bool {
+    let mut t = AdjacencyList::undirected(g.vertex_count());
+    for e in &tree.edges {
+        t.add_weighted_edge(e.from, e.to, e.weight);
+    }
+    tree.edges.len() + 1 == g.vertex_count()
+        && tree
+            .edges
+            .iter()
+            .all(|e| g.weight(e.from, e.to) == Some(e.weight))
+        && bfs(&t, 0).d.iter().all(Option::is_some)
+}
+
+/// Lightest spanning tree with an edge set different from `best`
+fn brute_force_second_best(g: &AdjacencyList, best: &SpanningTree) -> i64 {
+    let edges = g.edges();
+    let n = g.vertex_count();
+    let best = sorted(&best.edges);
+    let mut lightest = i64::MAX;
+    for mask in 0u32..1 << edges.len() {
+        if mask.count_ones() as usize != n - 1 {
+            continue;
+        }
+        let chosen: Vec<Edge> = (0..edges.len())
+            .filter(|i| mask & (1 << i) != 0)
+            .map(|i| edges[i])
+            .collect();
+        let tree = SpanningTree {
+            weight: chosen.iter().map(|e| e.weight).sum(),
+            edges: chosen,
+        };
+        if is_spanning_tree(g, &tree) && sorted(&tree.edges) != best {
+            lightest = lightest.min(tree.weight);
+        }
+    }
+    lightest
+}
+
+/// Edges as sorted `(min, max, weight)` triples
+fn sorted(edges: &[Edge]) -> Vec<(usize, usize, i64)> {
+    let mut triples: Vec<_> = edges
+        .iter()
+        .map(|e| (e.from.min(e.to), e.from.max(e.to), e.weight))
+        .collect();
+    triples.sort_unstable();
+    triples
+}
@@ -0,0 +1,124 @@
+use algorithms::analysis::{OpCounter, random_input, sorted_input};
+use algorithms::random::XorShift64;
+use algorithms::sorting::quicksort::{
+    hoare_partition, median_of_three_partition, partition, partition_three_way, quicksort,
+    quicksort_hoare, quicksort_median_of_three, quicksort_three_way, quicksort_traced,
+    randomized_quicksort, tail_recursive_quicksort,
+};
+
+/// Tests the quicksort family side by side.
+///
+/// Every variant must sort random input, inputs full of duplicates and
+/// already sorted input; the partitions are checked on CLRS Figure 7.1.
+
+#[test]
+fn lomuto_partition_matches_figure_7_1() {
+    let mut a = [2, 8, 7, 1, 3, 5, 6, 4];
+    let q = partition(&mut a, 0, 7);
+    assert_eq!(q, 3);
+    assert_eq!(a, [2, 1, 3, 4, 7, 5, 6, 8]);
+}
+
+#[test]
+fn hoare_partition_splits_around_first_element() {
+    // CLRS Problem 7-1 example array
+    let mut a = [13, 19, 9, 5, 12, 8, 7, 4, 11, 2, 6, 21];
+    let j = hoare_partition(&mut a, 0, 11);
+    assert!(j < 11);
+    assert!(a[..=j].iter().all(|&v| v <= 13));
+    assert!(a[j + 1..].iter().all(|&v| v >= 13));
+}
+
+#[test]
+fn median_of_three_uses_middle_value() {
+    let mut a = [1, 2, 3, 4, 5, 6, 7];
+    let q = median_of_three_partition(&mut a, 0, 6);
+    assert_eq!(a[q], 4);
+    assert_eq!(q, 3);
+}
+
+#[test]
+fn three_way_partition_groups_equal_keys() {
+    let mut a = [4, 9, 4, 1, 4, 7, 0, 4];
+    let (q, t) = partition_three_way(&mut a, 0, 7);
+    assert_eq!((q, t), (2, 5));
+    assert!(a[..q].iter().all(|&v| v < 4));
+    assert!(a[q..=t].iter().all(|&v| v == 4));
+    assert!(a[t + 1..].iter().all(|&v| v > 4));
+}
+
+#[test]
+fn all_variants_sort() {
+    let mut rng = XorShift64::new(77);
+    let mut inputs: Vec<Vec<i64>> = vec![vec![], vec![1], sorted_input(50)];
+    inputs.push(sorted_input(50).into_iter().rev().collect());
+    inputs.push((0..60).map(|i| i % 3).collect());
+    for n in [2, 3, 10, 101] {
+        inputs.push(random_input(n, &mut rng));
+    }
+
+    for input in inputs {
+        let mut expected = input.clone();
+        expected.sort();
+
+        let variants: [fn(&mut [i64]); 5] = [
+            quicksort,
+            quicksort_hoare,
+            quicksort_median_of_three,
+            quicksort_three_way,
+            tail_recursive_quicksort,
+        ];
+        for sort in variants {
+            let mut data = input.clone();
+            sort(&mut data);
+            assert_eq!(data, expected);
+        }
+
+        let mut data = input.clone();
+        randomized_quicksort(&mut data, &mut rng);
+        assert_eq!(data, expected);
+    }
+}
+
+#[test]
+fn variants_with_a_loop_keep_the_stack_shallow() {
+    // Sorted input makes every split maximally unbalanced; recursing on
+    // the larger side would need thousands of frames.
+    let sorts: [fn(&mut [i64]); 3] = [
+        quicksort_hoare,
+        quicksort_three_way,
+        tail_recursive_quicksort,
+    ];
+    for sort in sorts {
+        let sorted = std::thread::Builder::new()
+            .stack_size(64 * 1024)
+            .spawn(move || {
+                let mut data = sorted_input(5000);
+                data.reverse();
+                sort(&mut data);
+                data
+            })
+            .unwrap()
+            .join()
+            .unwrap();
+        assert_eq!(sorted, sorted_input(5000));
+    }
+}
+
+#[test]
+fn randomized_quicksort_is_reproducible() {
+    let input = random_input(200, &mut XorShift64::new(1));
+    let mut a = input.clone();
+    let mut b = input;
+    randomized_quicksort(&mut a, &mut XorShift64::new(5));
+    randomized_quicksort(&mut b, &mut XorShift64::new(5));
+    assert_eq!(a, b);
+}
+
+#[test]
+fn sorted_input_is_quadratic() {
+    let mut data = sorted_input(100);
+    let mut counter = OpCounter::new();
+    quicksort_traced(&mut data, &mut counter);
+    assert_eq!(counter.counts().comparisons, 100 * 99 / 2);
+}
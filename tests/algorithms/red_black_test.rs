@@ -0,0 +1,136 @@
+use std::collections::BTreeMap;
+
+use algorithms::random::{RandomSource, XorShift64};
+use algorithms::trees::red_black::{Color, FixupCase, RedBlackTree, Violation};
+
+/// Tests the red-black trees of CLRS Chapter 13.
+///
+/// Every insertion and deletion must preserve the red-black properties and
+/// agree with `BTreeMap`; the fixup cases must be the ones CLRS walks
+/// through, and the invariant checker must name the property that breaks.
+
+#[test]
+fn random_operations_keep_the_properties() {
+    let mut rng = XorShift64::new(13);
+    let mut tree = RedBlackTree::new();
+    let mut expected = BTreeMap::new();
+    for step in 0..3000 {
+        let k = rng.gen_range(0, 300);
+        if rng.gen_range(0, 3) > 0 {
+            if tree.search(&k).is_none() {
+                tree.insert(k, step);
+                assert_eq!(expected.insert(k, step), None);
+            }
+        } else if let Some(z) = tree.search(&k) {
+            assert_eq!(tree.delete(z), (k, expected.remove(&k).unwrap()));
+        }
+        if let Err(violation) = tree.check_invariants() {
+            panic!("step {}: {}", step, violation);
+        }
+    }
+    let pairs: Vec<(usize, usize)> = tree
+        .inorder()
+        .map(|x| (*tree.key(x), *tree.value(x)))
+        .collect();
+    assert_eq!(pairs, expected.into_iter().collect::<Vec<_>>());
+}
+
+#[test]
+fn height_is_at_most_twice_lg() {
+    // Sorted input would make a plain binary search tree a path
+    for n in [1, 10, 100, 1000] {
+        let mut tree = RedBlackTree::new();
+        for k in 0..n {
+            tree.insert(k, ());
+        }
+        let bound = 2.0 * ((n + 1) as f64).log2();
+        assert!(tree.height() as f64 + 1.0 <= bound, "n = {}", n);
+        let bh = tree.check_invariants().unwrap();
+        assert_eq!(tree.black_height(tree.root().unwrap()), bh);
+    }
+}
+
+#[test]
+fn insert_fixup_follows_figure_13_4() {
+    let mut tree = RedBlackTree::new();
+    for k in [11, 2, 14, 1, 7, 15, 5, 8] {
+        tree.insert(k, ());
+    }
+    let mut cases = Vec::new();
+    let z = tree.insert_traced(
+        4,
+        (),
+        &mut |case: &FixupCase, tree: &RedBlackTree<i32, ()>| {
+            if let FixupCase::Insert { case, z } = *case {
+                cases.push((case, *tree.key(z)));
+            }
+        },
+    );
+    // Case 1 moves z up to 7; case 2 rotates 7 up, leaving z = 2 for case 3
+    assert_eq!(cases, [(1, 4), (2, 7), (3, 2)]);
+    assert_eq!(tree.key(tree.root().unwrap()), &7);
+    assert_eq!(tree.color(z), Color::Red);
+    assert_eq!(tree.check_invariants(), Ok(2));
+}
+
+#[test]
+fn delete_fixup_reports_sibling_cases() {
+    let mut tree = RedBlackTree::new();
+    for k in [19, 30, 21, 32, 31, 8, 2, 20, 13, 11, 14, 26] {
+        tree.insert(k, ());
+    }
+    let mut cases = Vec::new();
+    let z = tree.search(&19).unwrap();
+    tree.delete_traced(z, &mut |case: &FixupCase, _: &RedBlackTree<i32, ()>| {
+        if let FixupCase::Delete { case, .. } = *case {
+            cases.push(case);
+        }
+    });
+    assert_eq!(cases, [1, 3, 4]);
+    assert!(tree.check_invariants().is_ok());
+    assert!(!tree.contains(z));
+}
+
+#[test]
+fn checker_names_the_broken_property() {
+    let mut tree = RedBlackTree::new();
+    for k in 1..=7 {
+        tree.insert(k, ());
+    }
+    let root = tree.root().unwrap();
+    tree.set_color(root, Color::Red);
+    let violation = tree.check_invariants().unwrap_err();
+    assert_eq!(violation, Violation::RedRoot);
+    assert_eq!(violation.property(), Some(2));
+    tree.set_color(root, Color::Black);
+
+    // 6 is black with red children 5 and 7
+    let six = tree.search(&6).unwrap();
+    assert_eq!(tree.color(six), Color::Black);
+    tree.set_color(six, Color::Red);
+    let violation = tree.check_invariants().unwrap_err();
+    assert_eq!(violation.property(), Some(4));
+    assert!(violation.to_string().contains("property 4"));
+    tree.set_color(six, Color::Black);
+
+    let five = tree.search(&5).unwrap();
+    tree.set_color(five, Color::Black);
+    let violation = tree.check_invariants().unwrap_err();
+    assert_eq!(
+        violation,
+        Violation::BlackHeight {
+            node: six,
+            left: 2,
+            right: 1
+        }
+    );
+    assert_eq!(violation.property(), Some(5));
+}
+
+#[test]
+fn empty_tree_has_black_height_zero() {
+    let tree: RedBlackTree<i32, ()> = RedBlackTree::new();
+    assert_eq!(tree.check_invariants(), Ok(0));
+    assert_eq!(tree.inorder().count(), 0);
+    assert_eq!(tree.height(), 0);
+}
@@ -0,0 +1,143 @@
+use std::collections::HashSet;
+use std::path::Path;
+
+use algorithms::analysis::{InputCase, random_input};
+use algorithms::graph::parse::parse_edge_list;
+use algorithms::random::XorShift64;
+use algorithms::registry::{self, Entry};
+
+/// Tests the algorithm registry shared by the CLI and the LaTeX generator.
+///
+/// Every entry must have a unique ID, point at an existing source file and
+/// actually do what its entry kind promises.
+
+#[test]
+fn ids_are_unique_and_sources_exist() {
+    let mut ids = HashSet::new();
+    for algorithm in registry::all() {
+        assert!(ids.insert(algorithm.id), "duplicate id {}", algorithm.id);
+        let source = Path::new(env!("CARGO_MANIFEST_DIR"))
+            .join("algorithms/src")
+            .join(algorithm.source);
+        assert!(source.is_file(), "{} not found", source.display());
+        assert_eq!(
+            registry::find(algorithm.id).map(|a| a.id),
+            Some(algorithm.id)
+        );
+    }
+    assert!(registry::find("no_such_algorithm").is_none());
+}
+
+#[test]
+fn integer_entries_sort_or_select() {
+    let input = random_input(50, &mut XorShift64::new(11));
+    for algorithm in registry::all() {
+        let mut data = input.clone();
+        match algorithm.entry {
+            Entry::Sort(_) => {
+                assert!(algorithm.run_on_array(&mut data));
+                assert!(data.is_sorted(), "{} did not sort", algorithm.id);
+            }
+            Entry::SortNaturals(sort) => {
+                assert!(algorithm.run_on_array(&mut data));
+                assert!(data.is_sorted(), "{} did not sort", algorithm.id);
+                let mut negative = [3, -1, 2];
+                assert!(sort(&mut negative).is_err(), "{}", algorithm.id);
+                assert_eq!(negative, [3, -1, 2]);
+                assert!(!algorithm.run_on_array(&mut negative));
+            }
+            Entry::Select(select) => {
+                let mut rng = XorShift64::new(1);
+                let mut tracer = |_: &_, _: &[i64]| {};
+                assert_eq!(select(&mut data, 7, &mut rng, &mut tracer), 7);
+            }
+            Entry::SortReals(sort) => {
+                let mut reals = [0.78, 0.17, 0.39, 0.26, 0.72];
+                sort(&mut reals).unwrap();
+                assert_eq!(reals, [0.17, 0.26, 0.39, 0.72, 0.78]);
+                assert!(sort(&mut [0.5, 1.5]).is_err());
+                assert!(sort(&mut [f64::NAN]).is_err());
+                assert!(!algorithm.run_on_array(&mut data));
+            }
+            Entry::Strings(combine) => {
+                assert_eq!(combine("ABCBDAB", "BDCABA"), "BCBA");
+                assert!(!algorithm.run_on_array(&mut data));
+            }
+            Entry::Graph(describe) => {
+                let g = parse_edge_list("undirected\na b\nb c\n").unwrap();
+                assert!(describe(&g, 0).contains('c'), "{}", algorithm.id);
+                assert!(!algorithm.run_on_array(&mut data));
+            }
+            Entry::Keys(describe) => {
+                let text = describe(&[5, 28, 19, 15, 20, 33, 12, 17, 10]);
+                assert!(text.contains("33"), "{}", algorithm.id);
+                assert!(!algorithm.run_on_array(&mut data));
+            }
+        }
+    }
+}
+
+#[test]
+fn counting_sort_rejects_keys_too_large_to_count() {
+    let Entry::SortNaturals(sort) = registry::find("counting_sort").unwrap().entry else {
+        panic!("counting_sort sorts natural numbers");
+    };
+    let mut data = [5, 9_000_000_000_000_000_000];
+    assert!(sort(&mut data).unwrap_err().contains("larger than"));
+    assert_eq!(data, [5, 9_000_000_000_000_000_000]);
+}
+
+#[test]
+fn measurable_entries_count_operations() {
+    let insertion_sort = registry::find("insertion_sort").unwrap();
+    let mut rng = XorShift64::new(3);
+    let worst = insertion_sort
+        .count_operations(InputCase::Worst, 10, &mut rng)
+        .unwrap();
+    assert_eq!(worst.comparisons, 45);
+
+    // A reversed array is already a max-heap, so heapsort is only measured
+    // on random permutations
+    let heapsort = registry::find("heapsort").unwrap();
+    assert!(
+        heapsort
+            .count_operations(InputCase::Worst, 10, &mut rng)
+            .is_none()
+    );
+    assert!(
+        heapsort
+            .count_operations(InputCase::Average, 10, &mut rng)
+            .is_some()
+    );
+    // Random pivots and median-of-medians have no inputs that are known
+    // extremes
+    for id in ["randomized_quicksort", "randomized_select", "select"] {
+        let algorithm = registry::find(id).unwrap();
+        assert!(
+            algorithm
+                .count_operations(InputCase::Best, 10, &mut rng)
+                .is_none(),
+            "{}",
+            id
+        );
+    }
+
+    let counting_sort = registry::find("counting_sort").unwrap();
+    assert!(
+        counting_sort
+            .count_operations(InputCase::Best, 10, &mut rng)
+            .is_none()
+    );
+}
+
+#[test]
+fn groups_by_chapter_in_order() {
+    let groups = registry::by_chapter();
+    let chapters: Vec<u8> = groups.iter().map(|(chapter, _)| *chapter).collect();
+
+    assert!(chapters.is_sorted());
+    assert_eq!(chapters.first(), Some(&2));
+    let total: usize = groups.iter().map(|(_, members)| members.len()).sum();
+    assert_eq!(total, registry::all().len());
+    assert_eq!(registry::chapter_title(14), "Dynamic Programming");
+}
@@ -0,0 +1,90 @@
+use algorithms::analysis::{OpCounter, random_input, reversed_input, sorted_input};
+use algorithms::random::XorShift64;
+use algorithms::selection::minimum_maximum::{minimum_maximum, minimum_maximum_traced};
+use algorithms::selection::randomized_select::{randomized_select, randomized_select_traced};
+use algorithms::selection::select::{select, select_traced};
+
+/// Tests the order-statistic algorithms of CLRS Chapter 9.
+///
+/// Ranks are 1-based; both selection algorithms must agree with sorting,
+/// and the comparison counts must match the bounds from the text.
+
+#[test]
+fn minimum_maximum_uses_three_halves_comparisons() {
+    for n in [1, 2, 7, 10, 101] {
+        let data = random_input(n, &mut XorShift64::new(n as u64));
+        let mut counter = OpCounter::new();
+        let (min, max) = minimum_maximum_traced(&data, &mut counter).unwrap();
+
+        assert_eq!((*min, *max), (1, n as i64));
+        assert!(counter.counts().comparisons <= 3 * (n as u64 / 2));
+    }
+    assert_eq!(minimum_maximum::<i32>(&[]), None);
+}
+
+#[test]
+fn selects_every_rank() {
+    let mut rng = XorShift64::new(9);
+    for n in [1, 4, 5, 13, 50] {
+        let input = random_input(n, &mut rng);
+        for i in 1..=n {
+            let mut a = input.clone();
+            let mut b = input.clone();
+            assert_eq!(*randomized_select(&mut a, i, &mut rng), i as i64);
+            assert_eq!(*select(&mut b, i), i as i64);
+        }
+    }
+}
+
+#[test]
+fn selects_with_duplicates() {
+    let input = [5, 1, 5, 3, 5, 1, 2, 5, 3, 3, 5, 0];
+    let mut expected = input;
+    expected.sort();
+    for i in 1..=input.len() {
+        let mut a = input;
+        let mut b = input;
+        assert_eq!(
+            *randomized_select(&mut a, i, &mut XorShift64::new(3)),
+            expected[i - 1]
+        );
+        assert_eq!(*select(&mut b, i), expected[i - 1]);
+    }
+}
+
+#[test]
+fn select_is_linear_on_adversarial_input() {
+    // Comparisons per element must stay bounded as n grows.
+    for input in [sorted_input(5000), reversed_input(5000)] {
+        let mut data = input;
+        let mut counter = OpCounter::new();
+        assert_eq!(*select_traced(&mut data, 2500, &mut counter), 2500);
+        assert!(counter.counts().comparisons < 30 * 5000);
+    }
+}
+
+#[test]
+fn selection_is_linear_on_equal_keys() {
+    // Every key equals the pivot, so one partition finds any rank.
+    let n = 5000;
+    for i in [1, n / 2, n] {
+        let mut data = vec![7; n];
+        let mut counter = OpCounter::new();
+        assert_eq!(
+            *randomized_select_traced(&mut data, i, &mut XorShift64::new(5), &mut counter),
+            7
+        );
+        assert_eq!(counter.counts().comparisons, n as u64);
+
+        let mut data = vec![7; n];
+        let mut counter = OpCounter::new();
+        assert_eq!(*select_traced(&mut data, i, &mut counter), 7);
+        assert!(counter.counts().comparisons < 30 * n as u64);
+    }
+}
+
+#[test]
+#[should_panic(expected = "rank 0 is outside")]
+fn rejects_rank_zero() {
+    select(&mut [1, 2, 3], 0);
+}
@@ -0,0 +1,162 @@
+use algorithms::analysis::random_connected_graph;
+use algorithms::graph::adjacency_list::AdjacencyList;
+use algorithms::graph::parse::parse_edge_list;
+use algorithms::graph::representation::Graph;
+use algorithms::graph::sssp::{
+    DifferenceConstraint, PathEvent, ShortestPaths, bellman_ford, bellman_ford_traced,
+    dag_shortest_paths, dijkstra, dijkstra_traced, solve_difference_constraints,
+};
+use algorithms::heap::fibonacci_heap::FibonacciHeap;
+use algorithms::heap::min_queue::ArrayQueue;
+use algorithms::heap::priority_queue::MinPriorityQueue;
+use algorithms::random::XorShift64;
+
+/// Tests Bellman-Ford, DAG-SHORTEST-PATHS and Dijkstra's algorithm (CLRS
+/// Chapter 22) and difference constraints (Section 22.4).
+///
+/// The graphs of Figures 22.4-22.6 check the final `v.d` and `v.π`;
+/// random graphs check that the algorithms agree with each other.
+
+#[test]
+fn bellman_ford_on_figure_22_4() {
+    let g = parse_edge_list(
+        "s t 6\ns y 7\nt x 5\nt y 8\nt z -4\nx t -2\ny x -3\ny z 9\nz s 2\nz x 7\n",
+    )
+    .unwrap();
+    let mut passes = 0;
+    let paths = bellman_ford_traced(&g, 0, &mut |event: &PathEvent, _: &ShortestPaths| {
+        passes += usize::from(matches!(event, PathEvent::PassComplete { .. }));
+    })
+    .unwrap();
+
+    assert_eq!(passes, 4);
+    assert_eq!(by_label(&g, &paths.d), ["s0", "t2", "y7", "x4", "z-2"]);
+    let z = g.vertex_named("z").unwrap();
+    assert_eq!(labels(&g, &paths.path(z).unwrap()), "s y x t z");
+}
+
+#[test]
+fn bellman_ford_returns_a_negative_cycle() {
+    let g = parse_edge_list("s a 1\na b 1\nb c -4\nc a 1\nc d 1\ne f -1\nf e -1\n").unwrap();
+    let cycle = bellman_ford(&g, 0).unwrap_err();
+
+    assert_eq!(labels(&g, &cycle.vertices), "a b c");
+    let closed = cycle
+        .vertices
+        .iter()
+        .zip(cycle.vertices.iter().cycle().skip(1));
+    let weight: i64 = closed.map(|(&u, &v)| g.weight(u, v).unwrap()).sum();
+    assert!(weight < 0);
+
+    // A negative cycle that the source cannot reach does no harm
+    let paths = bellman_ford(&g, g.vertex_named("d").unwrap()).unwrap();
+    assert_eq!(paths.d.iter().flatten().count(), 1);
+}
+
+#[test]
+fn dag_shortest_paths_on_figure_22_5() {
+    let g =
+        parse_edge_list("r s 5\nr t 3\ns t 2\ns x 6\nt x 7\nt y 4\nt z 2\nx y -1\nx z 1\ny z -2\n")
+            .unwrap();
+    let paths = dag_shortest_paths(&g, 1).unwrap();
+
+    assert_eq!(paths.d, [None, Some(0), Some(2), Some(6), Some(5), Some(3)]);
+    assert_eq!(paths.pi[5], Some(4));
+    assert!(dag_shortest_paths(&parse_edge_list("a b\nb a\n").unwrap(), 0).is_err());
+}
+
+#[test]
+fn dijkstra_on_figure_22_6() {
+    let g =
+        parse_edge_list("s t 10\ns y 5\nt x 1\nt y 2\nx z 4\ny t 3\ny x 9\ny z 2\nz s 7\nz x 6\n")
+            .unwrap();
+    let mut order = Vec::new();
+    let paths = dijkstra_traced(
+        &g,
+        0,
+        &mut MinPriorityQueue::new(),
+        &mut |event: &PathEvent, _: &ShortestPaths| {
+            if let PathEvent::VertexComplete { u } = event {
+                order.push(*u);
+            }
+        },
+    );
+
+    assert_eq!(labels(&g, &order), "s y z t x");
+    assert_eq!(by_label(&g, &paths.d), ["s0", "t8", "y5", "x9", "z7"]);
+    assert_eq!(dijkstra(&g, 0, &mut FibonacciHeap::new()), paths);
+    assert_eq!(dijkstra(&g, 0, &mut ArrayQueue::new()), paths);
+}
+
+#[test]
+fn dijkstra_agrees_with_bellman_ford() {
+    let mut rng = XorShift64::new(22);
+    for n in [1, 2, 10, 40] {
+        for m in [n, 4 * n, n * n] {
+            let g = random_connected_graph(n, m, 30, &mut rng);
+            let s = n / 2;
+            let expected = bellman_ford(&g, s).unwrap().d;
+            assert_eq!(dijkstra(&g, s, &mut MinPriorityQueue::new()).d, expected);
+            assert_eq!(dijkstra(&g, s, &mut FibonacciHeap::new()).d, expected);
+            assert_eq!(dijkstra(&g, s, &mut ArrayQueue::new()).d, expected);
+        }
+    }
+}
+
+#[test]
+#[should_panic(expected = "nonnegative edge weights")]
+fn dijkstra_rejects_negative_weights() {
+    let g = parse_edge_list("a b 1\nb c -1\n").unwrap();
+    dijkstra(&g, 0, &mut MinPriorityQueue::new());
+}
+
+#[test]
+fn path_weights_that_overflow_count_as_infinity() {
+    let g = parse_edge_list(&format!("s a {}\na b 1\n", i64::MAX)).unwrap();
+    let expected = [Some(0), Some(i64::MAX), None];
+
+    assert_eq!(bellman_ford(&g, 0).unwrap().d, expected);
+    assert_eq!(dag_shortest_paths(&g, 0).unwrap().d, expected);
+    assert_eq!(dijkstra(&g, 0, &mut MinPriorityQueue::new()).d, expected);
+}
+
+#[test]
+fn difference_constraints_of_section_22_4() {
+    // x1 - x2 ≤ 0, x1 - x5 ≤ -1, ... of (22.2), with variables from 0
+    let c = DifferenceConstraint::new;
+    let constraints = [
+        c(0, 1, 0),
+        c(0, 4, -1),
+        c(1, 4, 1),
+        c(2, 0, 5),
+        c(3, 0, 4),
+        c(3, 2, -1),
+        c(4, 2, -3),
+        c(4, 3, -3),
+    ];
+    let x = solve_difference_constraints(5, &constraints).unwrap();
+
+    assert_eq!(x, [-5, -3, 0, -1, -4]);
+    assert!(constraints.iter().all(|c| x[c.j] - x[c.i] <= c.b));
+
+    // x0 - x3 ≤ 4 and x3 - x0 ≤ -5 contradict
+    let infeasible = [c(0, 3, 4), c(3, 0, -5), c(1, 2, 0)];
+    let cycle = solve_difference_constraints(4, &infeasible).unwrap_err();
+    assert_eq!(cycle.vertices, [0, 3]);
+}
+
+/// `label + d` for every reachable vertex, in the order of the edge list
+fn by_label(g: &AdjacencyList, d: &[Option<i64>]) -> Vec<String> {
+    g.vertices()
+        .filter_map(|v| d[v].map(|d| format!("{}{}", g.label(v), d)))
+        .collect()
+}
+
+/// Vertex labels joined by spaces
+fn labels(g: &AdjacencyList, vertices: &[usize]) -> String {
+    vertices
+        .iter()
+        .map(|&v| g.label(v))
+        .collect::<Vec<_>>()
+        .join(" ")
+}
@@ -0,0 +1,70 @@
+use algorithms::sorting::insertion_sort::{insertion_sort_by_traced, insertion_sort_traced};
+use algorithms::trace::{EventLog, SnapshotCollector, TraceEvent};
+
+/// Tests the execution trace emitted by insertion sort.
+///
+/// The tests check the exact event sequence on a small input and
+/// that snapshots reproduce the per-iteration states of CLRS Figure 2.2.
+
+#[test]
+fn records_event_sequence() {
+    let mut data = [2, 1];
+    let mut log = EventLog::new();
+    insertion_sort_traced(&mut data, &mut log);
+
+    assert_eq!(
+        log.events(),
+        [
+            TraceEvent::SelectKey { index: 1, key: 1 },
+            TraceEvent::CompareKey { index: 0 },
+            TraceEvent::Shift { from: 0, to: 1 },
+            TraceEvent::Insert { index: 0 },
+        ]
+    );
+    assert_eq!(data, [1, 2]);
+}
+
+#[test]
+fn snapshots_match_figure_2_2() {
+    // CLRS Figure 2.2: INSERTION-SORT on ⟨5, 2, 4, 6, 1, 3⟩
+    let mut data = [5, 2, 4, 6, 1, 3];
+    let mut collector = SnapshotCollector::new();
+    insertion_sort_traced(&mut data, &mut collector);
+
+    let passes: Vec<Vec<i32>> = collector
+        .into_snapshots()
+        .into_iter()
+        .filter(|s| matches!(s.event, TraceEvent::Insert { .. }))
+        .map(|s| s.array)
+        .collect();
+
+    assert_eq!(
+        passes,
+        [
+            vec![2, 5, 4, 6, 1, 3],
+            vec![2, 4, 5, 6, 1, 3],
+            vec![2, 4, 5, 6, 1, 3],
+            vec![1, 2, 4, 5, 6, 3],
+            vec![1, 2, 3, 4, 5, 6],
+        ]
+    );
+}
+
+#[test]
+fn traces_custom_order() {
+    // Decreasing order (Exercise 2.1-3) through a closure tracer
+    let mut data = [31, 41, 59, 26, 41, 58];
+    let mut shifts = 0;
+    insertion_sort_by_traced(
+        &mut data,
+        |a: &i32, b: &i32| b.cmp(a),
+        &mut |event: &TraceEvent<i32>, _: &[i32]| {
+            if let TraceEvent::Shift { .. } = event {
+                shifts += 1;
+            }
+        },
+    );
+
+    assert_eq!(data, [59, 58, 41, 41, 31, 26]);
+    assert_eq!(shifts, 9);
+}
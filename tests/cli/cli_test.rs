@@ -0,0 +1,129 @@
+use algorithms::registry;
+use clrs::cli::{InputSource, list, parse_run_args, parse_values, run};
+
+/// Tests the registry-driven `list` and `run` subcommands.
+
+#[test]
+fn parses_input_and_options() {
+    let options = parse_run_args(&args("select --input 3, 1 2 --rank 2 --seed 7")).unwrap();
+
+    assert_eq!(options.id, "select");
+    assert_eq!(options.input, InputSource::Args(args("3, 1 2")));
+    assert_eq!(options.rank, Some(2));
+    assert_eq!(options.seed, 7);
+
+    let options = parse_run_args(&args("heapsort --file data.txt")).unwrap();
+    assert_eq!(options.input, InputSource::File("data.txt".to_string()));
+    assert_eq!(
+        parse_run_args(&args("heapsort")).unwrap().input,
+        InputSource::Stdin
+    );
+
+    assert!(parse_run_args(&[]).is_err());
+    assert!(parse_run_args(&args("heapsort --rank")).is_err());
+    assert!(parse_run_args(&args("heapsort --verbose")).is_err());
+}
+
+#[test]
+fn parses_values_separated_by_commas_and_whitespace() {
+    assert_eq!(
+        parse_values::<i64>("5, 2,4\n6 -1").unwrap(),
+        [5, 2, 4, 6, -1]
+    );
+    assert!(parse_values::<i64>("5 x").is_err());
+    assert!(parse_values::<i64>("").unwrap().is_empty());
+}
+
+#[test]
+fn lists_every_algorithm_under_its_chapter() {
+    let text = list();
+
+    assert!(text.starts_with("Chapter 2: Getting Started\n"));
+    assert!(text.contains("Chapter 14: Dynamic Programming\n  lcs"));
+    for algorithm in registry::all() {
+        assert!(text.contains(algorithm.id));
+    }
+}
+
+#[test]
+fn separates_every_id_from_its_name() {
+    let text = list();
+    let lines: Vec<&str> = text.lines().filter(|l| l.starts_with("  ")).collect();
+
+    assert_eq!(lines.len(), registry::all().len());
+    for (line, algorithm) in lines.iter().zip(registry::all()) {
+        let (id, rest) = line.trim_start().split_once(' ').unwrap();
+        assert_eq!(id, algorithm.id);
+        assert!(rest.trim_start().starts_with(algorithm.name), "{}", line);
+    }
+}
+
+#[test]
+fn runs_each_entry_kind() {
+    let output = run_text("insertion_sort --input 5 2 4 6 1 3");
+    assert!(output.contains("Output: [1, 2, 3, 4, 5, 6]"));
+
+    let output = run_text("randomized_select --rank 1 --input 9 4 7");
+    assert!(output.contains("Rank 1: 4"));
+
+    let output = run_text("bucket_sort --input 0.5 0.25");
+    assert!(output.contains("Output: [0.25, 0.5]"));
+
+    let output = run_text("lcs --input ABCBDAB BDCABA");
+    assert!(output.contains("Output: BCBA"));
+
+    let bfs = registry::find("bfs").unwrap();
+    let options = parse_run_args(&args("bfs --source w")).unwrap();
+    let output = run(bfs, &options, "undirected\nv w\nw x\n").unwrap();
+    assert!(output.contains("Input: 3 vertices, 2 edges"));
+    assert!(output.contains("w: d = 0"));
+    assert!(
+        run(
+            bfs,
+            &parse_run_args(&args("bfs --source q")).unwrap(),
+            "a b"
+        )
+        .is_err()
+    );
+}
+
+#[test]
+fn rejects_bad_input() {
+    let select = registry::find("select").unwrap();
+    let options = parse_run_args(&args("select --rank 4")).unwrap();
+    assert!(run(select, &options, "1 2 3").is_err());
+
+    let lcs = registry::find("lcs").unwrap();
+    let options = parse_run_args(&args("lcs")).unwrap();
+    assert!(run(lcs, &options, "ABC").is_err());
+
+    // Inputs outside what a linear-time sort accepts are errors, not panics
+    for line in [
+        "bucket_sort --input 0.5 1.5",
+        "bucket_sort --input 0.5 NaN",
+        "counting_sort --input 5 -3",
+        "counting_sort --input 5 9000000000000000000",
+        "radix_sort --input 5 -3",
+    ] {
+        assert!(try_run(line).is_err(), "{}", line);
+    }
+}
+
+fn args(line: &str) -> Vec<String> {
+    line.split(' ').map(String::from).collect()
+}
+
+/// Run `run <line>` with the input taken from `--input`
+fn run_text(line: &str) -> String {
+    try_run(line).unwrap()
+}
+
+/// Same as [`run_text`], keeping the error
+fn try_run(line: &str) -> Result<String, Box<dyn std::error::Error>> {
+    let options = parse_run_args(&args(line)).unwrap();
+    let InputSource::Args(values) = &options.input else {
+        panic!("expected --input");
+    };
+    let algorithm = registry::find(&options.id).unwrap();
+    run(algorithm, &options, &values.join(" "))
+}
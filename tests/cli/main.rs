@@ -0,0 +1 @@
+mod cli_test;
@@ -0,0 +1,30 @@
+use algorithms::graph::apsp::floyd_warshall_steps;
+use algorithms::graph::parse::parse_edge_list;
+use latex::apsp::{distance_matrix, floyd_warshall_tables};
+
+/// Tests the LaTeX tables of the all-pairs shortest-paths matrices.
+
+#[test]
+fn distance_matrix_marks_infinity_and_changes() {
+    let before = vec![vec![Some(0), None], vec![Some(-2), Some(0)]];
+    let after = vec![vec![Some(0), Some(4)], vec![Some(-2), Some(0)]];
+
+    assert_eq!(
+        distance_matrix(&before, None),
+        "\\left(\\begin{array}{rr} 0 & \\infty \\\\ -2 & 0 \\end{array}\\right)"
+    );
+    assert!(distance_matrix(&after, Some(&before)).contains("0 & \\mathbf{4}"));
+}
+
+#[test]
+fn floyd_warshall_tables_show_every_step() {
+    let g = parse_edge_list("a b 3\nb c -1\nc a 2\n").unwrap();
+    let tex = floyd_warshall_tables(&g, &floyd_warshall_steps(&g));
+
+    for k in 0..=3 {
+        assert!(tex.contains(&format!("$D^{{({k})}} = ")));
+        assert!(tex.contains(&format!("$\\Pi^{{({k})}} = ")));
+    }
+    assert_eq!(tex.matches("\\par\\medskip").count(), 3);
+    assert!(tex.contains("\\textsc{nil} & \\text{a} & \\text{b}"));
+}
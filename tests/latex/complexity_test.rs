@@ -0,0 +1,53 @@
+use algorithms::analysis::{Counts, InputCase};
+use latex::complexity::{Series, complexity_plot, complexity_table, fit_power_law};
+
+/// Tests the power-law fit and the LaTeX complexity table and chart.
+
+#[test]
+fn fits_exact_power_law() {
+    let points: Vec<(f64, f64)> = [10.0, 20.0, 40.0, 80.0]
+        .iter()
+        .map(|&n: &f64| (n, 3.0 * n.powi(2)))
+        .collect();
+    let fit = fit_power_law(&points).unwrap();
+
+    assert!((fit.exponent - 2.0).abs() < 1e-9);
+    assert!((fit.coefficient - 3.0).abs() < 1e-9);
+}
+
+#[test]
+fn needs_two_sizes() {
+    assert!(fit_power_law(&[(10.0, 100.0)]).is_none());
+    assert!(fit_power_law(&[(10.0, 100.0), (10.0, 90.0)]).is_none());
+}
+
+#[test]
+fn renders_table_and_plot() {
+    let series = vec![Series {
+        case: InputCase::Worst,
+        points: vec![
+            (
+                10,
+                Counts {
+                    comparisons: 45,
+                    writes: 54,
+                },
+            ),
+            (
+                20,
+                Counts {
+                    comparisons: 190,
+                    writes: 209,
+                },
+            ),
+        ],
+    }];
+
+    let table = complexity_table(&series);
+    assert!(table.contains("\\begin{tabular}{r|rrr}"));
+    assert!(table.contains("20 & 190 & 9.50 & 209 \\\\"));
+
+    let plot = complexity_plot(&series);
+    assert!(plot.contains("coordinates { (10,45) (20,190) };"));
+    assert!(plot.contains("\\addlegendentry{worst}"));
+}
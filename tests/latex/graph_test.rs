@@ -0,0 +1,97 @@
+use algorithms::graph::bfs::bfs;
+use algorithms::graph::dfs::dfs;
+use algorithms::graph::flow::ford_fulkerson::{Augmentation, edmonds_karp_traced};
+use algorithms::graph::flow::network::FlowNetwork;
+use algorithms::graph::parse::parse_edge_list;
+use algorithms::graph::sssp::{PathEvent, ShortestPaths, dijkstra_traced};
+use algorithms::heap::priority_queue::MinPriorityQueue;
+use latex::graph::{
+    bfs_figure, dfs_figure, max_flow_figure, parenthesis_figure, shortest_paths_figure,
+};
+
+/// Tests the TikZ figures of breadth-first and depth-first search, of
+/// shortest-paths traces and of maximum-flow augmentations.
+
+#[test]
+fn bfs_draws_one_panel_per_dequeue() {
+    let g = parse_edge_list("undirected\ns r\ns w\nw t\n").unwrap();
+    let tex = bfs_figure(&g, &bfs(&g, 0));
+
+    assert_eq!(tex.matches("\\begin{tikzpicture}").count(), 5);
+    assert!(tex.contains("(a) $Q = \\langle$ s $\\rangle$"));
+    assert!(tex.contains("(e) $Q = \\langle$  $\\rangle$"));
+    // In the last panel every vertex is black and every edge a tree edge
+    let last = tex.rsplit("\\begin{tikzpicture}").next().unwrap();
+    assert_eq!(last.matches("\\node[black]").count(), 4);
+    assert_eq!(last.matches("\\draw[tree]").count(), 3);
+}
+
+#[test]
+fn dfs_labels_timestamps_and_edge_classes() {
+    let g = parse_edge_list("u v\nv x\nx u\nu x\n").unwrap();
+    let result = dfs(&g);
+    let tex = dfs_figure(&g, &result);
+
+    assert!(tex.contains("{1/6}"));
+    assert!(tex.contains("node[class] {B}"));
+    assert!(tex.contains("node[class] {F}"));
+    assert_eq!(tex.matches("node[class] {T}").count(), 2);
+
+    let parentheses = parenthesis_figure(&g, &result);
+    assert!(parentheses.contains("{(u (v (x x) v) u)}"));
+}
+
+#[test]
+fn shortest_paths_draw_one_panel_per_finished_vertex() {
+    let g = parse_edge_list("s t 10\ns y 5\ny t 3\nt y 2\n").unwrap();
+    let mut steps = Vec::new();
+    dijkstra_traced(
+        &g,
+        0,
+        &mut MinPriorityQueue::new(),
+        &mut |event: &PathEvent, paths: &ShortestPaths| steps.push((*event, paths.clone())),
+    );
+    let tex = shortest_paths_figure(&g, &steps);
+
+    assert_eq!(tex.matches("\\begin{tikzpicture}").count(), 4);
+    let first = tex.split("\\end{tikzpicture}").next().unwrap();
+    assert_eq!(first.matches("{$\\infty$}").count(), 2);
+    assert!(first.contains("node[class] {$10$}"));
+    // Antiparallel edges are bent apart
+    assert_eq!(tex.matches("to[bend left=15]").count(), 2 * 4);
+
+    let last = tex.rsplit("\\begin{tikzpicture}").next().unwrap();
+    assert_eq!(last.matches("\\node[black]").count(), 2);
+    assert_eq!(last.matches("\\node[gray]").count(), 1);
+    assert!(last.contains("{$8$}"));
+    assert_eq!(
+        last.matches("\\draw[-{Stealth[length=2mm]}, tree]").count(),
+        2
+    );
+}
+
+#[test]
+fn max_flow_draws_one_residual_network_per_augmentation() {
+    let g = parse_edge_list("s a 3\ns b 2\na b 1\na t 2\nb t 3\n").unwrap();
+    let mut net = FlowNetwork::from_graph(&g);
+    let mut residuals = Vec::new();
+    let result = edmonds_karp_traced(
+        &mut net,
+        0,
+        3,
+        &mut |_: &Augmentation, net: &FlowNetwork| residuals.push(net.residual_network()),
+    );
+    let tex = max_flow_figure(&net, &result, &residuals);
+
+    assert_eq!(result.augmentations.len(), 3);
+    assert_eq!(tex.matches("\\begin{tikzpicture}").count(), 4);
+    assert!(tex.contains("(a) $c_f(p) = 2$"));
+    // The first path s → a → t is shaded in G_f
+    let first = tex.split("\\end{tikzpicture}").next().unwrap();
+    assert_eq!(first.matches(", tree]").count(), 2);
+
+    let last = tex.rsplit("\\begin{tikzpicture}").next().unwrap();
+    assert!(last.contains("(d) $|f| = 5$"));
+    assert!(last.contains("node[class] {$3/3$}"));
+    assert!(last.contains("node[class] {$1/1$}"));
+}
@@ -0,0 +1,22 @@
+use algorithms::dp::lcs::lcs_length;
+use latex::lcs::lcs_table_figure;
+
+/// Tests the Figure 14.8-style TikZ rendering of LCS tables.
+
+#[test]
+fn draws_every_entry_with_its_arrow() {
+    let x: Vec<char> = "ABCBDAB".chars().collect();
+    let y: Vec<char> = "BDCABA".chars().collect();
+    let tex = lcs_table_figure(&x, &y, &lcs_length(&x, &y));
+
+    assert!(tex.starts_with("\\begin{tikzpicture}"));
+    assert_eq!(
+        tex.matches("\\node[entry]").count() + tex.matches("\\node[path]").count(),
+        56
+    );
+    assert_eq!(tex.matches("\\node[arrow]").count(), 42);
+    // c[7, 6] = 4 is where PRINT-LCS starts, c[0, 0] is never visited
+    assert!(tex.contains("\\node[path] (c7x6) at (5.40,-6.30) {4};"));
+    assert!(tex.contains("\\node[entry] (c0x0) at (0.00,0.00) {0};"));
+    assert!(tex.contains("$\\nwarrow$"));
+}
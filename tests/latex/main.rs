@@ -0,0 +1,6 @@
+mod apsp_test;
+mod complexity_test;
+mod graph_test;
+mod lcs_test;
+mod tikz_test;
+mod tree_test;
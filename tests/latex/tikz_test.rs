@@ -0,0 +1,41 @@
+use algorithms::sorting::insertion_sort::insertion_sort_traced;
+use algorithms::trace::SnapshotCollector;
+use latex::tikz::array_trace_figure;
+
+/// Tests the Figure 2.2-style TikZ rendering of insertion sort traces.
+
+#[test]
+fn draws_one_panel_per_iteration_plus_result() {
+    let tex = figure_for(&[5, 2, 4, 6, 1, 3]);
+
+    assert!(tex.starts_with("\\begin{tikzpicture}"));
+    assert!(tex.contains("{(e)}"));
+    assert!(tex.contains("{(f)}"));
+    assert!(!tex.contains("{(g)}"));
+    // Panel (a): key A[2] = 2 is dark, A[1] = 5 is the sorted prefix
+    assert!(tex.contains("\\node[key] (p0c1) at (0.80,0.00) {2};"));
+    assert!(tex.contains("\\node[sorted] (p0c0) at (0.00,0.00) {5};"));
+}
+
+#[test]
+fn draws_shift_and_key_arrows() {
+    let tex = figure_for(&[2, 1]);
+
+    assert!(tex.contains("(p0c0.north east) to[bend left=50] (p0c1.north west)"));
+    assert!(tex.contains("(p0c1.south) to[bend left=25] (p0c0.south)"));
+}
+
+#[test]
+fn renders_short_input_without_passes() {
+    let tex = figure_for(&[42]);
+
+    assert!(tex.contains("{(a)}"));
+    assert!(!tex.contains("\\draw"));
+}
+
+fn figure_for(input: &[i32]) -> String {
+    let mut data = input.to_vec();
+    let mut collector = SnapshotCollector::new();
+    insertion_sort_traced(&mut data, &mut collector);
+    array_trace_figure(input, collector.snapshots())
+}
@@ -0,0 +1,64 @@
+use algorithms::trees::bst::BinarySearchTree;
+use algorithms::trees::order_statistic::OrderStatisticTree;
+use algorithms::trees::red_black::RedBlackTree;
+use latex::tree::{augmented_figure, red_black_figure, search_tree_figure};
+
+/// Tests the TikZ rendering of binary search trees, red-black trees and
+/// augmented trees.
+
+#[test]
+fn draws_nodes_in_inorder_columns_and_depth_rows() {
+    let mut tree = BinarySearchTree::new();
+    for k in [12, 5, 18, 2, 9] {
+        tree.insert(k, ());
+    }
+    let tex = search_tree_figure(&tree, 1, "delete 7");
+
+    assert!(tex.starts_with("\\begin{tikzpicture}"));
+    assert_eq!(tex.matches("\\node[node]").count(), 5);
+    assert_eq!(tex.matches("\\draw[edge]").count(), 4);
+    // 2 is leftmost two levels down, 18 rightmost one level down
+    assert!(tex.contains("\\node[node] (n3) at (0.00,-2.00) {2};"));
+    assert!(tex.contains("\\node[node] (n2) at (2.80,-1.00) {18};"));
+    assert!(tex.contains("\\draw[edge] (n0) -- (n2);"));
+    assert!(tex.contains("{(b) delete 7}"));
+}
+
+#[test]
+fn empty_tree_has_only_its_label() {
+    let tree: BinarySearchTree<i32, ()> = BinarySearchTree::new();
+    let tex = search_tree_figure(&tree, 0, "");
+    assert!(!tex.contains("\\node[node]"));
+    assert!(tex.contains("{(a)}"));
+}
+
+#[test]
+fn red_black_nodes_show_their_color_and_focus() {
+    let mut tree = RedBlackTree::new();
+    for k in [11, 2, 14, 1] {
+        tree.insert(k, ());
+    }
+    let one = tree.search(&1).unwrap();
+    let tex = red_black_figure(&tree, Some(one), 2, "case 1");
+
+    assert_eq!(tex.matches("\\node[blacknode]").count(), 3);
+    assert!(tex.contains("\\node[rednode, focus] (n4) at (0.00,-2.00) {1};"));
+    assert!(tex.contains("{(c) case 1}"));
+}
+
+#[test]
+fn augmented_nodes_carry_their_size() {
+    let mut tree = OrderStatisticTree::augmented();
+    for k in [26, 17, 41, 14] {
+        tree.insert(k, ());
+    }
+    let root = tree.root().unwrap();
+    let tex = augmented_figure(&tree, tree.os_select(2), 0, "");
+
+    assert!(tex.contains(&format!(
+        "\\node[blacknode, label={{[augment]below:4}}] (n{}) at (1.40,0.00) {{26}};",
+        root
+    )));
+    assert!(tex.contains("\\node[blacknode, focus, label={[augment]below:2}]"));
+    assert_eq!(tex.matches("[augment]below:1}").count(), 2);
+}